@@ -0,0 +1,313 @@
+//! Conversions of many colors at once.
+//!
+//! The colors are slices of `[f64; 3]`, in the units of the color space: RGB channels in
+//! `0..=255`, hues in degrees `0..360` and the other HSL / HSV / HWB channels in `0..=1`.
+//! Each color goes through the same conversion as [`Color::vec_of`](crate::Color::vec_of), applied
+//! straight to the arrays without building a [`Color`](crate::Color) or a `Vec` per color. The
+//! colors are converted one after another, and with the `rayon` feature in parallel chunks.
+//!
+//! Conversions to RGB round the channels to whole numbers, like 8 bit pixels, conversions from
+//! RGB are not rounded. HSL, HSV and HWB hues out of range wrap around, and a hue that is not
+//! finite is taken as 0, like a powerless hue in CSS.
+//!
+//! Translucent pixels are slices of normalized `[r, g, b, a]` in `0..=1`, like the buffers of
+//! GPU and video pipelines. [`composite`] works on premultiplied buffers directly, converting
+//! between straight and premultiplied alpha is explicit with [`premultiply`] and
+//! [`unpremultiply`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use color_art::bulk;
+//!
+//! let pixels = vec![[255.0, 0.0, 0.0], [0.0, 0.0, 255.0]];
+//! let hsl = bulk::rgb_to_hsl(&pixels);
+//! assert_eq!(hsl, vec![[0.0, 1.0, 0.5], [240.0, 1.0, 0.5]]);
+//! assert_eq!(bulk::hsl_to_rgb(&hsl), pixels);
+//! ```
+
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{
+    color::premultiplied,
+    conversion::{hsl, hsv, hwb, lab, lch, oklab, oklch, xyz},
+    utils::round,
+    CompositeOp,
+};
+use alloc::vec::Vec;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Number of colors converted by a thread at a time.
+#[cfg(feature = "rayon")]
+const CHUNK_SIZE: usize = 4096;
+
+macro_rules! bulk_conversion {
+    ($(#[$doc:meta] $name:ident => $convert:expr;)*) => {
+        $(
+            #[$doc]
+            pub fn $name(colors: &[[f64; 3]]) -> Vec<[f64; 3]> {
+                convert(colors, $convert)
+            }
+        )*
+    };
+}
+
+bulk_conversion! {
+    /// Converts RGB colors to HSL.
+    rgb_to_hsl => hsl::rgb2hsl;
+    /// Converts HSL colors to RGB.
+    hsl_to_rgb => |hsl| to_rgb(hsl::hsl2rgb(&wrap_hue(hsl)));
+    /// Converts RGB colors to HSV.
+    rgb_to_hsv => hsv::rgb2hsv;
+    /// Converts HSV colors to RGB.
+    hsv_to_rgb => |hsv| to_rgb(hsv::hsv2rgb(&wrap_hue(hsv)));
+    /// Converts RGB colors to HWB.
+    rgb_to_hwb => hwb::rgb2hwb;
+    /// Converts HWB colors to RGB.
+    hwb_to_rgb => |hwb| to_rgb(hwb::hwb2rgb(&wrap_hue(hwb)));
+    /// Converts RGB colors to CIE Lab (D50).
+    rgb_to_lab => lab::rgb2lab;
+    /// Converts CIE Lab (D50) colors to RGB.
+    lab_to_rgb => |lab| to_rgb(lab::lab2rgb(lab));
+    /// Converts RGB colors to CIE LCh (D50).
+    rgb_to_lch => lch::rgb2lch;
+    /// Converts CIE LCh (D50) colors to RGB.
+    lch_to_rgb => |lch| to_rgb(lch::lch2rgb(lch));
+    /// Converts RGB colors to OKLab.
+    rgb_to_oklab => oklab::rgb2oklab;
+    /// Converts OKLab colors to RGB.
+    oklab_to_rgb => |oklab| to_rgb(oklab::oklab2rgb(oklab));
+    /// Converts RGB colors to OKLCh.
+    rgb_to_oklch => oklch::rgb2oklch;
+    /// Converts OKLCh colors to RGB.
+    oklch_to_rgb => |oklch| to_rgb(oklch::oklch2rgb(oklch));
+    /// Converts RGB colors to CIE XYZ (D65).
+    rgb_to_xyz => xyz::rgb2xyz;
+    /// Converts CIE XYZ (D65) colors to RGB.
+    xyz_to_rgb => |xyz| to_rgb(xyz::xyz2rgb(xyz));
+}
+
+/// Multiplies the channels of straight alpha colors by their alpha.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::bulk;
+///
+/// let pixels = vec![[1.0, 0.2, 0.0, 0.5], [0.0, 0.0, 1.0, 1.0]];
+/// let premultiplied = bulk::premultiply(&pixels);
+/// assert_eq!(premultiplied, vec![[0.5, 0.1, 0.0, 0.5], [0.0, 0.0, 1.0, 1.0]]);
+/// assert_eq!(bulk::unpremultiply(&premultiplied), pixels);
+/// ```
+pub fn premultiply(colors: &[[f64; 4]]) -> Vec<[f64; 4]> {
+    convert(colors, premultiplied::premultiply)
+}
+
+/// Divides the channels of premultiplied colors by their alpha, fully transparent colors
+/// become transparent black.
+pub fn unpremultiply(colors: &[[f64; 4]]) -> Vec<[f64; 4]> {
+    convert(colors, premultiplied::unpremultiply)
+}
+
+/// Composites premultiplied source colors over the premultiplied backdrop colors at the same
+/// index, see [`composite_premultiplied`](crate::composite_premultiplied).
+///
+/// The result is premultiplied as well.
+///
+/// # Panics
+///
+/// Panics if the slices differ in length.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{bulk, CompositeOp};
+///
+/// let sources = vec![[0.5, 0.0, 0.0, 0.5], [0.0, 0.0, 0.0, 0.0]];
+/// let backdrops = vec![[0.0, 0.0, 1.0, 1.0], [0.0, 0.5, 0.0, 0.5]];
+/// let composited = bulk::composite(&sources, &backdrops, CompositeOp::Over);
+/// assert_eq!(composited, vec![[0.5, 0.0, 0.5, 1.0], [0.0, 0.5, 0.0, 0.5]]);
+/// ```
+pub fn composite(sources: &[[f64; 4]], backdrops: &[[f64; 4]], op: CompositeOp) -> Vec<[f64; 4]> {
+    assert_eq!(
+        sources.len(),
+        backdrops.len(),
+        "the sources and backdrops differ in length"
+    );
+    let composite = |(source, backdrop)| crate::composite_premultiplied(source, backdrop, op);
+    #[cfg(feature = "rayon")]
+    {
+        sources
+            .par_iter()
+            .zip(backdrops.par_iter())
+            .with_min_len(CHUNK_SIZE)
+            .map(composite)
+            .collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        sources.iter().zip(backdrops).map(composite).collect()
+    }
+}
+
+/// RGB channels rounded to whole numbers
+fn to_rgb(rgb: [f64; 3]) -> [f64; 3] {
+    rgb.map(|v| round(v, 0))
+}
+
+/// the hue of HSL, HSV or HWB wrapped to `0..360`, 0 if it is not finite
+fn wrap_hue(color: &[f64; 3]) -> [f64; 3] {
+    let [hue, a, b] = *color;
+    let hue = if hue.is_finite() {
+        hue.rem_euclid(360.0)
+    } else {
+        0.0
+    };
+    [hue, a, b]
+}
+
+/// Applies a conversion to each color, in parallel chunks with the `rayon` feature.
+fn convert<const N: usize, const M: usize>(
+    colors: &[[f64; N]],
+    convert: fn(&[f64; N]) -> [f64; M],
+) -> Vec<[f64; M]> {
+    #[cfg(feature = "rayon")]
+    {
+        colors
+            .par_iter()
+            .with_min_len(CHUNK_SIZE)
+            .map(convert)
+            .collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        colors.iter().map(convert).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bulk, *};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_bulk_conversions() {
+        assert!(bulk::rgb_to_lab(&[]).is_empty());
+
+        let colors = (0..10000)
+            .map(|i| {
+                let color = Color::new(i % 256, (i / 256) % 256, (i * 7) % 256, 1.0);
+                [color.red(), color.green(), color.blue()].map(f64::from)
+            })
+            .collect::<Vec<_>>();
+        let lab = bulk::rgb_to_lab(&colors);
+        let hsv = bulk::rgb_to_hsv(&colors);
+        for (i, rgb) in colors.iter().enumerate() {
+            let color = Color::new(rgb[0], rgb[1], rgb[2], 1.0);
+            assert_eq!(lab[i].to_vec(), color.vec_of(ColorSpace::Lab));
+            assert_eq!(hsv[i].to_vec(), color.vec_of(ColorSpace::HSV));
+        }
+        assert_eq!(bulk::lab_to_rgb(&lab), colors);
+    }
+
+    #[test]
+    fn test_bulk_round_trips() {
+        let colors = vec![
+            [255.0, 0.0, 0.0],
+            [30.0, 144.0, 255.0],
+            [0.0, 0.0, 0.0],
+            [255.0, 255.0, 255.0],
+        ];
+        let round = |colors: Vec<[f64; 3]>| {
+            colors
+                .into_iter()
+                .map(|c| c.map(f64::round))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(round(bulk::hsl_to_rgb(&bulk::rgb_to_hsl(&colors))), colors);
+        assert_eq!(round(bulk::hwb_to_rgb(&bulk::rgb_to_hwb(&colors))), colors);
+        assert_eq!(round(bulk::lch_to_rgb(&bulk::rgb_to_lch(&colors))), colors);
+        assert_eq!(
+            round(bulk::oklab_to_rgb(&bulk::rgb_to_oklab(&colors))),
+            colors
+        );
+        assert_eq!(
+            round(bulk::oklch_to_rgb(&bulk::rgb_to_oklch(&colors))),
+            colors
+        );
+        assert_eq!(round(bulk::xyz_to_rgb(&bulk::rgb_to_xyz(&colors))), colors);
+    }
+
+    #[test]
+    fn test_bulk_hues() {
+        let colors = [[360.0, 1.0, 0.5], [-120.0, 1.0, 0.5], [f64::NAN, 1.0, 0.5]];
+        let red_blue_red = vec![[255.0, 0.0, 0.0], [0.0, 0.0, 255.0], [255.0, 0.0, 0.0]];
+        assert_eq!(bulk::hsl_to_rgb(&colors), red_blue_red);
+        assert_eq!(
+            bulk::hsv_to_rgb(&[[600.0, 1.0, 1.0], [f64::INFINITY, 1.0, 1.0]]),
+            red_blue_red[1..]
+        );
+        assert_eq!(
+            bulk::hwb_to_rgb(&[[480.0, 0.0, 0.0]]),
+            vec![[0.0, 255.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn test_bulk_rgb_rounding() {
+        let colors = [[330.0, 1.0, 0.6], [200.0, 0.3, 0.7]];
+        for rgb in bulk::hsl_to_rgb(&colors)
+            .into_iter()
+            .chain(bulk::hsv_to_rgb(&colors))
+            .chain(bulk::hwb_to_rgb(&[[330.0, 0.2, 0.3]]))
+            .chain(bulk::lab_to_rgb(&[[54.3, 80.8, 69.9]]))
+        {
+            assert_eq!(rgb, rgb.map(f64::round));
+        }
+        assert_eq!(bulk::hsl_to_rgb(&colors)[0], [255.0, 51.0, 153.0]);
+    }
+
+    #[test]
+    fn test_bulk_premultiplied() {
+        let colors = (0..10000)
+            .map(|i| {
+                Color::new(
+                    i % 256,
+                    (i / 256) % 256,
+                    (i * 7) % 256,
+                    (i % 101) as f64 / 100.0,
+                )
+            })
+            .collect::<Vec<_>>();
+        let premultiplied = colors
+            .iter()
+            .map(Color::to_premultiplied)
+            .collect::<Vec<_>>();
+        let straight = colors
+            .iter()
+            .map(|color| {
+                let [r, g, b] = [color.red(), color.green(), color.blue()].map(f64::from);
+                [r / 255.0, g / 255.0, b / 255.0, color.alpha()]
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(bulk::premultiply(&straight), premultiplied);
+
+        let backdrops = bulk::premultiply(&straight.iter().rev().copied().collect::<Vec<_>>());
+        for op in [CompositeOp::Over, CompositeOp::Xor, CompositeOp::Plus] {
+            let composited = bulk::unpremultiply(&bulk::composite(&premultiplied, &backdrops, op));
+            for (i, color) in colors.iter().enumerate() {
+                let [r, g, b, a] = composited[i];
+                let expected = color.composite(&colors[colors.len() - 1 - i], op);
+                assert_eq!(Color::new(r * 255.0, g * 255.0, b * 255.0, a), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bulk_composite_lengths() {
+        bulk::composite(&[[0.0; 4]], &[], CompositeOp::Over);
+    }
+}
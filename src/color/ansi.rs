@@ -0,0 +1,170 @@
+use crate::{delta_e, Color};
+use alloc::{format, string::String};
+use core::fmt::Display;
+
+/// Escape sequence resetting all terminal colors and styles.
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// The 16 standard and bright colors of the xterm palette.
+const ANSI16_PALETTE: [[u8; 3]; 16] = [
+    [0x00, 0x00, 0x00],
+    [0xcd, 0x00, 0x00],
+    [0x00, 0xcd, 0x00],
+    [0xcd, 0xcd, 0x00],
+    [0x00, 0x00, 0xee],
+    [0xcd, 0x00, 0xcd],
+    [0x00, 0xcd, 0xcd],
+    [0xe5, 0xe5, 0xe5],
+    [0x7f, 0x7f, 0x7f],
+    [0xff, 0x00, 0x00],
+    [0x00, 0xff, 0x00],
+    [0xff, 0xff, 0x00],
+    [0x5c, 0x5c, 0xff],
+    [0xff, 0x00, 0xff],
+    [0x00, 0xff, 0xff],
+    [0xff, 0xff, 0xff],
+];
+
+/// Channel levels of the 6x6x6 color cube of the xterm 256 color palette.
+const ANSI256_CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// ANSI escape codes for terminal output.
+impl Color {
+    /// Truecolor ANSI escape sequence setting the foreground (text) color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 51, 153, 1.0);
+    /// assert_eq!(color.to_ansi_fg(), "\x1b[38;2;255;51;153m");
+    /// ```
+    pub fn to_ansi_fg(&self) -> String {
+        let [r, g, b, _] = self.rgba_bytes();
+        format!("\x1b[38;2;{};{};{}m", r, g, b)
+    }
+    /// Truecolor ANSI escape sequence setting the background color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 51, 153, 1.0);
+    /// assert_eq!(color.to_ansi_bg(), "\x1b[48;2;255;51;153m");
+    /// ```
+    pub fn to_ansi_bg(&self) -> String {
+        let [r, g, b, _] = self.rgba_bytes();
+        format!("\x1b[48;2;{};{};{}m", r, g, b)
+    }
+    /// Wraps the text in escape sequences that print it in the color, and reset the color after it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 0, 0, 1.0);
+    /// assert_eq!(color.paint("error"), "\x1b[38;2;255;0;0merror\x1b[0m");
+    /// ```
+    pub fn paint(&self, text: impl Display) -> String {
+        format!("{}{}{}", self.to_ansi_fg(), text, ANSI_RESET)
+    }
+    /// Creates a color from an index of the xterm 256 color palette.
+    ///
+    /// Indices 0 - 15 are the standard and bright colors, 16 - 231 a 6x6x6 color cube,
+    /// and 232 - 255 a grayscale ramp.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// assert_eq!(Color::from_ansi256(9).hex(), "#f00");
+    /// assert_eq!(Color::from_ansi256(208).hex(), "#ff8700");
+    /// assert_eq!(Color::from_ansi256(244).hex(), "#808080");
+    /// ```
+    pub fn from_ansi256(index: u8) -> Self {
+        let [r, g, b] = match index {
+            0..=15 => ANSI16_PALETTE[index as usize],
+            16..=231 => {
+                let i = (index - 16) as usize;
+                [i / 36, i / 6 % 6, i % 6].map(|level| ANSI256_CUBE_LEVELS[level])
+            }
+            232..=255 => [8 + (index - 232) * 10; 3],
+        };
+        Color::new(r, g, b, 1.0)
+    }
+    /// The perceptually nearest color of the xterm 256 color palette, by [`delta_e`].
+    ///
+    /// Only the color cube and the grayscale ramp are searched, as terminals commonly
+    /// customize the first 16 colors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(250, 130, 10, 1.0);
+    /// assert_eq!(color.to_ansi256(), 208);
+    /// ```
+    pub fn to_ansi256(&self) -> u8 {
+        self.nearest_ansi(16..=255)
+    }
+    /// The perceptually nearest of the 16 standard and bright xterm colors, by [`delta_e`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(250, 10, 10, 1.0);
+    /// assert_eq!(color.to_ansi16(), 9);
+    /// ```
+    pub fn to_ansi16(&self) -> u8 {
+        self.nearest_ansi(0..=15)
+    }
+
+    fn nearest_ansi(&self, indices: impl Iterator<Item = u8>) -> u8 {
+        let color = Color::new(self.rgb[0], self.rgb[1], self.rgb[2], 1.0);
+        indices
+            .map(|index| (index, delta_e(&color, &Color::from_ansi256(index))))
+            .min_by(|(_, d1), (_, d2)| d1.total_cmp(d2))
+            .map(|(index, _)| index)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ansi_escape_codes() {
+        let color = Color::new(0.4, 127.5, 300.0, 0.5);
+        assert_eq!(color.to_ansi_fg(), "\x1b[38;2;0;128;255m");
+        assert_eq!(color.to_ansi_bg(), "\x1b[48;2;0;128;255m");
+        assert_eq!(color.paint(42), "\x1b[38;2;0;128;255m42\x1b[0m");
+    }
+
+    #[test]
+    fn test_ansi_palettes() {
+        assert_eq!(Color::from_ansi256(0).hex(), "#000");
+        assert_eq!(Color::from_ansi256(12).hex(), "#5c5cff");
+        assert_eq!(Color::from_ansi256(16).hex(), "#000");
+        assert_eq!(Color::from_ansi256(21).hex(), "#00f");
+        assert_eq!(Color::from_ansi256(231).hex(), "#fff");
+        assert_eq!(Color::from_ansi256(232).hex(), "#080808");
+        assert_eq!(Color::from_ansi256(255).hex(), "#eee");
+
+        for index in 16..=255 {
+            assert_eq!(Color::from_ansi256(index).to_ansi256(), index);
+        }
+        for index in 0..=15 {
+            assert_eq!(Color::from_ansi256(index).to_ansi16(), index);
+        }
+        assert_eq!(Color::new(255, 255, 255, 1.0).to_ansi256(), 231);
+        assert_eq!(Color::new(120, 120, 125, 0.5).to_ansi16(), 8);
+    }
+}
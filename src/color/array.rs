@@ -0,0 +1,66 @@
+use crate::Color;
+
+impl From<[u8; 3]> for Color {
+    /// Converts `[r, g, b]` bytes into an opaque color.
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        Color::new(r, g, b, 1.0)
+    }
+}
+
+impl From<[u8; 4]> for Color {
+    /// Converts `[r, g, b, a]` bytes into a color, with the alpha byte scaled to 0.0 - 1.0.
+    fn from([r, g, b, a]: [u8; 4]) -> Self {
+        Color::new(r, g, b, a as f64 / 255.0)
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    /// Converts an `(r, g, b)` tuple into an opaque color.
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Color::new(r, g, b, 1.0)
+    }
+}
+
+impl From<Color> for [u8; 4] {
+    /// Converts a color into rounded `[r, g, b, a]` bytes.
+    fn from(color: Color) -> Self {
+        color.rgba_bytes()
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    /// Converts a color into normalized `[r, g, b, a]` floats between 0.0 and 1.0,
+    /// e.g. for GPU vertex data.
+    fn from(color: Color) -> Self {
+        let [r, g, b] = color.rgb.map(|c| (c / 255.0) as f32);
+        [r, g, b, color.alpha as f32]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_from_array() {
+        let color = Color::from([255, 51, 153]);
+        assert_eq!(color.hex(), "#f39");
+
+        let color = Color::from([255, 51, 153, 51]);
+        assert_eq!(color, Color::new(255.0, 51.0, 153.0, 0.2));
+
+        let color: Color = (255, 51, 153).into();
+        assert_eq!(color.hex(), "#f39");
+    }
+
+    #[test]
+    fn test_color_into_array() {
+        let color = Color::new(255.0, 51.0, 152.6, 0.2);
+
+        let bytes: [u8; 4] = color.into();
+        assert_eq!(bytes, [255, 51, 153, 51]);
+
+        let floats: [f32; 4] = Color::new(255, 51, 0, 0.5).into();
+        assert_eq!(floats, [1.0, 0.2, 0.0, 0.5]);
+    }
+}
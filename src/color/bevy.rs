@@ -0,0 +1,86 @@
+//! Conversions to and from [`bevy_color`](https://docs.rs/bevy_color), with the `bevy` feature.
+//!
+//! `Srgba` and `bevy_color::Color` hold the gamma encoded sRGB channels, `LinearRgba` the
+//! linear-light ones of [`Color::to_wgpu_color`]. The `f32` channels are rounded like
+//! [`Color32`](crate::Color32).
+
+use crate::{conversion::utils::gam_srgb, Color, Color32};
+use bevy_color::{LinearRgba, Srgba};
+
+impl From<Color> for Srgba {
+    /// Converts a color into sRGB `f32` channels.
+    fn from(color: Color) -> Self {
+        let Color32 { r, g, b, a } = color.into();
+        Srgba::new(r, g, b, a)
+    }
+}
+
+impl From<Srgba> for Color {
+    /// Converts sRGB `f32` channels exactly.
+    fn from(color: Srgba) -> Self {
+        Color32::new(color.red, color.green, color.blue, color.alpha).into()
+    }
+}
+
+impl From<Color> for LinearRgba {
+    /// Converts a color into linear-light `f32` channels.
+    fn from(color: Color) -> Self {
+        let [r, g, b, a] = color.to_wgpu_color().map(|v| v as f32);
+        LinearRgba::new(r, g, b, a)
+    }
+}
+
+impl From<LinearRgba> for Color {
+    /// Converts linear-light `f32` channels, applying the sRGB transfer function.
+    fn from(color: LinearRgba) -> Self {
+        let linear = [color.red, color.green, color.blue].map(|v| v as f64);
+        let [r, g, b] = gam_srgb(&linear).map(|v| v * 255.0);
+        Color::new(r, g, b, color.alpha as f64)
+    }
+}
+
+impl From<Color> for bevy_color::Color {
+    /// Converts a color into a `bevy_color::Color::Srgba`.
+    fn from(color: Color) -> Self {
+        bevy_color::Color::Srgba(color.into())
+    }
+}
+
+impl From<bevy_color::Color> for Color {
+    /// Converts any bevy color through its sRGB channels.
+    fn from(color: bevy_color::Color) -> Self {
+        Srgba::from(color).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use bevy_color::{LinearRgba, Srgba};
+
+    #[test]
+    fn test_bevy_srgba() {
+        let color = color!(#ff3399);
+        assert_eq!(Srgba::from(color), Srgba::new(1.0, 0.2, 0.6, 1.0));
+        assert_eq!(Color::from(Srgba::from(color)).hex(), "#f39");
+        assert_eq!(
+            Color::from(bevy_color::Color::srgba(1.0, 0.2, 0.6, 0.5)).hex(),
+            "#ff339980"
+        );
+    }
+
+    #[test]
+    fn test_bevy_linear_rgba() {
+        let color = Color::new(51, 102, 204, 0.5);
+        let linear = LinearRgba::from(color);
+        let bevy_linear = LinearRgba::from(Srgba::from(color));
+        assert!((linear.red - bevy_linear.red).abs() < 1e-6);
+        assert!((linear.blue - bevy_linear.blue).abs() < 1e-6);
+        assert_eq!(linear.alpha, 0.5);
+        assert_eq!(Color::from(linear).hex(), color.hex());
+        assert_eq!(
+            Color::from(bevy_color::Color::from(linear)).hex(),
+            color.hex()
+        );
+    }
+}
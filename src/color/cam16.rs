@@ -0,0 +1,68 @@
+use crate::{
+    conversion::cam16::{cam16_to_ucs, rgb2cam16},
+    Cam16, Color, ViewingConditions,
+};
+use alloc::vec::Vec;
+
+impl Color {
+    /// Get the [CAM16](https://doi.org/10.1002/col.22131) appearance attributes of the color
+    /// under the default [`ViewingConditions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let color = color!(#fff);
+    /// let cam = color.cam16();
+    /// assert!((cam.j - 100.0).abs() < 1e-6);
+    /// ```
+    pub fn cam16(&self) -> Cam16 {
+        self.cam16_with(&ViewingConditions::default())
+    }
+    /// Get the CAM16 appearance attributes of the color under the given viewing conditions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, Surround, ViewingConditions};
+    ///
+    /// let vc = ViewingConditions {
+    ///     surround: Surround::Dark,
+    ///     ..Default::default()
+    /// };
+    /// let color = color!(#ff3399);
+    /// assert!(color.cam16_with(&vc).j > color.cam16().j);
+    /// ```
+    pub fn cam16_with(&self, viewing_conditions: &ViewingConditions) -> Cam16 {
+        rgb2cam16(&self.rgb, viewing_conditions)
+    }
+    /// Get the CAM16-UCS `[J', a', b']` coordinates of the color under the default viewing conditions.
+    ///
+    /// Euclidean distances in CAM16-UCS are a perceptually uniform color difference, see [`delta_e_cam16`](crate::delta_e_cam16).
+    pub fn cam16_ucs(&self) -> Vec<f64> {
+        cam16_to_ucs(&self.cam16()).to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{utils::round, *};
+
+    #[test]
+    fn test_cam16() {
+        let cam = color!(#fff).cam16();
+        assert_eq!(round(cam.j, 4), 100.0);
+        assert_eq!(round(cam.c, 2), 3.09);
+
+        let cam = color!(#f00).cam16();
+        assert_eq!(round(cam.h, 2), 27.39);
+    }
+
+    #[test]
+    fn test_cam16_ucs() {
+        let ucs = color!(#fff).cam16_ucs();
+        assert_eq!(round(ucs[0], 4), 100.0);
+        assert_eq!(ucs.len(), 3);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{Color, ColorSpace};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Coarse hue families of [`Color::hue_family`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HueFamily {
+    /// HSL hue from 345° to 15°
+    Red,
+    /// HSL hue from 15° to 45°, browns included
+    Orange,
+    /// HSL hue from 45° to 70°
+    Yellow,
+    /// HSL hue from 70° to 165°
+    Green,
+    /// HSL hue from 165° to 195°
+    Cyan,
+    /// HSL hue from 195° to 255°
+    Blue,
+    /// HSL hue from 255° to 290°
+    Purple,
+    /// HSL hue from 290° to 345°, magentas included
+    Pink,
+    /// Grays, black and white, with a CIELCh chroma below 10
+    Neutral,
+}
+
+impl HueFamily {
+    /// Whether the family is warm: red, orange, yellow and pink.
+    pub fn is_warm(&self) -> bool {
+        matches!(
+            self,
+            HueFamily::Red | HueFamily::Orange | HueFamily::Yellow | HueFamily::Pink
+        )
+    }
+    /// Whether the family is cool: green, cyan, blue and purple.
+    pub fn is_cool(&self) -> bool {
+        matches!(
+            self,
+            HueFamily::Green | HueFamily::Cyan | HueFamily::Blue | HueFamily::Purple
+        )
+    }
+}
+
+/// Classification of colors, e.g. for choosing text colors or filtering assets.
+impl Color {
+    /// Whether the color is dark, its perceived [`brightness`](Color::brightness) is below 0.5.
+    ///
+    /// The alpha of the color is ignored. See [`Color::is_dark_with`] for another threshold.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::color;
+    ///
+    /// assert!(color!(#1e3a8a).is_dark());
+    /// assert!(color!(#fde68a).is_light());
+    /// // pure blue is dark, though its HSL lightness is 50%
+    /// assert!(color!(#00f).is_dark());
+    /// ```
+    pub fn is_dark(&self) -> bool {
+        self.is_dark_with(0.5)
+    }
+    /// Whether the color is light, the opposite of [`Color::is_dark`].
+    pub fn is_light(&self) -> bool {
+        !self.is_dark()
+    }
+    /// Whether the perceived [`brightness`](Color::brightness) of the color is below a threshold
+    /// between 0.0 and 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::color;
+    ///
+    /// let color = color!(#808080);
+    /// assert!(!color.is_dark());
+    /// assert!(color.is_dark_with(0.6));
+    /// ```
+    pub fn is_dark_with(&self, threshold: f64) -> bool {
+        self.brightness() < threshold
+    }
+    /// The coarse [`HueFamily`] of the color, by its HSL hue, or [`HueFamily::Neutral`] if it
+    /// has almost no chroma.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, HueFamily};
+    ///
+    /// assert_eq!(color!(#8b4513).hue_family(), HueFamily::Orange);
+    /// assert_eq!(color!(#40e0d0).hue_family(), HueFamily::Cyan);
+    /// assert_eq!(color!(#ff69b4).hue_family(), HueFamily::Pink);
+    /// assert_eq!(color!(#8a8580).hue_family(), HueFamily::Neutral);
+    /// ```
+    pub fn hue_family(&self) -> HueFamily {
+        if self.vec_of(ColorSpace::LCH)[1] < 10.0 {
+            return HueFamily::Neutral;
+        }
+        match self.hue() {
+            h if h < 15.0 => HueFamily::Red,
+            h if h < 45.0 => HueFamily::Orange,
+            h if h < 70.0 => HueFamily::Yellow,
+            h if h < 165.0 => HueFamily::Green,
+            h if h < 195.0 => HueFamily::Cyan,
+            h if h < 255.0 => HueFamily::Blue,
+            h if h < 290.0 => HueFamily::Purple,
+            h if h < 345.0 => HueFamily::Pink,
+            _ => HueFamily::Red,
+        }
+    }
+    /// Whether the color is warm, a red, orange, yellow or pink [`HueFamily`].
+    ///
+    /// Neutral colors are neither warm nor cool.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::color;
+    ///
+    /// assert!(color!(#ff7f50).is_warm());
+    /// assert!(color!(#4682b4).is_cool());
+    /// assert!(!color!(#808080).is_warm() && !color!(#808080).is_cool());
+    /// ```
+    pub fn is_warm(&self) -> bool {
+        self.hue_family().is_warm()
+    }
+    /// Whether the color is cool, a green, cyan, blue or purple [`HueFamily`].
+    pub fn is_cool(&self) -> bool {
+        self.hue_family().is_cool()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_is_dark() {
+        for hex in ["#000", "#00f", "#800000", "#333", "#1e3a8a", "#7f7f7f"] {
+            let color = Color::from_hex(hex).unwrap();
+            assert!(color.is_dark(), "{} is dark", hex);
+            assert!(!color.is_light());
+        }
+        for hex in ["#fff", "#ff0", "#0f0", "#f80", "#808080", "#fde68a"] {
+            let color = Color::from_hex(hex).unwrap();
+            assert!(color.is_light(), "{} is light", hex);
+        }
+        assert!(!color!(#000).is_dark_with(0.0));
+        assert!(color!(#fff).is_dark_with(1.1));
+    }
+
+    #[test]
+    fn test_hue_family() {
+        for (hex, family) in [
+            ("#f00", HueFamily::Red),
+            ("#dc143c", HueFamily::Red),
+            ("#ffa500", HueFamily::Orange),
+            ("#a0522d", HueFamily::Orange),
+            ("#ffd700", HueFamily::Yellow),
+            ("#228b22", HueFamily::Green),
+            ("#0ff", HueFamily::Cyan),
+            ("#000080", HueFamily::Blue),
+            ("#663399", HueFamily::Purple),
+            ("#f0f", HueFamily::Pink),
+            ("#000", HueFamily::Neutral),
+            ("#fff", HueFamily::Neutral),
+            ("#777", HueFamily::Neutral),
+        ] {
+            assert_eq!(
+                Color::from_hex(hex).unwrap().hue_family(),
+                family,
+                "{}",
+                hex
+            );
+        }
+        assert!(color!(#dc143c).is_warm());
+        assert!(color!(#663399).is_cool());
+        assert!(!color!(#fff).is_cool());
+    }
+}
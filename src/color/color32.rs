@@ -0,0 +1,120 @@
+use crate::Color;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A color with `f32` channels, for GPU and game engine code working in `f32` everywhere.
+///
+/// The sRGB channels and alpha are normalized to 0.0 - 1.0, like the `[f32; 4]` conversion of
+/// [`Color`]. Converting into a [`Color`] is exact, so a `Color32` round trips through a
+/// [`Color`] unchanged. Converting from a [`Color`] rounds each channel to the nearest `f32`.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{Color, Color32};
+///
+/// let color = Color32::from(Color::new(255, 51, 0, 0.5));
+/// assert_eq!(color, Color32::new(1.0, 0.2, 0.0, 0.5));
+///
+/// let color = Color32::new(0.3, 0.6, 0.9, 1.0);
+/// assert_eq!(Color32::from(Color::from(color)), color);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Color32 {
+    /// Red (0.0 - 1.0)
+    pub r: f32,
+    /// Green (0.0 - 1.0)
+    pub g: f32,
+    /// Blue (0.0 - 1.0)
+    pub b: f32,
+    /// Alpha (0.0 - 1.0)
+    pub a: f32,
+}
+
+impl Color32 {
+    /// Fully transparent black.
+    pub const TRANSPARENT: Color32 = Color32::new(0.0, 0.0, 0.0, 0.0);
+    /// Opaque black.
+    pub const BLACK: Color32 = Color32::new(0.0, 0.0, 0.0, 1.0);
+    /// Opaque white.
+    pub const WHITE: Color32 = Color32::new(1.0, 1.0, 1.0, 1.0);
+
+    /// Creates a new [`Color32`] from normalized channels.
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Color32 { r, g, b, a }
+    }
+    /// The `[r, g, b, a]` channels.
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
+
+impl Default for Color32 {
+    /// default returns a black color, like [`Color::default`].
+    fn default() -> Self {
+        Color32::BLACK
+    }
+}
+
+impl From<Color> for Color32 {
+    /// Converts a color, rounding each channel to the nearest `f32`.
+    fn from(color: Color) -> Self {
+        let [r, g, b, a]: [f32; 4] = color.into();
+        Color32 { r, g, b, a }
+    }
+}
+
+impl From<Color32> for Color {
+    /// Converts a [`Color32`] exactly, `f64` holds every `f32` channel times 255.
+    fn from(color: Color32) -> Self {
+        let Color32 { r, g, b, a } = color;
+        Color::new(
+            r as f64 * 255.0,
+            g as f64 * 255.0,
+            b as f64 * 255.0,
+            a as f64,
+        )
+    }
+}
+
+impl From<[f32; 4]> for Color32 {
+    /// Converts normalized `[r, g, b, a]` floats.
+    fn from([r, g, b, a]: [f32; 4]) -> Self {
+        Color32 { r, g, b, a }
+    }
+}
+
+impl From<Color32> for [f32; 4] {
+    /// Converts into normalized `[r, g, b, a]` floats.
+    fn from(color: Color32) -> Self {
+        color.to_array()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_color32_from_color() {
+        let color = Color32::from(color!(#ff3399));
+        assert_eq!(color, Color32::new(1.0, 0.2, 0.6, 1.0));
+        assert_eq!(Color32::from(Color::TRANSPARENT), Color32::TRANSPARENT);
+        assert_eq!(Color::from(Color32::WHITE), Color::WHITE);
+        assert_eq!(Color32::default(), Color32::from(Color::default()));
+    }
+
+    #[test]
+    fn test_color32_round_trip() {
+        for i in 0..=1000 {
+            let v = i as f32 / 1000.0;
+            let color = Color32::new(v, 1.0 - v, v * v, 1.0 - v * 0.5);
+            assert_eq!(Color32::from(Color::from(color)), color);
+        }
+        let array = [0.1, 0.2, 0.3, 0.4];
+        assert_eq!(<[f32; 4]>::from(Color32::from(array)), array);
+    }
+}
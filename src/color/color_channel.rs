@@ -1,3 +1,5 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
 use crate::utils::*;
 use crate::{Color, ColorSpace};
 
@@ -18,6 +20,7 @@ use crate::{Color, ColorSpace};
 /// assert_eq!(color.hue(), 90.0);
 /// assert_eq!(color.saturation(), 1.0);
 /// assert_eq!(color.lightness(), 0.5);
+/// assert_eq!(color.value(), 1.0);
 /// ```
 impl Color {
     /// Extracts the red channel of color as a number between 0 and 255.
@@ -76,6 +79,24 @@ impl Color {
         let b = luminance_x(color[2]);
         0.2126 * r + 0.7152 * g + 0.0722 * b
     }
+    /// Calculates the perceived brightness of color with the [HSP](https://alienryderflex.com/hsp.html)
+    /// model, between 0.0 for black and 1.0 for white.
+    ///
+    /// Unlike [`Color::luminance`] it follows the sRGB values, so mid gray is about 0.5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// assert_eq!((color!(#808080).brightness() * 100.0).round(), 50.0);
+    /// assert!(color!(#ff0).brightness() > color!(#00f).brightness());
+    /// ```
+    pub fn brightness(&self) -> f64 {
+        let [r, g, b] = normalize_color(&self.rgb);
+        // summed from the smallest weight, so white is exactly 1.0
+        (0.114 * b * b + 0.587 * g * g + 0.299 * r * r).sqrt()
+    }
     /// Extracts the hue channel of color in the HSV color space.
     pub fn hsv_hue(&self) -> f64 {
         self.vec_of(ColorSpace::HSV)[0]
@@ -88,6 +109,12 @@ impl Color {
     pub fn hsv_value(&self) -> f64 {
         self.vec_of(ColorSpace::HSV)[2]
     }
+    /// Extracts the HSV value (brightness) of color as a number between 0.0 and 1.0.
+    ///
+    /// same as `hsv_value()`
+    pub fn value(&self) -> f64 {
+        self.hsv_value()
+    }
     /// Calculates the [gray](http://en.wikipedia.org/wiki/Grayscale) value of color.
     pub fn gray(&self) -> f64 {
         let [r, g, b] = self.rgb;
@@ -95,6 +122,60 @@ impl Color {
     }
 }
 
+/// Color channel setter methods, returning a modified copy of the color.
+///
+/// Out of range values are clamped, and hues wrap around.
+///
+/// # Examples
+/// ```
+/// use color_art::{Color, color};
+///
+/// let color = color!(rgba(10, 20, 30, 0.8));
+/// assert_eq!(color.with_red(255.0).rgba(), "rgba(255, 20, 30, 0.8)");
+///
+/// let color = color!(#ff0000);
+/// assert_eq!(color.with_hue(200.0).hsl(), "hsl(200, 100%, 50%)");
+/// assert_eq!(color.with_lightness(0.25).hex(), "#800000");
+/// ```
+impl Color {
+    /// Returns the color with the red channel set to a number between 0 and 255.
+    pub fn with_red(&self, red: f64) -> Self {
+        self.with_rgb_channel(0, red)
+    }
+    /// Returns the color with the green channel set to a number between 0 and 255.
+    pub fn with_green(&self, green: f64) -> Self {
+        self.with_rgb_channel(1, green)
+    }
+    /// Returns the color with the blue channel set to a number between 0 and 255.
+    pub fn with_blue(&self, blue: f64) -> Self {
+        self.with_rgb_channel(2, blue)
+    }
+    /// Returns the color with the HSL hue set to an angle in degrees.
+    pub fn with_hue(&self, hue: f64) -> Self {
+        self.with_hsl_channel(0, hue.rem_euclid(360.0))
+    }
+    /// Returns the color with the HSL saturation set to a number between 0.0 and 1.0.
+    pub fn with_saturation(&self, saturation: f64) -> Self {
+        self.with_hsl_channel(1, saturation.clamp(0.0, 1.0))
+    }
+    /// Returns the color with the HSL lightness set to a number between 0.0 and 1.0.
+    pub fn with_lightness(&self, lightness: f64) -> Self {
+        self.with_hsl_channel(2, lightness.clamp(0.0, 1.0))
+    }
+
+    fn with_rgb_channel(&self, index: usize, value: f64) -> Self {
+        let mut color = *self;
+        color.rgb[index] = value.clamp(0.0, 255.0);
+        color
+    }
+    fn with_hsl_channel(&self, index: usize, value: f64) -> Self {
+        let mut hsl = self.vec_of(ColorSpace::HSL);
+        hsl[index] = value;
+        let color = Color::from_hsl(hsl[0], hsl[1], hsl[2]).unwrap();
+        color.with_alpha(self.alpha)
+    }
+}
+
 fn luminance_x(x: f64) -> f64 {
     if x <= 0.03928 {
         x / 12.92
@@ -105,7 +186,7 @@ fn luminance_x(x: f64) -> f64 {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use crate::{utils::round, *};
     use std::{assert_eq, str::FromStr};
 
     #[test]
@@ -142,6 +223,7 @@ mod tests {
         assert_eq!(color.hsv_hue(), 90.0);
         assert_eq!(color.hsv_saturation(), 1.0);
         assert_eq!(color.hsv_value(), 0.5);
+        assert_eq!(color.value(), 0.5);
         assert_eq!(color.gray(), 93.90375);
 
         let color = color!(rgb(100, 200, 30));
@@ -149,5 +231,23 @@ mod tests {
         assert_eq!(color.luma(), 0.44111615679100963);
         assert_eq!(color.luminance(), 0.44111615679100963);
         assert_eq!(color.gray(), 150.71999999999997);
+        assert_eq!(round(color.brightness(), 4), 0.6393);
+        assert_eq!(color!(#fff).brightness(), 1.0);
+        assert_eq!(color!(#000).brightness(), 0.0);
+    }
+
+    #[test]
+    fn test_color_with_channel() {
+        let color = color!(rgba(10, 20, 30, 0.8));
+
+        assert_eq!(color.with_red(100.0), Color::new(100, 20, 30, 0.8));
+        assert_eq!(color.with_green(300.0), Color::new(10, 255, 30, 0.8));
+        assert_eq!(color.with_blue(-5.0), Color::new(10, 20, 0, 0.8));
+
+        let color = Color::from_str("hsla(90, 100%, 50%, 0.5)").unwrap();
+
+        assert_eq!(color.with_hue(-90.0).hsla(), "hsla(270, 100%, 50%, 0.5)");
+        assert_eq!(color.with_saturation(0.5).hsla(), "hsla(90, 50%, 50%, 0.5)");
+        assert_eq!(color.with_lightness(2.0).hsla(), "hsla(0, 0%, 100%, 0.5)");
     }
 }
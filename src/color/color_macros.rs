@@ -1,3 +1,9 @@
+use crate::{
+    conversion::{hex::hex_digits2rgba, hsl::hsl2rgb},
+    data::hex_of_css_name_const,
+    Color,
+};
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! color_args {
@@ -15,20 +21,66 @@ macro_rules! color_args {
     };
 }
 
+/// Creates a [`Color`](crate::Color).
+///
+/// Hex colors, CSS color names and `rgb()`, `rgba()`, `hsl()` and `hsla()` with literal
+/// values are checked at compile time and evaluate to constants:
+///
+/// ```rust
+/// use color_art::{color, Color};
+///
+/// const ACCENT: Color = color!(hsl(330, 100%, 60%));
+/// assert_eq!(ACCENT.hex(), "#f39");
+/// assert_eq!(color!(rgb(255, 51, 153)), color!(#ff3399));
+/// assert_eq!(color!(hsla(0, 0%, 0%, 0.5)), color!(rgba(0, 0, 0, 0.5)));
+/// assert_eq!(color!(rebeccapurple), color!(#663399));
+/// ```
+///
+/// Invalid literals don't compile:
+///
+/// ```compile_fail
+/// let color = color_art::color!(rgb(256, 0, 0));
+/// ```
+///
+/// ```compile_fail
+/// let color = color_art::color!(notacolor);
+/// ```
+///
+/// `rgb()` and `rgba()` with other expressions, and the `color!(hsl, 60.0, 1.0, 0.5)` form
+/// with fractions, are checked at runtime and panic if invalid. Hex colors like `#0ea5e9`,
+/// which Rust reads as a number with a bad exponent, need [`Color::from_hex`](crate::Color::from_hex).
 #[macro_export]
 macro_rules! color {
     (#$hex:expr) => {
-        {
-            let hex = format!("#{}", stringify!($hex));
-            $crate::Color::from_hex(&hex).unwrap()
-        }
+        const { $crate::Color::__from_hex_literal(stringify!($hex)) }
+    };
+    (rgb($r:literal, $g:literal, $b:literal)) => {
+        const { $crate::Color::__from_rgba_literal($r as f64, $g as f64, $b as f64, 1.0) }
     };
     (rgb($r:expr, $g:expr, $b:expr)) => {
         $crate::Color::from_rgb($r, $g, $b).unwrap()
     };
+    (rgba($r:literal, $g:literal, $b:literal, $a:literal)) => {
+        const { $crate::Color::__from_rgba_literal($r as f64, $g as f64, $b as f64, $a as f64) }
+    };
     (rgba($r:expr, $g:expr, $b:expr, $a:expr)) => {
         $crate::Color::from_rgba($r, $g, $b, $a).unwrap()
     };
+    (hsl($h:literal, $s:literal %, $l:literal %)) => {
+        const {
+            $crate::Color::__from_hsla_literal($h as f64, $s as f64 / 100.0, $l as f64 / 100.0, 1.0)
+        }
+    };
+    (hsla($h:literal, $s:literal %, $l:literal %, $a:literal)) => {
+        const {
+            $crate::Color::__from_hsla_literal(
+                $h as f64,
+                $s as f64 / 100.0,
+                $l as f64 / 100.0,
+                $a as f64,
+            )
+        }
+    };
     (
         $color_space:ident,
         $($args:tt)*
@@ -58,10 +110,69 @@ macro_rules! color {
             }
         }
     };
+    ($name:ident) => {
+        const { $crate::Color::__from_name_literal(stringify!($name)) }
+    };
+}
+
+/// The `const` constructors behind the literal forms of [`color!`], panicking at compile time.
+impl Color {
+    #[doc(hidden)]
+    pub const fn __from_rgba_literal(r: f64, g: f64, b: f64, alpha: f64) -> Color {
+        if !(within(r, 255.0) && within(g, 255.0) && within(b, 255.0)) {
+            panic!("color!: RGB values must be between 0 and 255");
+        }
+        if !within(alpha, 1.0) {
+            panic!("color!: alpha must be between 0 and 1");
+        }
+        Color {
+            rgb: [r, g, b],
+            alpha,
+        }
+    }
+    #[doc(hidden)]
+    pub const fn __from_hsla_literal(h: f64, s: f64, l: f64, alpha: f64) -> Color {
+        if !within(h, 360.0) {
+            panic!("color!: hue must be between 0 and 360");
+        }
+        if !(within(s, 1.0) && within(l, 1.0)) {
+            panic!("color!: saturation and lightness must be between 0% and 100%");
+        }
+        let [r, g, b] = hsl2rgb(&[h, s, l]);
+        Color::__from_rgba_literal(r, g, b, alpha)
+    }
+    /// Takes the digits of `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, without the `#`.
+    #[doc(hidden)]
+    pub const fn __from_hex_literal(digits: &str) -> Color {
+        from_hex_digits(digits.as_bytes())
+    }
+    #[doc(hidden)]
+    pub const fn __from_name_literal(name: &str) -> Color {
+        match hex_of_css_name_const(name) {
+            Some(hex) => match hex.as_bytes() {
+                [b'#', digits @ ..] => from_hex_digits(digits),
+                _ => unreachable!(),
+            },
+            None => panic!("color!: unknown CSS color name, the `css-names` feature is required"),
+        }
+    }
+}
+
+const fn within(value: f64, max: f64) -> bool {
+    value >= 0.0 && value <= max
+}
+
+const fn from_hex_digits(digits: &[u8]) -> Color {
+    match hex_digits2rgba(digits) {
+        Some([r, g, b, a]) => Color::from_rgba8(r, g, b, a as f64 / 255.0),
+        None => panic!("color!: hex colors must have 3, 4, 6 or 8 hex digits"),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::*;
+
     #[test]
     fn test_color_args_macro() {
         let args = color_args!(255, 128, 0);
@@ -94,4 +205,48 @@ mod tests {
         let color = color!(#abcdef);
         assert_eq!(color.hex(), "#abcdef");
     }
+
+    #[test]
+    fn test_color_macro_literals() {
+        assert_eq!(color!(#F39), Color::from_hex("#f39").unwrap());
+        assert_eq!(color!(#ff339980), Color::from_hex("#ff339980").unwrap());
+        assert_eq!(color!(#f398), Color::from_hex("#f398").unwrap());
+
+        assert_eq!(
+            color!(rgb(255.0, 51.0, 153.0)),
+            Color::new(255, 51, 153, 1.0)
+        );
+        assert_eq!(
+            color!(rgba(255, 51, 153, 0.5)),
+            Color::new(255, 51, 153, 0.5)
+        );
+        let red = 255;
+        assert_eq!(color!(rgb(red, 0, 0)), Color::RED);
+
+        assert_eq!(
+            color!(hsl(120, 50%, 50%)),
+            Color::from_hsl(120.0, 0.5, 0.5).unwrap()
+        );
+        assert_eq!(
+            color!(hsl(360, 25.5%, 75%)),
+            Color::from_hsl(0.0, 0.255, 0.75).unwrap()
+        );
+        assert_eq!(color!(hsl(0, 0%, 100%)), Color::WHITE);
+        assert_eq!(
+            color!(hsla(330, 100%, 60%, 0.5)),
+            Color::from_hsl(330.0, 1.0, 0.6).unwrap().with_alpha(0.5)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "css-names")]
+    fn test_color_macro_names() {
+        assert_eq!(color!(red), Color::RED);
+        assert_eq!(
+            color!(RebeccaPurple),
+            Color::from_name("rebeccapurple").unwrap()
+        );
+        const SKY: Color = color!(skyblue);
+        assert_eq!(SKY.hex(), "#87ceeb");
+    }
 }
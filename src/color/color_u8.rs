@@ -0,0 +1,203 @@
+use crate::{conversion::hex::hex_digits2rgba, Color, ColorSpace, Error};
+use alloc::{format, string::String};
+use core::fmt::{self, Display};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A 4 byte color with 8-bit channels, for code pushing pixels.
+///
+/// Hex strings are parsed and formatted bit-exactly, without going through `f64`. Converting
+/// into a [`Color`] is exact, converting from a [`Color`] rounds the channels like
+/// [`Color::hex`] does.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{Color, ColorU8};
+///
+/// let color = ColorU8::from_hex("#ff339980").unwrap();
+/// assert_eq!(color, ColorU8::new(255, 51, 153, 128));
+/// assert_eq!(color.hex(), "#ff339980");
+///
+/// let color = ColorU8::from(Color::new(254.6, 51.0, 153.0, 1.0));
+/// assert_eq!(color.hex(), "#f39");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct ColorU8 {
+    /// Red
+    pub r: u8,
+    /// Green
+    pub g: u8,
+    /// Blue
+    pub b: u8,
+    /// Alpha
+    pub a: u8,
+}
+
+impl ColorU8 {
+    /// Fully transparent black.
+    pub const TRANSPARENT: ColorU8 = ColorU8::new(0, 0, 0, 0);
+    /// Opaque black.
+    pub const BLACK: ColorU8 = ColorU8::rgb(0, 0, 0);
+    /// Opaque white.
+    pub const WHITE: ColorU8 = ColorU8::rgb(255, 255, 255);
+
+    /// Creates a new [`ColorU8`].
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        ColorU8 { r, g, b, a }
+    }
+    /// Creates an opaque [`ColorU8`].
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        ColorU8::new(r, g, b, 255)
+    }
+    /// Creates a color from a packed `0xRRGGBBAA` number.
+    pub const fn from_rgba_u32(num: u32) -> Self {
+        let [r, g, b, a] = num.to_be_bytes();
+        ColorU8::new(r, g, b, a)
+    }
+    /// Packs the color into a `0xRRGGBBAA` number.
+    pub const fn to_rgba_u32(self) -> u32 {
+        u32::from_be_bytes(self.to_array())
+    }
+    /// The `[r, g, b, a]` bytes.
+    pub const fn to_array(self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+    /// Parses a `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` hex string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::ColorU8;
+    ///
+    /// assert_eq!(ColorU8::from_hex("#f39").unwrap(), ColorU8::rgb(255, 51, 153));
+    /// assert!(ColorU8::from_hex("#ff33").is_ok());
+    /// assert!(ColorU8::from_hex("ff3399").is_err());
+    /// ```
+    pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
+        let digits = hex_str.strip_prefix('#').map(str::as_bytes);
+        match digits.and_then(hex_digits2rgba) {
+            Some([r, g, b, a]) => Ok(ColorU8::new(r, g, b, a)),
+            None => {
+                ColorSpace::valid_hex(hex_str)?;
+                Err(Error::InvalidParamsError(format!(
+                    "Got a error hex string of '{}'!",
+                    hex_str
+                )))
+            }
+        }
+    }
+    /// The shortest hex string of the color, with the alpha channel if it is not opaque,
+    /// like [`Color::hex`].
+    pub fn hex(self) -> String {
+        let bytes = &self.to_array()[..self.hex_len()];
+        if bytes.iter().all(|b| b >> 4 == b & 0xf) {
+            bytes.iter().fold(String::from("#"), |mut hex, b| {
+                hex.push(char::from_digit((b & 0xf) as u32, 16).unwrap());
+                hex
+            })
+        } else {
+            self.hex_full()
+        }
+    }
+    /// The `#rrggbb` hex string of the color, `#rrggbbaa` if it is not opaque.
+    pub fn hex_full(self) -> String {
+        self.to_array()[..self.hex_len()]
+            .iter()
+            .fold(String::from("#"), |hex, b| hex + &format!("{:02x}", b))
+    }
+
+    fn hex_len(self) -> usize {
+        if self.a == 255 {
+            3
+        } else {
+            4
+        }
+    }
+}
+
+impl Default for ColorU8 {
+    /// default returns a black color, like [`Color::default`].
+    fn default() -> Self {
+        ColorU8::BLACK
+    }
+}
+
+impl Display for ColorU8 {
+    /// Formats the color as its shortest hex string, see [`ColorU8::hex`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.hex())
+    }
+}
+
+impl From<Color> for ColorU8 {
+    /// Converts a color, rounding the channels and clamping them to the byte range.
+    fn from(color: Color) -> Self {
+        let [r, g, b, a] = color.rgba_bytes();
+        ColorU8::new(r, g, b, a)
+    }
+}
+
+impl From<ColorU8> for Color {
+    /// Converts a [`ColorU8`] exactly, the alpha byte is scaled to 0.0 - 1.0.
+    fn from(color: ColorU8) -> Self {
+        Color::from_rgba8(color.r, color.g, color.b, color.a as f64 / 255.0)
+    }
+}
+
+impl From<[u8; 4]> for ColorU8 {
+    /// Converts `[r, g, b, a]` bytes.
+    fn from([r, g, b, a]: [u8; 4]) -> Self {
+        ColorU8::new(r, g, b, a)
+    }
+}
+
+impl From<ColorU8> for [u8; 4] {
+    /// Converts into `[r, g, b, a]` bytes.
+    fn from(color: ColorU8) -> Self {
+        color.to_array()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_color_u8_hex() {
+        for hex in ["#000", "#f39", "#ff339980", "#12345678", "#abcdef", "#0000"] {
+            assert_eq!(ColorU8::from_hex(hex).unwrap().hex(), hex);
+            assert_eq!(
+                ColorU8::from_hex(hex).unwrap().hex(),
+                Color::from_hex(hex).unwrap().hex()
+            );
+        }
+        let color = ColorU8::from_hex("#F398").unwrap();
+        assert_eq!(color, ColorU8::new(255, 51, 153, 136));
+        assert_eq!(color.hex_full(), "#ff339988");
+        assert_eq!(color.to_string(), "#f398");
+        assert_eq!(ColorU8::WHITE.hex_full(), "#ffffff");
+
+        assert!(ColorU8::from_hex("#ff33999").is_err());
+        assert!(ColorU8::from_hex("#ff33gg").is_err());
+        assert!(ColorU8::from_hex("").is_err());
+    }
+
+    #[test]
+    fn test_color_u8_conversion() {
+        for rgba in [0x00000000, 0xff339980, 0x12345678, 0xffffffff] {
+            let color = ColorU8::from_rgba_u32(rgba);
+            assert_eq!(color.to_rgba_u32(), rgba);
+            assert_eq!(Color::from(color), Color::from_rgba_u32(rgba));
+            assert_eq!(ColorU8::from(Color::from(color)), color);
+        }
+        let color = ColorU8::from(Color::new(300.0, -2.0, 0.4, 0.5));
+        assert_eq!(color, ColorU8::new(255, 0, 0, 128));
+        assert_eq!(<[u8; 4]>::from(ColorU8::from([1, 2, 3, 4])), [1, 2, 3, 4]);
+        assert_eq!(ColorU8::default(), ColorU8::from(Color::default()));
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    conversion::{
+        hex::{rgb2hex, rgba2hex},
+        hsl::rgb2hsl,
+        hwb::rgb2hwb,
+        lab::rgb2lab,
+        lch::rgb2lch,
+        oklab::rgb2oklab,
+        oklch::rgb2oklch,
+    },
+    utils::{hex::simplify_hex, round},
+    Color,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Syntax of the CSS color functions.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CssSyntax {
+    /// Comma separated values, e.g. `rgba(255, 0, 0, 0.5)`.
+    ///
+    /// The functions without a legacy syntax, e.g. `lab()`, fall back to the modern syntax
+    /// if the color is translucent.
+    #[default]
+    Legacy,
+    /// Space separated values with a slash before the alpha, e.g. `rgb(255 0 0 / 0.5)`.
+    Modern,
+}
+
+/// Format of the alpha channel in the CSS color functions.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlphaFormat {
+    /// A number between 0 and 1, e.g. `0.5`.
+    #[default]
+    Float,
+    /// A percentage, e.g. `50%`.
+    Percentage,
+}
+
+/// Configurable stringify of colors.
+///
+/// The default formatter produces the same strings as the stringify methods of [`Color`],
+/// e.g. [`Color::hex`] and [`Color::rgb`].
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{AlphaFormat, Color, ColorFormatter, CssSyntax};
+///
+/// let color = Color::new(255, 51, 153, 0.5);
+///
+/// let formatter = ColorFormatter::new();
+/// assert_eq!(formatter.hex(&color), "#ff339980");
+/// assert_eq!(formatter.rgb(&color), "rgba(255, 51, 153, 0.5)");
+///
+/// let formatter = ColorFormatter::new()
+///     .syntax(CssSyntax::Modern)
+///     .alpha_format(AlphaFormat::Percentage)
+///     .precision(2)
+///     .uppercase(true);
+/// assert_eq!(formatter.hex(&color), "#FF339980");
+/// assert_eq!(formatter.hsl(&color), "hsl(330 100% 60% / 50%)");
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColorFormatter {
+    precision: Option<u32>,
+    syntax: CssSyntax,
+    uppercase: bool,
+    full_hex: bool,
+    alpha_format: AlphaFormat,
+}
+
+impl ColorFormatter {
+    /// Creates a new [`ColorFormatter`] with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the number of decimal places of every value.
+    ///
+    /// By default, each color space uses its own precision, e.g. 0 for `rgb` and 2 for `lab`.
+    pub fn precision(mut self, precision: u32) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+    /// Sets the syntax of the CSS color functions, legacy by default.
+    pub fn syntax(mut self, syntax: CssSyntax) -> Self {
+        self.syntax = syntax;
+        self
+    }
+    /// Sets whether hex strings use uppercase digits, lowercase by default.
+    pub fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+    /// Sets whether hex strings are simplified to the short form if possible,
+    /// e.g. `#ffffff` to `#fff`. Enabled by default.
+    pub fn simplify_hex(mut self, simplify: bool) -> Self {
+        self.full_hex = !simplify;
+        self
+    }
+    /// Sets the format of the alpha channel, a float by default.
+    pub fn alpha_format(mut self, alpha_format: AlphaFormat) -> Self {
+        self.alpha_format = alpha_format;
+        self
+    }
+
+    /// `hex` string of the color, with the alpha channel if it is not opaque.
+    pub fn hex(&self, color: &Color) -> String {
+        let [r, g, b] = color.rgb;
+        let mut hex = if color.alpha == 1.0 {
+            rgb2hex(color.rgb)
+        } else {
+            rgba2hex([r, g, b, color.alpha])
+        };
+        if !self.full_hex {
+            hex = simplify_hex(hex);
+        }
+        if self.uppercase {
+            hex = hex.to_uppercase();
+        }
+        hex
+    }
+    /// `rgb` string of the color, `rgba` in the legacy syntax if it is not opaque.
+    pub fn rgb(&self, color: &Color) -> String {
+        let [r, g, b] = color.rgb.map(|v| self.number(v, 0));
+        self.function("rgb", Some("rgba"), [r, g, b], color.alpha)
+    }
+    /// `hsl` string of the color, `hsla` in the legacy syntax if it is not opaque.
+    pub fn hsl(&self, color: &Color) -> String {
+        let hsl = rgb2hsl(&color.rgb);
+        let h = self.number(hsl[0], 0);
+        let s = self.percentage(hsl[1], 0);
+        let l = self.percentage(hsl[2], 0);
+        self.function("hsl", Some("hsla"), [h, s, l], color.alpha)
+    }
+    /// `hwb` string of the color.
+    pub fn hwb(&self, color: &Color) -> String {
+        let hwb = rgb2hwb(&color.rgb);
+        let h = self.number(hwb[0], 0);
+        let w = self.percentage(hwb[1], 0);
+        let b = self.percentage(hwb[2], 0);
+        self.function("hwb", None, [h, w, b], color.alpha)
+    }
+    /// `lab` string of the color.
+    pub fn lab(&self, color: &Color) -> String {
+        let [l, a, b] = self.numbers(&rgb2lab(&color.rgb), [2, 2, 2]);
+        self.function("lab", None, [l, a, b], color.alpha)
+    }
+    /// `lch` string of the color.
+    pub fn lch(&self, color: &Color) -> String {
+        let [l, c, h] = self.numbers(&rgb2lch(&color.rgb), [2, 2, 2]);
+        self.function("lch", None, [l, c, h], color.alpha)
+    }
+    /// `oklab` string of the color.
+    pub fn oklab(&self, color: &Color) -> String {
+        let [l, a, b] = self.numbers(&rgb2oklab(&color.rgb), [4, 4, 4]);
+        self.function("oklab", None, [l, a, b], color.alpha)
+    }
+    /// `oklch` string of the color.
+    pub fn oklch(&self, color: &Color) -> String {
+        let [l, c, h] = self.numbers(&rgb2oklch(&color.rgb), [4, 4, 2]);
+        self.function("oklch", None, [l, c, h], color.alpha)
+    }
+
+    /// Joins the values of a color function, with the alpha channel if it is not opaque.
+    ///
+    /// In the legacy syntax, the alpha channel is the last comma separated value of the
+    /// `legacy_alpha_name` function if the color space has one, otherwise the modern syntax
+    /// is used, as CSS doesn't allow commas before `/ <alpha>`.
+    fn function(
+        &self,
+        name: &str,
+        legacy_alpha_name: Option<&str>,
+        values: [String; 3],
+        alpha: f64,
+    ) -> String {
+        let [v1, v2, v3] = values;
+        match (self.syntax, legacy_alpha_name) {
+            _ if alpha == 1.0 && self.syntax == CssSyntax::Legacy => {
+                format!("{}({}, {}, {})", name, v1, v2, v3)
+            }
+            _ if alpha == 1.0 => format!("{}({} {} {})", name, v1, v2, v3),
+            (CssSyntax::Legacy, Some(alpha_name)) => {
+                let alpha = self.alpha(alpha);
+                format!("{}({}, {}, {}, {})", alpha_name, v1, v2, v3, alpha)
+            }
+            _ => {
+                let alpha = self.alpha(alpha);
+                format!("{}({} {} {} / {})", name, v1, v2, v3, alpha)
+            }
+        }
+    }
+    fn number(&self, value: f64, default_precision: u32) -> String {
+        round(value, self.precision.unwrap_or(default_precision)).to_string()
+    }
+    fn numbers(&self, values: &[f64], default_precisions: [u32; 3]) -> [String; 3] {
+        [0, 1, 2].map(|i| self.number(values[i], default_precisions[i]))
+    }
+    fn percentage(&self, value: f64, default_precision: u32) -> String {
+        format!("{}%", self.number(value * 100.0, default_precision))
+    }
+    fn alpha(&self, alpha: f64) -> String {
+        match self.alpha_format {
+            AlphaFormat::Float => self.number(alpha, 2),
+            AlphaFormat::Percentage => self.percentage(alpha, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_formatter() {
+        let formatter = ColorFormatter::new();
+        for color in [
+            Color::new(255, 255, 0, 1.0),
+            Color::new(0, 128, 128, 1.0),
+            Color::new(255, 51, 153, 0.5),
+        ] {
+            assert_eq!(formatter.hex(&color), color.hex());
+        }
+        let color = Color::new(0, 128, 128, 1.0);
+        assert_eq!(formatter.rgb(&color), color.rgb());
+        assert_eq!(formatter.hsl(&color), color.hsl());
+        assert_eq!(formatter.hwb(&color), color.hwb());
+        assert_eq!(formatter.lab(&color), color.lab());
+        assert_eq!(formatter.lch(&color), color.lch());
+        assert_eq!(formatter.oklab(&color), color.oklab());
+        assert_eq!(formatter.oklch(&color), color.oklch());
+
+        let color = Color::new(0, 128, 128, 0.3);
+        assert_eq!(formatter.rgb(&color), color.rgba());
+        assert_eq!(formatter.hsl(&color), color.hsla());
+        assert_eq!(formatter.lab(&color), "lab(47.99 -30.39 -8.98 / 0.3)");
+    }
+
+    #[test]
+    fn test_formatter_options() {
+        let color = Color::new(255, 255, 255, 0.25);
+
+        let formatter = ColorFormatter::new().simplify_hex(false).uppercase(true);
+        assert_eq!(formatter.hex(&color), "#FFFFFF40");
+        assert_eq!(formatter.hex(&Color::new(255, 0, 0, 1.0)), "#FF0000");
+
+        let formatter = ColorFormatter::new().syntax(CssSyntax::Modern);
+        assert_eq!(formatter.rgb(&color), "rgb(255 255 255 / 0.25)");
+        assert_eq!(formatter.hwb(&color), "hwb(0 100% 0% / 0.25)");
+
+        let formatter = formatter.alpha_format(AlphaFormat::Percentage);
+        assert_eq!(formatter.rgb(&color), "rgb(255 255 255 / 25%)");
+
+        let color = Color::new(10, 20, 30, 1.0);
+        let formatter = ColorFormatter::new().precision(3);
+        assert_eq!(formatter.hsl(&color), "hsl(210, 50%, 7.843%)");
+        assert_eq!(formatter.lab(&color), "lab(5.851, -1.496, -8.255)");
+    }
+}
@@ -1,4 +1,16 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
 use crate::{Color, Error};
+use alloc::format;
+
+impl TryFrom<u32> for Color {
+    type Error = Error;
+
+    /// Converts a `0xRRGGBB` number, see [`Color::from_num`].
+    fn try_from(num: u32) -> Result<Self, Self::Error> {
+        Color::from_num(num)
+    }
+}
 
 impl Color {
     /// Returns the numeric representation of the hexadecimal color.
@@ -23,6 +35,67 @@ impl Color {
         let b = (num & 0xff) as f64;
         Ok(Color::new(r, g, b, 1.0))
     }
+    /// Creates a color from a packed `0xRRGGBBAA` number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_rgba_u32(0xff339980);
+    /// assert_eq!(color.hex(), "#ff339980");
+    /// ```
+    pub const fn from_rgba_u32(num: u32) -> Self {
+        let [r, g, b, a] = num.to_be_bytes();
+        Color::from_rgba8(r, g, b, a as f64 / 255.0)
+    }
+    /// Creates a color from a packed `0xAARRGGBB` number, as used by Android.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_argb_u32(0x80ff3399);
+    /// assert_eq!(color.hex(), "#ff339980");
+    /// ```
+    pub const fn from_argb_u32(num: u32) -> Self {
+        let [a, r, g, b] = num.to_be_bytes();
+        Color::from_rgba8(r, g, b, a as f64 / 255.0)
+    }
+    /// Packs the color into a `0xRRGGBBAA` number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 51, 153, 0.5);
+    /// assert_eq!(color.to_rgba_u32(), 0xff339980);
+    /// ```
+    pub fn to_rgba_u32(&self) -> u32 {
+        u32::from_be_bytes(self.rgba_bytes())
+    }
+    /// Packs the color into a `0xAARRGGBB` number, as used by Android.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 51, 153, 0.5);
+    /// assert_eq!(color.to_argb_u32(), 0x80ff3399);
+    /// ```
+    pub fn to_argb_u32(&self) -> u32 {
+        let [r, g, b, a] = self.rgba_bytes();
+        u32::from_be_bytes([a, r, g, b])
+    }
+    /// The rounded `[r, g, b, a]` bytes of the color.
+    pub(crate) fn rgba_bytes(&self) -> [u8; 4] {
+        let [r, g, b] = self.rgb.map(|c| c.round().clamp(0.0, 255.0) as u8);
+        let a = (self.alpha * 255.0).round().clamp(0.0, 255.0) as u8;
+        [r, g, b, a]
+    }
 }
 
 #[test]
@@ -36,3 +109,23 @@ fn test_color_from_num() {
     let color = Color::from_num(0x1000000);
     assert!(color.is_err());
 }
+
+#[test]
+fn test_color_packed_u32() {
+    let color = Color::try_from(0xff3399).unwrap();
+    assert_eq!(color.hex(), "#f39");
+    assert!(Color::try_from(0x1000000).is_err());
+
+    let color = Color::from_rgba_u32(0x00ff0033);
+    assert_eq!(color, Color::new(0.0, 255.0, 0.0, 0.2));
+    assert_eq!(color.to_rgba_u32(), 0x00ff0033);
+    assert_eq!(color.to_argb_u32(), 0x3300ff00);
+
+    let color = Color::from_argb_u32(0xff0000ff);
+    assert_eq!(color, Color::new(0.0, 0.0, 255.0, 1.0));
+    assert_eq!(color.to_argb_u32(), 0xff0000ff);
+    assert_eq!(color.to_rgba_u32(), 0x0000ffff);
+
+    let color = Color::new(127.6, 0.4, 300.0, 1.0);
+    assert_eq!(color.to_rgba_u32(), 0x8000ffff);
+}
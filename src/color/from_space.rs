@@ -1,4 +1,8 @@
-use crate::{conversion, data::hex_of_name, Color, ColorSpace, Error};
+use crate::{
+    conversion, data::hex_of_name, Color, ColorMatrix, ColorSpace, Error, HexOrder, NameRegistry,
+    YCbCrRange,
+};
+use alloc::format;
 
 impl Color {
     /// Create a color from RGB values.
@@ -64,7 +68,7 @@ impl Color {
     /// assert_eq!(color.hex(), "#f39");
     /// ```
     pub fn from_hsl(h: f64, s: f64, l: f64) -> Result<Self, Error> {
-        let hsl = vec![h, s, l];
+        let hsl = [h, s, l];
         ColorSpace::HSL.valid(&hsl)?;
         let rgb = conversion::hsl::hsl2rgb(&hsl);
         let r = rgb[0];
@@ -83,7 +87,7 @@ impl Color {
     /// assert_eq!(color.hex(), "#ffa500");
     /// ```
     pub fn from_hsv(h: f64, s: f64, v: f64) -> Result<Self, Error> {
-        let hsv = vec![h, s, v];
+        let hsv = [h, s, v];
         ColorSpace::HSV.valid(&hsv)?;
         let rgb = conversion::hsv::hsv2rgb(&hsv);
         let r = rgb[0];
@@ -91,6 +95,31 @@ impl Color {
         let b = rgb[2];
         Ok(Color::new(r, g, b, 1.0))
     }
+    /// Create a color from HWB values.
+    ///
+    /// # Parameters
+    ///
+    /// - `h`: Hue (0-360)
+    /// - `w`: Whiteness (0.0-1.0)
+    /// - `b`: Blackness (0.0-1.0)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_hwb(330.0, 0.2, 0.0).unwrap();
+    /// assert_eq!(color.hex(), "#f39");
+    /// ```
+    pub fn from_hwb(h: f64, w: f64, b: f64) -> Result<Self, Error> {
+        let hwb = [h, w, b];
+        ColorSpace::HWB.valid(&hwb)?;
+        let rgb = conversion::hwb::hwb2rgb(&hwb);
+        let r = rgb[0];
+        let g = rgb[1];
+        let b = rgb[2];
+        Ok(Color::new(r, g, b, 1.0))
+    }
     /// Create a color from CMYK values.
     ///
     /// # Examples
@@ -102,7 +131,7 @@ impl Color {
     /// assert_eq!(color.hex(), "#f39");
     /// ```
     pub fn from_cmyk(c: f64, m: f64, y: f64, k: f64) -> Result<Self, Error> {
-        let cmyk = vec![c, m, y, k];
+        let cmyk = [c, m, y, k];
         ColorSpace::CMYK.valid(&cmyk)?;
         let rgb = conversion::cmyk::cmyk2rgb(&cmyk);
         let r = rgb[0];
@@ -110,6 +139,269 @@ impl Color {
         let b = rgb[2];
         Ok(Color::new(r, g, b, 1.0))
     }
+    /// Create a color from CIE XYZ values, relative to the D65 white point.
+    ///
+    /// Colors outside of the sRGB gamut are clipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `x`: X (0.0-0.950456)
+    /// - `y`: Y, the luminance (0.0-1.0)
+    /// - `z`: Z (0.0-1.088754)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_xyz(0.412391, 0.212639, 0.019331).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// ```
+    pub fn from_xyz(x: f64, y: f64, z: f64) -> Result<Self, Error> {
+        let xyz = [x, y, z];
+        ColorSpace::XYZ.valid(&xyz)?;
+        let rgb = conversion::xyz::xyz2rgb(&xyz);
+        let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+        Ok(Color::new(r, g, b, 1.0))
+    }
+    /// Create a color from CIE Lab values, relative to the D50 white point as in CSS.
+    ///
+    /// Colors outside of the sRGB gamut are clipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `l`: Lightness (0-100)
+    /// - `a`: Green-red axis (-128-127)
+    /// - `b`: Blue-yellow axis (-128-127)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_lab(54.29, 80.8, 69.89).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// ```
+    pub fn from_lab(l: f64, a: f64, b: f64) -> Result<Self, Error> {
+        let lab = [l, a, b];
+        ColorSpace::Lab.valid(&lab)?;
+        let rgb = conversion::lab::lab2rgb(&lab);
+        let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+        Ok(Color::new(r, g, b, 1.0))
+    }
+    /// Create a color from CIE LCH values, relative to the D50 white point as in CSS.
+    ///
+    /// Colors outside of the sRGB gamut are clipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `l`: Lightness (0-100)
+    /// - `c`: Chroma (0-150)
+    /// - `h`: Hue (0-360)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_lch(54.29, 106.84, 40.86).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// ```
+    pub fn from_lch(l: f64, c: f64, h: f64) -> Result<Self, Error> {
+        let lch = [l, c, h];
+        ColorSpace::LCH.valid(&lch)?;
+        let rgb = conversion::lch::lch2rgb(&lch);
+        let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+        Ok(Color::new(r, g, b, 1.0))
+    }
+    /// Create a color from CIE Luv values.
+    ///
+    /// Colors outside of the sRGB gamut are clipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `l`: Lightness (0-100)
+    /// - `u`: u chromaticity (-134-224)
+    /// - `v`: v chromaticity (-140-122)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_luv(53.24, 175.01, 37.77).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// ```
+    pub fn from_luv(l: f64, u: f64, v: f64) -> Result<Self, Error> {
+        let luv = [l, u, v];
+        ColorSpace::Luv.valid(&luv)?;
+        let rgb = conversion::luv::luv2rgb(&luv);
+        let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+        Ok(Color::new(r, g, b, 1.0))
+    }
+    /// Create a color from LCHuv values.
+    ///
+    /// Colors outside of the sRGB gamut are clipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `l`: Lightness (0-100)
+    /// - `c`: Chroma (0-180)
+    /// - `h`: Hue (0-360)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_lchuv(53.24, 179.04, 12.18).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// ```
+    pub fn from_lchuv(l: f64, c: f64, h: f64) -> Result<Self, Error> {
+        let lchuv = [l, c, h];
+        ColorSpace::LCHuv.valid(&lchuv)?;
+        let rgb = conversion::lchuv::lchuv2rgb(&lchuv);
+        let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+        Ok(Color::new(r, g, b, 1.0))
+    }
+    /// Create a color from OKLab values.
+    ///
+    /// Colors outside of the sRGB gamut are clipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `l`: Perceptual lightness (0-1)
+    /// - `a`: Green-red axis (-0.4-0.4)
+    /// - `b`: Blue-yellow axis (-0.4-0.4)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_oklab(0.628, 0.2249, 0.1258).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// ```
+    pub fn from_oklab(l: f64, a: f64, b: f64) -> Result<Self, Error> {
+        let oklab = [l, a, b];
+        ColorSpace::OKLab.valid(&oklab)?;
+        let rgb = conversion::oklab::oklab2rgb(&oklab);
+        let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+        Ok(Color::new(r, g, b, 1.0))
+    }
+    /// Create a color from OKLCH values.
+    ///
+    /// Colors outside of the sRGB gamut are clipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `l`: Perceptual lightness (0-1)
+    /// - `c`: Chroma (0-0.4)
+    /// - `h`: Hue (0-360)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_oklch(0.628, 0.2577, 29.23).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// ```
+    pub fn from_oklch(l: f64, c: f64, h: f64) -> Result<Self, Error> {
+        let oklch = [l, c, h];
+        ColorSpace::OKLCH.valid(&oklch)?;
+        let rgb = conversion::oklch::oklch2rgb(&oklch);
+        let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+        Ok(Color::new(r, g, b, 1.0))
+    }
+    /// Create a color from gamma-encoded Rec. 2020 RGB values.
+    ///
+    /// Colors outside of the sRGB gamut are clipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `r`: Red value (0.0-1.0)
+    /// - `g`: Green value (0.0-1.0)
+    /// - `b`: Blue value (0.0-1.0)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_rec2020(0.792, 0.231, 0.0738).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// ```
+    pub fn from_rec2020(r: f64, g: f64, b: f64) -> Result<Self, Error> {
+        let rec2020 = [r, g, b];
+        ColorSpace::Rec2020.valid(&rec2020)?;
+        let rgb = conversion::rec2020::rec2020_to_rgb(&rec2020);
+        let r = rgb[0];
+        let g = rgb[1];
+        let b = rgb[2];
+        Ok(Color::new(r, g, b, 1.0))
+    }
+    /// Create a color from digital YCbCr values.
+    ///
+    /// # Parameters
+    ///
+    /// - `y`: Luma (0-255)
+    /// - `cb`: Blue difference (0-255)
+    /// - `cr`: Red difference (0-255)
+    /// - `matrix`: The luma coefficients the values were encoded with
+    /// - `range`: The quantization range the values were encoded with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{Color, ColorMatrix, YCbCrRange};
+    ///
+    /// let color = Color::from_ycbcr(235.0, 128.0, 128.0, ColorMatrix::BT709, YCbCrRange::Limited).unwrap();
+    /// assert_eq!(color.hex(), "#fff");
+    /// ```
+    pub fn from_ycbcr(
+        y: f64,
+        cb: f64,
+        cr: f64,
+        matrix: ColorMatrix,
+        range: YCbCrRange,
+    ) -> Result<Self, Error> {
+        let ycbcr = [y, cb, cr];
+        ColorSpace::YCbCr.valid(&ycbcr)?;
+        let rgb =
+            conversion::ycbcr::ycbcr2rgb_with(&ycbcr, matrix, range).map(|v| v.clamp(0.0, 255.0));
+        let r = rgb[0];
+        let g = rgb[1];
+        let b = rgb[2];
+        Ok(Color::new(r, g, b, 1.0))
+    }
+    /// Create a color from YPbPr values.
+    ///
+    /// Combinations outside of the RGB cube, e.g. full luma with full color difference, are
+    /// clipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `y`: Luma (0-1)
+    /// - `pb`: Blue difference (-0.5-0.5)
+    /// - `pr`: Red difference (-0.5-0.5)
+    /// - `matrix`: The luma coefficients the values were encoded with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{Color, ColorMatrix};
+    ///
+    /// let color = Color::from_ypbpr(0.2126, -0.1146, 0.5, ColorMatrix::BT709).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// ```
+    pub fn from_ypbpr(y: f64, pb: f64, pr: f64, matrix: ColorMatrix) -> Result<Self, Error> {
+        let ypbpr = [y, pb, pr];
+        ColorSpace::YPbPr.valid(&ypbpr)?;
+        let rgb = conversion::ypbpr::ypbpr2rgb(&ypbpr, matrix);
+        let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+        Ok(Color::new(r, g, b, 1.0))
+    }
     /// Create a color from a hex string.
     ///
     /// # Examples
@@ -124,33 +416,53 @@ impl Color {
     /// assert_eq!(color.hex(), "#f393");
     /// ```
     pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
+        Color::from_hex_with_order(hex_str, HexOrder::Rgba)
+    }
+    /// Create a color from a hex string, with the alpha channel in the given order.
+    ///
+    /// Use [`HexOrder::Argb`] for Android style `#AARRGGBB` and `#ARGB` strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{Color, HexOrder};
+    ///
+    /// let color = Color::from_hex_with_order("#80ff3399", HexOrder::Argb).unwrap();
+    /// assert_eq!(color.hex(), "#ff339980");
+    ///
+    /// let color = Color::from_hex_with_order("#8f39", HexOrder::Argb).unwrap();
+    /// assert_eq!(color.hex(), "#f398");
+    /// ```
+    pub fn from_hex_with_order(hex_str: &str, order: HexOrder) -> Result<Self, Error> {
+        if !hex_str.starts_with('#') {
+            return Err(Error::InvalidParamsError(format!(
+                "Got a error hex string of '{}'!",
+                hex_str
+            )));
+        }
         ColorSpace::valid_hex(hex_str)?;
-        let color_vec = match hex_str.len() {
-            4 | 7 => conversion::hex::hex2rgb(hex_str),
-            5 | 9 => conversion::hex::hex2rgba(hex_str),
-            _ => {
-                return Err(Error::InvalidParamsError(format!(
-                    "Got a error hex string of '{}'!",
-                    hex_str
-                )))
-            }
-        };
+        let color_vec = conversion::hex::hex2rgba_with_order(hex_str, order);
         let r = color_vec[0];
         let g = color_vec[1];
         let b = color_vec[2];
-        let a = if color_vec.len() == 4 {
-            color_vec[3]
-        } else {
-            1.0
-        };
+        let a = color_vec[3];
         Ok(Color::new(r, g, b, a))
     }
     /// Create a color from a color name.
     ///
     /// Currently supported color names are:
     ///
-    /// - English color names from [X11_color_names](https://en.wikipedia.org/wiki/X11_color_names)
-    /// - 中国传统色 (Chinese traditional colors)
+    /// - English color names from [X11_color_names](https://en.wikipedia.org/wiki/X11_color_names),
+    ///   with the `css-names` feature
+    /// - 中国传统色 (Chinese traditional colors), with the `chinese-names` feature
+    ///
+    /// Both features are enabled by default. The X11, xkcd and Japanese names of
+    /// [`NameSource`](crate::NameSource) are available with their own features.
+    ///
+    /// Custom names of the installed [`NameRegistry`](crate::NameRegistry) come after the
+    /// built-in names.
+    ///
+    /// The lookup is a perfect hash, it doesn't allocate. This is the reverse of [`Color::name`].
     ///
     /// # Examples
     ///
@@ -164,9 +476,11 @@ impl Color {
     /// assert_eq!(color.hex(), "#8cc269");
     /// ```
     pub fn from_name(name: &str) -> Result<Self, Error> {
-        let found = hex_of_name(name);
-        match found {
-            Some(hex) => Color::from_hex(hex),
+        if let Some(hex) = hex_of_name(name) {
+            return Color::from_hex(hex);
+        }
+        match NameRegistry::with_installed(|registry| registry.get(name)) {
+            Some(color) => Ok(color),
             None => Err(Error::InvalidParamsError(format!(
                 "Invalid color name: {}",
                 name
@@ -206,6 +520,39 @@ mod tests {
         assert_eq!(color.hex(), "#ffa500");
     }
 
+    #[test]
+    fn test_color_from_hwb() {
+        let color = Color::from_hwb(330.0, 0.2, 0.0).unwrap();
+        assert_eq!(color.hex(), "#f39");
+
+        let color = Color::from_hwb(0.0, 1.5, 0.0);
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_lab() {
+        let color = Color::from_lab(58.56, 78.81, -4.81).unwrap();
+        assert_eq!(color.hex(), "#f39");
+
+        let color = Color::from_lch(58.56, 78.95, 356.51).unwrap();
+        assert_eq!(color.hex(), "#f39");
+
+        let color = Color::from_oklch(0.6702, 0.2462, 356.4).unwrap();
+        assert_eq!(color.hex(), "#f39");
+
+        let color = Color::from_xyz(0.48172, 0.259311, 0.326066).unwrap();
+        assert_eq!(color.hex(), "#f39");
+
+        // out of the sRGB gamut
+        let color = Color::from_lab(50.0, -128.0, 0.0).unwrap();
+        assert_eq!(color.hex(), "#009b74");
+
+        assert!(Color::from_lab(101.0, 0.0, 0.0).is_err());
+        assert!(Color::from_lch(50.0, 0.0, 400.0).is_err());
+        assert!(Color::from_oklch(0.5, 0.5, 0.0).is_err());
+        assert!(Color::from_xyz(1.0, 0.0, 0.0).is_err());
+    }
+
     #[test]
     fn test_color_from_cmyk() {
         let color = Color::from_cmyk(0.0, 0.8, 0.4, 0.0).unwrap();
@@ -217,4 +564,92 @@ mod tests {
         let color = Color::from_cmyk(2.0, 0.0, 0.0, 1.0);
         assert!(color.is_err());
     }
+
+    #[test]
+    fn test_color_from_luv() {
+        let color = Color::from_luv(53.24, 175.01, 37.77).unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_lchuv(97.14, 107.09, 85.87).unwrap();
+        assert_eq!(color.hex(), "#ff0");
+
+        for color in [
+            Color::from_luv(0.01, 100.0, -100.0).unwrap(),
+            Color::from_luv(1e-9, 100.0, 100.0).unwrap(),
+            Color::from_lchuv(0.5, 150.0, 200.0).unwrap(),
+            Color::from_lchuv(90.0, 150.0, 270.0).unwrap(),
+        ] {
+            let rgb = color.vec_of(ColorSpace::RGB);
+            assert!(rgb.iter().all(|v| (0.0..=255.0).contains(v)), "{:?}", rgb);
+        }
+        assert_eq!(Color::from_luv(1e-9, 100.0, 100.0).unwrap(), Color::BLACK);
+
+        let color = Color::from_luv(101.0, 0.0, 0.0);
+        assert!(color.is_err());
+
+        let color = Color::from_lchuv(50.0, 200.0, 0.0);
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_ycbcr() {
+        let color = Color::from_ycbcr(
+            62.5594,
+            102.3358,
+            240.0,
+            ColorMatrix::BT709,
+            YCbCrRange::Limited,
+        )
+        .unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color =
+            Color::from_ycbcr(0.0, 128.0, 128.0, ColorMatrix::BT2020, YCbCrRange::Limited).unwrap();
+        assert_eq!(color.hex(), "#000");
+
+        let color = Color::from_ycbcr(300.0, 128.0, 128.0, ColorMatrix::BT601, YCbCrRange::Full);
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_ypbpr() {
+        let color = Color::from_ypbpr(0.299, -0.1687, 0.5, ColorMatrix::BT601).unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_ypbpr(1.0, 0.0, 0.0, ColorMatrix::BT709).unwrap();
+        assert_eq!(color.hex(), "#fff");
+
+        let color = Color::from_ypbpr(1.0, 0.5, 0.5, ColorMatrix::BT601).unwrap();
+        assert_eq!(color.vec_of(ColorSpace::RGB), vec![255.0, 120.0, 255.0]);
+
+        let color = Color::from_ypbpr(0.5, 0.7, 0.0, ColorMatrix::BT709);
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_rec2020() {
+        let color = Color::from_rec2020(0.792, 0.231, 0.0738).unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_rec2020(0.0, 1.0, 0.0).unwrap();
+        assert_eq!(color.hex(), "#0f0");
+
+        let color = Color::from_rec2020(0.5, -0.1, 0.0);
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_oklab() {
+        let color = Color::from_oklab(0.628, 0.2249, 0.1258).unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_oklab(0.5, 0.0, 0.0).unwrap();
+        assert_eq!(color.hex(), "#636363");
+
+        let color = Color::from_oklab(0.9, 0.4, 0.4).unwrap();
+        assert_eq!(color.vec_of(ColorSpace::RGB), vec![255.0, 0.0, 0.0]);
+
+        let color = Color::from_oklab(1.5, 0.0, 0.0);
+        assert!(color.is_err());
+    }
 }
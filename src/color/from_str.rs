@@ -1,5 +1,16 @@
-use crate::{conversion, data::hex_of_name, parser, Color, ColorSpace, Error};
-use std::str::FromStr;
+use crate::{
+    conversion::{self, hex::hex2rgba_with_order},
+    data::{fuzzy::suggest_names, hex_of_css_name, hex_of_name},
+    parser::{self, ParseMode},
+    Color, ColorMatrix, ColorSpace, Error, HexOrder, NameRegistry,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::str::FromStr;
 
 impl FromStr for Color {
     type Err = Error;
@@ -27,10 +38,30 @@ impl FromStr for Color {
     /// let color = Color::from_str(s).unwrap();
     /// assert_eq!(color, Color::new(255, 255, 255, 1.0));
     ///
+    /// let s = "rgb(100%, 100%, 100%)";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color, Color::new(255, 255, 255, 1.0));
+    ///
+    /// let s = "rgb(255 255 255 / 50%)";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color, Color::new(255, 255, 255, 0.5));
+    ///
+    /// let s = "hsl(0deg 0% 100%)";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color, Color::new(255, 255, 255, 1.0));
+    ///
+    /// let s = "rgb(from #ff0000 r g b / 0.5)";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color, Color::new(255, 0, 0, 0.5));
+    ///
     /// let s = "hsv(0, 0%, 100%)";
     /// let color = Color::from_str(s).unwrap();
     /// assert_eq!(color, Color::new(255, 255, 255, 1.0));
     ///
+    /// let s = "cmyk(0%, 35%, 100%, 0%)";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color.hex(), "#ffa600");
+    ///
     /// let s = "deeppink";
     /// let color = Color::from_str(s).unwrap();
     /// assert_eq!(color, Color::new(255, 20, 147, 1.0));
@@ -40,58 +71,251 @@ impl FromStr for Color {
     /// assert_eq!(color, Color::new(140, 194, 105, 1.0));
     /// ```
     fn from_str(s: &str) -> Result<Self, Error> {
-        let input = s.trim().to_lowercase();
-
-        let (color_space, color_vec) = if input.starts_with('#') {
-            let hex_str = parser::hex::parse_hex_str(&input)?;
-            (ColorSpace::RGB, conversion::hex::hex2rgb(&hex_str))
-        } else if let Some(hex) = hex_of_name(&input) {
-            (ColorSpace::RGB, conversion::hex::hex2rgb(hex))
-        } else {
-            let mut parser = parser::Parser::new();
-            parser.tokenize(&input).validate()?;
-            (parser.color_space, parser.values)
-        };
-
-        let color_vec = convert_color_vec_by_color_space(&color_vec, &color_space);
-
-        let r = color_vec[0];
-        let g = color_vec[1];
-        let b = color_vec[2];
-        let alpha = if color_vec.len() > 3 {
-            color_vec[3]
-        } else {
-            1.0
-        };
-
-        Ok(Color::new(r, g, b, alpha))
+        parse_color(s, ParseMode::Default)
+    }
+}
+
+impl Color {
+    /// Creates a new [`Color`] from a string, accepting only the CSS color grammar.
+    ///
+    /// Only hex colors, CSS named colors and the CSS color functions (`rgb()`, `rgba()`,
+    /// `hsl()`, `hsla()`, `hwb()`, `lab()`, `lch()`, `oklab()`, `oklch()` and `color()`) are accepted,
+    /// and the comma separated form is only allowed where CSS allows it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_str_strict("rgb(255 0 0 / 50%)").unwrap();
+    /// assert_eq!(color, Color::new(255, 0, 0, 0.5));
+    ///
+    /// assert!(Color::from_str_strict("hsv(0, 100%, 100%)").is_err());
+    /// assert!(Color::from_str_strict("rgb(255, 0, 0 / 50%)").is_err());
+    /// assert!(Color::from_str_strict("水绿").is_err());
+    /// ```
+    pub fn from_str_strict(s: &str) -> Result<Self, Error> {
+        parse_color(s, ParseMode::Strict)
+    }
+    /// Creates a new [`Color`] from a string, accepting sloppy inputs.
+    ///
+    /// Hex colors may omit the leading `#`, and out of range values are clamped
+    /// (hues wrapped around) instead of being an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_str_lenient("ff0000").unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    ///
+    /// let color = Color::from_str_lenient("rgba(300, -20, 0, 1.5)").unwrap();
+    /// assert_eq!(color, Color::new(255, 0, 0, 1.0));
+    ///
+    /// let color = Color::from_str_lenient("hsl(480, 120%, 50%)").unwrap();
+    /// assert_eq!(color.hex(), "#0f0");
+    /// ```
+    pub fn from_str_lenient(s: &str) -> Result<Self, Error> {
+        parse_color(s, ParseMode::Lenient)
+    }
+    /// Creates a new [`Color`] from a string like [`Color::from_str`], with the custom names of
+    /// a registry instead of the installed one.
+    ///
+    /// The built-in names can't be redefined, they come before the custom names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, NameRegistry};
+    ///
+    /// let names = NameRegistry::new()
+    ///     .with("Brand Blue", Color::new(0, 85, 255, 1.0))
+    ///     .with("red", Color::new(200, 0, 0, 1.0));
+    ///
+    /// let color = Color::from_str_with_names("brand blue", &names).unwrap();
+    /// assert_eq!(color.hex(), "#05f");
+    ///
+    /// let color = Color::from_str_with_names("#f00", &names).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// ```
+    pub fn from_str_with_names(s: &str, names: &NameRegistry) -> Result<Self, Error> {
+        parse_nested_color(s, ParseMode::Default, 0, Some(names))
+    }
+}
+
+/// The color of a custom name, built-in names and color functions can't be shadowed by one.
+///
+/// Only the non-strict modes know custom names, and inputs with parentheses are always color
+/// functions, so that parsing them doesn't lock the installed registry.
+fn custom_name(input: &str, mode: ParseMode, names: Option<&NameRegistry>) -> Option<Color> {
+    if mode == ParseMode::Strict || input.contains('(') {
+        return None;
+    }
+    match names {
+        Some(registry) => registry.get(input),
+        None => NameRegistry::with_installed(|registry| registry.get(input)),
+    }
+}
+
+/// An error suggesting the closest known names, if the input looks like a misspelled color name.
+fn unknown_name_error(input: &str, mode: ParseMode) -> Option<Error> {
+    if input.is_empty() || !input.chars().all(char::is_alphanumeric) {
+        return None;
+    }
+    let suggestions = suggest_names(input, mode == ParseMode::Strict);
+    if suggestions.is_empty() {
+        return None;
+    }
+    Some(Error::UnknownColorName {
+        name: input.to_string(),
+        suggestions: suggestions.into_iter().map(String::from).collect(),
+    })
+}
+
+impl Color {
+    /// Extracts every color from arbitrary text, e.g. a CSS or SVG file.
+    ///
+    /// Hex colors, color functions (e.g. `rgb()` or `hsl()`) and CSS color names are recognized.
+    /// Fails on the first color function that can not be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let css = ".a { color: #f39; border: 1px solid red; background: hsl(0, 0%, 100%); }";
+    /// let colors = Color::parse_many(css).unwrap();
+    /// let hexes = colors.iter().map(|color| color.hex()).collect::<Vec<_>>();
+    /// assert_eq!(hexes, vec!["#f39", "#f00", "#fff"]);
+    /// ```
+    pub fn parse_many(text: &str) -> Result<Vec<Color>, Error> {
+        Color::parse_iter(text).collect()
     }
+    /// Iterates over every color in arbitrary text, see [`Color::parse_many`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let text = "rgb(300, 0, 0) and #00ff00";
+    /// let colors = Color::parse_iter(text).filter_map(Result::ok).collect::<Vec<_>>();
+    /// assert_eq!(colors, vec![Color::new(0, 255, 0, 1.0)]);
+    /// ```
+    pub fn parse_iter(text: &str) -> impl Iterator<Item = Result<Color, Error>> + '_ {
+        parser::scan::scan_colors(text).map(Color::from_str)
+    }
+}
+
+fn parse_color(s: &str, mode: ParseMode) -> Result<Color, Error> {
+    parse_nested_color(s, mode, 0, None)
+}
+
+/// Parse a color nested `depth` levels deep, e.g. the origin of a relative color.
+///
+/// Custom names come from `names`, or the installed [`NameRegistry`] if it is `None`.
+pub(crate) fn parse_nested_color(
+    s: &str,
+    mode: ParseMode,
+    depth: usize,
+    names: Option<&NameRegistry>,
+) -> Result<Color, Error> {
+    let input = s.trim().to_lowercase();
+
+    let name_hex = match mode {
+        ParseMode::Strict => hex_of_css_name(&input),
+        _ => hex_of_name(&input),
+    };
+    let is_bare_hex =
+        matches!(input.len(), 3 | 4 | 6 | 8) && input.chars().all(|c| c.is_ascii_hexdigit());
+
+    let (color_space, color_vec, alpha) = if input.starts_with('#') {
+        let hex_str = parser::hex::parse_hex_str(&input)?;
+        (
+            ColorSpace::RGBA,
+            hex2rgba_with_order(&hex_str, HexOrder::Rgba).to_vec(),
+            None,
+        )
+    } else if input == "transparent" {
+        (ColorSpace::RGBA, vec![0.0, 0.0, 0.0, 0.0], None)
+    } else if input == "currentcolor" {
+        return Err(Error::CurrentColor);
+    } else if let Some(hex) = name_hex {
+        (
+            ColorSpace::RGB,
+            conversion::hex::hex2rgb(hex).to_vec(),
+            None,
+        )
+    } else if mode == ParseMode::Lenient && is_bare_hex {
+        let hex_str = parser::hex::parse_hex_str(format!("#{}", input))?;
+        (
+            ColorSpace::RGBA,
+            hex2rgba_with_order(&hex_str, HexOrder::Rgba).to_vec(),
+            None,
+        )
+    } else if parser::relative::is_relative(&input) {
+        let (color_space, values, alpha) =
+            parser::relative::parse_relative(&input, mode, depth, names)?;
+        (color_space, values, Some(alpha))
+    } else if let Some(color) = custom_name(&input, mode, names) {
+        return Ok(color);
+    } else if let Some(err) = unknown_name_error(&input, mode) {
+        return Err(err);
+    } else {
+        // tokenize the untrimmed input, so error offsets point into `s`
+        let mut parser = parser::Parser::new();
+        parser.mode = mode;
+        parser.tokenize(&s.to_lowercase()).validate()?;
+        (parser.color_space, parser.values, parser.alpha)
+    };
+
+    let [r, g, b] = convert_color_vec_by_color_space(&color_vec, &color_space);
+    let has_alpha = matches!(
+        color_space,
+        ColorSpace::RGB | ColorSpace::RGBA | ColorSpace::HEX | ColorSpace::HEXA | ColorSpace::HSLA
+    );
+    let alpha = match alpha {
+        Some(alpha) => alpha,
+        None if has_alpha && color_vec.len() > 3 => color_vec[3],
+        None => 1.0,
+    };
+
+    Ok(Color::new(r, g, b, alpha))
 }
 
-fn convert_color_vec_by_color_space(color_vec: &[f64], color_space: &ColorSpace) -> Vec<f64> {
+fn convert_color_vec_by_color_space(color_vec: &[f64], color_space: &ColorSpace) -> [f64; 3] {
+    let color = &[color_vec[0], color_vec[1], color_vec[2]];
     match color_space {
-        ColorSpace::RGB | ColorSpace::RGBA | ColorSpace::HEX | ColorSpace::HEXA => {
-            color_vec.to_vec()
-        }
-        ColorSpace::HSI => conversion::hsi::hsi2rgb(color_vec),
-        ColorSpace::HSL => conversion::hsl::hsl2rgb(color_vec),
-        ColorSpace::HSV => conversion::hsv::hsv2rgb(color_vec),
-        ColorSpace::CMYK => conversion::cmyk::cmyk2rgb(color_vec),
-        ColorSpace::XYZ => conversion::xyz::xyz2rgb(color_vec),
-        ColorSpace::YIQ => conversion::yiq::yiq2rgb(color_vec),
-        ColorSpace::YUV => conversion::yuv::yuv2rgb(color_vec),
-        ColorSpace::YCbCr => conversion::ycbcr::ycbcr2rgb(color_vec),
-        ColorSpace::Lab => conversion::lab::lab2rgb(color_vec),
-        ColorSpace::HWB => conversion::hwb::hwb2rgb(color_vec),
-        ColorSpace::HSLA => {
-            let mut rgb = conversion::hsl::hsl2rgb(color_vec);
-            rgb.push(color_vec[3]);
-            rgb
+        ColorSpace::RGB | ColorSpace::RGBA | ColorSpace::HEX | ColorSpace::HEXA => *color,
+        ColorSpace::HSI => conversion::hsi::hsi2rgb(color),
+        ColorSpace::HSL | ColorSpace::HSLA => conversion::hsl::hsl2rgb(color),
+        ColorSpace::HSV => conversion::hsv::hsv2rgb(color),
+        ColorSpace::CMYK => {
+            conversion::cmyk::cmyk2rgb(&[color[0], color[1], color[2], color_vec[3]])
         }
+        ColorSpace::XYZ => conversion::xyz::xyz2rgb(color),
+        ColorSpace::YIQ => conversion::yiq::yiq2rgb(color),
+        ColorSpace::YUV => conversion::yuv::yuv2rgb(color),
+        ColorSpace::YCbCr => conversion::ycbcr::ycbcr2rgb(color),
+        ColorSpace::YPbPr => clip(conversion::ypbpr::ypbpr2rgb(color, ColorMatrix::BT601)),
+        ColorSpace::Lab => clip(conversion::lab::lab2rgb(color)),
+        ColorSpace::LCH => clip(conversion::lch::lch2rgb(color)),
+        ColorSpace::Luv => clip(conversion::luv::luv2rgb(color)),
+        ColorSpace::LCHuv => clip(conversion::lchuv::lchuv2rgb(color)),
+        ColorSpace::OKLab => clip(conversion::oklab::oklab2rgb(color)),
+        ColorSpace::OKLCH => clip(conversion::oklch::oklch2rgb(color)),
+        ColorSpace::Rec2020 => conversion::rec2020::rec2020_to_rgb(color),
+        ColorSpace::HWB => conversion::hwb::hwb2rgb(color),
         ColorSpace::Unknown => todo!(),
     }
 }
 
+/// clip RGB values outside of the sRGB gamut, like the matching `Color::from_*` constructors
+fn clip(rgb: [f64; 3]) -> [f64; 3] {
+    rgb.map(|v| v.clamp(0.0, 255.0))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Error;
@@ -121,6 +345,217 @@ mod tests {
         assert_eq!(color, Color::new(0.0, 255.0, 255.0, 1.0));
     }
 
+    #[test]
+    fn test_color_from_space_separated_str() {
+        let color = Color::from_str("rgb(255 0 0)").unwrap();
+        assert_eq!(color, Color::new(255.0, 0.0, 0.0, 1.0));
+
+        let color = Color::from_str("rgb(255 0 0 / 0.5)").unwrap();
+        assert_eq!(color, Color::new(255.0, 0.0, 0.0, 0.5));
+
+        let color = Color::from_str("rgba(255 0 0 / 50%)").unwrap();
+        assert_eq!(color, Color::new(255.0, 0.0, 0.0, 0.5));
+
+        let color = Color::from_str("hsl(120deg 50% 50% / 40%)").unwrap();
+        assert_eq!(color.rgba(), "rgba(64, 191, 64, 0.4)");
+
+        let color = Color::from_str("hsl(0.5turn 100% 50%)").unwrap();
+        assert_eq!(color.hex(), "#0ff");
+
+        let color = Color::from_str("hsv(120deg 100% 100% / 0.2)").unwrap();
+        assert_eq!(color.alpha(), 0.2);
+
+        let color = Color::from_str("rgb(255 0 0 / 1.5)");
+        assert!(color.is_err());
+
+        let color = Color::from_str("hsl(120px 50% 50%)");
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_color_function_str() {
+        let color = Color::from_str("color(srgb 1 0 0)").unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_str("color(srgb 100% 20% 60% / 0.5)").unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 51, 153, 0.5)");
+
+        let color = Color::from_str("color(srgb-linear 1 0.2158605 0)").unwrap();
+        assert_eq!(color.hex(), "#ff8000");
+
+        let color = Color::from_str("color(xyz-d65 0.412391 0.212639 0.019331)").unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_str("color(xyz 0.412391 0.212639 0.019331)").unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_str("color(xyz-d50 0.436066 0.222493 0.013924)").unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_str("color(rec2020 0.792 0.231 0.0738)").unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_str("color(display-p3 1 0 0)");
+        assert!(color.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "css-names")]
+    fn test_color_from_relative_str() {
+        let color = Color::from_str("rgb(from #ff0000 r g b / 0.5)").unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 0, 0, 0.5)");
+
+        let color = Color::from_str("hsl(from #ff0000 h s calc(l + 10%))").unwrap();
+        assert_eq!(color.hex(), "#f33");
+
+        let color = Color::from_str("hsl(from rgba(255, 0, 0, 0.4) calc(h + 180) s l)").unwrap();
+        assert_eq!(color.rgba(), "rgba(0, 255, 255, 0.4)");
+
+        let color = Color::from_str("oklch(from teal l c calc(h + 360deg))").unwrap();
+        assert_eq!(color.hex(), "#008080");
+
+        let color = Color::from_str("hsl(from var(--primary) h s calc(l + 10%))");
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_nested_relative_str() {
+        let nested = |n| format!("{}#f00{}", "rgb(from ".repeat(n), " r g b)".repeat(n));
+        assert_eq!(Color::from_str(&nested(8)).unwrap().hex(), "#f00");
+        let calc = |n| format!("rgb(from #f00 {}r{} g b)", "calc(".repeat(n), ")".repeat(n));
+        assert_eq!(Color::from_str(&calc(8)).unwrap().hex(), "#f00");
+
+        for input in [
+            nested(10_000),
+            calc(10_000),
+            format!("rgb(from #f00 {}r g b)", "-".repeat(10_000)),
+            format!(
+                "rgb(from #f00 {}r{} g b)",
+                "(".repeat(10_000),
+                ")".repeat(10_000)
+            ),
+        ] {
+            let color = Color::from_str(&input);
+            assert!(matches!(color, Err(Error::ColorParserError(_))));
+        }
+    }
+
+    #[test]
+    fn test_color_from_rgb_percentage_str() {
+        let color = Color::from_str("rgb(100%, 0%, 0%)").unwrap();
+        assert_eq!(color, Color::new(255.0, 0.0, 0.0, 1.0));
+
+        let color = Color::from_str("rgb(50% 25% 25%)").unwrap();
+        assert_eq!(color, Color::new(127.5, 63.75, 63.75, 1.0));
+
+        let color = Color::from_str("rgba(100%, 20%, 60%, 50%)").unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 51, 153, 0.5)");
+
+        let color = Color::from_str("rgb(127.5, 0.25, 255)").unwrap();
+        assert_eq!(color, Color::new(127.5, 0.25, 255.0, 1.0));
+
+        let color = Color::from_str("rgb(101%, 0%, 0%)");
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_str_parse_error() {
+        let err = Color::from_str("rgb(255, 0 0)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected ',' or ')' at offset 11, found '0'"
+        );
+
+        let err = Color::from_str("  hsl(120 50%, 50%)").unwrap_err();
+        assert_eq!(
+            err,
+            Error::ParseError {
+                offset: 13,
+                expected: "a value, '/' or ')'".to_string(),
+                found: "','".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_color_from_css_keywords() {
+        let color = Color::from_str("transparent").unwrap();
+        assert_eq!(color, Color::new(0.0, 0.0, 0.0, 0.0));
+
+        let color = Color::from_str_strict(" Transparent ").unwrap();
+        assert_eq!(color.rgba(), "rgba(0, 0, 0, 0)");
+
+        let color = Color::from_str("currentColor");
+        assert_eq!(color, Err(Error::CurrentColor));
+
+        let color = Color::from_str("rgb(from currentcolor r g b)");
+        assert_eq!(color, Err(Error::CurrentColor));
+    }
+
+    #[test]
+    #[cfg(feature = "css-names")]
+    fn test_color_from_str_strict() {
+        for s in [
+            "#ff0000",
+            "red",
+            "rgb(255, 0, 0)",
+            "rgba(255, 0, 0, 0.5)",
+            "rgb(255 0 0 / 0.5)",
+            "hwb(0 0% 0%)",
+            "lab(54.29 80.8 69.89)",
+            "color(srgb 1 0 0)",
+            "rgb(from red r g b)",
+        ] {
+            assert!(Color::from_str_strict(s).is_ok(), "{}", s);
+        }
+        for s in [
+            "ff0000",
+            "水绿",
+            "hsv(0, 100%, 100%)",
+            "cmyk(0, 100%, 100%, 0)",
+            "rgb(255, 0, 0 / 0.5)",
+            "hwb(0, 0%, 0%)",
+            "lab(54.29, 80.8, 69.89)",
+            "rgb(300, 0, 0)",
+            "hsv(from red h s v)",
+            "rgb(from 水绿 r g b)",
+            "rgb(from red calc(r + 10) g b)",
+        ] {
+            assert!(Color::from_str_strict(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "css-names")]
+    fn test_color_from_str_lenient() {
+        let color = Color::from_str_lenient("  FF3399 ").unwrap();
+        assert_eq!(color.hex(), "#f39");
+
+        let color = Color::from_str_lenient("rgb( 300 ,  128 , -5 )").unwrap();
+        assert_eq!(color, Color::new(255.0, 128.0, 0.0, 1.0));
+
+        let color = Color::from_str_lenient("rgb(255 0 0 / 150%)").unwrap();
+        assert_eq!(color.alpha(), 1.0);
+
+        let color = Color::from_str_lenient("hsl(-120, 100%, 50%)").unwrap();
+        assert_eq!(color.hex(), "#00f");
+
+        let color = Color::from_str_lenient("oklch(0.7, 0.5, 29.23)").unwrap();
+        assert_eq!(
+            color.oklch(),
+            Color::from_str("oklch(0.7, 0.4, 29.23)").unwrap().oklch()
+        );
+
+        let color = Color::from_str_lenient("rgb(from red calc(r + 10) g calc(b - 10))").unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_str_lenient("hsl(from red h s l / calc(alpha * 2))").unwrap();
+        assert_eq!(color.alpha(), 1.0);
+
+        assert!(Color::from_str_lenient("rgb(255, 0)").is_err());
+        assert!(Color::from_str_lenient("not a color").is_err());
+    }
+
     #[test]
     fn test_color_from_rgb_str_err() {
         let s = "rgb(256, 255, 255)";
@@ -199,9 +634,18 @@ mod tests {
         let s = "#00ffff";
         let color = Color::from_str(s).unwrap();
         assert_eq!(color, Color::new(0.0, 255.0, 255.0, 1.0));
+
+        let s = "#0ff8";
+        let color = Color::from_str(s).unwrap();
+        assert_eq!(color, Color::new(0.0, 255.0, 255.0, 136.0 / 255.0));
+
+        let s = "#00ffff80";
+        let color = Color::from_str(s).unwrap();
+        assert_eq!(color, Color::new(0.0, 255.0, 255.0, 128.0 / 255.0));
     }
 
     #[test]
+    #[cfg(feature = "css-names")]
     fn test_color_from_hex_str_err() {
         let s = "#gggggg";
         let color = Color::from_str(s);
@@ -213,10 +657,32 @@ mod tests {
             _ => panic!("Should have failed"),
         }
 
+        let s = "Whiet";
+        let color = Color::from_str(s);
+        match color {
+            Err(e) => {
+                assert_eq!(
+                    e,
+                    Error::UnknownColorName {
+                        name: "whiet".to_string(),
+                        suggestions: vec!["white".to_string()],
+                    }
+                );
+                assert_eq!(
+                    e.to_string(),
+                    "Unknown color name `whiet`, did you mean `white`?"
+                );
+            }
+            _ => panic!("Should have failed"),
+        }
+
         let s = "fff";
         let color = Color::from_str(s);
         match color {
-            Err(e) => assert_eq!(e.to_string(), "Invalid input"),
+            Err(e) => assert_eq!(
+                e.to_string(),
+                "Expected a color space name at offset 0, found 'fff'"
+            ),
             _ => panic!("Should have failed"),
         }
     }
@@ -262,6 +728,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "css-names")]
     fn test_color_from_name_str() {
         let s = "red";
         let color = Color::from_str(s).unwrap();
@@ -290,6 +757,18 @@ mod tests {
 
         let color = Color::from_str("cmyk(35%, 0, 60%, 0)").unwrap();
         assert_eq!(color.hex(), "#a6ff66");
+
+        let color = Color::from_str("cmyk(0%, 35%, 100%, 0%)").unwrap();
+        assert_eq!(color.rgb(), "rgb(255, 166, 0)");
+
+        let color = Color::from_str("cmyk(0% 35% 100% 0% / 50%)").unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 166, 0, 0.5)");
+
+        let color = Color::from_str("cmyk(100%, 0%, 0%, 49.8%)").unwrap();
+        assert_eq!(color.cmyk(), "cmyk(100%, 0%, 0%, 50%)");
+
+        let color = Color::from_str("cmyk(0%, 35%, 120%, 0%)");
+        assert!(color.is_err());
     }
 
     #[test]
@@ -298,7 +777,7 @@ mod tests {
         assert_eq!(color.rgb(), "rgb(255, 0, 0)");
 
         let color = Color::from_str("xyz(0.70047, 0.723315, 1.048516)").unwrap();
-        assert_eq!(color.rgb(), "rgb(92, 122, 255)");
+        assert_eq!(color.rgb(), "rgb(209, 221, 255)");
     }
 
     #[test]
@@ -316,6 +795,117 @@ mod tests {
         assert_eq!(color.rgb(), "rgb(255, 255, 0)");
     }
 
+    #[test]
+    fn test_color_from_lab_str() {
+        let color = Color::from_str("lab(97.61, -15.75, 93.39)").unwrap();
+        assert_eq!(color.rgb(), "rgb(255, 255, 0)");
+
+        let color = Color::from_str("lab(52% 40 59)").unwrap();
+        assert_eq!(color.rgb(), "rgb(197, 92, 10)");
+
+        let color = Color::from_str("lab(52% 32% 47.2% / 0.5)").unwrap();
+        assert_eq!(color.rgba(), "rgba(197, 92, 10, 0.5)");
+
+        let color = Color::from_str("lab(50% 125 -125)").unwrap();
+        assert_eq!(color, Color::from_lab(50.0, 125.0, -125.0).unwrap());
+        assert_eq!(color.vec_of(ColorSpace::RGB), vec![214.0, 0.0, 255.0]);
+
+        let color = Color::from_str("lab(120% 40 59)");
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_lch_str() {
+        let color = Color::from_str("lch(97.61, 94.71, 99.57)").unwrap();
+        assert_eq!(color.rgb(), "rgb(255, 255, 0)");
+
+        let color = Color::from_str("lch(54.29, 106.84, 40.86)").unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_str("lch(52% 72 50)").unwrap();
+        assert_eq!(color.rgb(), "rgb(205, 85, 26)");
+
+        let color = Color::from_str("lch(52% 48% 50deg / 25%)").unwrap();
+        assert_eq!(color.rgba(), "rgba(205, 85, 26, 0.25)");
+
+        let color = Color::from_str("lch(50% 150 300)").unwrap();
+        assert_eq!(color, Color::from_lch(50.0, 150.0, 300.0).unwrap());
+        let rgb = color.vec_of(ColorSpace::RGB);
+        assert!(rgb.iter().all(|v| (0.0..=255.0).contains(v)));
+
+        let color = Color::from_str("lch(54.29, 106.84, 400)");
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_luv_str() {
+        let color = Color::from_str("luv(53.24, 175.01, 37.77)").unwrap();
+        assert_eq!(color.rgb(), "rgb(255, 0, 0)");
+
+        let color = Color::from_str("lchuv(53.24, 179.04, 12.18)").unwrap();
+        assert_eq!(color.rgb(), "rgb(255, 0, 0)");
+
+        let color = Color::from_str("luv(0.01, 100, -100)").unwrap();
+        assert_eq!(color, Color::from_luv(0.01, 100.0, -100.0).unwrap());
+        let rgb = color.vec_of(ColorSpace::RGB);
+        assert!(rgb.iter().all(|v| (0.0..=255.0).contains(v)));
+
+        let color = Color::from_str("luv(53.24, 175.01, 200)");
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_oklab_str() {
+        let color = Color::from_str("oklab(0.628, 0.2249, 0.1258)").unwrap();
+        assert_eq!(color.rgb(), "rgb(255, 0, 0)");
+
+        let color = Color::from_str("oklab(100%, 0, 0)").unwrap();
+        assert_eq!(color.hex(), "#fff");
+
+        let color = Color::from_str("oklab(0.9 0.4 0.4)").unwrap();
+        assert_eq!(color, Color::from_oklab(0.9, 0.4, 0.4).unwrap());
+        assert_eq!(color.vec_of(ColorSpace::RGB), vec![255.0, 0.0, 0.0]);
+
+        let color = Color::from_str("oklab(0.5, 0.6, 0)");
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_rec2020_str() {
+        let color = Color::from_str("rec2020(0.792, 0.231, 0.0738)").unwrap();
+        assert_eq!(color.rgb(), "rgb(255, 0, 0)");
+
+        let color = Color::from_str("rec2020(100%, 100%, 100%)").unwrap();
+        assert_eq!(color.hex(), "#fff");
+
+        let color = Color::from_str("rec2020(1.2, 0, 0)");
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_oklch_str() {
+        let color = Color::from_str("oklch(0.628, 0.2577, 29.23)").unwrap();
+        assert_eq!(color.rgb(), "rgb(255, 0, 0)");
+
+        let color = Color::from_str("oklch(62.8%, 0.2577, 29.23)").unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_str("oklch(1.2, 0.2577, 29.23)");
+        assert!(color.is_err());
+    }
+
+    #[test]
+    fn test_color_from_ypbpr_str() {
+        let color = Color::from_str("YPbPr(0.299, -0.1687, 0.5)").unwrap();
+        assert_eq!(color.rgb(), "rgb(255, 0, 0)");
+
+        let color = Color::from_str("ypbpr(1, 0.5, 0.5)").unwrap();
+        assert_eq!(color.vec_of(ColorSpace::RGB), vec![255.0, 120.0, 255.0]);
+
+        let color = Color::from_str("ypbpr(0.299, -0.1687, 0.6)");
+        assert!(color.is_err());
+    }
+
     #[test]
     fn test_color_from_yiq_str() {
         let color = Color::from_str("yiq(0.42337, -0.07301, 0.17583)").unwrap();
@@ -0,0 +1,213 @@
+use crate::{
+    conversion::{
+        acescg::{acescg2rgb, rgb2acescg},
+        rec2020::{lin_rec2020_to_lin_srgb, lin_srgb_to_lin_rec2020},
+        utils::{gam_srgb, lin_srgb},
+    },
+    utils::normalize_color,
+    Color, Error,
+};
+use alloc::{format, vec::Vec};
+
+impl Color {
+    /// Get the linear-light `[r, g, b]` values of the color, each in the range 0.0 to 1.0.
+    ///
+    /// The sRGB transfer function is removed, so the values are proportional to physical light intensity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let color = color!(#ff8000);
+    /// let linear = color.to_linear();
+    /// assert_eq!(linear[0], 1.0);
+    /// assert!((linear[1] - 0.2158605).abs() < 1e-6);
+    /// assert_eq!(linear[2], 0.0);
+    /// ```
+    pub fn to_linear(&self) -> Vec<f64> {
+        lin_srgb(&normalize_color(&self.rgb)).to_vec()
+    }
+    /// The linear-light `[r, g, b, a]` values of the color, the fields of a `wgpu::Color`.
+    ///
+    /// wgpu expects linear values, e.g. for the clear color of an sRGB render target, so the
+    /// sRGB transfer function is removed like [`Color::to_linear`]. The alpha is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let [r, g, b, a] = Color::new(255, 128, 0, 0.5).to_wgpu_color();
+    /// assert_eq!((r, b, a), (1.0, 0.0, 0.5));
+    /// assert!((g - 0.2158605).abs() < 1e-6);
+    /// ```
+    pub fn to_wgpu_color(&self) -> [f64; 4] {
+        let [r, g, b] = lin_srgb(&normalize_color(&self.rgb));
+        [r, g, b, self.alpha]
+    }
+    /// Create a color from linear-light RGB values.
+    ///
+    /// The sRGB transfer function is applied to the values, the inverse of [`Color::to_linear`].
+    ///
+    /// # Parameters
+    ///
+    /// - `r`: Linear red value (0.0-1.0)
+    /// - `g`: Linear green value (0.0-1.0)
+    /// - `b`: Linear blue value (0.0-1.0)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_linear(1.0, 0.2158605, 0.0).unwrap();
+    /// assert_eq!(color.hex(), "#ff8000");
+    /// ```
+    pub fn from_linear(r: f64, g: f64, b: f64) -> Result<Self, Error> {
+        let linear = valid_linear([r, g, b])?;
+        let rgb = gam_srgb(&linear).map(|v| v * 255.0);
+        Ok(Color::new(rgb[0], rgb[1], rgb[2], 1.0))
+    }
+    /// Get the linear-light Rec. 2020 `[r, g, b]` values of the color.
+    ///
+    /// sRGB is inside the Rec. 2020 gamut, so the values are always in the range 0.0 to 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let linear = color!(#fff).to_rec2020_linear();
+    /// assert!(linear.iter().all(|v| (v - 1.0).abs() < 1e-9));
+    /// ```
+    pub fn to_rec2020_linear(&self) -> Vec<f64> {
+        lin_srgb_to_lin_rec2020(&lin_srgb(&normalize_color(&self.rgb))).to_vec()
+    }
+    /// Create a color from linear-light Rec. 2020 RGB values.
+    ///
+    /// Colors outside of the sRGB gamut are clipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_rec2020_linear(1.0, 1.0, 1.0).unwrap();
+    /// assert_eq!(color.hex(), "#fff");
+    /// ```
+    pub fn from_rec2020_linear(r: f64, g: f64, b: f64) -> Result<Self, Error> {
+        let linear = valid_linear([r, g, b])?;
+        let rgb = gam_srgb(&lin_rec2020_to_lin_srgb(&linear)).map(|v| v.clamp(0.0, 1.0) * 255.0);
+        Ok(Color::new(rgb[0], rgb[1], rgb[2], 1.0))
+    }
+    /// Get the `(r, g, b)` values of the color in ACEScg, the scene-linear working space
+    /// with the AP1 primaries used by film and VFX pipelines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let (r, g, b) = color!(#f00).to_acescg();
+    /// assert!((r - 0.6131).abs() < 1e-4);
+    /// assert!((g - 0.0702).abs() < 1e-4);
+    /// assert!((b - 0.0206).abs() < 1e-4);
+    /// ```
+    pub fn to_acescg(&self) -> (f64, f64, f64) {
+        let acescg = rgb2acescg(&self.rgb);
+        (acescg[0], acescg[1], acescg[2])
+    }
+    /// Create a color from ACEScg values.
+    ///
+    /// Colors outside of the sRGB gamut are clipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_acescg(0.6131, 0.0702, 0.0206).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// ```
+    pub fn from_acescg(r: f64, g: f64, b: f64) -> Result<Self, Error> {
+        let acescg = valid_linear([r, g, b])?;
+        let rgb = acescg2rgb(&acescg);
+        Ok(Color::new(rgb[0], rgb[1], rgb[2], 1.0))
+    }
+}
+
+fn valid_linear(linear: [f64; 3]) -> Result<[f64; 3], Error> {
+    match linear.iter().find(|v| !(0.0..=1.0).contains(*v)) {
+        Some(v) => Err(Error::InvalidParamsError(format!(
+            "Linear RGB values must be between 0.0 and 1.0, got {}",
+            v
+        ))),
+        None => Ok(linear),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{utils::round, *};
+
+    #[test]
+    fn test_to_linear() {
+        assert_eq!(color!(#fff).to_linear(), vec![1.0, 1.0, 1.0]);
+        assert_eq!(color!(#000).to_linear(), vec![0.0, 0.0, 0.0]);
+
+        let linear = color!(#808080).to_linear();
+        assert_eq!(round(linear[0], 4), 0.2159);
+    }
+
+    #[test]
+    fn test_to_wgpu_color() {
+        assert_eq!(color!(#fff).to_wgpu_color(), [1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(Color::TRANSPARENT.to_wgpu_color(), [0.0; 4]);
+
+        let color = Color::new(51, 102, 204, 0.25);
+        let [r, g, b, a] = color.to_wgpu_color();
+        assert_eq!(color.to_linear(), vec![r, g, b]);
+        assert_eq!(a, 0.25);
+    }
+
+    #[test]
+    fn test_from_linear() {
+        let color = Color::from_linear(0.2158605, 0.2158605, 0.2158605).unwrap();
+        assert_eq!(color.hex(), "#808080");
+
+        let color = color!(#ff3399);
+        let linear = color.to_linear();
+        let color2 = Color::from_linear(linear[0], linear[1], linear[2]).unwrap();
+        assert_eq!(color2.hex(), "#f39");
+
+        assert!(Color::from_linear(1.5, 0.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_acescg() {
+        let (r, g, b) = color!(#fff).to_acescg();
+        assert_eq!((round(r, 4), round(g, 4), round(b, 4)), (1.0, 1.0, 1.0));
+
+        let (r, g, b) = color!(#ff3399).to_acescg();
+        let color = Color::from_acescg(r, g, b).unwrap();
+        assert_eq!(color.hex(), "#f39");
+
+        assert!(Color::from_acescg(-0.1, 0.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_rec2020_linear() {
+        let color = color!(#f00);
+        let linear = color.to_rec2020_linear();
+        assert_eq!(round(linear[0], 4), 0.6274);
+        assert_eq!(round(linear[1], 4), 0.0691);
+        assert_eq!(round(linear[2], 4), 0.0164);
+
+        let color2 = Color::from_rec2020_linear(linear[0], linear[1], linear[2]).unwrap();
+        assert_eq!(color2.hex(), "#f00");
+
+        let color = Color::from_rec2020_linear(0.0, 1.0, 0.0).unwrap();
+        assert_eq!(color.hex(), "#0f0");
+    }
+}
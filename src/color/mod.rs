@@ -1,19 +1,34 @@
+pub mod ansi;
+pub mod array;
+#[cfg(feature = "bevy")]
+mod bevy;
+pub mod cam16;
+pub mod classify;
+pub mod color32;
 pub mod color_channel;
 pub mod color_macros;
+pub mod color_u8;
+pub mod formatter;
 pub mod from_num;
 pub mod from_space;
 pub mod from_str;
+pub mod linear;
+pub mod names;
+pub mod premultiplied;
 pub mod stringify;
+pub mod temperature;
+pub mod tokens;
 pub mod vec_of;
 
-use std::fmt::Display;
-
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use core::fmt::{self, Debug, Display, LowerHex, UpperHex};
 
 /// Color is a struct that represents a color.
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(PartialEq, Debug, Clone, Copy)]
+///
+/// With the `serde` feature, it is serialized as a hex string, see [`crate::color_serde`].
+///
+/// With the `wasm` feature, it is exported to JavaScript as the `Color` class.
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "wasm", wasm_bindgen::prelude::wasm_bindgen)]
 pub struct Color {
     pub(crate) rgb: [f64; 3],
     pub(crate) alpha: f64,
@@ -33,21 +48,143 @@ impl Color {
             alpha,
         }
     }
+    /// Creates an opaque [`Color`] from 8-bit channels, usable in constants and statics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// const BRAND: Color = Color::from_rgb8(255, 51, 153);
+    /// assert_eq!(BRAND.hex(), "#f39");
+    /// ```
+    pub const fn from_rgb8(r: u8, g: u8, b: u8) -> Self {
+        Color::from_rgba8(r, g, b, 1.0)
+    }
+    /// Creates a [`Color`] from 8-bit channels and an alpha (0-1), usable in constants and statics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// static OVERLAY: Color = Color::from_rgba8(0, 0, 0, 0.5);
+    /// assert_eq!(OVERLAY.rgba(), "rgba(0, 0, 0, 0.5)");
+    /// ```
+    pub const fn from_rgba8(r: u8, g: u8, b: u8, alpha: f64) -> Self {
+        Color {
+            rgb: [r as f64, g as f64, b as f64],
+            alpha,
+        }
+    }
+
+    /// Fully transparent black, `rgba(0, 0, 0, 0)`.
+    pub const TRANSPARENT: Color = Color::from_rgba8(0, 0, 0, 0.0);
+    /// CSS `black`, `#000000`.
+    pub const BLACK: Color = Color::from_rgb8(0, 0, 0);
+    /// CSS `white`, `#ffffff`.
+    pub const WHITE: Color = Color::from_rgb8(255, 255, 255);
+    /// CSS `gray`, `#808080`.
+    pub const GRAY: Color = Color::from_rgb8(128, 128, 128);
+    /// CSS `silver`, `#c0c0c0`.
+    pub const SILVER: Color = Color::from_rgb8(192, 192, 192);
+    /// CSS `red`, `#ff0000`.
+    pub const RED: Color = Color::from_rgb8(255, 0, 0);
+    /// CSS `maroon`, `#800000`.
+    pub const MAROON: Color = Color::from_rgb8(128, 0, 0);
+    /// CSS `orange`, `#ffa500`.
+    pub const ORANGE: Color = Color::from_rgb8(255, 165, 0);
+    /// CSS `yellow`, `#ffff00`.
+    pub const YELLOW: Color = Color::from_rgb8(255, 255, 0);
+    /// CSS `olive`, `#808000`.
+    pub const OLIVE: Color = Color::from_rgb8(128, 128, 0);
+    /// CSS `lime`, `#00ff00`, the full green channel.
+    pub const LIME: Color = Color::from_rgb8(0, 255, 0);
+    /// CSS `green`, `#008000`. See [`Color::LIME`] for the full green channel.
+    pub const GREEN: Color = Color::from_rgb8(0, 128, 0);
+    /// CSS `cyan`, `#00ffff`.
+    pub const CYAN: Color = Color::from_rgb8(0, 255, 255);
+    /// CSS `teal`, `#008080`.
+    pub const TEAL: Color = Color::from_rgb8(0, 128, 128);
+    /// CSS `blue`, `#0000ff`.
+    pub const BLUE: Color = Color::from_rgb8(0, 0, 255);
+    /// CSS `navy`, `#000080`.
+    pub const NAVY: Color = Color::from_rgb8(0, 0, 128);
+    /// CSS `magenta`, `#ff00ff`.
+    pub const MAGENTA: Color = Color::from_rgb8(255, 0, 255);
+    /// CSS `purple`, `#800080`.
+    pub const PURPLE: Color = Color::from_rgb8(128, 0, 128);
 }
 
 impl Default for Color {
     /// default returns a black color.
     fn default() -> Self {
-        Color::new(0, 0, 0, 1.0)
+        Color::BLACK
     }
 }
 
 impl Display for Color {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    /// Formats the color as its simplified hex string, with the alpha channel if it is not opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 51, 153, 0.5);
+    /// assert_eq!(format!("{}", color), "#ff339980");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.hex())
     }
 }
 
+impl LowerHex for Color {
+    /// Formats the color as its full lowercase hex string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 255, 255, 1.0);
+    /// assert_eq!(format!("{:x}", color), "#ffffff");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.hex_full())
+    }
+}
+
+impl UpperHex for Color {
+    /// Formats the color as its full uppercase hex string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 51, 153, 0.5);
+    /// assert_eq!(format!("{:X}", color), "#FF339980");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.hex_full().to_uppercase())
+    }
+}
+
+impl Debug for Color {
+    /// Formats the color with its `rgb` and `hsl` values and alpha, for readable test failures.
+    ///
+    /// The `hsl` values are left out if a channel is not finite, they can't be converted.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Color");
+        debug.field("rgb", &format_args!("{:?}", self.rgb));
+        if self.rgb.iter().all(|v| v.is_finite()) {
+            debug.field("hsl", &format_args!("{}", self.hsl()));
+        }
+        debug.field("alpha", &self.alpha).finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,9 +195,71 @@ mod tests {
         assert_eq!(color, Color::new(0, 0, 0, 1.0));
     }
 
+    #[test]
+    #[cfg(feature = "css-names")]
+    fn test_color_constants() {
+        let constants = [
+            ("black", Color::BLACK),
+            ("white", Color::WHITE),
+            ("gray", Color::GRAY),
+            ("silver", Color::SILVER),
+            ("red", Color::RED),
+            ("maroon", Color::MAROON),
+            ("orange", Color::ORANGE),
+            ("yellow", Color::YELLOW),
+            ("olive", Color::OLIVE),
+            ("lime", Color::LIME),
+            ("green", Color::GREEN),
+            ("cyan", Color::CYAN),
+            ("teal", Color::TEAL),
+            ("blue", Color::BLUE),
+            ("navy", Color::NAVY),
+            ("magenta", Color::MAGENTA),
+            ("purple", Color::PURPLE),
+        ];
+        for (name, color) in constants {
+            assert_eq!(Color::from_name(name).unwrap(), color);
+        }
+        assert_eq!(Color::TRANSPARENT, Color::new(0, 0, 0, 0.0));
+
+        static STATIC: Color = Color::from_rgba8(255, 51, 153, 0.5);
+        assert_eq!(STATIC, Color::new(255, 51, 153, 0.5));
+        assert_eq!(
+            Color::from_rgba_u32(0xff339980),
+            Color::from_rgba8(255, 51, 153, 128.0 / 255.0)
+        );
+    }
+
     #[test]
     fn test_color_display() {
         let color = Color::new(255, 255, 0, 1.0);
-        println!("color is \"{}\"", color);
+        assert_eq!(format!("color is \"{}\"", color), "color is \"#ff0\"");
+        assert_eq!(format!("{:x} {:X}", color, color), "#ffff00 #FFFF00");
+
+        let color = Color::new(0, 0, 0, 0.2);
+        assert_eq!(
+            format!("{} {:x} {:X}", color, color, color),
+            "#0003 #00000033 #00000033"
+        );
+    }
+
+    #[test]
+    fn test_color_debug() {
+        let color = Color::new(255.0, 51.0, 153.0, 0.5);
+        assert_eq!(
+            format!("{:?}", color),
+            "Color { rgb: [255.0, 51.0, 153.0], hsl: hsl(330, 100%, 60%), alpha: 0.5 }"
+        );
+
+        let color = Color::new(f64::NAN, f64::NAN, f64::NAN, 1.0);
+        assert_eq!(
+            format!("{:?}", color),
+            "Color { rgb: [NaN, NaN, NaN], alpha: 1.0 }"
+        );
+        let color = Color::new(f64::INFINITY, 0.0, f64::NEG_INFINITY, f64::NAN);
+        assert_eq!(
+            format!("{:?}", color),
+            "Color { rgb: [inf, 0.0, -inf], alpha: NaN }"
+        );
     }
 }
@@ -0,0 +1,169 @@
+use crate::{
+    color_calc::delta_e::ciede2000,
+    conversion::{
+        hex::{hex2rgb, rgb2hex},
+        lab::rgb2lab,
+    },
+    data::{name_of_hex, CATALOGS},
+    Color, NameRegistry, NameSource,
+};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec,
+};
+
+/// Color names of the built-in catalogs, see [`NameSource`] for the cargo features enabling them.
+impl Color {
+    /// The name of the color and the catalog it comes from, if it is exactly a named color.
+    ///
+    /// The catalogs are searched in the order of [`NameSource`], so custom names of the
+    /// [`NameRegistry`] come first, then CSS names. Transparent colors only have custom names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, NameSource};
+    ///
+    /// let color = color!(#ffff00);
+    /// assert_eq!(color.name_with_source(), Some(("yellow".to_string(), NameSource::Css)));
+    ///
+    /// let color = color!(#f8df72);
+    /// assert_eq!(color.name_with_source(), Some(("茉莉黄".to_string(), NameSource::Chinese)));
+    ///
+    /// let color = color!(#123456);
+    /// assert_eq!(color.name_with_source(), None);
+    /// ```
+    pub fn name_with_source(&self) -> Option<(String, NameSource)> {
+        let custom = NameRegistry::with_installed(|registry| {
+            registry
+                .name_of(self)
+                .map(|name| (name.to_string(), NameSource::Custom))
+        });
+        if custom.is_some() || self.alpha != 1.0 {
+            return custom;
+        }
+        name_of_hex(&rgb2hex(self.rgb)).map(|(name, source)| (name.to_string(), source))
+    }
+    /// The perceptually nearest named color of all enabled catalogs, by [`delta_e`](crate::delta_e).
+    ///
+    /// The alpha of the color is ignored. Equally near names are taken in the order of
+    /// [`NameSource`]. `None` if no catalog is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, NameSource};
+    ///
+    /// let color = Color::new(255, 255, 254, 1.0);
+    /// assert_eq!(color.nearest_name(), Some(("white".to_string(), NameSource::Css)));
+    /// ```
+    pub fn nearest_name(&self) -> Option<(String, NameSource)> {
+        self.nearest_name_in(&[
+            NameSource::Custom,
+            NameSource::Css,
+            NameSource::Chinese,
+            NameSource::X11,
+            NameSource::Xkcd,
+            NameSource::Japanese,
+        ])
+    }
+    /// The perceptually nearest named color of some catalogs, by [`delta_e`](crate::delta_e).
+    ///
+    /// Catalogs disabled by their cargo feature are skipped, `None` if none is left.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, NameSource};
+    ///
+    /// let color = Color::new(250, 5, 5, 0.5);
+    /// assert_eq!(color.nearest_name_in(&[NameSource::Css]), Some(("red".to_string(), NameSource::Css)));
+    /// ```
+    pub fn nearest_name_in(&self, sources: &[NameSource]) -> Option<(String, NameSource)> {
+        let lab = rgb2lab(&self.rgb);
+        let distance = |rgb: &[f64; 3]| ciede2000(&lab, &rgb2lab(rgb));
+
+        let custom = if sources.contains(&NameSource::Custom) {
+            NameRegistry::with_installed(|registry| {
+                registry
+                    .iter()
+                    .map(|(name, color)| {
+                        let name = Cow::Owned(name.to_string());
+                        (distance(&color.rgb), name, NameSource::Custom)
+                    })
+                    .collect()
+            })
+        } else {
+            vec![]
+        };
+        let built_in = CATALOGS
+            .iter()
+            .filter(|catalog| sources.contains(&catalog.source))
+            .flat_map(|catalog| {
+                catalog.hexes.entries().map(|(hex, name)| {
+                    (
+                        distance(&hex2rgb(hex)),
+                        Cow::Borrowed(*name),
+                        catalog.source,
+                    )
+                })
+            });
+        custom
+            .into_iter()
+            .chain(built_in)
+            .min_by(|(d1, ..), (d2, ..)| d1.total_cmp(d2))
+            .map(|(_, name, source)| (name.into_owned(), source))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    #[cfg(all(feature = "css-names", feature = "chinese-names"))]
+    fn test_name_with_source() {
+        assert_eq!(
+            color!(#00ffff).name_with_source(),
+            Some(("aqua".to_string(), NameSource::Css))
+        );
+        assert_eq!(color!(#8cc269).name(), "水绿");
+        assert_eq!(
+            color!(#8cc269).name_with_source(),
+            Some(("水绿".to_string(), NameSource::Chinese))
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "css-names", feature = "chinese-names"))]
+    fn test_nearest_name() {
+        assert_eq!(
+            color!(#fffffe).nearest_name(),
+            Some(("white".to_string(), NameSource::Css))
+        );
+        assert_eq!(
+            Color::new(0, 0, 130, 1.0).nearest_name_in(&[NameSource::Css]),
+            Some(("navy".to_string(), NameSource::Css))
+        );
+        assert_eq!(
+            color!(#8dc26a).nearest_name_in(&[NameSource::Chinese]),
+            Some(("水绿".to_string(), NameSource::Chinese))
+        );
+    }
+
+    #[test]
+    fn test_no_name() {
+        assert_eq!(Color::new(255, 255, 0, 0.5).name_with_source(), None);
+        assert_eq!(color!(#fff).nearest_name_in(&[]), None);
+    }
+
+    #[test]
+    #[cfg(feature = "xkcd-names")]
+    fn test_nearest_xkcd_name() {
+        assert_eq!(
+            color!(#c1747b).nearest_name_in(&[NameSource::Xkcd]),
+            Some(("dusty rose".to_string(), NameSource::Xkcd))
+        );
+    }
+}
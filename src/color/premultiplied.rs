@@ -0,0 +1,101 @@
+use crate::{Color, Error};
+use alloc::format;
+
+/// multiply normalized `[r, g, b]` channels by the alpha
+pub(crate) fn premultiply(rgba: &[f64; 4]) -> [f64; 4] {
+    let [r, g, b, alpha] = *rgba;
+    [r * alpha, g * alpha, b * alpha, alpha]
+}
+
+/// divide premultiplied `[r, g, b]` channels by the alpha, transparent black if the alpha is 0
+pub(crate) fn unpremultiply(rgba: &[f64; 4]) -> [f64; 4] {
+    let [r, g, b, alpha] = *rgba;
+    if alpha <= 0.0 {
+        return [0.0; 4];
+    }
+    [r / alpha, g / alpha, b / alpha, alpha]
+}
+
+impl Color {
+    /// The premultiplied `[r, g, b, a]` values of the color, each in the range 0.0 to 1.0.
+    ///
+    /// The sRGB channels are multiplied by the alpha, the way GPU and video pipelines store
+    /// translucent colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let color = color!(rgba(255, 51, 0, 0.5));
+    /// assert_eq!(color.to_premultiplied(), [0.5, 0.1, 0.0, 0.5]);
+    /// ```
+    pub fn to_premultiplied(&self) -> [f64; 4] {
+        let [r, g, b] = self.rgb.map(|v| v / 255.0);
+        premultiply(&[r, g, b, self.alpha])
+    }
+    /// Create a color from premultiplied `[r, g, b, a]` values, each in the range 0.0 to 1.0.
+    ///
+    /// The channels are divided by the alpha, a fully transparent color is transparent black.
+    /// A channel greater than the alpha can't be premultiplied, so it is an error instead of a
+    /// silently wrong color, as is the alpha being out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_premultiplied([0.5, 0.1, 0.0, 0.5]).unwrap();
+    /// assert_eq!(color.rgba(), "rgba(255, 51, 0, 0.5)");
+    ///
+    /// // straight, not premultiplied values
+    /// assert!(Color::from_premultiplied([1.0, 0.2, 0.0, 0.5]).is_err());
+    /// ```
+    pub fn from_premultiplied(rgba: [f64; 4]) -> Result<Self, Error> {
+        let [r, g, b, alpha] = rgba;
+        if !(0.0..=1.0).contains(&alpha) || [r, g, b].iter().any(|v| !(0.0..=alpha).contains(v)) {
+            return Err(Error::InvalidParamsError(format!(
+                "Invalid premultiplied color {:?}, the channels must be between 0 and the alpha, and the alpha between 0 and 1",
+                rgba
+            )));
+        }
+        let [r, g, b, alpha] = unpremultiply(&rgba);
+        Ok(Color::new(r * 255.0, g * 255.0, b * 255.0, alpha))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_premultiplied_round_trip() {
+        for color in [
+            color!(#f39),
+            color!(rgba(30, 144, 255, 0.25)),
+            color!(rgba(255, 255, 255, 0.01)),
+            Color::BLACK,
+        ] {
+            let premultiplied = color.to_premultiplied();
+            assert!(premultiplied[..3].iter().all(|v| *v <= premultiplied[3]));
+            assert_eq!(
+                Color::from_premultiplied(premultiplied).unwrap().rgba(),
+                color.rgba()
+            );
+        }
+        assert_eq!(
+            Color::from_premultiplied([0.0; 4]).unwrap(),
+            Color::TRANSPARENT
+        );
+        assert_eq!(Color::TRANSPARENT.to_premultiplied(), [0.0; 4]);
+    }
+
+    #[test]
+    fn test_from_premultiplied_error() {
+        assert!(Color::from_premultiplied([0.6, 0.0, 0.0, 0.5]).is_err());
+        assert!(Color::from_premultiplied([0.1, 0.0, 0.0, 0.0]).is_err());
+        assert!(Color::from_premultiplied([0.0, -0.1, 0.0, 1.0]).is_err());
+        assert!(Color::from_premultiplied([0.0, 0.0, 0.0, 1.5]).is_err());
+        assert!(Color::from_premultiplied([0.0, 0.0, f64::NAN, 1.0]).is_err());
+    }
+}
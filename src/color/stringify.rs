@@ -1,21 +1,32 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use core::fmt;
+
 use crate::{
     conversion::{
         cmyk::rgb2cmyk,
-        hex::{rgb2hex, rgba2hex},
+        hex::rgb2hex,
         hsi::rgb2hsi,
         hsl::rgb2hsl,
         hsv::rgb2hsv,
         hwb::rgb2hwb,
-        lab::rgb2lab,
-        xyz::rgb2xyz,
-        ycbcr::rgb2ycbcr,
+        lab::{rgb2lab, rgb2lab_with_illuminant},
+        lch::rgb2lch,
+        lchuv::rgb2lchuv,
+        luv::rgb2luv,
+        oklab::rgb2oklab,
+        oklch::rgb2oklch,
+        rec2020::rgb2rec2020,
+        xyz::{rgb2xyz, rgb2xyz_with_illuminant},
+        ycbcr::{rgb2ycbcr, rgb2ycbcr_with},
         yiq::rgb2yiq,
+        ypbpr::rgb2ypbpr,
         yuv::rgb2yuv,
     },
-    data::name_of_hex,
-    utils::{hex::simplify_hex, round},
-    Color,
+    utils::round,
+    AlphaFormat, Color, ColorFormatter, ColorMatrix, CssSyntax, Illuminant, YCbCrRange,
 };
+use alloc::{format, string::String, vec::Vec};
 
 /// Stringify a color to a string.
 impl Color {
@@ -39,7 +50,9 @@ impl Color {
     /// assert_eq!(color.hex(), "#ffffff80");
     /// ```
     pub fn hex(self) -> String {
-        simplify_hex(self.hex_full())
+        let mut hex = String::with_capacity(9);
+        self.write_hex(&mut hex).unwrap();
+        hex
     }
     /// `hex` string of the color with the full length.
     ///
@@ -52,12 +65,9 @@ impl Color {
     /// assert_eq!(color.hex_full(), "#ff00ff");
     /// ```
     pub fn hex_full(self) -> String {
-        if self.alpha == 1.0 {
-            rgb2hex(self.rgb)
-        } else {
-            let [r, g, b] = self.rgb;
-            rgba2hex([r, g, b, self.alpha])
-        }
+        let mut hex = String::with_capacity(9);
+        self.write_hex_full(&mut hex).unwrap();
+        hex
     }
     /// `rgb` string of the color
     ///
@@ -70,11 +80,9 @@ impl Color {
     /// assert_eq!(color.rgb(), "rgb(255, 255, 255)");
     /// ```
     pub fn rgb(self) -> String {
-        let [r, g, b] = self.rgb;
-        let r = r.round() as u8;
-        let g = g.round() as u8;
-        let b = b.round() as u8;
-        format!("rgb({}, {}, {})", r, g, b)
+        let mut rgb = String::new();
+        self.write_rgb(&mut rgb).unwrap();
+        rgb
     }
     /// `rgba` string of the color
     ///
@@ -87,11 +95,9 @@ impl Color {
     /// assert_eq!(color.rgba(), "rgba(255, 255, 255, 0.5)");
     /// ```
     pub fn rgba(self) -> String {
-        let [r, g, b] = self.rgb;
-        let r = r.round() as u8;
-        let g = g.round() as u8;
-        let b = b.round() as u8;
-        format!("rgba({}, {}, {}, {})", r, g, b, self.alpha())
+        let mut rgba = String::new();
+        self.write_rgba(&mut rgba).unwrap();
+        rgba
     }
     /// `hsl` string of the color
     ///
@@ -104,11 +110,9 @@ impl Color {
     /// assert_eq!(color.hsl(), "hsl(0, 0%, 100%)");
     /// ```
     pub fn hsl(self) -> String {
-        let hsl = rgb2hsl(&self.rgb);
-        let h = round(hsl[0], 0);
-        let s = round(hsl[1] * 100.0, 0);
-        let l = round(hsl[2] * 100.0, 0);
-        format!("hsl({}, {}%, {}%)", h, s, l)
+        let mut hsl = String::new();
+        self.write_hsl(&mut hsl).unwrap();
+        hsl
     }
     /// `hsla` string of the color
     ///
@@ -121,11 +125,9 @@ impl Color {
     /// assert_eq!(color.hsla(), "hsla(0, 0%, 100%, 0.3)");
     /// ```
     pub fn hsla(self) -> String {
-        let hsl = rgb2hsl(&self.rgb);
-        let h = round(hsl[0], 0);
-        let s = round(hsl[1] * 100.0, 0);
-        let l = round(hsl[2] * 100.0, 0);
-        format!("hsla({}, {}%, {}%, {})", h, s, l, self.alpha())
+        let mut hsla = String::new();
+        self.write_hsla(&mut hsla).unwrap();
+        hsla
     }
     /// `hsv` string of the color
     ///
@@ -215,6 +217,25 @@ impl Color {
             .collect::<Vec<_>>();
         format!("xyz({}, {}, {})", xyz[0], xyz[1], xyz[2])
     }
+    /// `xyz` string of the color, relative to the given reference illuminant
+    ///
+    /// [`Color::xyz`] is relative to D65, the white point of sRGB.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, Illuminant};
+    ///
+    /// let color = Color::new(255.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(color.xyz_with_illuminant(Illuminant::D50), "xyz(0.436066, 0.222493, 0.013924)");
+    /// ```
+    pub fn xyz_with_illuminant(self, illuminant: Illuminant) -> String {
+        let xyz = rgb2xyz_with_illuminant(&self.rgb, illuminant)
+            .iter()
+            .map(|&v| round(v, 6))
+            .collect::<Vec<_>>();
+        format!("xyz({}, {}, {})", xyz[0], xyz[1], xyz[2])
+    }
     /// `yiq` string of the color
     ///
     /// # Examples
@@ -266,6 +287,128 @@ impl Color {
             .collect::<Vec<_>>();
         format!("lab({}, {}, {})", lab[0], lab[1], lab[2])
     }
+    /// `lab` string of the color, relative to the given reference illuminant
+    ///
+    /// [`Color::lab`] is relative to D50, as in CSS and ICC profiles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, Illuminant};
+    ///
+    /// let color = Color::new(255.0, 255.0, 0.0, 1.0);
+    /// assert_eq!(color.lab_with_illuminant(Illuminant::D50), "lab(97.61, -15.75, 93.39)");
+    /// assert_eq!(color.lab_with_illuminant(Illuminant::D65), "lab(97.14, -21.56, 94.48)");
+    /// ```
+    pub fn lab_with_illuminant(self, illuminant: Illuminant) -> String {
+        let lab = rgb2lab_with_illuminant(&self.rgb, illuminant)
+            .iter()
+            .map(|&v| round(v, 2))
+            .collect::<Vec<_>>();
+        format!("lab({}, {}, {})", lab[0], lab[1], lab[2])
+    }
+    /// `lch` string of the color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.0, 255.0, 0.0, 1.0);
+    /// assert_eq!(color.lch(), "lch(97.61, 94.71, 99.57)");
+    /// ```
+    pub fn lch(self) -> String {
+        let lch = rgb2lch(&self.rgb)
+            .iter()
+            .map(|&v| round(v, 2))
+            .collect::<Vec<_>>();
+        format!("lch({}, {}, {})", lch[0], lch[1], lch[2])
+    }
+    /// `luv` string of the color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(color.luv(), "luv(53.24, 175.01, 37.77)");
+    /// ```
+    pub fn luv(self) -> String {
+        let luv = rgb2luv(&self.rgb)
+            .iter()
+            .map(|&v| round(v, 2))
+            .collect::<Vec<_>>();
+        format!("luv({}, {}, {})", luv[0], luv[1], luv[2])
+    }
+    /// `lchuv` string of the color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(color.lchuv(), "lchuv(53.24, 179.04, 12.18)");
+    /// ```
+    pub fn lchuv(self) -> String {
+        let lchuv = rgb2lchuv(&self.rgb)
+            .iter()
+            .map(|&v| round(v, 2))
+            .collect::<Vec<_>>();
+        format!("lchuv({}, {}, {})", lchuv[0], lchuv[1], lchuv[2])
+    }
+    /// `oklab` string of the color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(color.oklab(), "oklab(0.628, 0.2249, 0.1258)");
+    /// ```
+    pub fn oklab(self) -> String {
+        let oklab = rgb2oklab(&self.rgb)
+            .iter()
+            .map(|&v| round(v, 4))
+            .collect::<Vec<_>>();
+        format!("oklab({}, {}, {})", oklab[0], oklab[1], oklab[2])
+    }
+    /// `oklch` string of the color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(color.oklch(), "oklch(0.628, 0.2577, 29.23)");
+    /// ```
+    pub fn oklch(self) -> String {
+        let oklch = rgb2oklch(&self.rgb);
+        let l = round(oklch[0], 4);
+        let c = round(oklch[1], 4);
+        let h = round(oklch[2], 2);
+        format!("oklch({}, {}, {})", l, c, h)
+    }
+    /// `rec2020` string of the color, gamma-encoded Rec. 2020 RGB
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(color.rec2020(), "rec2020(0.792, 0.231, 0.0738)");
+    /// ```
+    pub fn rec2020(self) -> String {
+        let rec2020 = rgb2rec2020(&self.rgb)
+            .iter()
+            .map(|&v| round(v, 4))
+            .collect::<Vec<_>>();
+        format!("rec2020({}, {}, {})", rec2020[0], rec2020[1], rec2020[2])
+    }
     /// `YCbCr` string of the color
     ///
     /// # Examples
@@ -283,9 +426,60 @@ impl Color {
             .collect::<Vec<_>>();
         format!("YCbCr({}, {}, {})", ycbcr[0], ycbcr[1], ycbcr[2])
     }
+    /// `YCbCr` string of the color, using the given luma coefficients and quantization range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, ColorMatrix, YCbCrRange};
+    ///
+    /// let color = Color::new(255.0, 255.0, 255.0, 1.0);
+    /// assert_eq!(
+    ///     color.ycbcr_with(ColorMatrix::BT709, YCbCrRange::Limited),
+    ///     "YCbCr(235, 128, 128)"
+    /// );
+    /// ```
+    pub fn ycbcr_with(self, matrix: ColorMatrix, range: YCbCrRange) -> String {
+        let ycbcr = rgb2ycbcr_with(&self.rgb, matrix, range)
+            .iter()
+            .map(|&v| round(v, 4))
+            .collect::<Vec<_>>();
+        format!("YCbCr({}, {}, {})", ycbcr[0], ycbcr[1], ycbcr[2])
+    }
+    /// `YPbPr` string of the color, using the BT.601 coefficients
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(color.ypbpr(), "YPbPr(0.299, -0.1687, 0.5)");
+    /// ```
+    pub fn ypbpr(self) -> String {
+        self.ypbpr_with(ColorMatrix::BT601)
+    }
+    /// `YPbPr` string of the color, using the given luma coefficients
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, ColorMatrix};
+    ///
+    /// let color = Color::new(255.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(color.ypbpr_with(ColorMatrix::BT709), "YPbPr(0.2126, -0.1146, 0.5)");
+    /// ```
+    pub fn ypbpr_with(self, matrix: ColorMatrix) -> String {
+        let ypbpr = rgb2ypbpr(&self.rgb, matrix)
+            .iter()
+            .map(|&v| round(v, 4))
+            .collect::<Vec<_>>();
+        format!("YPbPr({}, {}, {})", ypbpr[0], ypbpr[1], ypbpr[2])
+    }
     /// `name` of the color
     ///
-    /// The color name is based on the [CSS3 color name](https://www.w3.org/TR/css-color-3/#svg-color) or 中国传统色彩.
+    /// The color name is based on the [CSS3 color name](https://www.w3.org/TR/css-color-3/#svg-color) or 中国传统色彩,
+    /// and the optional catalogs of [`NameSource`](crate::NameSource) when their feature is enabled.
     ///
     /// If the color is not named, the hex string will be returned.
     ///
@@ -304,16 +498,201 @@ impl Color {
     /// assert_eq!(color.name(), "#2a2a2a");
     /// ```
     pub fn name(self) -> String {
+        match self.name_with_source() {
+            Some((name, _)) => name,
+            None if self.alpha == 1.0 => rgb2hex(self.rgb),
+            None => self.hex(),
+        }
+    }
+    /// `rgb` string of the color in the modern CSS syntax, with space separated values
+    /// and a slash before the alpha if it is not opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 0, 0, 0.5);
+    /// assert_eq!(color.rgb_modern(), "rgb(255 0 0 / 50%)");
+    /// ```
+    pub fn rgb_modern(self) -> String {
+        modern_formatter().rgb(&self)
+    }
+    /// `hsl` string of the color in the modern CSS syntax, with space separated values
+    /// and a slash before the alpha if it is not opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 0, 0, 0.5);
+    /// assert_eq!(color.hsl_modern(), "hsl(0 100% 50% / 50%)");
+    /// ```
+    pub fn hsl_modern(self) -> String {
+        modern_formatter().hsl(&self)
+    }
+    /// `hwb` string of the color in the modern CSS syntax, with space separated values
+    /// and a slash before the alpha if it is not opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 0, 0, 0.5);
+    /// assert_eq!(color.hwb_modern(), "hwb(0 0% 0% / 50%)");
+    /// ```
+    pub fn hwb_modern(self) -> String {
+        modern_formatter().hwb(&self)
+    }
+    /// `lab` string of the color in the modern CSS syntax, with space separated values
+    /// and a slash before the alpha if it is not opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 0, 0, 0.5);
+    /// assert_eq!(color.lab_modern(), "lab(54.29 80.8 69.89 / 50%)");
+    /// ```
+    pub fn lab_modern(self) -> String {
+        modern_formatter().lab(&self)
+    }
+    /// `lch` string of the color in the modern CSS syntax, with space separated values
+    /// and a slash before the alpha if it is not opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 0, 0, 0.5);
+    /// assert_eq!(color.lch_modern(), "lch(54.29 106.84 40.86 / 50%)");
+    /// ```
+    pub fn lch_modern(self) -> String {
+        modern_formatter().lch(&self)
+    }
+    /// `oklab` string of the color in the modern CSS syntax, with space separated values
+    /// and a slash before the alpha if it is not opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 0, 0, 0.5);
+    /// assert_eq!(color.oklab_modern(), "oklab(0.628 0.2249 0.1258 / 50%)");
+    /// ```
+    pub fn oklab_modern(self) -> String {
+        modern_formatter().oklab(&self)
+    }
+    /// `oklch` string of the color in the modern CSS syntax, with space separated values
+    /// and a slash before the alpha if it is not opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 0, 0, 0.5);
+    /// assert_eq!(color.oklch_modern(), "oklch(0.628 0.2577 29.23 / 50%)");
+    /// ```
+    pub fn oklch_modern(self) -> String {
+        modern_formatter().oklch(&self)
+    }
+}
+
+/// Stringify a color into a [`fmt::Write`], without allocating a `String` per color.
+impl Color {
+    /// Writes the `hex` string of the color, see [`Color::hex`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let mut css = String::new();
+    /// for color in [Color::new(255, 0, 255, 1.0), Color::new(255, 255, 255, 0.5)] {
+    ///     color.write_hex(&mut css).unwrap();
+    ///     css.push(' ');
+    /// }
+    /// assert_eq!(css, "#f0f #ffffff80 ");
+    /// ```
+    pub fn write_hex(self, w: &mut impl fmt::Write) -> fmt::Result {
+        let bytes = self.rgba_bytes();
+        let bytes = &bytes[..self.hex_len()];
+        if bytes.iter().all(|b| b >> 4 == b & 0xf) {
+            w.write_char('#')?;
+            bytes.iter().try_for_each(|b| write!(w, "{:x}", b & 0xf))
+        } else {
+            self.write_hex_full(w)
+        }
+    }
+    /// Writes the `hex` string of the color with the full length, see [`Color::hex_full`].
+    pub fn write_hex_full(self, w: &mut impl fmt::Write) -> fmt::Result {
+        let bytes = self.rgba_bytes();
+        w.write_char('#')?;
+        bytes[..self.hex_len()]
+            .iter()
+            .try_for_each(|b| write!(w, "{:02x}", b))
+    }
+    /// Writes the `rgb` string of the color, see [`Color::rgb`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    /// use std::fmt::Write;
+    ///
+    /// let mut css = String::new();
+    /// write!(css, "color: ").unwrap();
+    /// Color::new(255, 255, 0, 1.0).write_rgb(&mut css).unwrap();
+    /// assert_eq!(css, "color: rgb(255, 255, 0)");
+    /// ```
+    pub fn write_rgb(self, w: &mut impl fmt::Write) -> fmt::Result {
+        let [r, g, b] = self.rgb.map(|v| v.round() as u8);
+        write!(w, "rgb({}, {}, {})", r, g, b)
+    }
+    /// Writes the `rgba` string of the color, see [`Color::rgba`].
+    pub fn write_rgba(self, w: &mut impl fmt::Write) -> fmt::Result {
+        let [r, g, b] = self.rgb.map(|v| v.round() as u8);
+        write!(w, "rgba({}, {}, {}, {})", r, g, b, self.alpha())
+    }
+    /// Writes the `hsl` string of the color, see [`Color::hsl`].
+    pub fn write_hsl(self, w: &mut impl fmt::Write) -> fmt::Result {
+        let [h, s, l] = self.hsl_rounded();
+        write!(w, "hsl({}, {}%, {}%)", h, s, l)
+    }
+    /// Writes the `hsla` string of the color, see [`Color::hsla`].
+    pub fn write_hsla(self, w: &mut impl fmt::Write) -> fmt::Result {
+        let [h, s, l] = self.hsl_rounded();
+        write!(w, "hsla({}, {}%, {}%, {})", h, s, l, self.alpha())
+    }
+
+    /// Number of bytes in the hex string, the alpha byte is left out for opaque colors.
+    fn hex_len(self) -> usize {
         if self.alpha == 1.0 {
-            let hex = rgb2hex(self.rgb);
-            match name_of_hex(&hex) {
-                Some(name) => name.to_string(),
-                None => hex,
-            }
+            3
         } else {
-            self.hex()
+            4
         }
     }
+    fn hsl_rounded(self) -> [f64; 3] {
+        let hsl = rgb2hsl(&self.rgb);
+        [
+            round(hsl[0], 0),
+            round(hsl[1] * 100.0, 0),
+            round(hsl[2] * 100.0, 0),
+        ]
+    }
+}
+
+fn modern_formatter() -> ColorFormatter {
+    ColorFormatter::new()
+        .syntax(CssSyntax::Modern)
+        .alpha_format(AlphaFormat::Percentage)
 }
 
 #[cfg(test)]
@@ -321,6 +700,22 @@ mod tests {
     use super::*;
 
     #[test]
+    fn test_stringify_modern() {
+        let color = Color::new(255, 255, 0, 1.0);
+        assert_eq!(color.rgb_modern(), "rgb(255 255 0)");
+        assert_eq!(color.hsl_modern(), "hsl(60 100% 50%)");
+        assert_eq!(color.hwb_modern(), "hwb(60 0% 0%)");
+        assert_eq!(color.lab_modern(), "lab(97.61 -15.75 93.39)");
+        assert_eq!(color.lch_modern(), "lch(97.61 94.71 99.57)");
+
+        let color = Color::new(0, 0, 0, 0.25);
+        assert_eq!(color.rgb_modern(), "rgb(0 0 0 / 25%)");
+        assert_eq!(color.oklab_modern(), "oklab(0 0 0 / 25%)");
+        assert_eq!(color.oklch_modern(), "oklch(0 0 0 / 25%)");
+    }
+
+    #[test]
+    #[cfg(feature = "css-names")]
     fn test_stringify_color() {
         let color = Color::new(255.0, 255.0, 255.0, 1.0);
         assert_eq!(color.hex(), "#fff");
@@ -334,7 +729,14 @@ mod tests {
         assert_eq!(color.hwb(), "hwb(0, 100%, 0%)");
         assert_eq!(color.xyz(), "xyz(0.950456, 1, 1.089058)");
         assert_eq!(color.ycbcr(), "YCbCr(255, 128, 128)");
+        assert_eq!(color.ypbpr(), "YPbPr(1, 0, 0)");
         assert_eq!(color.lab(), "lab(100, 0, 0)");
+        assert_eq!(color.lch(), "lch(100, 0, 0)");
+        assert_eq!(color.luv(), "luv(100, 0, 0)");
+        assert_eq!(color.lchuv(), "lchuv(100, 0, 0)");
+        assert_eq!(color.oklab(), "oklab(1, 0, 0)");
+        assert_eq!(color.oklch(), "oklch(1, 0, 0)");
+        assert_eq!(color.rec2020(), "rec2020(1, 1, 1)");
         assert_eq!(color.name(), "white");
 
         let color = Color::new(0.0, 0.0, 0.0, 0.2);
@@ -349,7 +751,14 @@ mod tests {
         assert_eq!(color.hwb(), "hwb(0, 0%, 100%)");
         assert_eq!(color.xyz(), "xyz(0, 0, 0)");
         assert_eq!(color.ycbcr(), "YCbCr(0, 128, 128)");
+        assert_eq!(color.ypbpr(), "YPbPr(0, 0, 0)");
         assert_eq!(color.lab(), "lab(0, 0, 0)");
+        assert_eq!(color.lch(), "lch(0, 0, 0)");
+        assert_eq!(color.luv(), "luv(0, 0, 0)");
+        assert_eq!(color.lchuv(), "lchuv(0, 0, 0)");
+        assert_eq!(color.oklab(), "oklab(0, 0, 0)");
+        assert_eq!(color.oklch(), "oklch(0, 0, 0)");
+        assert_eq!(color.rec2020(), "rec2020(0, 0, 0)");
         assert_eq!(color.name(), "#0003");
 
         let color = Color::new(0.0, 128.0, 128.0, 1.0);
@@ -364,7 +773,14 @@ mod tests {
         assert_eq!(color.hwb(), "hwb(180, 0%, 50%)");
         assert_eq!(color.xyz(), "xyz(0.116147, 0.16996, 0.230912)");
         assert_eq!(color.ycbcr(), "YCbCr(89.728, 149.5854, 64.0239)");
+        assert_eq!(color.ypbpr(), "YPbPr(0.3519, 0.0847, -0.251)");
         assert_eq!(color.lab(), "lab(47.99, -30.39, -8.98)");
+        assert_eq!(color.lch(), "lch(47.99, 31.69, 196.45)");
+        assert_eq!(color.luv(), "luv(48.26, -37.32, -8.05)");
+        assert_eq!(color.lchuv(), "lchuv(48.26, 38.18, 192.18)");
+        assert_eq!(color.oklab(), "oklab(0.5431, -0.0896, -0.0236)");
+        assert_eq!(color.oklch(), "oklch(0.5431, 0.0927, 194.77)");
+        assert_eq!(color.rec2020(), "rec2020(0.2543, 0.4347, 0.4481)");
         assert_eq!(color.name(), "teal");
 
         let color = Color::new(161, 110, 87, 1.0);
@@ -379,7 +795,14 @@ mod tests {
         assert_eq!(color.hwb(), "hwb(19, 34%, 37%)");
         assert_eq!(color.xyz(), "xyz(0.219934, 0.194179, 0.116068)");
         assert_eq!(color.ycbcr(), "YCbCr(122.627, 107.9064, 155.3599)");
+        assert_eq!(color.ypbpr(), "YPbPr(0.4809, -0.0788, 0.1073)");
         assert_eq!(color.lab(), "lab(51.48, 18.82, 21.44)");
+        assert_eq!(color.lch(), "lch(51.48, 28.53, 48.73)");
+        assert_eq!(color.luv(), "luv(51.17, 36.53, 22.45)");
+        assert_eq!(color.lchuv(), "lchuv(51.17, 42.88, 31.58)");
+        assert_eq!(color.oklab(), "oklab(0.5856, 0.052, 0.0522)");
+        assert_eq!(color.oklch(), "oklch(0.5856, 0.0737, 45.11)");
+        assert_eq!(color.rec2020(), "rec2020(0.5197, 0.3947, 0.2993)");
         assert_eq!(color.name(), "#a16e57");
     }
 }
@@ -0,0 +1,124 @@
+use crate::{
+    conversion::{
+        temperature::{blackbody_xyz, xyz2cct},
+        utils::{gam_srgb, xyz_to_lin_srgb},
+        xyz::rgb2xyz,
+    },
+    Color, Error,
+};
+use alloc::format;
+
+impl Color {
+    /// Create the color of a blackbody radiator at a temperature from 1000K to 40000K.
+    ///
+    /// The chromaticity is exact on the Planckian locus, scaled to the brightest sRGB color of
+    /// it. Low temperatures are deep orange, 6500K is close to white, high temperatures are
+    /// blue. Channels outside of the sRGB gamut are clipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let warm_white = Color::from_temperature(2700).unwrap();
+    /// assert_eq!(warm_white.hex(), "#ffad59");
+    ///
+    /// let candle = Color::from_temperature(1900).unwrap();
+    /// assert_eq!(candle.hex(), "#ff8400");
+    ///
+    /// assert!(Color::from_temperature(500).is_err());
+    /// ```
+    pub fn from_temperature<T>(kelvin: T) -> Result<Self, Error>
+    where
+        T: Into<f64>,
+    {
+        let kelvin = kelvin.into();
+        if !(1000.0..=40000.0).contains(&kelvin) {
+            return Err(Error::InvalidParamsError(format!(
+                "Invalid color temperature, must be between 1000K and 40000K, but got {}K",
+                kelvin
+            )));
+        }
+        let rgb = xyz_to_lin_srgb(&blackbody_xyz(kelvin)).map(|v| v.max(0.0));
+        let max = rgb[0].max(rgb[1]).max(rgb[2]);
+        let [r, g, b] = gam_srgb(&rgb.map(|v| v / max)).map(|v| v * 255.0);
+        Ok(Color::new(r, g, b, 1.0))
+    }
+    /// The correlated color temperature of the color in kelvin and its Duv, the inverse of
+    /// [`Color::from_temperature`].
+    ///
+    /// The temperature is the closest point of the Planckian locus, clamped to 1000K - 40000K,
+    /// and Duv is the distance to it in CIE 1960 uv: positive for a green tint, negative for a
+    /// magenta one. The temperature is only meaningful for near-white colors, conventionally
+    /// with a Duv within ±0.05. Returns `None` for black, which has no chromaticity.
+    ///
+    /// McCamy's cubic formula only gives the starting point, which is then refined with Newton
+    /// steps onto the closest point of the locus. Plain McCamy is off by a few kelvin around
+    /// daylight, but by over a hundred at 1500K or 10000K and far more beyond, so the results
+    /// differ from tools using the formula alone, e.g. 6510K instead of McCamy's 6505K for
+    /// the white of sRGB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, Color};
+    ///
+    /// let (cct, duv) = Color::from_temperature(2700).unwrap().cct().unwrap();
+    /// assert_eq!(cct.round(), 2700.0);
+    /// assert!(duv.abs() < 1e-4);
+    ///
+    /// // the D65 white of sRGB is slightly green of the locus
+    /// let (cct, duv) = color!(#fff).cct().unwrap();
+    /// assert_eq!(cct.round(), 6510.0);
+    /// assert!((duv - 0.0032).abs() < 1e-4);
+    ///
+    /// assert!(color!(#000).cct().is_none());
+    /// ```
+    pub fn cct(&self) -> Option<(f64, f64)> {
+        let xyz = rgb2xyz(&self.rgb);
+        if xyz.iter().sum::<f64>() <= 0.0 {
+            return None;
+        }
+        let [cct, duv] = xyz2cct(&xyz);
+        Some((cct, duv))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_from_temperature() {
+        for (kelvin, hex) in [
+            (1000.0, "#ff1700"),
+            (3000.0, "#ffb86d"),
+            (4000.0, "#ffd3a5"),
+            (5000.0, "#ffe6d0"),
+            (6500.0, "#fff9fe"),
+            (10000.0, "#cdd9ff"),
+            (40000.0, "#9eb8ff"),
+        ] {
+            assert_eq!(Color::from_temperature(kelvin).unwrap().hex(), hex);
+        }
+        assert!(Color::from_temperature(999.0).is_err());
+        assert!(Color::from_temperature(40001).is_err());
+        assert!(Color::from_temperature(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_cct() {
+        for kelvin in [2000.0, 3000.0, 4500.0, 6500.0, 9000.0, 20000.0] {
+            let (cct, duv) = Color::from_temperature(kelvin).unwrap().cct().unwrap();
+            assert!((cct - kelvin).abs() / kelvin < 1e-3, "{}K", kelvin);
+            assert!(duv.abs() < 1e-4);
+        }
+        let (cct, duv) = color!(#ffe0ff).cct().unwrap();
+        assert_eq!(cct.round(), 6703.0);
+        assert!(duv < -0.01);
+        let (_, duv) = color!(#e0ffe0).cct().unwrap();
+        assert!(duv > 0.01);
+        let (gray, _) = color!(#808080).cct().unwrap();
+        assert!((gray - 6510.0).abs() < 1.0);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    color_calc::delta_e::ciede2000,
+    conversion::{hex::hex2rgb, lab::rgb2lab},
+    data::DESIGN_SYSTEMS,
+    Color, DesignSystem, Error,
+};
+use alloc::{format, string::String};
+
+/// Color tokens of design systems, see [`DesignSystem`] for the cargo features enabling them.
+impl Color {
+    /// Create a color from a design system token like `tailwind:sky-400` or `material:red-500`.
+    ///
+    /// Without a prefix, the first enabled [`DesignSystem`] having the token is used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// # #[cfg(all(feature = "material", feature = "tailwind"))]
+    /// # {
+    /// let color = Color::from_token("tailwind:sky-400").unwrap();
+    /// assert_eq!(color.hex(), "#38bdf8");
+    ///
+    /// let color = Color::from_token("material:deep-purple-a200").unwrap();
+    /// assert_eq!(color.hex(), "#7c4dff");
+    /// # }
+    /// ```
+    pub fn from_token(token: &str) -> Result<Self, Error> {
+        let token = token.trim().to_lowercase();
+        let hex = match token.split_once(':') {
+            Some((prefix, name)) => DESIGN_SYSTEMS
+                .iter()
+                .find(|(system, _)| system.prefix() == prefix)
+                .and_then(|(_, tokens)| tokens.get(name)),
+            None => DESIGN_SYSTEMS
+                .iter()
+                .find_map(|(_, tokens)| tokens.get(token.as_str())),
+        };
+        match hex {
+            Some(hex) => Color::from_hex(hex),
+            None => Err(Error::InvalidParamsError(format!(
+                "Invalid color token: {}",
+                token
+            ))),
+        }
+    }
+    /// The perceptually nearest token of all enabled design systems, by [`delta_e`](crate::delta_e),
+    /// with its prefix.
+    ///
+    /// The alpha of the color is ignored. `None` if no design system is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// # #[cfg(feature = "tailwind")]
+    /// # {
+    /// let color = Color::new(57, 190, 247, 1.0);
+    /// assert_eq!(color.nearest_token().unwrap(), "tailwind:sky-400");
+    /// # }
+    /// ```
+    pub fn nearest_token(&self) -> Option<String> {
+        self.nearest_token_in(&[DesignSystem::Material, DesignSystem::Tailwind])
+    }
+    /// The perceptually nearest token of some design systems, by [`delta_e`](crate::delta_e),
+    /// with its prefix.
+    ///
+    /// Design systems disabled by their cargo feature are skipped, `None` if none is left.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, DesignSystem};
+    ///
+    /// # #[cfg(feature = "material")]
+    /// # {
+    /// let color = Color::new(240, 70, 55, 1.0);
+    /// assert_eq!(
+    ///     color.nearest_token_in(&[DesignSystem::Material]).unwrap(),
+    ///     "material:red-500"
+    /// );
+    /// # }
+    /// ```
+    pub fn nearest_token_in(&self, systems: &[DesignSystem]) -> Option<String> {
+        let lab = rgb2lab(&self.rgb);
+        DESIGN_SYSTEMS
+            .iter()
+            .filter(|(system, _)| systems.contains(system))
+            .flat_map(|(system, tokens)| tokens.entries().map(move |entry| (system, entry)))
+            .map(|(system, (token, hex))| {
+                let distance = ciede2000(&lab, &rgb2lab(&hex2rgb(hex)));
+                (distance, system, token)
+            })
+            .min_by(|(d1, ..), (d2, ..)| d1.total_cmp(d2))
+            .map(|(_, system, token)| format!("{}:{}", system.prefix(), token))
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "material", feature = "tailwind"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_from_token() {
+        assert_eq!(
+            Color::from_token("tailwind:slate-700").unwrap().hex(),
+            "#334155"
+        );
+        assert_eq!(
+            Color::from_token(" Material:Blue-Grey-500 ").unwrap().hex(),
+            "#607d8b"
+        );
+        assert_eq!(Color::from_token("red-500").unwrap().hex(), "#f44336");
+        assert_eq!(Color::from_token("slate-950").unwrap().hex(), "#020617");
+        assert!(Color::from_token("material:slate-700").is_err());
+        assert!(Color::from_token("bootstrap:red-500").is_err());
+        assert!(Color::from_token("red-1000").is_err());
+    }
+
+    #[test]
+    fn test_nearest_token() {
+        assert_eq!(color!(#f44336).nearest_token().unwrap(), "material:red-500");
+        assert_eq!(
+            Color::new(50, 65, 86, 0.5)
+                .nearest_token_in(&[DesignSystem::Tailwind])
+                .unwrap(),
+            "tailwind:slate-700"
+        );
+        assert_eq!(color!(#fff).nearest_token_in(&[]), None);
+
+        for system in [DesignSystem::Material, DesignSystem::Tailwind] {
+            let token = Color::new(14, 165, 233, 1.0)
+                .nearest_token_in(&[system])
+                .unwrap();
+            assert!(token.starts_with(system.prefix()));
+            let snapped = Color::from_token(&token).unwrap();
+            assert_eq!(snapped.nearest_token_in(&[system]).unwrap(), token);
+        }
+    }
+}
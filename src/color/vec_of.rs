@@ -1,4 +1,5 @@
-use crate::{conversion, Color, ColorSpace};
+use crate::{conversion, Color, ColorMatrix, ColorSpace};
+use alloc::{vec, vec::Vec};
 
 impl Color {
     /// Get the color space vector of the color instance.
@@ -18,29 +19,35 @@ impl Color {
     /// assert_eq!(vec, vec![330.0, 0.8, 1.0]);
     /// ```
     pub fn vec_of(&self, color_space: impl Into<ColorSpace>) -> Vec<f64> {
-        let color = self.rgb.to_vec();
+        let color = self.rgb;
         let color_space = color_space.into();
         match color_space {
-            ColorSpace::RGB | ColorSpace::HEX => color,
+            ColorSpace::RGB | ColorSpace::HEX => color.to_vec(),
             ColorSpace::RGBA | ColorSpace::HEXA => {
                 let [r, g, b] = self.rgb;
                 vec![r, g, b, self.alpha]
             }
-            ColorSpace::HSI => conversion::hsi::rgb2hsi(&color),
-            ColorSpace::HSL => conversion::hsl::rgb2hsl(&color),
+            ColorSpace::HSI => conversion::hsi::rgb2hsi(&color).to_vec(),
+            ColorSpace::HSL => conversion::hsl::rgb2hsl(&color).to_vec(),
             ColorSpace::HSLA => {
-                let mut hsl = conversion::hsl::rgb2hsl(&color);
-                hsl.push(self.alpha);
-                hsl
+                let [h, s, l] = conversion::hsl::rgb2hsl(&color);
+                vec![h, s, l, self.alpha]
             }
-            ColorSpace::HSV => conversion::hsv::rgb2hsv(&color),
-            ColorSpace::HWB => conversion::hwb::rgb2hwb(&color),
-            ColorSpace::CMYK => conversion::cmyk::rgb2cmyk(&color),
-            ColorSpace::XYZ => conversion::xyz::rgb2xyz(&color),
-            ColorSpace::YIQ => conversion::yiq::rgb2yiq(&color),
-            ColorSpace::YUV => conversion::yuv::rgb2yuv(&color),
-            ColorSpace::YCbCr => conversion::ycbcr::rgb2ycbcr(&color),
-            ColorSpace::Lab => conversion::lab::rgb2lab(&color),
+            ColorSpace::HSV => conversion::hsv::rgb2hsv(&color).to_vec(),
+            ColorSpace::HWB => conversion::hwb::rgb2hwb(&color).to_vec(),
+            ColorSpace::CMYK => conversion::cmyk::rgb2cmyk(&color).to_vec(),
+            ColorSpace::XYZ => conversion::xyz::rgb2xyz(&color).to_vec(),
+            ColorSpace::YIQ => conversion::yiq::rgb2yiq(&color).to_vec(),
+            ColorSpace::YUV => conversion::yuv::rgb2yuv(&color).to_vec(),
+            ColorSpace::YCbCr => conversion::ycbcr::rgb2ycbcr(&color).to_vec(),
+            ColorSpace::YPbPr => conversion::ypbpr::rgb2ypbpr(&color, ColorMatrix::BT601).to_vec(),
+            ColorSpace::Lab => conversion::lab::rgb2lab(&color).to_vec(),
+            ColorSpace::LCH => conversion::lch::rgb2lch(&color).to_vec(),
+            ColorSpace::Luv => conversion::luv::rgb2luv(&color).to_vec(),
+            ColorSpace::LCHuv => conversion::lchuv::rgb2lchuv(&color).to_vec(),
+            ColorSpace::OKLab => conversion::oklab::rgb2oklab(&color).to_vec(),
+            ColorSpace::OKLCH => conversion::oklch::rgb2oklch(&color).to_vec(),
+            ColorSpace::Rec2020 => conversion::rec2020::rgb2rec2020(&color).to_vec(),
             ColorSpace::Unknown => todo!("Unknown color space not yet implemented `vec_of`"),
         }
     }
@@ -49,6 +56,7 @@ impl Color {
 #[cfg(test)]
 mod tests {
     use crate::{utils::round, *};
+    use alloc::vec::Vec;
 
     #[test]
     fn test_vec_of_hsl() {
@@ -0,0 +1,108 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::Color;
+
+// APCA 0.0.98G-4g constants
+const MAIN_TRC: f64 = 2.4;
+const SRGB_COEFFS: [f64; 3] = [0.2126729, 0.7151522, 0.0721750];
+const NORM_BG: f64 = 0.56;
+const NORM_TXT: f64 = 0.57;
+const REV_TXT: f64 = 0.62;
+const REV_BG: f64 = 0.65;
+const BLK_THRS: f64 = 0.022;
+const BLK_CLMP: f64 = 1.414;
+const SCALE_BOW: f64 = 1.14;
+const SCALE_WOB: f64 = 1.14;
+const LO_BOW_OFFSET: f64 = 0.027;
+const LO_WOB_OFFSET: f64 = 0.027;
+const DELTA_Y_MIN: f64 = 0.0005;
+const LO_CLIP: f64 = 0.1;
+
+/// Computes the [APCA](https://github.com/Myndex/apca-w3) (Accessible Perceptual Contrast Algorithm)
+/// lightness contrast `Lc` of text on a background.
+///
+/// Unlike the WCAG 2 [`contrast_ratio`](crate::contrast_ratio), the result depends on which color is
+/// the text: it is positive for dark text on a light background, and negative for light text on a
+/// dark background. Its magnitude ranges from 0 (no contrast) to about 108.
+///
+/// **Tips**: An `Lc` of 75 is the minimum for body text, and 60 for large or bold text.
+///
+/// # Example
+///
+/// ```
+/// use color_art::{apca_contrast, color};
+///
+/// let text = color!(#888);
+/// let background = color!(#fff);
+///
+/// let lc = apca_contrast(&text, &background);
+/// assert!((lc - 63.056469930209424).abs() < 1e-9);
+///
+/// let lc = apca_contrast(&background, &text);
+/// assert!((lc - -68.54146436644962).abs() < 1e-9);
+/// ```
+pub fn apca_contrast(text: &Color, background: &Color) -> f64 {
+    let y_text = screen_luminance(text);
+    let y_bg = screen_luminance(background);
+
+    if (y_bg - y_text).abs() < DELTA_Y_MIN {
+        return 0.0;
+    }
+
+    let lc = if y_bg > y_text {
+        // dark text on a light background
+        let sapc = (y_bg.powf(NORM_BG) - y_text.powf(NORM_TXT)) * SCALE_BOW;
+        if sapc < LO_CLIP {
+            0.0
+        } else {
+            sapc - LO_BOW_OFFSET
+        }
+    } else {
+        // light text on a dark background
+        let sapc = (y_bg.powf(REV_BG) - y_text.powf(REV_TXT)) * SCALE_WOB;
+        if sapc > -LO_CLIP {
+            0.0
+        } else {
+            sapc + LO_WOB_OFFSET
+        }
+    };
+    lc * 100.0
+}
+
+/// The estimated screen luminance `Y` of a color, with a soft clamp of near blacks.
+fn screen_luminance(color: &Color) -> f64 {
+    let y = color
+        .rgb
+        .iter()
+        .zip(SRGB_COEFFS)
+        .map(|(&c, coeff)| (c / 255.0).powf(MAIN_TRC) * coeff)
+        .sum::<f64>();
+    if y < BLK_THRS {
+        y + (BLK_THRS - y).powf(BLK_CLMP)
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_apca_contrast() {
+        let black = color!(#000);
+        let white = color!(#fff);
+
+        let lc = apca_contrast(&black, &white);
+        assert!((lc - 106.04067321268862).abs() < 1e-9);
+
+        let lc = apca_contrast(&white, &black);
+        assert!((lc - -107.88473318309848).abs() < 1e-9);
+
+        let lc = apca_contrast(&white, &white);
+        assert_eq!(lc, 0.0);
+
+        let lc = apca_contrast(&color!(#fafafa), &white);
+        assert_eq!(lc, 0.0);
+    }
+}
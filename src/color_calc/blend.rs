@@ -1,4 +1,5 @@
 use crate::{utils::blend_fn::*, Color, ColorSpace};
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -0,0 +1,176 @@
+use crate::{color::premultiplied::unpremultiply, Color};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Porter-Duff compositing operator, how a translucent source color is combined with its
+/// backdrop.
+///
+/// reference: [Porter Duff compositing operators](https://www.w3.org/TR/compositing-1/#porterduffcompositingoperators)
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CompositeOp {
+    /// The source is drawn over the backdrop, the usual alpha compositing.
+    #[default]
+    Over,
+    /// The part of the source inside the backdrop, the backdrop is discarded.
+    In,
+    /// The part of the source outside the backdrop, the backdrop is discarded.
+    Out,
+    /// The part of the source inside the backdrop is drawn over the backdrop.
+    Atop,
+    /// The parts of the source and the backdrop outside of each other.
+    Xor,
+    /// The sum of the source and the backdrop, `plus-lighter` in CSS.
+    Plus,
+}
+
+impl CompositeOp {
+    /// the fractions of the source and the backdrop kept in the result
+    fn fractions(&self, source_alpha: f64, backdrop_alpha: f64) -> (f64, f64) {
+        match self {
+            CompositeOp::Over => (1.0, 1.0 - source_alpha),
+            CompositeOp::In => (backdrop_alpha, 0.0),
+            CompositeOp::Out => (1.0 - backdrop_alpha, 0.0),
+            CompositeOp::Atop => (backdrop_alpha, 1.0 - source_alpha),
+            CompositeOp::Xor => (1.0 - backdrop_alpha, 1.0 - source_alpha),
+            CompositeOp::Plus => (1.0, 1.0),
+        }
+    }
+}
+
+/// Composites a premultiplied source color over a premultiplied backdrop with a Porter-Duff
+/// operator.
+///
+/// The colors are premultiplied `[r, g, b, a]` values in the range 0.0 to 1.0, like
+/// [`Color::to_premultiplied`], the pixels of a GPU or video pipeline. The result is
+/// premultiplied as well, so nothing is multiplied twice.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{composite_premultiplied, CompositeOp};
+///
+/// let source = [0.5, 0.0, 0.0, 0.5];
+/// let backdrop = [0.0, 0.0, 0.5, 0.5];
+/// let color = composite_premultiplied(&source, &backdrop, CompositeOp::Over);
+/// assert_eq!(color, [0.5, 0.0, 0.25, 0.75]);
+/// ```
+pub fn composite_premultiplied(
+    source: &[f64; 4],
+    backdrop: &[f64; 4],
+    op: CompositeOp,
+) -> [f64; 4] {
+    let (fa, fb) = op.fractions(source[3], backdrop[3]);
+    [0, 1, 2, 3].map(|i| (source[i] * fa + backdrop[i] * fb).min(1.0))
+}
+
+impl Color {
+    /// Composite the color as the source over a backdrop color with a Porter-Duff operator.
+    ///
+    /// The sRGB channels are premultiplied by their alpha, composited with
+    /// [`composite_premultiplied`], and divided by the resulting alpha again, so translucent
+    /// colors combine like they do when drawn by a browser or a GPU. A fully transparent result
+    /// is transparent black.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, CompositeOp};
+    ///
+    /// let source = color!(rgba(255, 0, 0, 0.5));
+    /// let backdrop = color!(rgba(0, 0, 255, 0.5));
+    ///
+    /// let color = source.composite(&backdrop, CompositeOp::Over);
+    /// assert_eq!(color.rgba(), "rgba(170, 0, 85, 0.75)");
+    ///
+    /// let color = source.composite(&backdrop, CompositeOp::Atop);
+    /// assert_eq!(color.rgba(), "rgba(128, 0, 128, 0.5)");
+    ///
+    /// let color = source.composite(&color!(#00f), CompositeOp::Over);
+    /// assert_eq!(color.hex(), "#800080");
+    /// ```
+    pub fn composite(&self, backdrop: &Color, op: CompositeOp) -> Color {
+        let rgba =
+            composite_premultiplied(&self.to_premultiplied(), &backdrop.to_premultiplied(), op);
+        let [r, g, b, alpha] = unpremultiply(&rgba);
+        Color::new(r * 255.0, g * 255.0, b * 255.0, alpha)
+    }
+    /// The opaque color seen when the color is drawn over a background, the color composited
+    /// [`Over`](CompositeOp::Over) it.
+    ///
+    /// The background is taken as opaque, its alpha is ignored. Flattening is needed for the
+    /// real [`contrast_ratio`](crate::contrast_ratio) of translucent text or borders, which
+    /// ignores alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, contrast_ratio};
+    ///
+    /// let background = color!(#1e3a8a);
+    /// let text = color!(rgba(255, 255, 255, 0.6));
+    ///
+    /// let seen = text.flatten(&background);
+    /// assert_eq!(seen.hex(), "#a5b0d0");
+    /// assert!(contrast_ratio(&seen, &background) < contrast_ratio(&text, &background));
+    /// ```
+    pub fn flatten(&self, background: &Color) -> Color {
+        self.composite(&background.with_alpha(1.0), CompositeOp::Over)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use CompositeOp::*;
+
+    #[test]
+    fn test_composite() {
+        let source = color!(rgba(255, 0, 0, 0.5));
+        let backdrop = color!(rgba(0, 0, 255, 0.5));
+        for (op, rgba) in [
+            (Over, "rgba(170, 0, 85, 0.75)"),
+            (In, "rgba(255, 0, 0, 0.25)"),
+            (Out, "rgba(255, 0, 0, 0.25)"),
+            (Atop, "rgba(128, 0, 128, 0.5)"),
+            (Xor, "rgba(128, 0, 128, 0.5)"),
+            (Plus, "rgba(128, 0, 128, 1)"),
+        ] {
+            assert_eq!(source.composite(&backdrop, op).rgba(), rgba, "{:?}", op);
+        }
+    }
+
+    #[test]
+    fn test_composite_opaque() {
+        let source = color!(#f39);
+        let backdrop = color!(#4cbbfc);
+        assert_eq!(source.composite(&backdrop, Over), source);
+        assert_eq!(source.composite(&backdrop, In), source);
+        assert_eq!(source.composite(&backdrop, Atop), source);
+        assert_eq!(source.composite(&backdrop, Out), Color::TRANSPARENT);
+        assert_eq!(source.composite(&backdrop, Xor), Color::TRANSPARENT);
+        assert_eq!(source.composite(&backdrop, Plus).hex(), "#fef");
+
+        assert_eq!(Color::TRANSPARENT.composite(&backdrop, Over), backdrop);
+        assert_eq!(source.composite(&Color::TRANSPARENT, Over), source);
+        assert_eq!(
+            source.composite(&Color::TRANSPARENT, In),
+            Color::TRANSPARENT
+        );
+    }
+
+    #[test]
+    fn test_flatten() {
+        let background = color!(#fff);
+        assert_eq!(
+            color!(rgba(0, 0, 0, 0.5)).flatten(&background).hex(),
+            "#808080"
+        );
+        assert_eq!(color!(#f39).flatten(&background), color!(#f39));
+        assert_eq!(Color::TRANSPARENT.flatten(&background), background);
+
+        let color = color!(rgba(255, 51, 153, 0.25)).flatten(&color!(rgba(0, 0, 0, 0.1)));
+        assert_eq!(color.rgba(), "rgba(64, 13, 38, 1)");
+    }
+}
@@ -24,11 +24,56 @@ pub fn contrast_ratio(color1: &Color, color2: &Color) -> f64 {
     (l_max + 0.05) / (l_min + 0.05)
 }
 
+impl Color {
+    /// Picks the candidate with the highest WCAG [`contrast_ratio`] against the color,
+    /// e.g. the most readable text color on a background.
+    ///
+    /// Falls back to [`Color::text_color`] if there are no candidates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let background = color!(#1e3a8a);
+    /// let candidates = [color!(#93c5fd), color!(#fde047), color!(#3b82f6)];
+    /// assert_eq!(background.best_contrast(&candidates).hex(), "#fde047");
+    /// ```
+    pub fn best_contrast(&self, candidates: &[Color]) -> Color {
+        candidates
+            .iter()
+            .map(|candidate| (candidate, contrast_ratio(self, candidate)))
+            .max_by(|(_, c1), (_, c2)| c1.total_cmp(c2))
+            .map(|(candidate, _)| *candidate)
+            .unwrap_or_else(|| self.text_color())
+    }
+    /// Returns black or white, whichever has the higher WCAG [`contrast_ratio`] against the color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// assert_eq!(color!(#fefe0e).text_color().hex(), "#000");
+    /// assert_eq!(color!(#800080).text_color().hex(), "#fff");
+    /// ```
+    pub fn text_color(&self) -> Color {
+        let black = Color::new(0, 0, 0, 1.0);
+        let white = Color::new(255, 255, 255, 1.0);
+        if contrast_ratio(self, &black) >= contrast_ratio(self, &white) {
+            black
+        } else {
+            white
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
 
     #[test]
+    #[cfg(feature = "css-names")]
     fn test_distance() {
         let color1 = color!(#000);
         let color2 = color!(#fff);
@@ -48,4 +93,17 @@ mod tests {
         let contrast = contrast_ratio(&color1, &color2);
         assert_eq!(contrast, 6.124225406859997);
     }
+
+    #[test]
+    fn test_best_contrast() {
+        let background = color!(#fff);
+        let candidates = [color!(#ff0), color!(#777), color!(#00f)];
+        assert_eq!(background.best_contrast(&candidates).hex(), "#00f");
+        assert_eq!(background.best_contrast(&[]).hex(), "#000");
+
+        assert_eq!(color!(#000).text_color().hex(), "#fff");
+        assert_eq!(color!(#fff).text_color().hex(), "#000");
+        assert_eq!(color!(#777).text_color().hex(), "#000");
+        assert_eq!(color!(#555).text_color().hex(), "#fff");
+    }
 }
@@ -1,3 +1,5 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
 use crate::{Color, ColorSpace};
 
 /// Computes [color difference](https://en.wikipedia.org/wiki/Color_difference#CIEDE2000) as developed by the International Commission on Illumination (CIE) in 2000.
@@ -22,9 +24,14 @@ use crate::{Color, ColorSpace};
 /// assert!(is_equal(d, 30.165629067733235));
 /// ```
 pub fn delta_e(color1: &Color, color2: &Color) -> f64 {
-    let lab1 = color1.vec_of(ColorSpace::Lab);
-    let lab2 = color2.vec_of(ColorSpace::Lab);
+    ciede2000(
+        &color1.vec_of(ColorSpace::Lab),
+        &color2.vec_of(ColorSpace::Lab),
+    )
+}
 
+/// CIEDE2000 color difference of two Lab colors, see [`delta_e`].
+pub(crate) fn ciede2000(lab1: &[f64], lab2: &[f64]) -> f64 {
     let [l1, a1, b1] = [lab1[0], lab1[1], lab1[2]];
     let [l2, a2, b2] = [lab2[0], lab2[1], lab2[2]];
 
@@ -104,6 +111,127 @@ pub fn delta_e(color1: &Color, color2: &Color) -> f64 {
     result.min(100.0).max(0.0)
 }
 
+/// Computes the color difference of two colors in the [CAM16-UCS](https://doi.org/10.1002/col.22131) uniform color space.
+///
+/// The distance is the Euclidean distance of the `[J', a', b']` coordinates under the default
+/// [`ViewingConditions`](crate::ViewingConditions).
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{delta_e_cam16, color};
+///
+/// let color1 = color!(#fff);
+/// let color2 = color!(#000);
+///
+/// let d = delta_e_cam16(&color1, &color2);
+/// assert!(d > 100.0);
+/// assert_eq!(delta_e_cam16(&color1, &color1), 0.0);
+/// ```
+pub fn delta_e_cam16(color1: &Color, color2: &Color) -> f64 {
+    let ucs1 = color1.cam16_ucs();
+    let ucs2 = color2.cam16_ucs();
+
+    ucs1.iter()
+        .zip(ucs2.iter())
+        .map(|(a, b)| (a - b).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+impl Color {
+    /// Computes the [CIE76](https://en.wikipedia.org/wiki/Color_difference#CIE76) color difference,
+    /// the Euclidean distance of the two colors in Lab color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let color1 = color!(#fefe0e);
+    /// let color2 = color!(#fff);
+    ///
+    /// let d = color1.delta_e_76(&color2);
+    /// assert!((d - 93.52771129220156).abs() < 0.000001);
+    /// ```
+    pub fn delta_e_76(&self, other: &Color) -> f64 {
+        let lab1 = self.vec_of(ColorSpace::Lab);
+        let lab2 = other.vec_of(ColorSpace::Lab);
+
+        lab1.iter()
+            .zip(lab2.iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Computes the [CIE94](https://en.wikipedia.org/wiki/Color_difference#CIE94) color difference,
+    /// with the weighting factors for graphic arts.
+    ///
+    /// CIE94 is not symmetric: `self` is the reference color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let color1 = color!(#fefe0e);
+    /// let color2 = color!(#fff);
+    ///
+    /// let d = color1.delta_e_94(&color2);
+    /// assert!((d - 18.159323260024685).abs() < 0.000001);
+    ///
+    /// let d = color2.delta_e_94(&color1);
+    /// assert!((d - 93.52766784500862).abs() < 0.000001);
+    /// ```
+    pub fn delta_e_94(&self, other: &Color) -> f64 {
+        let lab1 = self.vec_of(ColorSpace::Lab);
+        let lab2 = other.vec_of(ColorSpace::Lab);
+
+        let [l1, a1, b1] = [lab1[0], lab1[1], lab1[2]];
+        let [l2, a2, b2] = [lab2[0], lab2[1], lab2[2]];
+
+        let c1 = (a1.powi(2) + b1.powi(2)).sqrt();
+        let c2 = (a2.powi(2) + b2.powi(2)).sqrt();
+
+        let delta_l = l1 - l2;
+        let delta_c = c1 - c2;
+        let delta_a = a1 - a2;
+        let delta_b = b1 - b2;
+        // rounding errors can make the squared hue difference slightly negative
+        let delta_h_2 = (delta_a.powi(2) + delta_b.powi(2) - delta_c.powi(2)).max(0.0);
+
+        let kl = 1.0;
+        let k1 = 0.045;
+        let k2 = 0.015;
+
+        let sl = 1.0;
+        let sc = 1.0 + k1 * c1;
+        let sh = 1.0 + k2 * c1;
+
+        ((delta_l / (kl * sl)).powi(2) + (delta_c / sc).powi(2) + delta_h_2 / sh.powi(2)).sqrt()
+    }
+
+    /// Computes the [CIEDE2000](https://en.wikipedia.org/wiki/Color_difference#CIEDE2000) color difference.
+    ///
+    /// This is the method form of [`delta_e`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let color1 = color!(#fefe0e);
+    /// let color2 = color!(#fff);
+    ///
+    /// let d = color1.delta_e_2000(&color2);
+    /// assert!((d - 30.165629067733235).abs() < 0.000001);
+    /// ```
+    pub fn delta_e_2000(&self, other: &Color) -> f64 {
+        delta_e(self, other)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -138,4 +266,53 @@ mod tests {
         let d = delta_e(&color1, &color2);
         assert_eq!(d, 100.0);
     }
+
+    #[test]
+    fn test_delta_e_76() {
+        let color1 = color!(#ededee);
+        let color2 = color!(#edeeed);
+        assert!(is_equal(color1.delta_e_76(&color2), 1.0689658281436605));
+
+        let color1 = color!(#fff);
+        let color2 = color!(#000);
+        assert!((color1.delta_e_76(&color2) - 100.0).abs() < 0.00001);
+        assert_eq!(color1.delta_e_76(&color1), 0.0);
+    }
+
+    #[test]
+    fn test_delta_e_94() {
+        let color1 = color!(#e0e0ee);
+        let color2 = color!(#e0eee0);
+        assert!(is_equal(color1.delta_e_94(&color2), 13.706534225275115));
+        assert!(is_equal(color2.delta_e_94(&color1), 13.479204783037863));
+
+        let color1 = color!(#fff);
+        let color2 = color!(#000);
+        assert!((color1.delta_e_94(&color2) - 100.0).abs() < 0.00001);
+        assert_eq!(color1.delta_e_94(&color1), 0.0);
+    }
+
+    #[test]
+    fn test_delta_e_2000() {
+        let color1 = color!(#e0e0ee);
+        let color2 = color!(#e0eee0);
+        assert!(is_equal(color1.delta_e_2000(&color2), 14.618185117695797));
+        assert_eq!(color1.delta_e_2000(&color2), color2.delta_e_2000(&color1));
+        assert_eq!(color!(#fff).delta_e_2000(&color!(#000)), 100.0);
+    }
+
+    #[test]
+    fn test_delta_e_cam16() {
+        let color1 = color!(#ededee);
+        let color2 = color!(#edeeed);
+
+        let d = delta_e_cam16(&color1, &color2);
+        assert!(d > 0.0 && d < 2.0);
+
+        let color1 = color!(#fff);
+        let color2 = color!(#000);
+
+        let d = delta_e_cam16(&color1, &color2);
+        assert!(is_equal(d, 100.02379677325425));
+    }
 }
@@ -1,4 +1,45 @@
-use crate::{Color, ColorSpace};
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{delta_e, Color, ColorSpace};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Metric used to measure how far apart two colors are.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DistanceMetric {
+    /// Euclidean distance of the RGB channels.
+    EuclideanRgb,
+    /// CIE76 color difference, see [`Color::delta_e_76`].
+    DeltaE76,
+    /// CIEDE2000 color difference, see [`delta_e`].
+    #[default]
+    DeltaE2000,
+}
+
+impl DistanceMetric {
+    /// Measures the distance between two colors with the metric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, DistanceMetric};
+    ///
+    /// let color1 = color!(#fefe0e);
+    /// let color2 = color!(#fff);
+    ///
+    /// let d = DistanceMetric::EuclideanRgb.distance(&color1, &color2);
+    /// assert_eq!(d, 241.00414934187336);
+    /// ```
+    pub fn distance(&self, color1: &Color, color2: &Color) -> f64 {
+        match self {
+            DistanceMetric::EuclideanRgb => distance_with(color1, color2, ColorSpace::RGB),
+            DistanceMetric::DeltaE76 => color1.delta_e_76(color2),
+            DistanceMetric::DeltaE2000 => delta_e(color1, color2),
+        }
+    }
+}
 
 /// Computes the [Euclidean distance](https://en.wikipedia.org/wiki/Euclidean_distance#Three_dimensions) between two colors in a given color space.
 ///
@@ -43,6 +84,43 @@ pub fn distance(color1: &Color, color2: &Color) -> f64 {
     distance_with(color1, color2, ColorSpace::RGBA)
 }
 
+impl Color {
+    /// Finds the color of a palette that is closest to the color.
+    ///
+    /// Returns the index of the closest color in the palette and its distance. If several colors
+    /// are equally close, the first one wins.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the palette is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, DistanceMetric};
+    ///
+    /// let palette = [color!(#000), color!(#f00), color!(#0f0), color!(#00f), color!(#fff)];
+    ///
+    /// let (index, d) = color!(#e23c3c).closest(&palette, DistanceMetric::DeltaE2000);
+    /// assert_eq!(index, 1);
+    /// assert!(d < 10.0);
+    /// ```
+    pub fn closest(&self, palette: &[Color], metric: DistanceMetric) -> (usize, f64) {
+        palette
+            .iter()
+            .map(|color| metric.distance(self, color))
+            .enumerate()
+            .fold(
+                None,
+                |closest: Option<(usize, f64)>, (index, d)| match closest {
+                    Some((_, min)) if min <= d => closest,
+                    _ => Some((index, d)),
+                },
+            )
+            .expect("palette must not be empty")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -61,4 +139,31 @@ mod tests {
         let d = distance_with(&color1, &color2, ColorSpace::HSL);
         assert_eq!(d, 60.01007164576413);
     }
+
+    #[test]
+    fn test_closest() {
+        let palette = [color!(#000), color!(#808080), color!(#fff), color!(#000)];
+
+        let (index, d) = color!(#000).closest(&palette, DistanceMetric::EuclideanRgb);
+        assert_eq!((index, d), (0, 0.0));
+
+        let (index, d) = color!(#a0a0a0).closest(&palette, DistanceMetric::EuclideanRgb);
+        assert_eq!(index, 1);
+        assert_eq!(d, (3.0_f64 * 32.0 * 32.0).sqrt());
+
+        // sRGB is not perceptually uniform, so the metrics can disagree
+        let color = color!(#404040);
+        let (index, _) = color.closest(&palette, DistanceMetric::EuclideanRgb);
+        assert_eq!(index, 0);
+        let (index, _) = color.closest(&palette, DistanceMetric::DeltaE76);
+        assert_eq!(index, 1);
+        let (index, _) = color.closest(&palette, DistanceMetric::DeltaE2000);
+        assert_eq!(index, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_closest_empty() {
+        color!(#fff).closest(&[], DistanceMetric::default());
+    }
 }
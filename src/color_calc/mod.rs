@@ -1,4 +1,6 @@
+pub mod apca;
 pub mod blend;
+pub mod composite;
 pub mod contrast_ratio;
 pub mod delta_e;
 pub mod distance;
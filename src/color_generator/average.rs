@@ -1,4 +1,5 @@
 use crate::Color;
+use alloc::vec::Vec;
 
 impl Color {
     /// Average a list of colors.
@@ -44,10 +45,12 @@ impl Color {
 #[cfg(test)]
 mod tests {
     use crate::*;
-    use std::str::FromStr;
 
     #[test]
+    #[cfg(feature = "css-names")]
     fn test_average_colors() {
+        use std::str::FromStr;
+
         let colors = vec![Color::from_str("red").unwrap(), color!(rgba(0, 0, 0, 0.5))];
 
         let averaged_color = Color::average(&colors);
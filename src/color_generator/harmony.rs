@@ -0,0 +1,227 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{conversion::lch::lch2rgb, Color, ColorSpace, Palette};
+
+impl Color {
+    /// Generates `n` tints and shades of the color, from dark to light with evenly spaced
+    /// perceptual lightness.
+    ///
+    /// The colors keep the LCh hue and chroma of the color, the lightness goes evenly from
+    /// black to white, both left out. The chroma is lowered where needed to stay in sRGB.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, ColorSpace};
+    ///
+    /// let palette = color!(#1e90ff).monochromatic(4);
+    /// let lightness = palette.iter().map(|c| c.vec_of(ColorSpace::Lab)[0].round()).collect::<Vec<_>>();
+    /// assert_eq!(lightness, vec![20.0, 40.0, 60.0, 80.0]);
+    /// ```
+    pub fn monochromatic(&self, n: usize) -> Palette {
+        let lch = self.vec_of(ColorSpace::LCH);
+        (1..=n)
+            .map(|i| {
+                let l = 100.0 * i as f64 / (n + 1) as f64;
+                let [r, g, b] = lch_in_gamut(l, lch[1], lch[2]);
+                Color::new(r, g, b, self.alpha)
+            })
+            .collect()
+    }
+    /// Generates `n` analogous colors, with hues `angle` degrees apart centered on the hue of
+    /// the color.
+    ///
+    /// The hue is rotated in HSL, the saturation, lightness and alpha are kept. With an odd
+    /// `n` the color itself is in the middle, with an even `n` it falls between the two middle
+    /// colors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::color;
+    ///
+    /// let palette = color!(#f00).analogous(3, 30.0);
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#ff0080", "#f00", "#ff8000"]);
+    /// ```
+    pub fn analogous(&self, n: usize, angle: f64) -> Palette {
+        let center = (n as f64 - 1.0) / 2.0;
+        (0..n)
+            .map(|i| self.rotate_hue((i as f64 - center) * angle))
+            .collect()
+    }
+
+    /// Generates the triadic harmony, the color and the two colors a third of the hue circle
+    /// away.
+    ///
+    /// Like all harmonies, the hue is rotated in HSL and the saturation, lightness and alpha
+    /// are kept. The color itself comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::color;
+    ///
+    /// let palette = color!(#f00).triadic();
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#f00", "#0f0", "#00f"]);
+    /// ```
+    pub fn triadic(&self) -> Palette {
+        self.harmony(&[0.0, 120.0, 240.0])
+    }
+    /// Generates the tetradic (rectangle) harmony, two pairs of complementary colors 60 degrees
+    /// apart.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::color;
+    ///
+    /// let palette = color!(#f00).tetradic();
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#f00", "#ff0", "#0ff", "#00f"]);
+    /// ```
+    pub fn tetradic(&self) -> Palette {
+        self.harmony(&[0.0, 60.0, 180.0, 240.0])
+    }
+    /// Generates the square harmony, four colors evenly spaced around the hue circle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::color;
+    ///
+    /// let palette = color!(#f00).square();
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#f00", "#80ff00", "#0ff", "#8000ff"]);
+    /// ```
+    pub fn square(&self) -> Palette {
+        self.harmony(&[0.0, 90.0, 180.0, 270.0])
+    }
+    /// Generates the split-complementary harmony, the color and its two
+    /// [compound](Color::compound) colors, 150 and 210 degrees away.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::color;
+    ///
+    /// let palette = color!(#f00).split_complementary();
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#f00", "#00ff80", "#0080ff"]);
+    /// ```
+    pub fn split_complementary(&self) -> Palette {
+        let [left, right] = self.compound();
+        [self.rotate_hue(0.0), left, right]
+            .into_iter()
+            .map(|mut color| {
+                color.alpha = self.alpha;
+                color
+            })
+            .collect()
+    }
+
+    /// The color with its hue rotated by each angle.
+    fn harmony(&self, angles: &[f64]) -> Palette {
+        angles.iter().map(|&angle| self.rotate_hue(angle)).collect()
+    }
+    /// Rotates the HSL hue, keeping the alpha.
+    fn rotate_hue(&self, angle: f64) -> Color {
+        let mut color = self.spin(angle.rem_euclid(360.0));
+        color.alpha = self.alpha;
+        color
+    }
+}
+
+/// Converts LCh to RGB, lowering the chroma by bisection until the color is in sRGB.
+fn lch_in_gamut(l: f64, c: f64, h: f64) -> [f64; 3] {
+    let in_gamut = |rgb: &[f64]| rgb.iter().all(|v| (0.0..=255.0).contains(v));
+    let rgb = lch2rgb(&[l, c, h]);
+    if in_gamut(&rgb) {
+        return [rgb[0], rgb[1], rgb[2]];
+    }
+    let (mut low, mut high) = (0.0, c);
+    for _ in 0..20 {
+        let mid = (low + high) / 2.0;
+        if in_gamut(&lch2rgb(&[l, mid, h])) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    let rgb = lch2rgb(&[l, low, h]);
+    [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_monochromatic() {
+        assert!(color!(#f00).monochromatic(0).is_empty());
+
+        let palette = color!(rgba(255, 0, 0, 0.5)).monochromatic(9);
+        assert_eq!(palette.len(), 9);
+        let hue = color!(#f00).vec_of(ColorSpace::LCH)[2];
+        for (i, color) in palette.iter().enumerate() {
+            let lch = color.vec_of(ColorSpace::LCH);
+            assert!((lch[0] - 10.0 * (i + 1) as f64).abs() < 0.5);
+            assert!((lch[2] - hue).abs() < 3.0);
+            assert_eq!(color.alpha(), 0.5);
+        }
+
+        // greys stay grey
+        let palette = color!(#808080).monochromatic(3);
+        assert_eq!(
+            palette.iter().map(|c| c.hex()).collect::<Vec<_>>(),
+            vec!["#3b3b3b", "#777", "#b9b9b9"]
+        );
+    }
+
+    #[test]
+    fn test_analogous() {
+        assert!(color!(#f00).analogous(0, 30.0).is_empty());
+        assert_eq!(
+            color!(#f00).analogous(1, 30.0),
+            Palette::new(vec![color!(#f00)])
+        );
+
+        let hexes = |palette: Palette| palette.iter().map(|c| c.hex()).collect::<Vec<_>>();
+        let hsla = |hues: &[f64]| {
+            hues.iter()
+                .map(|h| Color::from_hsl(*h, 0.5, 0.4).unwrap().with_alpha(0.5).hex())
+                .collect::<Vec<_>>()
+        };
+        let color = Color::from_hsl(100.0, 0.5, 0.4).unwrap().with_alpha(0.5);
+        assert_eq!(
+            hexes(color.analogous(4, 20.0)),
+            hsla(&[70.0, 90.0, 110.0, 130.0])
+        );
+        assert_eq!(
+            hexes(color.analogous(5, 60.0)),
+            hsla(&[340.0, 40.0, 100.0, 160.0, 220.0])
+        );
+        assert_eq!(hexes(color.analogous(3, 15.0)), hsla(&[85.0, 100.0, 115.0]));
+    }
+
+    #[test]
+    fn test_harmonies() {
+        let hexes = |palette: Palette| palette.iter().map(|c| c.hex()).collect::<Vec<_>>();
+        let hsla = |hues: &[f64]| {
+            hues.iter()
+                .map(|h| Color::from_hsl(*h, 0.6, 0.7).unwrap().with_alpha(0.8).hex())
+                .collect::<Vec<_>>()
+        };
+        let color = Color::from_hsl(200.0, 0.6, 0.7).unwrap().with_alpha(0.8);
+        assert_eq!(hexes(color.triadic()), hsla(&[200.0, 320.0, 80.0]));
+        assert_eq!(hexes(color.tetradic()), hsla(&[200.0, 260.0, 20.0, 80.0]));
+        assert_eq!(hexes(color.square()), hsla(&[200.0, 290.0, 20.0, 110.0]));
+        assert_eq!(
+            hexes(color.split_complementary()),
+            hsla(&[200.0, 350.0, 50.0])
+        );
+
+        // greys have no hue to rotate
+        assert_eq!(
+            color!(#808080).triadic(),
+            Palette::new(vec![color!(#808080); 3])
+        );
+    }
+}
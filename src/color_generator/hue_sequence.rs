@@ -0,0 +1,108 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{Color, Error};
+use alloc::format;
+
+/// The golden angle in degrees, 360 / φ², the hue step of [`Color::hue_sequence`].
+const GOLDEN_ANGLE: f64 = 137.50776405003785;
+
+impl Color {
+    /// An endless sequence of colors with hues a golden angle (about 137.5 degrees) apart.
+    ///
+    /// The golden angle is the most irrational turn of the hue circle, so each new hue falls
+    /// in the largest gap left by the hues before it. Any number of first colors of the
+    /// sequence are well apart, which makes it handy for chart series added on the fly.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The hue of the first color in degrees.
+    /// * `saturation` - The HSL saturation of the colors, between 0.0 and 1.0.
+    /// * `lightness` - The HSL lightness of the colors, between 0.0 and 1.0.
+    ///
+    /// Saturation and lightness out of range are clamped, and any argument that is not a
+    /// finite number is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let hues = Color::hue_sequence(0.0, 0.8, 0.5)
+    ///     .unwrap()
+    ///     .take(4)
+    ///     .map(|color| color.hue().round())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(hues, vec![0.0, 138.0, 275.0, 53.0]);
+    ///
+    /// assert!(Color::hue_sequence(f64::NAN, 0.8, 0.5).is_err());
+    /// ```
+    pub fn hue_sequence(
+        start: f64,
+        saturation: f64,
+        lightness: f64,
+    ) -> Result<impl Iterator<Item = Color>, Error> {
+        if [start, saturation, lightness]
+            .iter()
+            .any(|v| !v.is_finite())
+        {
+            return Err(Error::InvalidParamsError(format!(
+                "hue sequence arguments must be finite, got start {}, saturation {} and lightness {}",
+                start, saturation, lightness
+            )));
+        }
+        let start = start.rem_euclid(360.0);
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+        Ok((0..).map(move |i: u64| {
+            let hue = (start + GOLDEN_ANGLE * i as f64).rem_euclid(360.0);
+            Color::from_hsl(hue, saturation, lightness).unwrap()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_hue_sequence() {
+        let colors = Color::hue_sequence(-30.0, 0.6, 0.4)
+            .unwrap()
+            .take(20)
+            .collect::<Vec<_>>();
+        assert_eq!(colors[0].hue().round(), 330.0);
+        for color in &colors {
+            assert!((color.saturation() - 0.6).abs() < 0.01);
+            assert!((color.lightness() - 0.4).abs() < 0.01);
+        }
+
+        // the hues of the first n colors are never closer than 360 / n / 3
+        for n in [3, 5, 8, 13, 20] {
+            let mut hues = colors[..n].iter().map(|c| c.hue()).collect::<Vec<_>>();
+            hues.sort_by(f64::total_cmp);
+            let gaps = hues
+                .windows(2)
+                .map(|w| w[1] - w[0])
+                .chain([hues[0] + 360.0 - hues[n - 1]]);
+            assert!(gaps.fold(f64::INFINITY, f64::min) > 360.0 / n as f64 / 3.0);
+        }
+    }
+
+    #[test]
+    fn test_hue_sequence_non_finite() {
+        for (start, saturation, lightness) in [
+            (f64::NAN, 0.5, 0.5),
+            (f64::INFINITY, 0.5, 0.5),
+            (0.0, f64::NAN, 0.5),
+            (0.0, 0.5, f64::NEG_INFINITY),
+        ] {
+            assert!(Color::hue_sequence(start, saturation, lightness).is_err());
+        }
+        let color = Color::hue_sequence(1e300, 2.0, -1.0)
+            .unwrap()
+            .next()
+            .unwrap();
+        assert_eq!(color.hex(), "#000");
+    }
+}
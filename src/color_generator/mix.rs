@@ -1,4 +1,5 @@
 use crate::{Color, Error};
+use alloc::string::ToString;
 
 impl Color {
     /// Mix two colors with a weight.
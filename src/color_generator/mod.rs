@@ -1,3 +1,6 @@
 pub mod average;
+pub mod harmony;
+pub mod hue_sequence;
 pub mod mix;
 pub mod random;
+pub mod random_color;
@@ -1,11 +1,12 @@
-use crate::Color;
-use std::str::FromStr;
-
-static DIGITS: &str = "0123456789abcdef";
+use crate::{utils::rng::SplitMix64, Color};
+#[cfg(feature = "random")]
+use rand::Rng;
 
 impl Color {
     /// Generate a random color.
     ///
+    /// See [`Color::random_seeded`] and [`Color::random_with`] for reproducible colors.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -13,19 +14,50 @@ impl Color {
     ///
     /// let color = Color::random();
     /// ```
+    #[cfg(feature = "random")]
     pub fn random() -> Color {
-        let mut color = String::from("#");
-        for _ in 0..6 {
-            let index = rand::random::<usize>() % DIGITS.len();
-            color.push(DIGITS.chars().nth(index).unwrap());
-        }
-        Color::from_str(&color).unwrap()
+        Color::random_with(&mut rand::thread_rng())
+    }
+    /// Generate a random color from a seed, the same seed always gives the same color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// assert_eq!(Color::random_seeded(42), Color::random_seeded(42));
+    /// ```
+    pub fn random_seeded(seed: u64) -> Color {
+        let [r, g, b, ..] = SplitMix64::new(seed).next_u64().to_le_bytes();
+        Color::new(r, g, b, 1.0)
+    }
+    /// Generate a random color with a random number generator, e.g. a seeded one for
+    /// reproducible tests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let colors = [Color::random_with(&mut rng), Color::random_with(&mut rng)];
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// assert_eq!(colors, [Color::random_with(&mut rng), Color::random_with(&mut rng)]);
+    /// ```
+    #[cfg(feature = "random")]
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Color {
+        let [r, g, b] = rng.gen::<[u8; 3]>();
+        Color::new(r, g, b, 1.0)
     }
 }
 
 #[cfg(test)]
+#[cfg(feature = "random")]
 mod tests {
     use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
 
     #[test]
     fn test_random() {
@@ -37,4 +69,18 @@ mod tests {
             assert!(color.alpha >= 0.0 && color.alpha <= 1.0);
         }
     }
+
+    #[test]
+    fn test_random_seeded() {
+        assert_eq!(Color::random_seeded(0), Color::random_seeded(0));
+        let colors = (0..100).map(Color::random_seeded).collect::<Vec<_>>();
+        assert!(colors.windows(2).all(|w| w[0] != w[1]));
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let colors = (0..10)
+            .map(|_| Color::random_with(&mut rng))
+            .collect::<Vec<_>>();
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!(colors.iter().all(|c| *c == Color::random_with(&mut rng)));
+    }
 }
@@ -0,0 +1,458 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{utils::rng::SplitMix64, Color, Palette};
+use core::ops::RangeInclusive;
+#[cfg(feature = "random")]
+use rand::Rng;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The hue of the colors of a [`RandomColor`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Hue {
+    /// Greys, without any hue.
+    Monochrome,
+    /// Hues from 334 to 18 degrees.
+    Red,
+    /// Hues from 18 to 46 degrees.
+    Orange,
+    /// Hues from 46 to 62 degrees.
+    Yellow,
+    /// Hues from 62 to 178 degrees.
+    Green,
+    /// Hues from 178 to 257 degrees.
+    Blue,
+    /// Hues from 257 to 282 degrees.
+    Purple,
+    /// Hues from 282 to 334 degrees.
+    Pink,
+    /// Hues between two angles in degrees, e.g. `Range(-20.0, 20.0)` for hues around red.
+    ///
+    /// A range of a full turn or more, or with an end that is not finite, is any hue.
+    Range(f64, f64),
+}
+
+/// The brightness of the colors of a [`RandomColor`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Luminosity {
+    /// Saturated colors.
+    Bright,
+    /// Pale, light colors.
+    Light,
+    /// Deep, dark colors.
+    Dark,
+    /// Any saturation and brightness, including dull colors.
+    Random,
+}
+
+/// Hue range and the lowest brightness at each saturation of a color of the hue wheel,
+/// the dictionary of [randomColor](https://github.com/davidmerfield/randomColor).
+struct ColorInfo {
+    hue_range: [i32; 2],
+    lower_bounds: &'static [[i32; 2]],
+}
+
+const MONOCHROME: ColorInfo = ColorInfo {
+    hue_range: [0, 0],
+    lower_bounds: &[[0, 0], [100, 0]],
+};
+const COLOR_INFOS: [ColorInfo; 7] = [
+    // red
+    ColorInfo {
+        hue_range: [-26, 18],
+        lower_bounds: &[
+            [20, 100],
+            [30, 92],
+            [40, 89],
+            [50, 85],
+            [60, 78],
+            [70, 70],
+            [80, 60],
+            [90, 55],
+            [100, 50],
+        ],
+    },
+    // orange
+    ColorInfo {
+        hue_range: [18, 46],
+        lower_bounds: &[
+            [20, 100],
+            [30, 93],
+            [40, 88],
+            [50, 86],
+            [60, 85],
+            [70, 70],
+            [100, 70],
+        ],
+    },
+    // yellow
+    ColorInfo {
+        hue_range: [46, 62],
+        lower_bounds: &[
+            [25, 100],
+            [40, 85],
+            [50, 81],
+            [60, 74],
+            [70, 72],
+            [80, 67],
+            [90, 58],
+            [100, 57],
+        ],
+    },
+    // green
+    ColorInfo {
+        hue_range: [62, 178],
+        lower_bounds: &[
+            [30, 100],
+            [40, 90],
+            [50, 85],
+            [60, 81],
+            [70, 74],
+            [80, 64],
+            [90, 50],
+            [100, 40],
+        ],
+    },
+    // blue
+    ColorInfo {
+        hue_range: [178, 257],
+        lower_bounds: &[
+            [20, 100],
+            [30, 86],
+            [40, 80],
+            [50, 74],
+            [60, 60],
+            [70, 52],
+            [80, 44],
+            [90, 39],
+            [100, 35],
+        ],
+    },
+    // purple
+    ColorInfo {
+        hue_range: [257, 282],
+        lower_bounds: &[
+            [20, 100],
+            [30, 87],
+            [40, 79],
+            [50, 70],
+            [60, 65],
+            [70, 59],
+            [80, 52],
+            [90, 45],
+            [100, 42],
+        ],
+    },
+    // pink
+    ColorInfo {
+        hue_range: [282, 334],
+        lower_bounds: &[
+            [20, 100],
+            [30, 90],
+            [40, 86],
+            [60, 84],
+            [80, 80],
+            [90, 75],
+            [100, 73],
+        ],
+    },
+];
+
+impl ColorInfo {
+    fn of_hue(hue: i32) -> &'static ColorInfo {
+        // the red range wraps around 0
+        let hue = if hue >= 334 { hue - 360 } else { hue };
+        COLOR_INFOS
+            .iter()
+            .find(|info| (info.hue_range[0]..=info.hue_range[1]).contains(&hue))
+            .unwrap_or(&COLOR_INFOS[0])
+    }
+    fn saturation_range(&self) -> [i32; 2] {
+        [
+            self.lower_bounds[0][0],
+            self.lower_bounds[self.lower_bounds.len() - 1][0],
+        ]
+    }
+    /// The lowest brightness at a saturation, interpolated between the lower bounds.
+    fn min_brightness(&self, saturation: i32) -> i32 {
+        for bounds in self.lower_bounds.windows(2) {
+            let ([s1, v1], [s2, v2]) = (bounds[0], bounds[1]);
+            if (s1..=s2).contains(&saturation) {
+                let m = (v2 - v1) as f64 / (s2 - s1) as f64;
+                return (m * (saturation - s1) as f64 + v1 as f64) as i32;
+            }
+        }
+        0
+    }
+}
+
+/// Generates attractive random colors, like [randomColor](https://github.com/davidmerfield/randomColor).
+///
+/// Uniformly random RGB colors are often dull or muddy. The saturation and brightness of the
+/// colors are instead picked from ranges tuned for each part of the hue wheel.
+///
+/// # Seed
+///
+/// Without the `random` feature there is no source of randomness, and colors generated
+/// without a [seed](RandomColor::seed) always use the seed 0, so every call gives the same
+/// colors. Set a seed, e.g. from the clock or the platform, for different colors each time.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{Hue, Luminosity, RandomColor};
+///
+/// let colors = RandomColor::new()
+///     .hue(Hue::Blue)
+///     .luminosity(Luminosity::Bright)
+///     .seed(42)
+///     .count(3)
+///     .generate();
+/// assert_eq!(colors.len(), 3);
+/// assert!(colors.iter().all(|c| (178.0..=257.0).contains(&c.hsv_hue())));
+/// // the same seed gives the same colors
+/// assert_eq!(colors, RandomColor::new().hue(Hue::Blue).luminosity(Luminosity::Bright).seed(42).count(3).generate());
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RandomColor {
+    hue: Option<Hue>,
+    luminosity: Option<Luminosity>,
+    seed: Option<u64>,
+    alpha: RangeInclusive<f64>,
+    count: usize,
+}
+
+impl Default for RandomColor {
+    fn default() -> Self {
+        RandomColor::new()
+    }
+}
+
+impl RandomColor {
+    /// Creates a new [`RandomColor`] generating one opaque color of any hue.
+    pub fn new() -> Self {
+        RandomColor {
+            hue: None,
+            luminosity: None,
+            seed: None,
+            alpha: 1.0..=1.0,
+            count: 1,
+        }
+    }
+    /// Sets the hue of the colors.
+    pub fn hue(mut self, hue: Hue) -> Self {
+        self.hue = Some(hue);
+        self
+    }
+    /// Sets the brightness of the colors.
+    pub fn luminosity(mut self, luminosity: Luminosity) -> Self {
+        self.luminosity = Some(luminosity);
+        self
+    }
+    /// Sets the seed of the random number generator, the same seed always gives the same
+    /// colors.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+    /// Sets the range of the alpha of the colors, `1.0..=1.0` by default.
+    pub fn alpha(mut self, alpha: RangeInclusive<f64>) -> Self {
+        self.alpha = alpha;
+        self
+    }
+    /// Sets the number of colors to generate, 1 by default.
+    pub fn count(mut self, n: usize) -> Self {
+        self.count = n;
+        self
+    }
+    /// Generates the colors.
+    ///
+    /// Without a [seed](RandomColor::seed), the seed is random with the `random` feature.
+    /// **Without the `random` feature, the seed is 0 and the colors are the same on every
+    /// call**, see [the seed section](RandomColor#seed).
+    pub fn generate(&self) -> Palette {
+        #[cfg(feature = "random")]
+        let seed = self.seed.unwrap_or_else(rand::random);
+        #[cfg(not(feature = "random"))]
+        let seed = self.seed.unwrap_or_default();
+        let mut rng = SplitMix64::new(seed);
+        (0..self.count).map(|_| self.pick(&mut rng)).collect()
+    }
+    /// Generates the colors with a random number generator instead of the seed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::RandomColor;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let generator = RandomColor::new().count(3);
+    /// let colors = generator.generate_with(&mut StdRng::seed_from_u64(7));
+    /// assert_eq!(colors, generator.generate_with(&mut StdRng::seed_from_u64(7)));
+    /// ```
+    #[cfg(feature = "random")]
+    pub fn generate_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Palette {
+        let mut rng = SplitMix64::new(rng.gen());
+        (0..self.count).map(|_| self.pick(&mut rng)).collect()
+    }
+
+    fn pick(&self, rng: &mut SplitMix64) -> Color {
+        let h = self.pick_hue(rng);
+        let s = self.pick_saturation(h, rng);
+        let v = self.pick_brightness(h, s, rng);
+        let (min, max) = (*self.alpha.start(), *self.alpha.end());
+        let alpha = (min + (max - min) * rng.next_f64()).clamp(0.0, 1.0);
+        let mut color = Color::from_hsv(h as f64, s as f64 / 100.0, v as f64 / 100.0).unwrap();
+        color.alpha = alpha;
+        color
+    }
+    fn pick_hue(&self, rng: &mut SplitMix64) -> i32 {
+        let [min, max] = match self.hue {
+            None => [0, 360],
+            Some(Hue::Monochrome) => return 0,
+            Some(Hue::Range(min, max)) => hue_range(min, max),
+            Some(hue) => {
+                let index = [
+                    Hue::Red,
+                    Hue::Orange,
+                    Hue::Yellow,
+                    Hue::Green,
+                    Hue::Blue,
+                    Hue::Purple,
+                    Hue::Pink,
+                ]
+                .iter()
+                .position(|h| *h == hue)
+                .unwrap();
+                COLOR_INFOS[index].hue_range
+            }
+        };
+        random_within(min, max, rng).rem_euclid(360)
+    }
+    fn pick_saturation(&self, hue: i32, rng: &mut SplitMix64) -> i32 {
+        if self.hue == Some(Hue::Monochrome) {
+            return 0;
+        }
+        let [mut min, mut max] = ColorInfo::of_hue(hue).saturation_range();
+        match self.luminosity {
+            Some(Luminosity::Random) => return random_within(0, 100, rng),
+            Some(Luminosity::Bright) => min = 55,
+            Some(Luminosity::Dark) => min = max - 10,
+            Some(Luminosity::Light) => max = 55,
+            None => {}
+        }
+        random_within(min, max, rng)
+    }
+    fn pick_brightness(&self, hue: i32, saturation: i32, rng: &mut SplitMix64) -> i32 {
+        let info = match self.hue {
+            Some(Hue::Monochrome) => &MONOCHROME,
+            _ => ColorInfo::of_hue(hue),
+        };
+        let mut min = info.min_brightness(saturation);
+        let mut max = 100;
+        match self.luminosity {
+            Some(Luminosity::Dark) => max = min + 20,
+            Some(Luminosity::Light) => min = (max + min) / 2,
+            Some(Luminosity::Random) => min = 0,
+            _ => {}
+        }
+        random_within(min, max, rng)
+    }
+}
+
+/// The bounds of a [`Hue::Range`] in whole degrees, starting in `0..360` and at most a turn
+/// apart, so they can't overflow.
+fn hue_range(a: f64, b: f64) -> [i32; 2] {
+    let (min, max) = (a.min(b).round(), a.max(b).round());
+    if !(a.is_finite() && b.is_finite()) || max - min >= 360.0 {
+        return [0, 360];
+    }
+    let start = min.rem_euclid(360.0);
+    [start as i32, (start + max - min) as i32]
+}
+
+/// A random integer in `min..=max`.
+fn random_within(min: i32, max: i32, rng: &mut SplitMix64) -> i32 {
+    let width = i64::from(max) - i64::from(min) + 1;
+    (i64::from(min) + (rng.next_f64() * width as f64).floor() as i64) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_random_color_hue() {
+        let hues = [
+            (Hue::Red, 334.0, 18.0),
+            (Hue::Orange, 18.0, 46.0),
+            (Hue::Yellow, 46.0, 62.0),
+            (Hue::Green, 62.0, 178.0),
+            (Hue::Blue, 178.0, 257.0),
+            (Hue::Purple, 257.0, 282.0),
+            (Hue::Pink, 282.0, 334.0),
+            (Hue::Range(-10.0, 10.0), 350.0, 10.0),
+            (Hue::Range(710.0, 730.0), 350.0, 10.0),
+            (Hue::Range(-1e20, 1e20), 0.0, 360.0),
+            (Hue::Range(f64::NAN, 10.0), 0.0, 360.0),
+            (Hue::Range(0.0, f64::INFINITY), 0.0, 360.0),
+        ];
+        for (hue, min, max) in hues {
+            let colors = RandomColor::new().hue(hue).seed(7).count(50).generate();
+            assert_eq!(colors.len(), 50);
+            for color in colors.iter() {
+                let h = color.hsv_hue();
+                if min < max {
+                    assert!((min - 0.5..=max + 0.5).contains(&h), "{:?} {}", hue, h);
+                } else {
+                    assert!(h >= min - 0.5 || h <= max + 0.5, "{:?} {}", hue, h);
+                }
+            }
+        }
+
+        let greys = RandomColor::new().hue(Hue::Monochrome).count(20).generate();
+        assert!(greys.iter().all(|c| c.saturation() == 0.0));
+    }
+
+    #[test]
+    fn test_random_color_luminosity() {
+        let lightness = |luminosity| {
+            let colors = RandomColor::new()
+                .luminosity(luminosity)
+                .seed(1)
+                .count(100)
+                .generate();
+            colors
+                .iter()
+                .map(|c| c.vec_of(ColorSpace::Lab)[0])
+                .sum::<f64>()
+                / 100.0
+        };
+        assert!(lightness(Luminosity::Dark) < lightness(Luminosity::Bright));
+        assert!(lightness(Luminosity::Bright) < lightness(Luminosity::Light));
+
+        let bright = RandomColor::new()
+            .luminosity(Luminosity::Bright)
+            .count(50)
+            .generate();
+        assert!(bright.iter().all(|c| c.hsv_saturation() >= 0.545));
+    }
+
+    #[test]
+    fn test_random_color_seed_alpha() {
+        let generator = RandomColor::new().seed(123).count(5);
+        assert_eq!(generator.generate(), generator.generate());
+        assert_ne!(generator.generate(), generator.clone().seed(124).generate());
+        assert!(RandomColor::new().count(0).generate().is_empty());
+        assert_eq!(RandomColor::default().generate().len(), 1);
+
+        let colors = RandomColor::new().alpha(0.2..=0.6).count(50).generate();
+        assert!(colors.iter().all(|c| (0.2..=0.6).contains(&c.alpha())));
+        let colors = RandomColor::new().alpha(0.5..=0.5).count(5).generate();
+        assert!(colors.iter().all(|c| c.alpha() == 0.5));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    conversion::{
+        chromatic_adaptation::adapt_xyz,
+        xyz::{rgb2xyz, xyz2rgb},
+    },
+    AdaptationMethod, Color, Illuminant,
+};
+
+impl Color {
+    /// Adapt the color from one reference white to another with the Bradford transform.
+    ///
+    /// The result is the color that appears the same under the `to` illuminant as the
+    /// original does under the `from` illuminant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, Illuminant};
+    ///
+    /// let color = color!(#fff);
+    /// let adapted = color.adapt(Illuminant::D65, Illuminant::D50);
+    /// assert_eq!(adapted.hex(), "#fffcdd");
+    ///
+    /// let color = color!(#808080);
+    /// let adapted = color.adapt(Illuminant::D65, Illuminant::D50);
+    /// assert_eq!(adapted.rgb(), "rgb(138, 127, 110)");
+    /// ```
+    pub fn adapt(&self, from: Illuminant, to: Illuminant) -> Self {
+        self.adapt_with(from, to, AdaptationMethod::Bradford)
+    }
+    /// Adapt the color from one reference white to another with the given transform.
+    ///
+    /// Colors adapted outside of the sRGB gamut are clipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, AdaptationMethod, Illuminant};
+    ///
+    /// let color = color!(#808080);
+    /// let adapted = color.adapt_with(Illuminant::D65, Illuminant::D50, AdaptationMethod::VonKries);
+    /// assert_eq!(adapted.rgb(), "rgb(138, 127, 110)");
+    /// ```
+    pub fn adapt_with(&self, from: Illuminant, to: Illuminant, method: AdaptationMethod) -> Self {
+        let xyz = adapt_xyz(&rgb2xyz(&self.rgb), from, to, method);
+        let [r, g, b] = xyz2rgb(&xyz).map(|v| v.clamp(0.0, 255.0));
+        Color::new(r, g, b, self.alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_color_adapt() {
+        let color = color!(#ff3399);
+        let adapted = color.adapt(Illuminant::D65, Illuminant::D65);
+        assert_eq!(adapted.hex(), "#f39");
+
+        let color = color!(rgba(128, 128, 128, 0.5));
+        let adapted = color.adapt(Illuminant::D65, Illuminant::D50);
+        assert_eq!(adapted.alpha(), 0.5);
+
+        let back = adapted.adapt(Illuminant::D50, Illuminant::D65);
+        assert_eq!(back.hex(), "#80808080");
+    }
+
+    #[test]
+    fn test_color_adapt_with() {
+        let color = color!(#fff);
+        let adapted =
+            color.adapt_with(Illuminant::D50, Illuminant::D65, AdaptationMethod::VonKries);
+        assert_eq!(adapted.hex(), "#ecffff");
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{
+    conversion::utils::{gam_srgb, lin_srgb, multiply_matrix_vec},
+    delta_e,
+    utils::normalize_color,
+    Color,
+};
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Type of color vision deficiency (color blindness).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CvdType {
+    /// Missing or anomalous long-wavelength (red) cones.
+    Protanopia,
+    /// Missing or anomalous medium-wavelength (green) cones, the most common deficiency.
+    Deuteranopia,
+    /// Missing or anomalous short-wavelength (blue) cones.
+    Tritanopia,
+    /// No color vision at all, only luminance.
+    Achromatopsia,
+}
+
+impl CvdType {
+    /// All the color vision deficiencies.
+    pub const ALL: [CvdType; 4] = [
+        CvdType::Protanopia,
+        CvdType::Deuteranopia,
+        CvdType::Tritanopia,
+        CvdType::Achromatopsia,
+    ];
+
+    /// Linear RGB simulation matrix of the deficiency at full severity.
+    ///
+    /// reference: [Machado et al. 2009](https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html)
+    fn matrix(&self) -> [[f64; 3]; 3] {
+        match self {
+            CvdType::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            CvdType::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            CvdType::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+            CvdType::Achromatopsia => [[0.2126, 0.7152, 0.0722]; 3],
+        }
+    }
+}
+
+impl Color {
+    /// Simulates how the color is seen with a color vision deficiency.
+    ///
+    /// The simulation is done in linear RGB with the matrices of Machado et al., and
+    /// partial severities blend linearly between normal vision and the full deficiency.
+    ///
+    /// # Arguments
+    ///
+    /// * `cvd` - The color vision deficiency.
+    /// * `severity` - How severe the deficiency is. 0.0 is normal vision, 1.0 is the full deficiency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, CvdType};
+    ///
+    /// let red = color!(#f00);
+    /// assert_eq!(red.simulate_cvd(CvdType::Protanopia, 1.0).hex(), "#6d5f00");
+    /// assert_eq!(red.simulate_cvd(CvdType::Deuteranopia, 1.0).hex(), "#a39000");
+    /// assert_eq!(red.simulate_cvd(CvdType::Achromatopsia, 1.0).hex(), "#7f7f7f");
+    /// assert_eq!(red.simulate_cvd(CvdType::Protanopia, 0.0).hex(), "#f00");
+    /// ```
+    pub fn simulate_cvd(&self, cvd: CvdType, severity: f64) -> Self {
+        let severity = severity.clamp(0.0, 1.0);
+        let linear = lin_srgb(&normalize_color(&self.rgb));
+        let simulated = multiply_matrix_vec(&cvd.matrix(), &linear);
+        let mixed = [0, 1, 2]
+            .map(|i| (linear[i] * (1.0 - severity) + simulated[i] * severity).clamp(0.0, 1.0));
+        let rgb = gam_srgb(&mixed);
+        Color::new(rgb[0] * 255.0, rgb[1] * 255.0, rgb[2] * 255.0, self.alpha)
+    }
+
+    /// Shifts the color to be easier to distinguish with a color vision deficiency.
+    ///
+    /// The information lost by the deficiency (the difference between the color and its
+    /// simulation) is redistributed to the channels that are still seen, following the
+    /// daltonization of Fidaner et al. Achromatopsia can not be corrected, so the color
+    /// is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, CvdType};
+    ///
+    /// let red = color!(#f00);
+    /// assert_eq!(red.daltonize(CvdType::Protanopia).hex(), "#ff0766");
+    /// assert_eq!(red.daltonize(CvdType::Achromatopsia).hex(), "#f00");
+    /// ```
+    pub fn daltonize(&self, cvd: CvdType) -> Self {
+        let shift = match cvd {
+            CvdType::Protanopia | CvdType::Deuteranopia => {
+                [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]]
+            }
+            CvdType::Tritanopia => [[1.0, 0.0, 0.7], [0.0, 1.0, 0.7], [0.0, 0.0, 0.0]],
+            CvdType::Achromatopsia => return *self,
+        };
+        let simulated = self.simulate_cvd(cvd, 1.0);
+        let error = [0, 1, 2].map(|i| self.rgb[i] - simulated.rgb[i]);
+        let correction = multiply_matrix_vec(&shift, &error);
+        let [r, g, b] = [0, 1, 2].map(|i| (self.rgb[i] + correction[i]).clamp(0.0, 255.0));
+        Color::new(r, g, b, self.alpha)
+    }
+}
+
+/// Checks whether all colors of a palette stay distinguishable with color vision deficiencies.
+///
+/// Every pair of colors is compared with [`delta_e`] as seen with normal vision, and with full
+/// protanopia, deuteranopia and tritanopia. Achromatopsia is rare enough that palettes are not
+/// expected to be safe for it.
+///
+/// # Arguments
+///
+/// * `colors` - The colors of the palette.
+/// * `min_delta_e` - The smallest color difference that counts as distinguishable, e.g. 10.0.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{color, is_cvd_safe};
+///
+/// // red and green look alike for most colorblind people
+/// let palette = [color!(#d62728), color!(#2ca02c), color!(#1f77b4)];
+/// assert!(!is_cvd_safe(&palette, 10.0));
+///
+/// let palette = [color!(#e69f00), color!(#56b4e9), color!(#000)];
+/// assert!(is_cvd_safe(&palette, 10.0));
+/// ```
+pub fn is_cvd_safe(colors: &[Color], min_delta_e: f64) -> bool {
+    let visions = [
+        None,
+        Some(CvdType::Protanopia),
+        Some(CvdType::Deuteranopia),
+        Some(CvdType::Tritanopia),
+    ];
+    visions.iter().all(|vision| {
+        let seen = colors
+            .iter()
+            .map(|color| match vision {
+                Some(cvd) => color.simulate_cvd(*cvd, 1.0),
+                None => *color,
+            })
+            .collect::<Vec<_>>();
+        seen.iter().enumerate().all(|(i, color1)| {
+            seen[i + 1..]
+                .iter()
+                .all(|color2| delta_e(color1, color2) >= min_delta_e)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_simulate_cvd() {
+        let green = color!(rgba(0, 255, 0, 0.5));
+        assert_eq!(
+            green.simulate_cvd(CvdType::Deuteranopia, 1.0).hex(),
+            "#efd63a80"
+        );
+        assert_eq!(
+            green.simulate_cvd(CvdType::Tritanopia, 0.5).hex(),
+            "#00fb9f80"
+        );
+
+        let blue = color!(#00f);
+        assert_eq!(blue.simulate_cvd(CvdType::Tritanopia, 1.0).hex(), "#006b96");
+
+        // greys are seen the same with any deficiency
+        let grey = color!(#808080);
+        for cvd in CvdType::ALL {
+            assert_eq!(grey.simulate_cvd(cvd, 1.0).hex(), "#808080");
+        }
+    }
+
+    #[test]
+    fn test_daltonize() {
+        let green = color!(rgba(0, 255, 0, 0.5));
+        assert_eq!(green.daltonize(CvdType::Deuteranopia).hex(), "#00810080");
+        assert_eq!(color!(#00f).daltonize(CvdType::Tritanopia).hex(), "#4a00ff");
+
+        // greys are not changed
+        let grey = color!(#808080);
+        for cvd in CvdType::ALL {
+            assert_eq!(grey.daltonize(cvd).hex(), "#808080");
+        }
+
+        // the corrected red and green are further apart for a deuteranope
+        let (red, green) = (color!(#d62728), color!(#2ca02c));
+        let seen = |color: Color| color.simulate_cvd(CvdType::Deuteranopia, 1.0);
+        assert!(
+            delta_e(
+                &seen(red.daltonize(CvdType::Deuteranopia)),
+                &seen(green.daltonize(CvdType::Deuteranopia))
+            ) > delta_e(&seen(red), &seen(green))
+        );
+    }
+
+    #[test]
+    fn test_is_cvd_safe() {
+        assert!(is_cvd_safe(&[], 10.0));
+        assert!(is_cvd_safe(&[color!(#f00)], 10.0));
+
+        let palette = [color!(#000), color!(#fff)];
+        assert!(is_cvd_safe(&palette, 50.0));
+        assert!(!is_cvd_safe(&palette, 200.0));
+
+        // orange and yellow-green merge with deuteranopia
+        let palette = [color!(#ff7f0e), color!(#bcbd22)];
+        assert!(is_cvd_safe(&palette, 3.0));
+        assert!(!is_cvd_safe(&palette, 5.0));
+    }
+}
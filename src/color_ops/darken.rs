@@ -1,4 +1,4 @@
-use crate::{Color, ColorSpace};
+use crate::{conversion::lab::lab2rgb, Color, ColorSpace};
 
 impl Color {
     /// Decrease the lightness of a color in the HSL color space by an absolute amount.
@@ -40,6 +40,49 @@ impl Color {
     pub fn lighten(&self, amount: f64) -> Self {
         self.darken(-amount)
     }
+    /// Decrease the lightness of a color in the Lab color space by an absolute amount.
+    ///
+    /// Unlike [`Color::darken`], equal amounts give perceptually equal steps in lightness
+    /// for any hue.
+    ///
+    /// # Arguments
+    /// `amount` - The amount to decrease the lightness by, as a fraction of the Lab lightness
+    /// range (0 - 100). Must be between 0.0 and 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let color = color!(#426105);
+    /// let color = color.darken_lab(0.1);
+    /// assert_eq!(color.hex(), "#2a4900");
+    /// ```
+    pub fn darken_lab(&self, amount: f64) -> Self {
+        let lab = self.vec_of(ColorSpace::Lab);
+        let l = (lab[0] - amount * 100.0).clamp(0.0, 100.0);
+        let rgb = lab2rgb(&[l, lab[1], lab[2]]);
+        let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+        Color::new(r, g, b, self.alpha)
+    }
+    /// Increase the lightness of a color in the Lab color space by an absolute amount.
+    ///
+    /// # Arguments
+    /// `amount` - The amount to increase the lightness by, as a fraction of the Lab lightness
+    /// range (0 - 100). Must be between 0.0 and 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let color = color!(#80e619);
+    /// let color = color.lighten_lab(0.2);
+    /// assert_eq!(color.hex(), "#b3ff59");
+    /// ```
+    pub fn lighten_lab(&self, amount: f64) -> Self {
+        self.darken_lab(-amount)
+    }
 }
 
 #[cfg(test)]
@@ -61,6 +104,22 @@ mod tests {
         assert_eq!(color.hex(), "#4d8a0f");
     }
 
+    #[test]
+    fn test_color_darken_lab() {
+        let color = color!(#808080);
+        let color = color.darken_lab(1.0);
+        assert_eq!(color.hex(), "#000");
+
+        let color = Color::new(128, 128, 128, 0.5);
+        let darker = color.darken_lab(0.1);
+        assert_eq!(darker.alpha(), 0.5);
+        let lab = darker.vec_of(ColorSpace::Lab);
+        assert!((lab[0] - (color.vec_of(ColorSpace::Lab)[0] - 10.0)).abs() < 0.5);
+
+        let color = color!(#808080);
+        assert_eq!(color.lighten_lab(1.0).hex(), "#fff");
+    }
+
     #[test]
     fn test_color_lighten() {
         let color = color!(#426105);
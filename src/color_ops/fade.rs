@@ -51,6 +51,22 @@ impl Color {
     pub fn fade_out(&self, amount: f64) -> Self {
         self.fade_in(-amount)
     }
+    /// Returns a copy of the color with the given alpha, clamped between 0.0 and 1.0.
+    ///
+    /// The same as [`Color::fade`], named after the channel it changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::color;
+    ///
+    /// let color = color!(#ff0000);
+    /// assert_eq!(color.with_alpha(0.25).rgba(), "rgba(255, 0, 0, 0.25)");
+    /// assert_eq!(color.with_alpha(1.5).alpha(), 1.0);
+    /// ```
+    pub fn with_alpha(&self, alpha: f64) -> Self {
+        self.fade(alpha)
+    }
 }
 
 #[cfg(test)]
@@ -76,5 +92,15 @@ mod tests {
         let color = color!(rgba(255, 0, 0, 0.5));
         let color = color.fade_out(0.2);
         assert_eq!(color.rgba(), "rgba(255, 0, 0, 0.3)");
+
+        let color = color.fade_out(0.5);
+        assert_eq!(color.alpha(), 0.0);
+    }
+
+    #[test]
+    fn test_with_alpha() {
+        let color = color!(rgba(0, 128, 255, 0.5));
+        assert_eq!(color.with_alpha(0.8), Color::new(0, 128, 255, 0.8));
+        assert_eq!(color.with_alpha(-1.0).alpha(), 0.0);
     }
 }
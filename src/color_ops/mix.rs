@@ -1,4 +1,126 @@
-use crate::Color;
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{
+    conversion::{
+        hsl::{hsl2rgb, rgb2hsl},
+        lab::{lab2rgb, rgb2lab},
+        lch::{lch2rgb, rgb2lch},
+        oklab::{oklab2rgb, rgb2oklab},
+        oklch::{oklch2rgb, rgb2oklch},
+        utils::{gam_srgb, lin_srgb},
+    },
+    utils::normalize_color,
+    Color,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Direction around the hue circle when interpolating in a polar color space,
+/// like the [hue interpolation methods](https://www.w3.org/TR/css-color-4/#hue-interpolation) of CSS.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HueInterpolation {
+    /// Take the shorter arc between the hues.
+    #[default]
+    Shorter,
+    /// Take the longer arc between the hues.
+    Longer,
+    /// Go around the hue circle with increasing hue angles.
+    Increasing,
+    /// Go around the hue circle with decreasing hue angles.
+    Decreasing,
+}
+
+/// Color space in which colors are interpolated, like the `in <space>` of CSS `color-mix()`.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InterpolationSpace {
+    /// Gamma-encoded sRGB, cheap but mixes tend to be dark and muddy.
+    Srgb,
+    /// Linear-light sRGB, physically correct mixing of light.
+    LinearRgb,
+    /// HSL, a polar space.
+    Hsl,
+    /// CIE Lab.
+    Lab,
+    /// CIE LCH, a polar space.
+    Lch,
+    /// OKLab, perceptually uniform, the default of CSS `color-mix()`.
+    #[default]
+    Oklab,
+    /// OKLCH, a polar space.
+    Oklch,
+}
+
+impl InterpolationSpace {
+    /// Index of the hue channel, if the space is polar.
+    pub(crate) fn hue_index(&self) -> Option<usize> {
+        match self {
+            InterpolationSpace::Hsl => Some(0),
+            InterpolationSpace::Lch | InterpolationSpace::Oklch => Some(2),
+            _ => None,
+        }
+    }
+    pub(crate) fn values_of(&self, rgb: &[f64; 3]) -> [f64; 3] {
+        match self {
+            InterpolationSpace::Srgb => *rgb,
+            InterpolationSpace::LinearRgb => lin_srgb(&normalize_color(rgb)),
+            InterpolationSpace::Hsl => rgb2hsl(rgb),
+            InterpolationSpace::Lab => rgb2lab(rgb),
+            InterpolationSpace::Lch => rgb2lch(rgb),
+            InterpolationSpace::Oklab => rgb2oklab(rgb),
+            InterpolationSpace::Oklch => rgb2oklch(rgb),
+        }
+    }
+    pub(crate) fn rgb_of(&self, values: &[f64; 3]) -> [f64; 3] {
+        let rgb = match self {
+            InterpolationSpace::Srgb => *values,
+            InterpolationSpace::LinearRgb => gam_srgb(values).map(|v| v * 255.0),
+            InterpolationSpace::Hsl => hsl2rgb(values),
+            InterpolationSpace::Lab => lab2rgb(values),
+            InterpolationSpace::Lch => lch2rgb(values),
+            InterpolationSpace::Oklab => oklab2rgb(values),
+            InterpolationSpace::Oklch => oklch2rgb(values),
+        };
+        rgb.map(|v| v.clamp(0.0, 255.0))
+    }
+    /// Interpolates the values of two colors in the space, `t` is the weight of the second color.
+    pub(crate) fn interpolate(
+        &self,
+        from: &[f64; 3],
+        to: &[f64; 3],
+        hue: HueInterpolation,
+        t: f64,
+    ) -> [f64; 3] {
+        let mut from = *from;
+        let mut to = *to;
+        if let Some(h) = self.hue_index() {
+            // the hue of an achromatic color (saturation or chroma of 0) is powerless,
+            // take it from the other color
+            if from[1] < 1e-4 {
+                from[h] = to[h];
+            } else if to[1] < 1e-4 {
+                to[h] = from[h];
+            }
+            let diff = to[h] - from[h];
+            match hue {
+                HueInterpolation::Shorter if diff > 180.0 => from[h] += 360.0,
+                HueInterpolation::Shorter if diff < -180.0 => to[h] += 360.0,
+                HueInterpolation::Longer if diff > 0.0 && diff < 180.0 => from[h] += 360.0,
+                HueInterpolation::Longer if diff > -180.0 && diff <= 0.0 => to[h] += 360.0,
+                HueInterpolation::Increasing if diff < 0.0 => to[h] += 360.0,
+                HueInterpolation::Decreasing if diff > 0.0 => from[h] += 360.0,
+                _ => {}
+            }
+        }
+        let mut values = [0, 1, 2].map(|i| from[i] * (1.0 - t) + to[i] * t);
+        if let Some(h) = self.hue_index() {
+            values[h] = values[h].rem_euclid(360.0);
+        }
+        values
+    }
+}
 
 impl Color {
     /// Mix two colors with a weight.
@@ -32,6 +154,69 @@ impl Color {
 
         Color::new(r, g, b, alpha)
     }
+    /// Mix two colors with a weight, interpolating in the given color space.
+    ///
+    /// Like CSS `color-mix()`, polar spaces take the shorter arc between the hues, and the alpha
+    /// is interpolated linearly.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to mix with.
+    /// * `space` - The color space to interpolate in.
+    /// * `weight` - The weight of the new color to mix with. 0.0 is all the original color, 1.0 is all the new color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, InterpolationSpace};
+    ///
+    /// let red = color!(#f00);
+    /// let blue = color!(#00f);
+    /// assert_eq!(red.mix_in(&blue, InterpolationSpace::Srgb, 0.5).hex(), "#800080");
+    /// assert_eq!(red.mix_in(&blue, InterpolationSpace::LinearRgb, 0.5).hex(), "#bc00bc");
+    /// assert_eq!(red.mix_in(&blue, InterpolationSpace::Oklab, 0.5).hex(), "#8c53a2");
+    /// assert_eq!(red.mix_in(&blue, InterpolationSpace::Hsl, 0.5).hex(), "#f0f");
+    /// ```
+    pub fn mix_in(&self, new_color: &Color, space: InterpolationSpace, weight: f64) -> Self {
+        self.mix_in_with_hue(new_color, space, HueInterpolation::default(), weight)
+    }
+    /// Mix two colors with a weight, interpolating in the given color space along the given
+    /// direction around the hue circle.
+    ///
+    /// The hue interpolation only matters for the polar spaces HSL, LCH and OKLCH.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to mix with.
+    /// * `space` - The color space to interpolate in.
+    /// * `hue` - The direction around the hue circle.
+    /// * `weight` - The weight of the new color to mix with. 0.0 is all the original color, 1.0 is all the new color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, HueInterpolation, InterpolationSpace};
+    ///
+    /// let red = color!(#f00);
+    /// let blue = color!(#00f);
+    /// let space = InterpolationSpace::Hsl;
+    /// assert_eq!(red.mix_in_with_hue(&blue, space, HueInterpolation::Shorter, 0.5).hex(), "#f0f");
+    /// assert_eq!(red.mix_in_with_hue(&blue, space, HueInterpolation::Longer, 0.5).hex(), "#0f0");
+    /// ```
+    pub fn mix_in_with_hue(
+        &self,
+        new_color: &Color,
+        space: InterpolationSpace,
+        hue: HueInterpolation,
+        weight: f64,
+    ) -> Self {
+        let weight = weight.clamp(0.0, 1.0);
+        let from = space.values_of(&self.rgb);
+        let to = space.values_of(&new_color.rgb);
+        let [r, g, b] = space.rgb_of(&space.interpolate(&from, &to, hue, weight));
+        let alpha = self.alpha * (1.0 - weight) + new_color.alpha * weight;
+        Color::new(r, g, b, alpha)
+    }
     /// Mix color with white in variable proportion.
     ///
     /// # Arguments
@@ -89,6 +274,81 @@ mod tests {
         assert_eq!(color3.hex(), "#800080");
     }
 
+    #[test]
+    fn test_mix_in() {
+        let color1 = color!(#003366);
+        let color2 = color!(#d2e1dd);
+        assert_eq!(
+            color1.mix_in(&color2, InterpolationSpace::Srgb, 0.5),
+            color1.mix_with(&color2, 0.5)
+        );
+
+        let red = color!(#f00);
+        let white = color!(#fff);
+        for space in [
+            InterpolationSpace::Srgb,
+            InterpolationSpace::LinearRgb,
+            InterpolationSpace::Hsl,
+            InterpolationSpace::Lab,
+            InterpolationSpace::Lch,
+            InterpolationSpace::Oklab,
+            InterpolationSpace::Oklch,
+        ] {
+            assert_eq!(red.mix_in(&white, space, 0.0).hex(), "#f00");
+            assert_eq!(red.mix_in(&white, space, 1.0).hex(), "#fff");
+        }
+        // the powerless hue of white does not shift the hue of red
+        let pink = red.mix_in(&white, InterpolationSpace::Oklch, 0.5);
+        assert_eq!(pink.hex(), "#ffa191");
+        let pink = red.mix_in(&white, InterpolationSpace::Hsl, 0.5);
+        assert_eq!(pink.hsl(), "hsl(0, 50%, 75%)");
+
+        // hues take the shorter arc through 0
+        let color1 = Color::from_hsl(350.0, 1.0, 0.5).unwrap();
+        let color2 = Color::from_hsl(30.0, 1.0, 0.5).unwrap();
+        let color3 = color1.mix_in(&color2, InterpolationSpace::Hsl, 0.5);
+        assert_eq!(color3.hsl(), "hsl(10, 100%, 50%)");
+
+        let color = color!(rgba(255, 0, 0, 0.2)).mix_in(&white, InterpolationSpace::Lab, 0.5);
+        assert_eq!(color.alpha(), 0.6);
+    }
+
+    #[test]
+    fn test_mix_in_with_hue() {
+        let color1 = Color::from_hsl(350.0, 1.0, 0.5).unwrap();
+        let color2 = Color::from_hsl(30.0, 1.0, 0.5).unwrap();
+        let mix = |hue| {
+            color1
+                .mix_in_with_hue(&color2, InterpolationSpace::Hsl, hue, 0.5)
+                .hsl()
+        };
+        assert_eq!(mix(HueInterpolation::Shorter), "hsl(10, 100%, 50%)");
+        assert_eq!(mix(HueInterpolation::Longer), "hsl(190, 100%, 50%)");
+        assert_eq!(mix(HueInterpolation::Increasing), "hsl(10, 100%, 50%)");
+        assert_eq!(mix(HueInterpolation::Decreasing), "hsl(190, 100%, 50%)");
+
+        let mix = |hue| {
+            color2
+                .mix_in_with_hue(&color1, InterpolationSpace::Hsl, hue, 0.5)
+                .hsl()
+        };
+        assert_eq!(mix(HueInterpolation::Increasing), "hsl(190, 100%, 50%)");
+        assert_eq!(mix(HueInterpolation::Decreasing), "hsl(10, 100%, 50%)");
+
+        // rectangular spaces ignore the hue interpolation
+        let red = color!(#f00);
+        let blue = color!(#00f);
+        assert_eq!(
+            red.mix_in_with_hue(
+                &blue,
+                InterpolationSpace::Oklab,
+                HueInterpolation::Longer,
+                0.5
+            ),
+            red.mix_in(&blue, InterpolationSpace::Oklab, 0.5)
+        );
+    }
+
     #[test]
     fn test_tint() {
         let color = color!(rgba(0, 0, 255, 0.5));
@@ -1,6 +1,9 @@
+pub mod adapt;
+pub mod cvd;
 pub mod darken;
 pub mod fade;
 pub mod mix;
 pub mod negate;
 pub mod saturate;
 pub mod spin;
+pub mod white_balance;
@@ -1,4 +1,7 @@
-use crate::Color;
+use crate::{
+    conversion::oklab::{oklab2rgb, rgb2oklab},
+    Color,
+};
 
 impl Color {
     /// Negates a color with rgb channels. The alpha channel is not affected.
@@ -20,6 +23,40 @@ impl Color {
             self.alpha,
         )
     }
+    /// Inverts a color to its RGB complement (255 - channel). The alpha channel is not affected.
+    ///
+    /// The same as [`Color::negate`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let color = color!(rgba(0, 120, 255, 0.5));
+    /// assert_eq!(color.invert().rgba(), "rgba(255, 135, 0, 0.5)");
+    /// ```
+    pub fn invert(self) -> Self {
+        self.negate()
+    }
+    /// Inverts the perceptual lightness of a color in the OKLab color space, keeping its hue and
+    /// chroma, e.g. for a "smart invert" dark mode. The alpha channel is not affected.
+    ///
+    /// Unlike [`Color::invert`], a saturated blue stays blue, and white and black swap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// assert_eq!(color!(#fff).invert_lightness().hex(), "#000");
+    /// assert_eq!(color!(#1e3a8a).invert_lightness().hex(), "#5e82d9");
+    /// ```
+    pub fn invert_lightness(self) -> Self {
+        let oklab = rgb2oklab(&self.rgb);
+        let rgb = oklab2rgb(&[1.0 - oklab[0], oklab[1], oklab[2]]);
+        let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+        Color::new(r, g, b, self.alpha)
+    }
 }
 
 #[cfg(test)]
@@ -40,4 +77,18 @@ mod tests {
         let negated_color = color.negate();
         assert_eq!(negated_color.rgb(), "rgb(255, 255, 255)");
     }
+
+    #[test]
+    fn test_invert_lightness() {
+        let color = color!(rgba(128, 128, 128, 0.3)).invert_lightness();
+        assert_eq!(color.rgba(), "rgba(72, 72, 72, 0.3)");
+
+        let color = color!(#000).invert_lightness();
+        assert_eq!(color.hex(), "#fff");
+
+        let blue = color!(#1e3a8a);
+        let hue = blue.vec_of(ColorSpace::OKLCH)[2];
+        let inverted_hue = blue.invert_lightness().vec_of(ColorSpace::OKLCH)[2];
+        assert!((hue - inverted_hue).abs() < 5.0);
+    }
 }
@@ -1,4 +1,21 @@
-use crate::{Color, ColorSpace};
+use crate::{conversion::lab::lab2rgb, Color, ColorSpace};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How [`Color::greyscale_with`] removes the color of a color.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GreyscaleStrategy {
+    /// Sets the saturation in the HSL color space to 0, keeping the HSL lightness.
+    #[default]
+    Hsl,
+    /// Weights the channels by the BT.601 luma coefficients (0.299, 0.587, 0.114),
+    /// as most image editors do.
+    Luminance,
+    /// Sets the chroma (`a` and `b`) in the Lab color space to 0, keeping the perceptual lightness.
+    Lab,
+}
 
 impl Color {
     /// Increase the saturation of a color in the HSL color space by an absolute amount.
@@ -57,6 +74,34 @@ impl Color {
     pub fn greyscale(&self) -> Self {
         self.desaturate(1.0)
     }
+    /// Remove all saturation from a color with the given strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, GreyscaleStrategy};
+    ///
+    /// let color = color!(#80e619);
+    /// assert_eq!(color.greyscale_with(GreyscaleStrategy::Hsl).hex(), "#808080");
+    /// assert_eq!(color.greyscale_with(GreyscaleStrategy::Luminance).hex(), "#b0b0b0");
+    /// assert_eq!(color.greyscale_with(GreyscaleStrategy::Lab).hex(), "#cecece");
+    /// ```
+    pub fn greyscale_with(&self, strategy: GreyscaleStrategy) -> Self {
+        match strategy {
+            GreyscaleStrategy::Hsl => self.greyscale().with_alpha(self.alpha),
+            GreyscaleStrategy::Luminance => {
+                let [r, g, b] = self.rgb;
+                let luma = 0.299 * r + 0.587 * g + 0.114 * b;
+                Color::new(luma, luma, luma, self.alpha)
+            }
+            GreyscaleStrategy::Lab => {
+                let l = self.vec_of(ColorSpace::Lab)[0];
+                let rgb = lab2rgb(&[l, 0.0, 0.0]);
+                let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+                Color::new(r, g, b, self.alpha)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -92,4 +137,30 @@ mod tests {
         let color = color.greyscale();
         assert_eq!(color.hex(), "#808080");
     }
+
+    #[test]
+    fn greyscale_with() {
+        let color = Color::from_str("rgba(0, 0, 255, 0.5)").unwrap();
+        assert_eq!(
+            color.greyscale_with(GreyscaleStrategy::Hsl).hex(),
+            "#80808080"
+        );
+        assert_eq!(
+            color.greyscale_with(GreyscaleStrategy::Luminance).hex(),
+            "#1d1d1d80"
+        );
+        assert_eq!(
+            color.greyscale_with(GreyscaleStrategy::Lab).hex(),
+            "#46464680"
+        );
+
+        let color = Color::from_str("#777").unwrap();
+        for strategy in [
+            GreyscaleStrategy::Hsl,
+            GreyscaleStrategy::Luminance,
+            GreyscaleStrategy::Lab,
+        ] {
+            assert_eq!(color.greyscale_with(strategy).hex(), "#777");
+        }
+    }
 }
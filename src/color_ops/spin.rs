@@ -30,9 +30,35 @@ impl Color {
         Color::from_hsl(h, s, l).unwrap()
     }
     /// Returns the [complement](https://en.wikipedia.org/wiki/Complementary_colors) of color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let color = color!(#036);
+    /// assert_eq!(color.complement().hex(), "#630");
+    /// ```
     pub fn complement(&self) -> Self {
         self.spin(180.0)
     }
+    /// Returns the two compound colors of color, the hues 30 degrees to either side of its
+    /// complement (also known as split complementary).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    /// use std::str::FromStr;
+    ///
+    /// let color = Color::from_str("hsl(10, 90%, 50%)").unwrap();
+    /// let [color1, color2] = color.compound();
+    /// assert_eq!(color1.hsl(), "hsl(160, 90%, 50%)");
+    /// assert_eq!(color2.hsl(), "hsl(220, 90%, 50%)");
+    /// ```
+    pub fn compound(&self) -> [Self; 2] {
+        [self.spin(150.0), self.spin(210.0)]
+    }
 }
 
 #[cfg(test)]
@@ -65,4 +91,16 @@ mod tests {
         let color = color.complement();
         assert_eq!(color.hex(), "#630");
     }
+
+    #[test]
+    fn test_color_compound() {
+        let color = Color::from_str("hsl(300, 50%, 40%)").unwrap();
+        let [color1, color2] = color.compound();
+        assert_eq!(color1.hsl(), "hsl(90, 50%, 40%)");
+        assert_eq!(color2.hsl(), "hsl(150, 50%, 40%)");
+
+        let [color1, color2] = color!(#808080).compound();
+        assert_eq!(color1.hex(), "#808080");
+        assert_eq!(color2.hex(), "#808080");
+    }
 }
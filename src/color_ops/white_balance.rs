@@ -0,0 +1,73 @@
+use crate::{
+    conversion::{
+        chromatic_adaptation::adapt_xyz_white,
+        temperature::{locus_white_point, xyz2cct},
+        utils::D65,
+        xyz::{rgb2xyz, xyz2rgb},
+    },
+    AdaptationMethod, Color,
+};
+
+impl Color {
+    /// Warm or cool the color, and shift its tint to magenta or green, like the white balance
+    /// sliders of a photo editor.
+    ///
+    /// The white point of sRGB is moved along the Planckian locus by `temperature_shift` mired,
+    /// warmer for positive values, and away from it by `tint_shift` Duv, more magenta for
+    /// positive values. The color is then adapted to the new white point with the Bradford
+    /// transform, in linear light. A color temperature filter of 50 mired, e.g. a quarter CTO gel
+    /// for tungsten light, is a clear but moderate shift; a tint of 0.01 is a visible cast.
+    ///
+    /// Colors adapted outside of the sRGB gamut are clipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::color;
+    ///
+    /// let color = color!(#808080);
+    /// assert_eq!(color.adjust_white_balance(0.0, 0.0).hex(), "#808080");
+    /// assert_eq!(color.adjust_white_balance(50.0, 0.0).hex(), "#8b7e6d");
+    /// assert_eq!(color.adjust_white_balance(-50.0, 0.0).hex(), "#768194");
+    /// assert_eq!(color.adjust_white_balance(0.0, 0.01).hex(), "#887d86");
+    /// ```
+    pub fn adjust_white_balance(&self, temperature_shift: f64, tint_shift: f64) -> Self {
+        let [cct, duv] = xyz2cct(&D65);
+        let mired = (1e6 / cct + temperature_shift).clamp(25.0, 1000.0);
+        let white = locus_white_point(mired, duv - tint_shift);
+
+        let xyz = adapt_xyz_white(
+            &rgb2xyz(&self.rgb),
+            &D65,
+            &white,
+            AdaptationMethod::Bradford,
+        );
+        let [r, g, b] = xyz2rgb(&xyz).map(|v| v.clamp(0.0, 255.0));
+        Color::new(r, g, b, self.alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_adjust_white_balance() {
+        let color = color!(rgba(255, 51, 153, 0.5));
+        assert_eq!(color.adjust_white_balance(0.0, 0.0), color);
+
+        let warm = color!(#fff).adjust_white_balance(100.0, 0.0);
+        assert!(warm.red() > warm.green() && warm.green() > warm.blue());
+        let cool = color!(#ccc).adjust_white_balance(-100.0, 0.0);
+        assert!(cool.red() < cool.green() && cool.green() < cool.blue());
+
+        let magenta = color!(#ccc).adjust_white_balance(0.0, 0.02);
+        assert!(magenta.green() < magenta.red() && magenta.green() < magenta.blue());
+        let green = color!(#ccc).adjust_white_balance(0.0, -0.02);
+        assert!(green.green() > green.red() && green.green() > green.blue());
+
+        let (cct, _) = color!(#ccc).adjust_white_balance(60.0, 0.0).cct().unwrap();
+        assert!(cct < 5000.0);
+        assert_eq!(color.adjust_white_balance(30.0, 0.01).alpha(), 0.5);
+    }
+}
@@ -0,0 +1,231 @@
+//! serde support for [`Color`].
+//!
+//! A [`Color`] is serialized as its `#rrggbb` hex string, or `#rrggbbaa` if it is not opaque.
+//! It is deserialized from any color string [`Color::from_str`] accepts. Only strings are
+//! requested from the deserializer, so formats that aren't self-describing, like bincode or
+//! postcard, work as well.
+//!
+//! The hex string rounds the channels to integers, use [`channels`] in a
+//! `#[serde(with = "color_art::color_serde::channels")]` attribute to keep them exactly.
+//! Data written by the derived implementation of earlier versions, a struct of `rgb` and
+//! `alpha`, is read with [`legacy`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use color_art::{color, Color};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Theme {
+//!     background: Color,
+//!     #[serde(with = "color_art::color_serde::channels")]
+//!     accent: Color,
+//! }
+//!
+//! let theme = Theme {
+//!     background: color!(#1e1e2e),
+//!     accent: Color::new(137.5, 180.0, 250.0, 0.5),
+//! };
+//! let json = serde_json::to_string(&theme).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r##"{"background":"#1e1e2e","accent":{"r":137.5,"g":180.0,"b":250.0,"alpha":0.5}}"##
+//! );
+//! assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), theme);
+//!
+//! let theme: Theme = serde_json::from_str(
+//!     r#"{"background": "rgb(30 30 46)", "accent": {"r": 137.5, "g": 180, "b": 250}}"#,
+//! )
+//! .unwrap();
+//! assert_eq!(theme.accent.alpha(), 1.0);
+//! ```
+
+use crate::Color;
+use core::{fmt, str::FromStr};
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        hex::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        hex::deserialize(deserializer)
+    }
+}
+
+/// Accepts color strings.
+struct ColorVisitor;
+
+impl<'de> Visitor<'de> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a color string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Color, E> {
+        Color::from_str(value).map_err(E::custom)
+    }
+}
+
+/// The channels of a color, the alpha defaults to opaque.
+#[derive(Serialize, Deserialize)]
+struct Channels {
+    r: f64,
+    g: f64,
+    b: f64,
+    #[serde(default = "opaque")]
+    alpha: f64,
+}
+
+fn opaque() -> f64 {
+    1.0
+}
+
+/// (De)serializes a [`Color`] as a hex string, the default representation.
+///
+/// Deserializing only accepts strings, but any color string, not only hex strings.
+pub mod hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&color.hex_full())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        deserializer.deserialize_str(ColorVisitor)
+    }
+}
+
+/// (De)serializes a [`Color`] as a struct of its exact channels, `r`, `g`, `b` (0.0 to 255.0)
+/// and `alpha` (0.0 to 1.0, opaque if missing). Channels out of range are an error.
+pub mod channels {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        let [r, g, b] = color.rgb;
+        Channels {
+            r,
+            g,
+            b,
+            alpha: color.alpha,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let Channels { r, g, b, alpha } = Channels::deserialize(deserializer)?;
+        Color::from_rgba(r, g, b, alpha).map_err(de::Error::custom)
+    }
+}
+
+/// (De)serializes a [`Color`] as the struct `{ rgb: [r, g, b], alpha }` written by the derived
+/// implementation of earlier versions, to keep reading existing data.
+pub mod legacy {
+    use super::*;
+
+    /// The fields of a color in earlier versions.
+    #[derive(Serialize, Deserialize)]
+    struct LegacyColor {
+        rgb: [f64; 3],
+        alpha: f64,
+    }
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        LegacyColor {
+            rgb: color.rgb,
+            alpha: color.alpha,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let LegacyColor {
+            rgb: [r, g, b],
+            alpha,
+        } = LegacyColor::deserialize(deserializer)?;
+        Color::from_rgba(r, g, b, alpha).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_serde_color() {
+        let color = Color::new(255.0, 0.0, 127.6, 0.5);
+        assert_eq!(serde_json::to_string(&color).unwrap(), r##""#ff008080""##);
+        assert_eq!(
+            serde_json::from_str::<Color>(r##""#ff008080""##).unwrap(),
+            Color::new(255, 0, 128, 128.0 / 255.0)
+        );
+        assert_eq!(
+            serde_json::from_str::<Color>(r#""hsl(120, 100%, 50%)""#).unwrap(),
+            color!(#0f0)
+        );
+        assert!(serde_json::from_str::<Color>(r#"{"r": 1.5, "g": 2, "b": 3}"#).is_err());
+        assert!(serde_json::from_str::<Color>(r#""not a color""#).is_err());
+        assert!(serde_json::from_str::<Color>("42").is_err());
+    }
+
+    #[test]
+    fn test_serde_with() {
+        #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+        struct Swatch {
+            #[serde(with = "crate::color_serde::channels")]
+            exact: Color,
+            #[serde(with = "crate::color_serde::hex")]
+            hex: Color,
+        }
+
+        let swatch = Swatch {
+            exact: Color::new(0.5, 100.0, 200.0, 0.3),
+            hex: color!(#abc),
+        };
+        let json = serde_json::to_string(&swatch).unwrap();
+        assert_eq!(
+            json,
+            r##"{"exact":{"r":0.5,"g":100.0,"b":200.0,"alpha":0.3},"hex":"#aabbcc"}"##
+        );
+        assert_eq!(serde_json::from_str::<Swatch>(&json).unwrap(), swatch);
+        assert!(serde_json::from_str::<Swatch>(r##"{"exact":"#fff","hex":"#fff"}"##).is_err());
+        for exact in [
+            r#"{"r":1000,"g":0,"b":0}"#,
+            r#"{"r":0,"g":-5,"b":0}"#,
+            r#"{"r":0,"g":0,"b":0,"alpha":7}"#,
+            r#"{"r":0,"g":0,"b":0,"alpha":-0.5}"#,
+        ] {
+            let json = format!(r##"{{"exact":{},"hex":"#fff"}}"##, exact);
+            assert!(serde_json::from_str::<Swatch>(&json).is_err(), "{}", exact);
+        }
+    }
+
+    #[test]
+    fn test_serde_legacy() {
+        #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+        struct Swatch {
+            #[serde(with = "crate::color_serde::legacy")]
+            color: Color,
+        }
+
+        let json = r#"{"color":{"rgb":[255.0,51.0,153.5],"alpha":0.5}}"#;
+        let swatch = serde_json::from_str::<Swatch>(json).unwrap();
+        assert_eq!(swatch.color, Color::new(255.0, 51.0, 153.5, 0.5));
+        assert_eq!(serde_json::to_string(&swatch).unwrap(), json);
+
+        for json in [
+            r#"{"color":{"rgb":[1000.0,-5.0,0.0],"alpha":1.0}}"#,
+            r#"{"color":{"rgb":[0.0,0.0,0.0],"alpha":-3.0}}"#,
+        ] {
+            assert!(serde_json::from_str::<Swatch>(json).is_err(), "{}", json);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::string::ToString;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -58,10 +59,40 @@ pub enum ColorSpace {
     ///
     /// YCbCr stands for luminance (Y), and the chrominance components Cb and Cr.
     YCbCr,
+    /// YPbPr color Space.
+    ///
+    /// YPbPr stands for luminance (Y), and the analog color difference components Pb and Pr.
+    ///
+    /// Uses the BT.601 coefficients, see [`ColorMatrix`](crate::ColorMatrix).
+    YPbPr,
     /// Lab color space.
     ///
     /// Lab stands for lightness, a, and b.
     Lab,
+    /// LCH color space.
+    ///
+    /// LCH stands for lightness, chroma, and hue, the cylindrical form of CIE Lab.
+    LCH,
+    /// [Luv](https://en.wikipedia.org/wiki/CIELUV) color space.
+    ///
+    /// Luv stands for lightness, u, and v.
+    Luv,
+    /// LCHuv color space.
+    ///
+    /// LCHuv stands for lightness, chroma, and hue, the cylindrical form of CIE Luv.
+    LCHuv,
+    /// [OKLab](https://bottosson.github.io/posts/oklab/) color space.
+    ///
+    /// OKLab stands for perceptual lightness, a, and b.
+    OKLab,
+    /// [OKLCH](https://www.w3.org/TR/css-color-4/#ok-lab) color space.
+    ///
+    /// OKLCH stands for lightness, chroma, and hue, the cylindrical form of OKLab.
+    OKLCH,
+    /// [Rec. 2020](https://en.wikipedia.org/wiki/Rec._2020) color space.
+    ///
+    /// Gamma-encoded red, green, and blue with the wide gamut primaries of ITU-R BT.2020.
+    Rec2020,
     /// Unknown color space.
     ///
     /// To be used when the color space is not known.
@@ -88,13 +119,48 @@ where
             "yiq" => ColorSpace::YIQ,
             "yuv" => ColorSpace::YUV,
             "ycbcr" => ColorSpace::YCbCr,
+            "ypbpr" => ColorSpace::YPbPr,
             "lab" => ColorSpace::Lab,
+            "lch" => ColorSpace::LCH,
+            "luv" => ColorSpace::Luv,
+            "lchuv" => ColorSpace::LCHuv,
+            "oklab" => ColorSpace::OKLab,
+            "oklch" => ColorSpace::OKLCH,
+            "rec2020" => ColorSpace::Rec2020,
             _ => ColorSpace::Unknown,
         }
     }
 }
 
 impl ColorSpace {
+    /// The value a percentage of `100%` resolves to, for the value at `index`.
+    ///
+    /// Follows the CSS Color 4 reference ranges, e.g. `lab(50% 0 0)` has a lightness of 50.
+    pub(crate) fn percent_reference(&self, index: usize) -> f64 {
+        match (self, index) {
+            (ColorSpace::RGB | ColorSpace::RGBA, 0..=2) => 255.0,
+            (ColorSpace::Lab, 0) | (ColorSpace::LCH, 0) => 100.0,
+            (ColorSpace::Lab, 1 | 2) => 125.0,
+            (ColorSpace::LCH, 1) => 150.0,
+            (ColorSpace::OKLab, 1 | 2) | (ColorSpace::OKLCH, 1) => 0.4,
+            _ => 1.0,
+        }
+    }
+    /// Whether the color space has a CSS color function, e.g. `rgb()` or `oklch()`.
+    pub(crate) fn is_css_function(&self) -> bool {
+        matches!(
+            self,
+            ColorSpace::RGB
+                | ColorSpace::RGBA
+                | ColorSpace::HSL
+                | ColorSpace::HSLA
+                | ColorSpace::HWB
+                | ColorSpace::Lab
+                | ColorSpace::LCH
+                | ColorSpace::OKLab
+                | ColorSpace::OKLCH
+        )
+    }
     pub(crate) fn value_count(&self) -> usize {
         match self {
             ColorSpace::RGB => 3,
@@ -111,7 +177,14 @@ impl ColorSpace {
             ColorSpace::YIQ => 3,
             ColorSpace::YUV => 3,
             ColorSpace::YCbCr => 3,
+            ColorSpace::YPbPr => 3,
             ColorSpace::Lab => 3,
+            ColorSpace::LCH => 3,
+            ColorSpace::Luv => 3,
+            ColorSpace::LCHuv => 3,
+            ColorSpace::OKLab => 3,
+            ColorSpace::OKLCH => 3,
+            ColorSpace::Rec2020 => 3,
             ColorSpace::Unknown => 0,
         }
     }
@@ -127,6 +200,13 @@ mod tests {
         assert_eq!(ColorSpace::from("rgba"), ColorSpace::RGBA);
         assert_eq!(ColorSpace::from("hsl"), ColorSpace::HSL);
         assert_eq!(ColorSpace::from("YCbCr"), ColorSpace::YCbCr);
+        assert_eq!(ColorSpace::from("YPbPr"), ColorSpace::YPbPr);
+        assert_eq!(ColorSpace::from("lch"), ColorSpace::LCH);
+        assert_eq!(ColorSpace::from("luv"), ColorSpace::Luv);
+        assert_eq!(ColorSpace::from("LCHuv"), ColorSpace::LCHuv);
+        assert_eq!(ColorSpace::from("oklab"), ColorSpace::OKLab);
+        assert_eq!(ColorSpace::from("oklch"), ColorSpace::OKLCH);
+        assert_eq!(ColorSpace::from("Rec2020"), ColorSpace::Rec2020);
 
         let rgb: ColorSpace = "rgb".into();
         assert_eq!(rgb, ColorSpace::RGB);
@@ -140,4 +220,15 @@ mod tests {
         let rgba: ColorSpace = "RGBA".into();
         assert_eq!(rgba, ColorSpace::RGBA);
     }
+
+    #[test]
+    fn test_color_space_percent_reference() {
+        assert_eq!(ColorSpace::RGB.percent_reference(0), 255.0);
+        assert_eq!(ColorSpace::RGBA.percent_reference(3), 1.0);
+        assert_eq!(ColorSpace::Lab.percent_reference(0), 100.0);
+        assert_eq!(ColorSpace::Lab.percent_reference(2), 125.0);
+        assert_eq!(ColorSpace::LCH.percent_reference(1), 150.0);
+        assert_eq!(ColorSpace::LCH.percent_reference(2), 1.0);
+        assert_eq!(ColorSpace::HSL.percent_reference(1), 1.0);
+    }
 }
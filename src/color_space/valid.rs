@@ -1,4 +1,11 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
 use crate::{ColorSpace, Error};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 impl ColorSpace {
     /// Check if a vector of values is valid for a given color space.
@@ -16,7 +23,14 @@ impl ColorSpace {
             ColorSpace::YIQ => valid_yiq(vec),
             ColorSpace::YUV => valid_yuv(vec),
             ColorSpace::YCbCr => valid_ycbcr(vec),
+            ColorSpace::YPbPr => valid_ypbpr(vec),
             ColorSpace::Lab => valid_lab(vec),
+            ColorSpace::LCH => valid_lch(vec),
+            ColorSpace::Luv => valid_luv(vec),
+            ColorSpace::LCHuv => valid_lchuv(vec),
+            ColorSpace::OKLab => valid_oklab(vec),
+            ColorSpace::OKLCH => valid_oklch(vec),
+            ColorSpace::Rec2020 => valid_rec2020(vec),
             ColorSpace::HEX | ColorSpace::HEXA => Some(
                 "HEX color space not implemented yet, please use `ColorSpace::valid_hex` instead"
                     .to_string(),
@@ -28,6 +42,44 @@ impl ColorSpace {
             None => Ok(()),
         }
     }
+    /// Clamp a vector of values into the valid ranges of the color space.
+    ///
+    /// Hue angles are wrapped around instead of clamped.
+    pub(crate) fn clamp(&self, vec: &[f64]) -> Vec<f64> {
+        const HUE: (f64, f64) = (0.0, 360.0);
+        const UNIT: (f64, f64) = (0.0, 1.0);
+        const BYTE: (f64, f64) = (0.0, 255.0);
+        let ranges: &[(f64, f64)] = match self {
+            ColorSpace::RGB => &[BYTE, BYTE, BYTE],
+            ColorSpace::RGBA => &[BYTE, BYTE, BYTE, UNIT],
+            ColorSpace::HSI | ColorSpace::HSL | ColorSpace::HSV | ColorSpace::HWB => {
+                &[HUE, UNIT, UNIT]
+            }
+            ColorSpace::HSLA => &[HUE, UNIT, UNIT, UNIT],
+            ColorSpace::CMYK => &[UNIT, UNIT, UNIT, UNIT],
+            ColorSpace::XYZ => &[(0.0, 0.950456), UNIT, (0.0, 1.088754)],
+            ColorSpace::YIQ => &[UNIT, (-0.5957, 0.5957), (-0.5226, 0.5226)],
+            ColorSpace::YUV => &[UNIT, (-0.436, 0.436), (-0.615, 0.615)],
+            ColorSpace::YCbCr => &[BYTE, BYTE, BYTE],
+            ColorSpace::YPbPr => &[UNIT, (-0.5, 0.5), (-0.5, 0.5)],
+            ColorSpace::Lab => &[(0.0, 100.0), (-128.0, 127.0), (-128.0, 127.0)],
+            ColorSpace::LCH => &[(0.0, 100.0), (0.0, 150.0), HUE],
+            ColorSpace::Luv => &[(0.0, 100.0), (-134.0, 224.0), (-140.0, 122.0)],
+            ColorSpace::LCHuv => &[(0.0, 100.0), (0.0, 180.0), HUE],
+            ColorSpace::OKLab => &[UNIT, (-0.4, 0.4), (-0.4, 0.4)],
+            ColorSpace::OKLCH => &[UNIT, (0.0, 0.4), HUE],
+            ColorSpace::Rec2020 => &[UNIT, UNIT, UNIT],
+            ColorSpace::HEX | ColorSpace::HEXA | ColorSpace::Unknown => &[],
+        };
+        vec.iter()
+            .enumerate()
+            .map(|(i, &v)| match ranges.get(i) {
+                Some(&HUE) => v.rem_euclid(360.0),
+                Some(&(min, max)) => v.clamp(min, max),
+                None => v,
+            })
+            .collect()
+    }
     /// Validate a hex color string
     pub(crate) fn valid_hex(hex: &str) -> Result<(), Error> {
         if !hex.chars().skip(1).all(|c| c.is_ascii_hexdigit()) {
@@ -235,6 +287,102 @@ fn valid_lab(vec: &[f64]) -> Option<String> {
     }
 }
 
+fn valid_oklab(vec: &[f64]) -> Option<String> {
+    if let [l, a, b] = vec[..] {
+        if !(0.0..=1.0).contains(&l) {
+            Some(format!("L must be between 0.0 and 1.0, got {}", l))
+        } else if !(-0.4..=0.4).contains(&a) {
+            Some(format!("A must be between -0.4 and 0.4, got {}", a))
+        } else if !(-0.4..=0.4).contains(&b) {
+            Some(format!("B must be between -0.4 and 0.4, got {}", b))
+        } else {
+            None
+        }
+    } else {
+        Some("OKLab color space requires 3 values".to_string())
+    }
+}
+
+fn valid_oklch(vec: &[f64]) -> Option<String> {
+    if let [l, c, h] = vec[..] {
+        if !(0.0..=1.0).contains(&l) {
+            Some(format!("L must be between 0.0 and 1.0, got {}", l))
+        } else if !(0.0..=0.4).contains(&c) {
+            Some(format!("C must be between 0.0 and 0.4, got {}", c))
+        } else if !(0.0..=360.0).contains(&h) {
+            Some(format!("H must be between 0.0 and 360.0, got {}", h))
+        } else {
+            None
+        }
+    } else {
+        Some("OKLCH color space requires 3 values".to_string())
+    }
+}
+
+fn valid_rec2020(vec: &[f64]) -> Option<String> {
+    if let [r, g, b] = vec[..] {
+        if !(0.0..=1.0).contains(&r) {
+            Some(format!("R must be between 0.0 and 1.0, got {}", r))
+        } else if !(0.0..=1.0).contains(&g) {
+            Some(format!("G must be between 0.0 and 1.0, got {}", g))
+        } else if !(0.0..=1.0).contains(&b) {
+            Some(format!("B must be between 0.0 and 1.0, got {}", b))
+        } else {
+            None
+        }
+    } else {
+        Some("Rec2020 color space requires 3 values".to_string())
+    }
+}
+
+fn valid_lch(vec: &[f64]) -> Option<String> {
+    if let [l, c, h] = vec[..] {
+        if !(0.0..=100.0).contains(&l) {
+            Some(format!("L must be between 0.0 and 100.0, got {}", l))
+        } else if !(0.0..=150.0).contains(&c) {
+            Some(format!("C must be between 0.0 and 150.0, got {}", c))
+        } else if !(0.0..=360.0).contains(&h) {
+            Some(format!("H must be between 0.0 and 360.0, got {}", h))
+        } else {
+            None
+        }
+    } else {
+        Some("LCH color space requires 3 values".to_string())
+    }
+}
+
+fn valid_luv(vec: &[f64]) -> Option<String> {
+    if let [l, u, v] = vec[..] {
+        if !(0.0..=100.0).contains(&l) {
+            Some(format!("L must be between 0.0 and 100.0, got {}", l))
+        } else if !(-134.0..=224.0).contains(&u) {
+            Some(format!("U must be between -134.0 and 224.0, got {}", u))
+        } else if !(-140.0..=122.0).contains(&v) {
+            Some(format!("V must be between -140.0 and 122.0, got {}", v))
+        } else {
+            None
+        }
+    } else {
+        Some("Luv color space requires 3 values".to_string())
+    }
+}
+
+fn valid_lchuv(vec: &[f64]) -> Option<String> {
+    if let [l, c, h] = vec[..] {
+        if !(0.0..=100.0).contains(&l) {
+            Some(format!("L must be between 0.0 and 100.0, got {}", l))
+        } else if !(0.0..=180.0).contains(&c) {
+            Some(format!("C must be between 0.0 and 180.0, got {}", c))
+        } else if !(0.0..=360.0).contains(&h) {
+            Some(format!("H must be between 0.0 and 360.0, got {}", h))
+        } else {
+            None
+        }
+    } else {
+        Some("LCHuv color space requires 3 values".to_string())
+    }
+}
+
 fn valid_yuv(vec: &[f64]) -> Option<String> {
     if let [y, u, v] = vec[..] {
         if !(0.0..=1.0).contains(&y) {
@@ -267,6 +415,22 @@ fn valid_ycbcr(vec: &[f64]) -> Option<String> {
     }
 }
 
+fn valid_ypbpr(vec: &[f64]) -> Option<String> {
+    if let [y, pb, pr] = vec[..] {
+        if !(0.0..=1.0).contains(&y) {
+            Some(format!("Y must be between 0.0 and 1.0, got {}", y))
+        } else if !(-0.5..=0.5).contains(&pb) {
+            Some(format!("Pb must be between -0.5 and 0.5, got {}", pb))
+        } else if !(-0.5..=0.5).contains(&pr) {
+            Some(format!("Pr must be between -0.5 and 0.5, got {}", pr))
+        } else {
+            None
+        }
+    } else {
+        Some("YPbPr color space requires 3 values".to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
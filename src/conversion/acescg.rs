@@ -0,0 +1,59 @@
+use super::utils::*;
+use crate::utils::*;
+
+// linear sRGB (D65) to ACEScg (AP1, D60), Bradford adapted
+static LIN_SRGB_TO_ACESCG_MATRIX: [[f64; 3]; 3] = [
+    [0.6130973, 0.3395229, 0.0473793],
+    [0.0701942, 0.9163556, 0.0134526],
+    [0.0206156, 0.1095698, 0.8698151],
+];
+
+static ACESCG_TO_LIN_SRGB_MATRIX: [[f64; 3]; 3] = [
+    [1.7048587, -0.6217160, -0.0832931],
+    [-0.1300768, 1.1407357, -0.0105598],
+    [-0.0239640, -0.1289755, 1.1530140],
+];
+
+/// Convert `RGB` to `ACEScg`, the linear working space with AP1 primaries
+///
+/// reference: [ACEScg](https://docs.acescentral.com/specifications/acescg/)
+pub fn rgb2acescg(color: &[f64; 3]) -> [f64; 3] {
+    let color = lin_srgb(&normalize_color(color));
+    multiply_matrix_vec(&LIN_SRGB_TO_ACESCG_MATRIX, &color)
+}
+
+/// Convert `ACEScg` to `RGB`
+///
+/// Colors outside of the sRGB gamut are clipped.
+pub fn acescg2rgb(color: &[f64; 3]) -> [f64; 3] {
+    let color = multiply_matrix_vec(&ACESCG_TO_LIN_SRGB_MATRIX, color);
+    gam_srgb(&color).map(|v| round(v.clamp(0.0, 1.0) * 255.0, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round4_vec<const N: usize>(vec: [f64; N]) -> [f64; N] {
+        vec.map(|v| round(v, 4))
+    }
+
+    #[test]
+    fn test_rgb2acescg() {
+        assert_eq!(
+            round4_vec(rgb2acescg(&[255.0, 255.0, 255.0])),
+            [1.0, 1.0, 1.0]
+        );
+        assert_eq!(
+            round4_vec(rgb2acescg(&[255.0, 0.0, 0.0])),
+            [0.6131, 0.0702, 0.0206]
+        );
+    }
+
+    #[test]
+    fn test_acescg2rgb() {
+        assert_eq!(acescg2rgb(&[1.0, 1.0, 1.0]), [255.0, 255.0, 255.0]);
+        assert_eq!(acescg2rgb(&[0.6131, 0.0702, 0.0206]), [255.0, 0.0, 0.0]);
+        assert_eq!(acescg2rgb(&[0.0, 0.0, 1.0]), [0.0, 0.0, 255.0]);
+    }
+}
@@ -0,0 +1,215 @@
+use super::{
+    utils::{multiply_matrix_vec, D65},
+    xyz::rgb2xyz,
+};
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+static M16: [[f64; 3]; 3] = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+/// Surround of the viewing field, used by the CAM16 model.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Surround {
+    /// Average surround, e.g. viewing surface colors.
+    #[default]
+    Average,
+    /// Dim surround, e.g. viewing a television.
+    Dim,
+    /// Dark surround, e.g. viewing a projector in a dark room.
+    Dark,
+}
+
+impl Surround {
+    /// `[F, c, Nc]` factors of the surround.
+    fn factors(&self) -> [f64; 3] {
+        match self {
+            Surround::Average => [1.0, 0.69, 1.0],
+            Surround::Dim => [0.9, 0.59, 0.9],
+            Surround::Dark => [0.8, 0.525, 0.8],
+        }
+    }
+}
+
+/// Viewing conditions of the CAM16 color appearance model.
+///
+/// The default conditions match the ones used by CSS Color 5 and color.js:
+/// a D65 white point, an adapting luminance of `64 / π * 0.2` cd/m², a background
+/// luminance of 20 and an average surround.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ViewingConditions {
+    /// XYZ of the reference white, scaled so that Y is 100.
+    pub white_point: [f64; 3],
+    /// Luminance of the adapting field in cd/m² (`L_A`).
+    pub adapting_luminance: f64,
+    /// Relative luminance of the background (`Y_b`).
+    pub background_luminance: f64,
+    /// Surround of the viewing field.
+    pub surround: Surround,
+    /// Whether the illuminant is discounted, i.e. full chromatic adaptation.
+    pub discounting: bool,
+}
+
+impl Default for ViewingConditions {
+    fn default() -> Self {
+        ViewingConditions {
+            white_point: D65.map(|v| v * 100.0),
+            adapting_luminance: 64.0 / core::f64::consts::PI * 0.2,
+            background_luminance: 20.0,
+            surround: Surround::default(),
+            discounting: false,
+        }
+    }
+}
+
+/// Attributes of a color in the [CAM16](https://doi.org/10.1002/col.22131) color appearance model.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cam16 {
+    /// Lightness
+    pub j: f64,
+    /// Chroma
+    pub c: f64,
+    /// Hue angle in degrees
+    pub h: f64,
+    /// Brightness
+    pub q: f64,
+    /// Colorfulness
+    pub m: f64,
+    /// Saturation
+    pub s: f64,
+}
+
+fn m16(vec: &[f64; 3]) -> [f64; 3] {
+    multiply_matrix_vec(&M16, vec)
+}
+
+/// post-adaptation non-linear response compression
+fn compress(v: f64, fl: f64) -> f64 {
+    let x = (fl * v.abs() / 100.0).powf(0.42);
+    v.signum() * 400.0 * x / (x + 27.13) + 0.1
+}
+
+/// Convert XYZ (scaled so that Y is 0-100) to CAM16 under the given viewing conditions.
+///
+/// reference: [colour-science](https://github.com/colour-science/colour/blob/develop/colour/appearance/cam16.py)
+pub(crate) fn xyz2cam16(xyz: &[f64; 3], vc: &ViewingConditions) -> Cam16 {
+    let [f, c, nc] = vc.surround.factors();
+    let xyz_w = vc.white_point;
+    let la = vc.adapting_luminance;
+    let yw = xyz_w[1];
+
+    let rgb_w = m16(&xyz_w);
+
+    let d = if vc.discounting {
+        1.0
+    } else {
+        (f * (1.0 - (1.0 / 3.6) * ((-la - 42.0) / 92.0).exp())).clamp(0.0, 1.0)
+    };
+    let d_rgb = rgb_w.map(|v| d * yw / v + 1.0 - d);
+
+    let k = 1.0 / (5.0 * la + 1.0);
+    let k4 = k.powi(4);
+    let fl = k4 * la + 0.1 * (1.0 - k4).powi(2) * (5.0 * la).cbrt();
+
+    let n = vc.background_luminance / yw;
+    let z = 1.48 + n.sqrt();
+    let nbb = 0.725 / n.powf(0.2);
+    let ncb = nbb;
+
+    let rgb_aw = [0, 1, 2].map(|i| compress(rgb_w[i] * d_rgb[i], fl));
+    let a_w = (2.0 * rgb_aw[0] + rgb_aw[1] + rgb_aw[2] / 20.0 - 0.305) * nbb;
+
+    let rgb_a = m16(xyz);
+    let [ra, ga, ba] = [0, 1, 2].map(|i| compress(rgb_a[i] * d_rgb[i], fl));
+
+    let a = ra - 12.0 * ga / 11.0 + ba / 11.0;
+    let b = (ra + ga - 2.0 * ba) / 9.0;
+
+    let h = b.atan2(a).to_degrees();
+    let h = if h < 0.0 { h + 360.0 } else { h };
+
+    let e_t = 0.25 * ((h.to_radians() + 2.0).cos() + 3.8);
+
+    let achromatic = (2.0 * ra + ga + ba / 20.0 - 0.305) * nbb;
+    let j = 100.0 * (achromatic / a_w).powf(c * z);
+    let q = (4.0 / c) * (j / 100.0).sqrt() * (a_w + 4.0) * fl.powf(0.25);
+
+    let t =
+        (50000.0 / 13.0 * nc * ncb * e_t * (a * a + b * b).sqrt()) / (ra + ga + 21.0 / 20.0 * ba);
+    let chroma = t.powf(0.9) * (j / 100.0).sqrt() * (1.64 - 0.29_f64.powf(n)).powf(0.73);
+    let m = chroma * fl.powf(0.25);
+    let s = if q == 0.0 {
+        0.0
+    } else {
+        100.0 * (m / q).sqrt()
+    };
+
+    Cam16 {
+        j,
+        c: chroma,
+        h,
+        q,
+        m,
+        s,
+    }
+}
+
+/// Convert `RGB` to CAM16 under the given viewing conditions.
+pub(crate) fn rgb2cam16(color: &[f64; 3], vc: &ViewingConditions) -> Cam16 {
+    let xyz = rgb2xyz(color).map(|v| v * 100.0);
+    xyz2cam16(&xyz, vc)
+}
+
+/// Convert CAM16 attributes to CAM16-UCS `[J', a', b']`.
+///
+/// reference: [Li et al. 2017](https://doi.org/10.1002/col.22131)
+pub(crate) fn cam16_to_ucs(cam: &Cam16) -> [f64; 3] {
+    const C1: f64 = 0.007;
+    const C2: f64 = 0.0228;
+    let j = 1.7 * cam.j / (1.0 + C1 * cam.j);
+    let m = (1.0 + C2 * cam.m).ln() / C2;
+    let h = cam.h.to_radians();
+    [j, m * h.cos(), m * h.sin()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::round;
+
+    #[test]
+    fn test_xyz2cam16() {
+        let vc = ViewingConditions {
+            white_point: [95.05, 100.0, 108.88],
+            adapting_luminance: 318.31,
+            background_luminance: 20.0,
+            surround: Surround::Average,
+            discounting: false,
+        };
+        let cam = xyz2cam16(&[19.01, 20.0, 21.78], &vc);
+        assert_eq!(round(cam.j, 4), 41.7312);
+        assert_eq!(round(cam.c, 4), 0.1034);
+        assert_eq!(round(cam.h, 4), 217.068);
+        assert_eq!(round(cam.q, 4), 195.3717);
+        assert_eq!(round(cam.m, 4), 0.1074);
+        assert_eq!(round(cam.s, 4), 2.345);
+    }
+
+    #[test]
+    fn test_rgb2cam16() {
+        let cam = rgb2cam16(&[255.0, 255.0, 255.0], &ViewingConditions::default());
+        assert_eq!(round(cam.j, 4), 100.0);
+
+        let cam = rgb2cam16(&[0.0, 0.0, 0.0], &ViewingConditions::default());
+        assert_eq!(round(cam.j, 4), 0.0);
+    }
+}
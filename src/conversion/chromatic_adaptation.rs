@@ -0,0 +1,191 @@
+use super::utils::{multiply_matrix_vec, D50, D65};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Standard illuminant, used as the reference white point of a color.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Illuminant {
+    /// CIE standard illuminant A, incandescent tungsten light.
+    A,
+    /// CIE standard illuminant C, average daylight (obsolete, superseded by D65).
+    C,
+    /// CIE standard illuminant D50, horizon light. The white point of ICC profiles and print.
+    D50,
+    /// CIE standard illuminant D55, mid-morning and mid-afternoon daylight.
+    D55,
+    /// CIE standard illuminant D65, noon daylight. The white point of sRGB.
+    #[default]
+    D65,
+    /// CIE standard illuminant D75, north sky daylight.
+    D75,
+    /// CIE standard illuminant E, equal energy.
+    E,
+    /// CIE standard illuminant F2, cool white fluorescent.
+    F2,
+    /// CIE standard illuminant F7, broadband daylight fluorescent.
+    F7,
+    /// CIE standard illuminant F11, narrow band white fluorescent.
+    F11,
+}
+
+impl Illuminant {
+    /// The XYZ tristimulus values of the white point, normalized to `Y = 1.0`.
+    ///
+    /// Derived from the CIE 1931 2° standard observer chromaticities.
+    pub fn white_point(&self) -> [f64; 3] {
+        match self {
+            Illuminant::A => xy_to_xyz(0.44757, 0.40745),
+            Illuminant::C => xy_to_xyz(0.31006, 0.31616),
+            Illuminant::D50 => D50,
+            Illuminant::D55 => xy_to_xyz(0.33242, 0.34743),
+            Illuminant::D65 => D65,
+            Illuminant::D75 => xy_to_xyz(0.29902, 0.31485),
+            Illuminant::E => [1.0, 1.0, 1.0],
+            Illuminant::F2 => xy_to_xyz(0.37208, 0.37529),
+            Illuminant::F7 => xy_to_xyz(0.31292, 0.32933),
+            Illuminant::F11 => xy_to_xyz(0.38052, 0.37713),
+        }
+    }
+}
+
+/// convert xy chromaticity coordinates to XYZ with `Y = 1.0`
+pub(crate) fn xy_to_xyz(x: f64, y: f64) -> [f64; 3] {
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+/// Chromatic adaptation transform, the cone response model used to adapt a color between white points.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AdaptationMethod {
+    /// The Bradford transform, as used by ICC profiles and CSS Color 4.
+    #[default]
+    Bradford,
+    /// The von Kries transform, using the Hunt-Pointer-Estevez cone responses.
+    VonKries,
+}
+
+static BRADFORD_MATRIX: [[f64; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+static VON_KRIES_MATRIX: [[f64; 3]; 3] = [
+    [0.40024, 0.7076, -0.08081],
+    [-0.2263, 1.16532, 0.0457],
+    [0.0, 0.0, 0.91822],
+];
+
+impl AdaptationMethod {
+    fn cone_matrix(&self) -> [[f64; 3]; 3] {
+        match self {
+            AdaptationMethod::Bradford => BRADFORD_MATRIX,
+            AdaptationMethod::VonKries => VON_KRIES_MATRIX,
+        }
+    }
+}
+
+/// invert a 3x3 matrix by its adjugate
+fn invert(m: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let cofactor =
+        |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    let det = m[0][0] * cofactor(1, 2, 1, 2) - m[0][1] * cofactor(1, 2, 0, 2)
+        + m[0][2] * cofactor(1, 2, 0, 1);
+    [
+        [
+            cofactor(1, 2, 1, 2) / det,
+            -cofactor(0, 2, 1, 2) / det,
+            cofactor(0, 1, 1, 2) / det,
+        ],
+        [
+            -cofactor(1, 2, 0, 2) / det,
+            cofactor(0, 2, 0, 2) / det,
+            -cofactor(0, 1, 0, 2) / det,
+        ],
+        [
+            cofactor(1, 2, 0, 1) / det,
+            -cofactor(0, 2, 0, 1) / det,
+            cofactor(0, 1, 0, 1) / det,
+        ],
+    ]
+}
+
+/// Adapt XYZ values from one white point to another
+///
+/// reference: [Chromatic Adaptation](http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html)
+pub(crate) fn adapt_xyz(
+    xyz: &[f64; 3],
+    from: Illuminant,
+    to: Illuminant,
+    method: AdaptationMethod,
+) -> [f64; 3] {
+    if from == to {
+        return *xyz;
+    }
+    adapt_xyz_white(xyz, &from.white_point(), &to.white_point(), method)
+}
+
+/// Adapt XYZ values between two arbitrary white points, given as XYZ
+pub(crate) fn adapt_xyz_white(
+    xyz: &[f64; 3],
+    from: &[f64; 3],
+    to: &[f64; 3],
+    method: AdaptationMethod,
+) -> [f64; 3] {
+    let matrix = method.cone_matrix();
+    let cone_from = multiply_matrix_vec(&matrix, from);
+    let cone_to = multiply_matrix_vec(&matrix, to);
+
+    let cone = multiply_matrix_vec(&matrix, xyz);
+    let cone = [0, 1, 2].map(|i| cone[i] * cone_to[i] / cone_from[i]);
+
+    multiply_matrix_vec(&invert(&matrix), &cone)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::round;
+
+    fn round6_vec<const N: usize>(vec: [f64; N]) -> [f64; N] {
+        vec.map(|v| round(v, 6))
+    }
+
+    #[test]
+    fn test_adapt_white_point() {
+        for method in [AdaptationMethod::Bradford, AdaptationMethod::VonKries] {
+            assert_eq!(
+                round6_vec(adapt_xyz(&D65, Illuminant::D65, Illuminant::D50, method)),
+                round6_vec(D50)
+            );
+            assert_eq!(
+                round6_vec(adapt_xyz(&D50, Illuminant::D50, Illuminant::D65, method)),
+                round6_vec(D65)
+            );
+        }
+    }
+
+    #[test]
+    fn test_white_point() {
+        assert_eq!(Illuminant::D65.white_point(), D65);
+        assert_eq!(
+            round6_vec(Illuminant::A.white_point()),
+            [1.098466, 1.0, 0.355823]
+        );
+        assert_eq!(Illuminant::E.white_point(), [1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_adapt_bradford() {
+        // sRGB red, adapted with the CSS Color 4 D65 to D50 Bradford matrix
+        let xyz = adapt_xyz(
+            &[0.4123907992659595, 0.21263900587151036, 0.01933081871559185],
+            Illuminant::D65,
+            Illuminant::D50,
+            AdaptationMethod::Bradford,
+        );
+        assert_eq!(round6_vec(xyz), [0.436066, 0.222493, 0.013924]);
+    }
+}
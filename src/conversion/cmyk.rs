@@ -1,7 +1,10 @@
 use crate::utils::*;
 
 /// [RGB to CMYK color conversion](https://www.rapidtables.com/convert/color/rgb-to-cmyk.html)
-pub fn rgb2cmyk(color: &[f64]) -> Vec<f64> {
+///
+/// Each value is in the range 0.0 to 1.0. Black is taken out first as `K = 1 - max(R, G, B)`,
+/// and the remaining inks are relative to the non-black part.
+pub fn rgb2cmyk(color: &[f64; 3]) -> [f64; 4] {
     let color = normalize_color(color);
     let r = color[0];
     let g = color[1];
@@ -10,15 +13,21 @@ pub fn rgb2cmyk(color: &[f64]) -> Vec<f64> {
     let rgb_max = f64::max(f64::max(r, g), b);
 
     let k = 1.0 - rgb_max;
-    let c = (1.0 - r - k) * (1.0 - k);
-    let m = (1.0 - g - k) * (1.0 - k);
-    let y = (1.0 - b - k) * (1.0 - k);
+    if k == 1.0 {
+        return [0.0, 0.0, 0.0, 1.0];
+    }
+    let c = (1.0 - r - k) / (1.0 - k);
+    let m = (1.0 - g - k) / (1.0 - k);
+    let y = (1.0 - b - k) / (1.0 - k);
 
-    vec![c, m, y, k]
+    [c, m, y, k]
 }
 
 /// [CMYK to RGB color conversion](https://www.rapidtables.com/convert/color/cmyk-to-rgb.html)
-pub fn cmyk2rgb(color: &[f64]) -> Vec<f64> {
+///
+/// Each channel is `255 × (1 - ink) × (1 - K)`. This is the device independent conversion used by
+/// design tools for quick previews, it does not apply any ICC print profile.
+pub fn cmyk2rgb(color: &[f64; 4]) -> [f64; 3] {
     let c = color[0];
     let m = color[1];
     let y = color[2];
@@ -28,5 +37,42 @@ pub fn cmyk2rgb(color: &[f64]) -> Vec<f64> {
     let g = (1.0 - m) * (1.0 - k) * 255.0;
     let b = (1.0 - y) * (1.0 - k) * 255.0;
 
-    vec![r, g, b]
+    [r, g, b]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round4_vec<const N: usize>(vec: [f64; N]) -> [f64; N] {
+        vec.map(|v| round(v, 4))
+    }
+
+    #[test]
+    fn test_rgb2cmyk() {
+        assert_eq!(rgb2cmyk(&[255.0, 255.0, 255.0]), [0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(rgb2cmyk(&[0.0, 0.0, 0.0]), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(
+            round4_vec(rgb2cmyk(&[0.0, 128.0, 128.0])),
+            [1.0, 0.0, 0.0, 0.498]
+        );
+        assert_eq!(
+            round4_vec(rgb2cmyk(&[128.0, 64.0, 0.0])),
+            [0.0, 0.5, 1.0, 0.498]
+        );
+    }
+
+    #[test]
+    fn test_cmyk2rgb() {
+        assert_eq!(cmyk2rgb(&[0.0, 0.0, 0.0, 0.0]), [255.0, 255.0, 255.0]);
+        assert_eq!(cmyk2rgb(&[0.0, 0.0, 0.0, 1.0]), [0.0, 0.0, 0.0]);
+        assert_eq!(
+            round4_vec(cmyk2rgb(&[0.0, 0.35, 1.0, 0.0])),
+            [255.0, 165.75, 0.0]
+        );
+        assert_eq!(
+            round4_vec(cmyk2rgb(&rgb2cmyk(&[128.0, 64.0, 0.0]))),
+            [128.0, 64.0, 0.0]
+        );
+    }
 }
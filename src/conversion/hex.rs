@@ -1,4 +1,24 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
 use crate::utils::*;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Order of the channels in a hex color string with an alpha channel.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HexOrder {
+    /// `#RRGGBBAA` and `#RGBA`, as used by CSS.
+    #[default]
+    Rgba,
+    /// `#AARRGGBB` and `#ARGB`, as used by Android.
+    Argb,
+}
 
 pub fn rgb2hex(color: [f64; 3]) -> String {
     let [r, g, b] = color;
@@ -17,7 +37,7 @@ pub fn rgba2hex(color: [f64; 4]) -> String {
     format!("{}{:02x}", hex, alpha as i64)
 }
 
-pub fn hex2rgb(hex: &str) -> Vec<f64> {
+pub fn hex2rgb(hex: &str) -> [f64; 3] {
     let mut hex = String::from(hex);
     // #rgb -> #rrggbb
     if hex.len() == 4 {
@@ -33,27 +53,65 @@ pub fn hex2rgb(hex: &str) -> Vec<f64> {
     let g = u8::from_str_radix(&hex[3..5], 16).unwrap();
     let b = u8::from_str_radix(&hex[5..7], 16).unwrap();
 
-    vec![r as f64, g as f64, b as f64]
+    [r as f64, g as f64, b as f64]
 }
 
-pub fn hex2rgba(hex: &str) -> Vec<f64> {
-    let mut hex = String::from(hex);
-    // #rgba -> #rrggbbaa
-    if hex.len() == 5 {
-        let mut hex_new = String::from("#");
-        for c in hex[1..].chars() {
-            hex_new.push(c);
-            hex_new.push(c);
-        }
-        hex = hex_new;
-    }
+/// Convert a 3, 4, 6 or 8 digit hex string (with the leading `#`) to `RGBA`,
+/// reading the alpha channel in the given order.
+///
+/// Strings without an alpha channel are fully opaque.
+pub fn hex2rgba_with_order(hex: &str, order: HexOrder) -> [f64; 4] {
+    let digits = &hex[1..];
+    // #rgb -> #rrggbb, #rgba -> #rrggbbaa
+    let digits = match digits.len() {
+        3 | 4 => digits.chars().flat_map(|c| [c, c]).collect(),
+        _ => digits.to_string(),
+    };
+    let channel = |i: usize| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).unwrap() as f64;
+
+    let [r, g, b, alpha] = match digits.len() {
+        8 if order == HexOrder::Argb => [channel(1), channel(2), channel(3), channel(0)],
+        8 => [channel(0), channel(1), channel(2), channel(3)],
+        _ => [channel(0), channel(1), channel(2), 255.0],
+    };
+    [r, g, b, alpha / 255.0]
+}
 
-    let r = u8::from_str_radix(&hex[1..3], 16).unwrap();
-    let g = u8::from_str_radix(&hex[3..5], 16).unwrap();
-    let b = u8::from_str_radix(&hex[5..7], 16).unwrap();
-    let a = u8::from_str_radix(&hex[7..9], 16).unwrap();
+/// The `[r, g, b, a]` bytes of `rgb`, `rgba`, `rrggbb` or `rrggbbaa` hex digits, without the `#`.
+///
+/// `const` for the literals of the [`color!`](crate::color) macro.
+pub const fn hex_digits2rgba(digits: &[u8]) -> Option<[u8; 4]> {
+    let (channels, short) = match digits.len() {
+        3 | 4 => (digits.len(), true),
+        6 | 8 => (digits.len() / 2, false),
+        _ => return None,
+    };
+    let mut rgba = [255; 4];
+    let mut i = 0;
+    while i < channels {
+        rgba[i] = if short {
+            match hex_digit(digits[i]) {
+                Some(digit) => digit * 17,
+                None => return None,
+            }
+        } else {
+            match (hex_digit(digits[i * 2]), hex_digit(digits[i * 2 + 1])) {
+                (Some(high), Some(low)) => high * 16 + low,
+                _ => return None,
+            }
+        };
+        i += 1;
+    }
+    Some(rgba)
+}
 
-    vec![r as f64, g as f64, b as f64, (a as f64) / 255.0]
+const fn hex_digit(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -64,11 +122,41 @@ mod tests {
     fn test_hex2rgb() {
         let hex = "#ffffff";
         let rgb = hex2rgb(hex);
-        assert_eq!(rgb, vec![255.0, 255.0, 255.0]);
+        assert_eq!(rgb, [255.0, 255.0, 255.0]);
 
         let hex = "#000";
         let rgb = hex2rgb(hex);
-        assert_eq!(rgb, vec![0.0, 0.0, 0.0]);
+        assert_eq!(rgb, [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_hex_digits2rgba() {
+        assert_eq!(hex_digits2rgba(b"f08"), Some([255, 0, 136, 255]));
+        assert_eq!(hex_digits2rgba(b"F08c"), Some([255, 0, 136, 204]));
+        assert_eq!(hex_digits2rgba(b"ff0088"), Some([255, 0, 136, 255]));
+        assert_eq!(hex_digits2rgba(b"ff0088cc"), Some([255, 0, 136, 204]));
+        assert_eq!(hex_digits2rgba(b"ff008"), None);
+        assert_eq!(hex_digits2rgba(b"ff00gg"), None);
+    }
+
+    #[test]
+    fn test_hex2rgba_with_order() {
+        for (hex, rgba) in [
+            ("#f08", [255.0, 0.0, 136.0, 1.0]),
+            ("#f08c", [255.0, 0.0, 136.0, 0.8]),
+            ("#ff0088", [255.0, 0.0, 136.0, 1.0]),
+            ("#ff0088cc", [255.0, 0.0, 136.0, 0.8]),
+        ] {
+            assert_eq!(hex2rgba_with_order(hex, HexOrder::Rgba), rgba);
+        }
+        for (hex, rgba) in [
+            ("#f08", [255.0, 0.0, 136.0, 1.0]),
+            ("#cf08", [255.0, 0.0, 136.0, 0.8]),
+            ("#ff0088", [255.0, 0.0, 136.0, 1.0]),
+            ("#ccff0088", [255.0, 0.0, 136.0, 0.8]),
+        ] {
+            assert_eq!(hex2rgba_with_order(hex, HexOrder::Argb), rgba);
+        }
     }
 
     #[test]
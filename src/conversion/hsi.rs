@@ -1,6 +1,8 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
 use crate::utils::*;
 
-pub(crate) fn rgb2hsi(color: &[f64]) -> Vec<f64> {
+pub(crate) fn rgb2hsi(color: &[f64; 3]) -> [f64; 3] {
     let color = normalize_color(color);
     let r = color[0];
     let g = color[1];
@@ -19,10 +21,10 @@ pub(crate) fn rgb2hsi(color: &[f64]) -> Vec<f64> {
     let i = (r + g + b) / 3.0;
     let s = if i == 0.0 { 0.0 } else { 1.0 - min / i };
 
-    vec![h, s, i]
+    [h, s, i]
 }
 
-pub(crate) fn hsi2rgb(color: &[f64]) -> Vec<f64> {
+pub(crate) fn hsi2rgb(color: &[f64; 3]) -> [f64; 3] {
     let h = color[0];
     let s = color[1];
     let i = color[2];
@@ -35,116 +37,114 @@ pub(crate) fn hsi2rgb(color: &[f64]) -> Vec<f64> {
             let h = h.to_radians();
 
             let b = i * (1.0 - s);
-            let r = i * (1.0 + (s * h.cos()) / (std::f64::consts::FRAC_PI_3 - h).cos());
+            let r = i * (1.0 + (s * h.cos()) / (core::f64::consts::FRAC_PI_3 - h).cos());
             let g = 3.0 * i - (r + b);
 
-            vec![r, g, b]
+            [r, g, b]
         }
         h if (120.0..240.0).contains(&h) => {
             let h = (h - 120.0).to_radians();
 
             let r = i * (1.0 - s);
-            let g = i * (1.0 + (s * h.cos()) / (std::f64::consts::FRAC_PI_3 - h).cos());
+            let g = i * (1.0 + (s * h.cos()) / (core::f64::consts::FRAC_PI_3 - h).cos());
             let b = 3.0 * i - (r + g);
 
-            vec![r, g, b]
+            [r, g, b]
         }
         h if (240.0..360.0).contains(&h) => {
             let h = (h - 240.0).to_radians();
 
             let g = i * (1.0 - s);
-            let b = i * (1.0 + (s * h.cos()) / (std::f64::consts::FRAC_PI_3 - h).cos());
+            let b = i * (1.0 + (s * h.cos()) / (core::f64::consts::FRAC_PI_3 - h).cos());
             let r = 3.0 * i - (g + b);
 
-            vec![r, g, b]
+            [r, g, b]
         }
         _ => panic!("Hue must be between 0 and 360"),
     };
 
-    rgb.iter().map(|&x| round(x * 255.0, 0)).collect()
+    rgb.map(|x| round(x * 255.0, 0))
 }
 
 #[cfg(test)]
 mod tests {
-    use std::vec;
-
     use super::*;
 
     #[test]
     fn test_rgb2hsi() {
-        let color = vec![0.0, 0.0, 0.0];
+        let color = [0.0, 0.0, 0.0];
         let hsi = rgb2hsi(&color);
-        assert_eq!(hsi, vec![0.0, 0.0, 0.0]);
+        assert_eq!(hsi, [0.0, 0.0, 0.0]);
 
-        let color = vec![255.0, 255.0, 255.0];
+        let color = [255.0, 255.0, 255.0];
         let hsi = rgb2hsi(&color);
-        assert_eq!(hsi, vec![0.0, 0.0, 1.0]);
+        assert_eq!(hsi, [0.0, 0.0, 1.0]);
 
-        let color = vec![255.0, 0.0, 0.0];
+        let color = [255.0, 0.0, 0.0];
         let hsi = rgb2hsi(&color);
-        assert_eq!(hsi, vec![0.0, 1.0, 0.3333333333333333]);
+        assert_eq!(hsi, [0.0, 1.0, 0.3333333333333333]);
 
-        let color = vec![0.0, 255.0, 0.0];
+        let color = [0.0, 255.0, 0.0];
         let hsi = rgb2hsi(&color);
-        assert_eq!(hsi, vec![120.00000000000001, 1.0, 0.3333333333333333]);
+        assert_eq!(hsi, [120.00000000000001, 1.0, 0.3333333333333333]);
 
-        let color = vec![0.0, 0.0, 255.0];
+        let color = [0.0, 0.0, 255.0];
         let hsi = rgb2hsi(&color);
-        assert_eq!(hsi, vec![240.0, 1.0, 0.3333333333333333]);
+        assert_eq!(hsi, [240.0, 1.0, 0.3333333333333333]);
 
-        let color = vec![255.0, 255.0, 0.0];
+        let color = [255.0, 255.0, 0.0];
         let hsi = rgb2hsi(&color);
-        assert_eq!(hsi, vec![60.00000000000001, 1.0, 0.6666666666666666]);
+        assert_eq!(hsi, [60.00000000000001, 1.0, 0.6666666666666666]);
 
-        let color = vec![0.0, 255.0, 255.0];
+        let color = [0.0, 255.0, 255.0];
         let hsi = rgb2hsi(&color);
-        assert_eq!(hsi, vec![180.0, 1.0, 0.6666666666666666]);
+        assert_eq!(hsi, [180.0, 1.0, 0.6666666666666666]);
 
-        let color = vec![255.0, 0.0, 255.0];
+        let color = [255.0, 0.0, 255.0];
         let hsi = rgb2hsi(&color);
-        assert_eq!(hsi, vec![300.0, 1.0, 0.6666666666666666]);
+        assert_eq!(hsi, [300.0, 1.0, 0.6666666666666666]);
 
-        let color = vec![255.0, 128.0, 128.0];
+        let color = [255.0, 128.0, 128.0];
         let hsi = rgb2hsi(&color);
-        assert_eq!(hsi, vec![0.0, 0.24853228962817997, 0.6679738562091503]);
+        assert_eq!(hsi, [0.0, 0.24853228962817997, 0.6679738562091503]);
     }
 
     #[test]
     fn test_hsi2rgb() {
-        let color = vec![0.0, 0.0, 0.0];
+        let color = [0.0, 0.0, 0.0];
         let rgb = hsi2rgb(&color);
-        assert_eq!(rgb, vec![0.0, 0.0, 0.0]);
+        assert_eq!(rgb, [0.0, 0.0, 0.0]);
 
-        let color = vec![0.0, 0.0, 1.0];
+        let color = [0.0, 0.0, 1.0];
         let rgb = hsi2rgb(&color);
-        assert_eq!(rgb, vec![255.0, 255.0, 255.0]);
+        assert_eq!(rgb, [255.0, 255.0, 255.0]);
 
-        let color = vec![0.0, 1.0, 0.3333];
+        let color = [0.0, 1.0, 0.3333];
         let rgb = hsi2rgb(&color);
-        assert_eq!(rgb, vec![255.0, 0.0, 0.0]);
+        assert_eq!(rgb, [255.0, 0.0, 0.0]);
 
-        let color = vec![120.0, 1.0, 0.3333];
+        let color = [120.0, 1.0, 0.3333];
         let rgb = hsi2rgb(&color);
-        assert_eq!(rgb, vec![0.0, 255.0, 0.0]);
+        assert_eq!(rgb, [0.0, 255.0, 0.0]);
 
-        let color = vec![240.0, 1.0, 0.3333];
+        let color = [240.0, 1.0, 0.3333];
         let rgb = hsi2rgb(&color);
-        assert_eq!(rgb, vec![0.0, 0.0, 255.0]);
+        assert_eq!(rgb, [0.0, 0.0, 255.0]);
 
-        let color = vec![60.0, 1.0, 0.6667];
+        let color = [60.0, 1.0, 0.6667];
         let rgb = hsi2rgb(&color);
-        assert_eq!(rgb, vec![255.0, 255.0, 0.0]);
+        assert_eq!(rgb, [255.0, 255.0, 0.0]);
 
-        let color = vec![180.0, 1.0, 0.6667];
+        let color = [180.0, 1.0, 0.6667];
         let rgb = hsi2rgb(&color);
-        assert_eq!(rgb, vec![0.0, 255.0, 255.0]);
+        assert_eq!(rgb, [0.0, 255.0, 255.0]);
 
-        let color = vec![300.0, 1.0, 0.6667];
+        let color = [300.0, 1.0, 0.6667];
         let rgb = hsi2rgb(&color);
-        assert_eq!(rgb, vec![255.0, 0.0, 255.0]);
+        assert_eq!(rgb, [255.0, 0.0, 255.0]);
 
-        let color = vec![0.0, 0.2485, 0.668];
+        let color = [0.0, 0.2485, 0.668];
         let rgb = hsi2rgb(&color);
-        assert_eq!(rgb, vec![255.0, 128.0, 128.0]);
+        assert_eq!(rgb, [255.0, 128.0, 128.0]);
     }
 }
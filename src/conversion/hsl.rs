@@ -1,30 +1,36 @@
 use crate::utils::*;
 
 /// [HSL to RGB color conversion](https://www.rapidtables.com/convert/color/hsl-to-rgb.html)
-pub fn hsl2rgb(color: &[f64]) -> Vec<f64> {
-    let h = color[0];
-    let s = color[1];
-    let l = color[2];
+///
+/// `const` for the literals of the [`color!`](crate::color) macro.
+pub const fn hsl2rgb(color: &[f64; 3]) -> [f64; 3] {
+    let [h, s, l] = *color;
 
     let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
     let x = c * (1.0 - (((h / 60.0) % 2.0) - 1.0).abs());
     let m = l - c / 2.0;
 
-    let rgb = match h {
-        h if (0.0..60.0).contains(&h) => vec![c, x, 0.0],
-        h if (60.0..120.0).contains(&h) => vec![x, c, 0.0],
-        h if (120.0..180.0).contains(&h) => vec![0.0, c, x],
-        h if (180.0..240.0).contains(&h) => vec![0.0, x, c],
-        h if (240.0..300.0).contains(&h) => vec![x, 0.0, c],
-        h if (300.0..360.0).contains(&h) => vec![c, 0.0, x],
-        _ => panic!(),
+    let [r, g, b] = if !(h >= 0.0 && h <= 360.0) {
+        panic!("hue must be between 0 and 360")
+    } else if h < 60.0 {
+        [c, x, 0.0]
+    } else if h < 120.0 {
+        [x, c, 0.0]
+    } else if h < 180.0 {
+        [0.0, c, x]
+    } else if h < 240.0 {
+        [0.0, x, c]
+    } else if h < 300.0 {
+        [x, 0.0, c]
+    } else {
+        [c, 0.0, x]
     };
 
-    rgb.iter().map(|x| (x + m) * 255.0).collect()
+    [(r + m) * 255.0, (g + m) * 255.0, (b + m) * 255.0]
 }
 
 /// [RGB to HSL color conversion](https://www.rapidtables.com/convert/color/rgb-to-hsl.html)
-pub fn rgb2hsl(color: &[f64]) -> Vec<f64> {
+pub fn rgb2hsl(color: &[f64; 3]) -> [f64; 3] {
     let color = normalize_color(color);
     let r = color[0];
     let g = color[1];
@@ -55,7 +61,7 @@ pub fn rgb2hsl(color: &[f64]) -> Vec<f64> {
         s = s.max(0.0).min(1.0);
     }
 
-    vec![h, s, l]
+    [h, s, l]
 }
 
 #[cfg(test)]
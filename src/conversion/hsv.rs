@@ -1,7 +1,7 @@
 use crate::utils::*;
 
 /// [HSV to RGB color conversion](https://www.rapidtables.com/convert/color/hsv-to-rgb.html)
-pub fn hsv2rgb(color: &[f64]) -> Vec<f64> {
+pub fn hsv2rgb(color: &[f64; 3]) -> [f64; 3] {
     let h = color[0];
     let s = color[1];
     let v = color[2];
@@ -11,20 +11,20 @@ pub fn hsv2rgb(color: &[f64]) -> Vec<f64> {
     let m = v - c;
 
     let rgb = match h {
-        h if (0.0..60.0).contains(&h) => vec![c, x, 0.0],
-        h if (60.0..120.0).contains(&h) => vec![x, c, 0.0],
-        h if (120.0..180.0).contains(&h) => vec![0.0, c, x],
-        h if (180.0..240.0).contains(&h) => vec![0.0, x, c],
-        h if (240.0..300.0).contains(&h) => vec![x, 0.0, c],
-        h if (300.0..360.0).contains(&h) => vec![c, 0.0, x],
+        h if (0.0..60.0).contains(&h) => [c, x, 0.0],
+        h if (60.0..120.0).contains(&h) => [x, c, 0.0],
+        h if (120.0..180.0).contains(&h) => [0.0, c, x],
+        h if (180.0..240.0).contains(&h) => [0.0, x, c],
+        h if (240.0..300.0).contains(&h) => [x, 0.0, c],
+        h if (300.0..360.0).contains(&h) => [c, 0.0, x],
         _ => panic!("Hue must be between 0 and 360"),
     };
 
-    rgb.iter().map(|&x| (x + m) * 255.0).collect()
+    rgb.map(|x| (x + m) * 255.0)
 }
 
 /// [RGB to HSV color conversion](https://www.rapidtables.com/convert/color/rgb-to-hsv.html)
-pub fn rgb2hsv(color: &[f64]) -> Vec<f64> {
+pub fn rgb2hsv(color: &[f64; 3]) -> [f64; 3] {
     let color = normalize_color(color);
     let r = color[0];
     let g = color[1];
@@ -55,7 +55,7 @@ pub fn rgb2hsv(color: &[f64]) -> Vec<f64> {
         s = delta / max;
     }
 
-    vec![h, s, v]
+    [h, s, v]
 }
 
 #[cfg(test)]
@@ -64,31 +64,31 @@ mod tests {
 
     #[test]
     fn test_hsv2rgb() {
-        let hsv = vec![330.0, 0.8, 1.0];
+        let hsv = [330.0, 0.8, 1.0];
         let rgb = hsv2rgb(&hsv);
-        assert_eq!(rgb, vec![255.0, 50.999999999999986, 153.0]);
+        assert_eq!(rgb, [255.0, 50.999999999999986, 153.0]);
 
-        let hsv = vec![0.0, 0.0, 0.0];
+        let hsv = [0.0, 0.0, 0.0];
         let rgb = hsv2rgb(&hsv);
-        assert_eq!(rgb, vec![0.0, 0.0, 0.0]);
+        assert_eq!(rgb, [0.0, 0.0, 0.0]);
 
-        let hsv = vec![0.0, 0.0, 1.0];
+        let hsv = [0.0, 0.0, 1.0];
         let rgb = hsv2rgb(&hsv);
-        assert_eq!(rgb, vec![255.0, 255.0, 255.0]);
+        assert_eq!(rgb, [255.0, 255.0, 255.0]);
     }
 
     #[test]
     fn test_rgb2hsv() {
-        let rgb = vec![255.0, 51.0, 153.0];
+        let rgb = [255.0, 51.0, 153.0];
         let hsv = rgb2hsv(&rgb);
-        assert_eq!(hsv, vec![330.0, 0.8, 1.0]);
+        assert_eq!(hsv, [330.0, 0.8, 1.0]);
 
-        let rgb = vec![0.0, 0.0, 0.0];
+        let rgb = [0.0, 0.0, 0.0];
         let hsv = rgb2hsv(&rgb);
-        assert_eq!(hsv, vec![0.0, 0.0, 0.0]);
+        assert_eq!(hsv, [0.0, 0.0, 0.0]);
 
-        let rgb = vec![255.0, 255.0, 255.0];
+        let rgb = [255.0, 255.0, 255.0];
         let hsv = rgb2hsv(&rgb);
-        assert_eq!(hsv, vec![0.0, 0.0, 1.0]);
+        assert_eq!(hsv, [0.0, 0.0, 1.0]);
     }
 }
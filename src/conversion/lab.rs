@@ -1,18 +1,21 @@
 use super::{
-    utils::{d50_to_d65, d65_to_d50, lab2xyz, xyz2lab},
-    xyz::{rgb2xyz, xyz2rgb},
+    chromatic_adaptation::Illuminant,
+    utils::{d50_to_d65, d65_to_d50, lab2xyz, xyz2lab, xyz2lab_with_white},
+    xyz::{rgb2xyz, rgb2xyz_with_illuminant, xyz2rgb},
 };
 
-pub fn rgb2lab(color: &[f64]) -> Vec<f64> {
-    let xyz = rgb2xyz(color);
-    let xyz = d65_to_d50(xyz);
-    xyz2lab(xyz)
+pub fn rgb2lab(color: &[f64; 3]) -> [f64; 3] {
+    xyz2lab(&d65_to_d50(&rgb2xyz(color)))
 }
 
-pub fn lab2rgb(color: &[f64]) -> Vec<f64> {
-    let xyz = lab2xyz(color);
-    let xyz = d50_to_d65(xyz);
-    xyz2rgb(&xyz)
+/// Convert RGB to Lab relative to the given illuminant
+pub fn rgb2lab_with_illuminant(color: &[f64; 3], illuminant: Illuminant) -> [f64; 3] {
+    let xyz = rgb2xyz_with_illuminant(color, illuminant);
+    xyz2lab_with_white(&xyz, &illuminant.white_point())
+}
+
+pub fn lab2rgb(color: &[f64; 3]) -> [f64; 3] {
+    xyz2rgb(&d50_to_d65(&lab2xyz(color)))
 }
 
 #[cfg(test)]
@@ -32,15 +35,28 @@ mod tests {
         assert!(vec[2] - 80.99459785152246 < 0.0000001);
     }
 
+    #[test]
+    fn test_rgb2lab_with_illuminant() {
+        assert_eq!(
+            rgb2lab_with_illuminant(&[255.0, 255.0, 0.0], Illuminant::D50),
+            rgb2lab(&[255.0, 255.0, 0.0])
+        );
+
+        let vec = rgb2lab_with_illuminant(&[255.0, 255.0, 255.0], Illuminant::F2);
+        assert!((vec[0] - 100.0).abs() < 1e-6);
+        assert!(vec[1].abs() < 1e-6);
+        assert!(vec[2].abs() < 1e-6);
+    }
+
     #[test]
     fn test_lab2rgb() {
         assert_eq!(
             lab2rgb(&[97.60701009682252, -15.749846639252551, 93.39361164266084]),
-            vec![255.0, 255.0, 0.0]
+            [255.0, 255.0, 0.0]
         );
         assert_eq!(
             lab2rgb(&[87.818536331152, -79.271082238548, 80.99459785152246]),
-            vec![0.0, 255.0, 0.0]
+            [0.0, 255.0, 0.0]
         );
     }
 }
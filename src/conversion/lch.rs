@@ -0,0 +1,39 @@
+use super::{
+    lab::{lab2rgb, rgb2lab},
+    utils::{lab_to_lch, lch_to_lab},
+};
+
+/// Convert `RGB` to CIE `LCH`
+///
+/// LCH is the cylindrical form of CIE Lab, see [CIELAB](https://en.wikipedia.org/wiki/CIELAB_color_space#Cylindrical_model).
+pub fn rgb2lch(color: &[f64; 3]) -> [f64; 3] {
+    lab_to_lch(&rgb2lab(color))
+}
+
+/// Convert CIE `LCH` to `RGB`
+pub fn lch2rgb(color: &[f64; 3]) -> [f64; 3] {
+    lab2rgb(&lch_to_lab(color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::round;
+
+    #[test]
+    fn test_rgb2lch() {
+        let vec = rgb2lch(&[255.0, 255.0, 0.0]);
+        assert_eq!(round(vec[0], 2), 97.61);
+        assert_eq!(round(vec[1], 2), 94.71);
+        assert_eq!(round(vec[2], 2), 99.57);
+
+        let vec = rgb2lch(&[255.0, 255.0, 255.0]);
+        assert_eq!(vec[2], 0.0);
+    }
+
+    #[test]
+    fn test_lch2rgb() {
+        assert_eq!(lch2rgb(&[97.61, 94.71, 99.57]), [255.0, 255.0, 0.0]);
+        assert_eq!(lch2rgb(&[100.0, 0.0, 0.0]), [255.0, 255.0, 255.0]);
+    }
+}
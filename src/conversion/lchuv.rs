@@ -0,0 +1,36 @@
+use super::{
+    luv::{luv2rgb, rgb2luv},
+    utils::{lab_to_lch, lch_to_lab},
+};
+
+/// Convert `RGB` to `LCHuv`
+///
+/// LCHuv is the cylindrical form of [CIE Luv](https://en.wikipedia.org/wiki/CIELUV#Cylindrical_representation_(CIELCh)).
+pub fn rgb2lchuv(color: &[f64; 3]) -> [f64; 3] {
+    lab_to_lch(&rgb2luv(color))
+}
+
+/// Convert `LCHuv` to `RGB`
+pub fn lchuv2rgb(color: &[f64; 3]) -> [f64; 3] {
+    luv2rgb(&lch_to_lab(color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::round;
+
+    #[test]
+    fn test_rgb2lchuv() {
+        let vec = rgb2lchuv(&[255.0, 0.0, 0.0]);
+        assert_eq!(round(vec[0], 2), 53.24);
+        assert_eq!(round(vec[1], 2), 179.04);
+        assert_eq!(round(vec[2], 2), 12.18);
+    }
+
+    #[test]
+    fn test_lchuv2rgb() {
+        assert_eq!(lchuv2rgb(&[53.24, 179.04, 12.18]), [255.0, 0.0, 0.0]);
+        assert_eq!(lchuv2rgb(&[100.0, 0.0, 0.0]), [255.0, 255.0, 255.0]);
+    }
+}
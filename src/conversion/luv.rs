@@ -0,0 +1,111 @@
+use super::{
+    utils::D65,
+    xyz::{rgb2xyz, xyz2rgb},
+};
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+
+const K: f64 = 24389.0 / 27.0; // 29^3/3^3
+const E: f64 = 216.0 / 24389.0; // 6^3/29^3
+
+/// chromaticity coordinates `u'` and `v'` of an XYZ color
+fn uv_prime(xyz: &[f64; 3]) -> (f64, f64) {
+    let denom = xyz[0] + 15.0 * xyz[1] + 3.0 * xyz[2];
+    if denom == 0.0 {
+        (0.0, 0.0)
+    } else {
+        (4.0 * xyz[0] / denom, 9.0 * xyz[1] / denom)
+    }
+}
+
+/// Convert XYZ to CIE Luv, relative to the D65 white point
+///
+/// <http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Luv.html>
+pub(crate) fn xyz2luv(xyz: &[f64; 3]) -> [f64; 3] {
+    let (un, vn) = uv_prime(&D65);
+    let (u, v) = uv_prime(xyz);
+    let y = xyz[1] / D65[1];
+
+    let l = if y > E {
+        116.0 * y.cbrt() - 16.0
+    } else {
+        K * y
+    };
+
+    if l == 0.0 {
+        return [0.0, 0.0, 0.0];
+    }
+
+    [l, 13.0 * l * (u - un), 13.0 * l * (v - vn)]
+}
+
+/// Convert CIE Luv to XYZ, relative to the D65 white point
+///
+/// <http://www.brucelindbloom.com/index.html?Eqn_Luv_to_XYZ.html>
+pub(crate) fn luv2xyz(luv: &[f64; 3]) -> [f64; 3] {
+    let [l, u, v] = *luv;
+    // black has no chromaticity, and close to it `u / l` and `v / l` blow up
+    if l < 1e-6 {
+        return [0.0, 0.0, 0.0];
+    }
+
+    let (un, vn) = uv_prime(&D65);
+    let u = u / (13.0 * l) + un;
+    let v = v / (13.0 * l) + vn;
+    // only reachable with a tiny lightness, no real color has `v' <= 0`
+    if v <= 0.0 {
+        return [0.0, 0.0, 0.0];
+    }
+
+    let y = if l > K * E {
+        ((l + 16.0) / 116.0).powi(3)
+    } else {
+        l / K
+    } * D65[1];
+    let x = y * 9.0 * u / (4.0 * v);
+    let z = y * (12.0 - 3.0 * u - 20.0 * v) / (4.0 * v);
+
+    [x, y, z]
+}
+
+/// Convert `RGB` to CIE `Luv`
+pub fn rgb2luv(color: &[f64; 3]) -> [f64; 3] {
+    xyz2luv(&rgb2xyz(color))
+}
+
+/// Convert CIE `Luv` to `RGB`
+pub fn luv2rgb(color: &[f64; 3]) -> [f64; 3] {
+    xyz2rgb(&luv2xyz(color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::round;
+
+    fn round2_vec<const N: usize>(vec: [f64; N]) -> [f64; N] {
+        vec.map(|v| round(v, 2))
+    }
+
+    #[test]
+    fn test_rgb2luv() {
+        assert_eq!(
+            round2_vec(rgb2luv(&[255.0, 255.0, 255.0])),
+            [100.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            round2_vec(rgb2luv(&[255.0, 0.0, 0.0])),
+            [53.24, 175.01, 37.77]
+        );
+        assert_eq!(rgb2luv(&[0.0, 0.0, 0.0]), [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_luv2rgb() {
+        assert_eq!(luv2rgb(&[100.0, 0.0, 0.0]), [255.0, 255.0, 255.0]);
+        assert_eq!(luv2rgb(&[53.24, 175.01, 37.77]), [255.0, 0.0, 0.0]);
+        assert_eq!(luv2rgb(&[0.0, 0.0, 0.0]), [0.0, 0.0, 0.0]);
+        assert_eq!(luv2rgb(&[1e-9, 100.0, 100.0]), [0.0, 0.0, 0.0]);
+        assert_eq!(luv2xyz(&[0.01, 0.0, -0.061]), [0.0, 0.0, 0.0]);
+    }
+}
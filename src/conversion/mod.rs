@@ -1,3 +1,6 @@
+pub(crate) mod acescg;
+pub(crate) mod cam16;
+pub(crate) mod chromatic_adaptation;
 pub(crate) mod cmyk;
 pub(crate) mod hex;
 pub(crate) mod hsi;
@@ -5,8 +8,16 @@ pub(crate) mod hsl;
 pub(crate) mod hsv;
 pub(crate) mod hwb;
 pub(crate) mod lab;
+pub(crate) mod lch;
+pub(crate) mod lchuv;
+pub(crate) mod luv;
+pub(crate) mod oklab;
+pub(crate) mod oklch;
+pub(crate) mod rec2020;
+pub(crate) mod temperature;
 pub(crate) mod utils;
 pub(crate) mod xyz;
 pub(crate) mod ycbcr;
 pub(crate) mod yiq;
+pub(crate) mod ypbpr;
 pub(crate) mod yuv;
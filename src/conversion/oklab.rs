@@ -0,0 +1,74 @@
+use super::utils::*;
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::utils::*;
+
+static XYZ2LMS_MATRIX: [[f64; 3]; 3] = [
+    [0.819022437996703, 0.3619062600528904, -0.1288737815209879],
+    [0.0329836539323885, 0.9292868615863434, 0.0361446663506424],
+    [0.0481771893596242, 0.2642395317527308, 0.6335478284694309],
+];
+
+static LMS2OKLAB_MATRIX: [[f64; 3]; 3] = [
+    [0.210454268309314, 0.7936177747023054, -0.0040720430116193],
+    [1.9779985324311684, -2.42859224204858, 0.450593709617411],
+    [0.0259040424655478, 0.7827717124575296, -0.8086757549230774],
+];
+
+static LMS2XYZ_MATRIX: [[f64; 3]; 3] = [
+    [1.2268798758459243, -0.5578149944602171, 0.2813910456659647],
+    [-0.0405757452148008, 1.112286803280317, -0.0717110580655164],
+    [-0.0763729366746601, -0.4214933324022432, 1.5869240198367816],
+];
+
+static OKLAB2LMS_MATRIX: [[f64; 3]; 3] = [
+    [1.0, 0.3963377773761749, 0.2158037573299021],
+    [1.0, -0.1055613458156586, -0.0638541728258133],
+    [1.0, -0.0894841775298119, -1.2914855480194092],
+];
+
+/// Convert `RGB` to `OKLab`
+///
+/// reference: [Converting from XYZ to OKLab](https://www.w3.org/TR/css-color-4/#color-conversion-code)
+pub fn rgb2oklab(color: &[f64; 3]) -> [f64; 3] {
+    let xyz = lin_srgb_to_xyz(&lin_srgb(&normalize_color(color)));
+    let lms = multiply_matrix_vec(&XYZ2LMS_MATRIX, &xyz).map(f64::cbrt);
+    multiply_matrix_vec(&LMS2OKLAB_MATRIX, &lms)
+}
+
+/// Convert `OKLab` to `RGB`
+///
+/// reference: [Converting from OKLab to XYZ](https://www.w3.org/TR/css-color-4/#color-conversion-code)
+pub fn oklab2rgb(color: &[f64; 3]) -> [f64; 3] {
+    let lms = multiply_matrix_vec(&OKLAB2LMS_MATRIX, color).map(|v| v.powi(3));
+    let xyz = multiply_matrix_vec(&LMS2XYZ_MATRIX, &lms);
+
+    gam_srgb(&xyz_to_lin_srgb(&xyz)).map(|v| round(v * 255.0, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round4_vec<const N: usize>(vec: [f64; N]) -> [f64; N] {
+        vec.map(|v| round(v, 4))
+    }
+
+    #[test]
+    fn test_rgb2oklab() {
+        assert_eq!(
+            round4_vec(rgb2oklab(&[255.0, 255.0, 255.0])),
+            [1.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            round4_vec(rgb2oklab(&[255.0, 0.0, 0.0])),
+            [0.628, 0.2249, 0.1258]
+        );
+    }
+
+    #[test]
+    fn test_oklab2rgb() {
+        assert_eq!(oklab2rgb(&[1.0, 0.0, 0.0]), [255.0, 255.0, 255.0]);
+        assert_eq!(oklab2rgb(&[0.62796, 0.22486, 0.12585]), [255.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,39 @@
+use super::{
+    oklab::{oklab2rgb, rgb2oklab},
+    utils::{lab_to_lch, lch_to_lab},
+};
+
+/// Convert `RGB` to `OKLCH`
+///
+/// OKLCH is the cylindrical form of [OKLab](https://bottosson.github.io/posts/oklab/).
+pub fn rgb2oklch(color: &[f64; 3]) -> [f64; 3] {
+    lab_to_lch(&rgb2oklab(color))
+}
+
+/// Convert `OKLCH` to `RGB`
+pub fn oklch2rgb(color: &[f64; 3]) -> [f64; 3] {
+    oklab2rgb(&lch_to_lab(color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::round;
+
+    #[test]
+    fn test_rgb2oklch() {
+        let vec = rgb2oklch(&[255.0, 0.0, 0.0]);
+        assert_eq!(round(vec[0], 4), 0.628);
+        assert_eq!(round(vec[1], 4), 0.2577);
+        assert_eq!(round(vec[2], 2), 29.23);
+
+        let vec = rgb2oklch(&[128.0, 128.0, 128.0]);
+        assert_eq!(vec[2], 0.0);
+    }
+
+    #[test]
+    fn test_oklch2rgb() {
+        assert_eq!(oklch2rgb(&[0.628, 0.2577, 29.23]), [255.0, 0.0, 0.0]);
+        assert_eq!(oklch2rgb(&[1.0, 0.0, 0.0]), [255.0, 255.0, 255.0]);
+    }
+}
@@ -0,0 +1,124 @@
+use super::utils::*;
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::utils::*;
+
+static REC2020_TO_XYZ_MATRIX: [[f64; 3]; 3] = [
+    [
+        63426534.0 / 99577255.0,
+        20160776.0 / 139408157.0,
+        47086771.0 / 278816314.0,
+    ],
+    [
+        26158966.0 / 99577255.0,
+        472592308.0 / 697040785.0,
+        8267143.0 / 139408157.0,
+    ],
+    [0.0, 19567812.0 / 697040785.0, 295819943.0 / 278816314.0],
+];
+
+static XYZ_TO_REC2020_MATRIX: [[f64; 3]; 3] = [
+    [
+        30757411.0 / 17917100.0,
+        -6372589.0 / 17917100.0,
+        -4539589.0 / 17917100.0,
+    ],
+    [-0.666684351832489, 1.616481236634939, 467509.0 / 29648200.0],
+    [
+        792561.0 / 44930125.0,
+        -1921689.0 / 44930125.0,
+        0.942103121235474,
+    ],
+];
+
+// constants of the Rec. 2020 transfer function
+const ALPHA: f64 = 1.09929682680944;
+const BETA: f64 = 0.018053968510807;
+
+/// convert linear-light sRGB to linear-light Rec. 2020
+pub(crate) fn lin_srgb_to_lin_rec2020(rgb: &[f64; 3]) -> [f64; 3] {
+    multiply_matrix_vec(&XYZ_TO_REC2020_MATRIX, &lin_srgb_to_xyz(rgb))
+}
+
+/// convert linear-light Rec. 2020 to linear-light sRGB
+pub(crate) fn lin_rec2020_to_lin_srgb(rgb: &[f64; 3]) -> [f64; 3] {
+    xyz_to_lin_srgb(&multiply_matrix_vec(&REC2020_TO_XYZ_MATRIX, rgb))
+}
+
+/// remove the Rec. 2020 transfer function, gamma-encoded to linear-light
+pub(crate) fn lin_rec2020(rgb: &[f64; 3]) -> [f64; 3] {
+    rgb.map(|v| {
+        let sign = v.signum();
+        let abs = v.abs();
+        if abs < BETA * 4.5 {
+            v / 4.5
+        } else {
+            sign * ((abs + ALPHA - 1.0) / ALPHA).powf(1.0 / 0.45)
+        }
+    })
+}
+
+/// apply the Rec. 2020 transfer function, linear-light to gamma-encoded
+pub(crate) fn gam_rec2020(rgb: &[f64; 3]) -> [f64; 3] {
+    rgb.map(|v| {
+        let sign = v.signum();
+        let abs = v.abs();
+        if abs < BETA {
+            v * 4.5
+        } else {
+            sign * (ALPHA * abs.powf(0.45) - (ALPHA - 1.0))
+        }
+    })
+}
+
+/// Convert `RGB` to gamma-encoded `Rec. 2020` RGB, each value in the range 0.0 to 1.0
+///
+/// reference: [Rec. 2020](https://en.wikipedia.org/wiki/Rec._2020)
+pub fn rgb2rec2020(color: &[f64; 3]) -> [f64; 3] {
+    let color = lin_srgb(&normalize_color(color));
+    gam_rec2020(&lin_srgb_to_lin_rec2020(&color))
+}
+
+/// Convert gamma-encoded `Rec. 2020` RGB to `RGB`
+///
+/// Colors outside of the sRGB gamut are clipped.
+pub fn rec2020_to_rgb(color: &[f64; 3]) -> [f64; 3] {
+    let color = lin_rec2020_to_lin_srgb(&lin_rec2020(color));
+    gam_srgb(&color).map(|v| round(v.clamp(0.0, 1.0) * 255.0, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round4_vec<const N: usize>(vec: [f64; N]) -> [f64; N] {
+        vec.map(|v| round(v, 4))
+    }
+
+    #[test]
+    fn test_rgb2rec2020() {
+        assert_eq!(
+            round4_vec(rgb2rec2020(&[255.0, 255.0, 255.0])),
+            [1.0, 1.0, 1.0]
+        );
+        assert_eq!(round4_vec(rgb2rec2020(&[0.0, 0.0, 0.0])), [0.0, 0.0, 0.0]);
+        assert_eq!(
+            round4_vec(rgb2rec2020(&[255.0, 0.0, 0.0])),
+            [0.792, 0.231, 0.0738]
+        );
+    }
+
+    #[test]
+    fn test_rec2020_to_rgb() {
+        assert_eq!(rec2020_to_rgb(&[0.792, 0.231, 0.0738]), [255.0, 0.0, 0.0]);
+        assert_eq!(rec2020_to_rgb(&[1.0, 1.0, 1.0]), [255.0, 255.0, 255.0]);
+        assert_eq!(rec2020_to_rgb(&[0.0, 1.0, 0.0]), [0.0, 255.0, 0.0]);
+    }
+
+    #[test]
+    fn test_rec2020_transfer() {
+        for linear in [[0.0, 0.01, 0.5], [0.25, 0.75, 1.0]] {
+            assert_eq!(round4_vec(lin_rec2020(&gam_rec2020(&linear))), linear);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use super::chromatic_adaptation::xy_to_xyz;
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+
+/// second radiation constant `c2 = hc / k`, in m·K
+const C2: f64 = 1.4387769e-2;
+
+/// CIE 1931 2° standard observer color matching functions, from 380nm to 780nm in 10nm steps
+static CIE_CMF: [[f64; 3]; 41] = [
+    [0.001368, 0.000039, 0.006450], // 380
+    [0.004243, 0.000120, 0.020050], // 390
+    [0.014310, 0.000396, 0.067850], // 400
+    [0.043510, 0.001210, 0.207400], // 410
+    [0.134380, 0.004000, 0.645600], // 420
+    [0.283900, 0.011600, 1.385600], // 430
+    [0.348280, 0.023000, 1.747060], // 440
+    [0.336200, 0.038000, 1.772110], // 450
+    [0.290800, 0.060000, 1.669200], // 460
+    [0.195360, 0.090980, 1.287640], // 470
+    [0.095640, 0.139020, 0.812950], // 480
+    [0.032010, 0.208020, 0.465180], // 490
+    [0.004900, 0.323000, 0.272000], // 500
+    [0.009300, 0.503000, 0.158200], // 510
+    [0.063270, 0.710000, 0.078250], // 520
+    [0.165500, 0.862000, 0.042160], // 530
+    [0.290400, 0.954000, 0.020300], // 540
+    [0.433450, 0.994950, 0.008750], // 550
+    [0.594500, 0.995000, 0.003900], // 560
+    [0.762100, 0.952000, 0.002100], // 570
+    [0.916300, 0.870000, 0.001650], // 580
+    [1.026300, 0.757000, 0.001100], // 590
+    [1.062200, 0.631000, 0.000800], // 600
+    [1.002600, 0.503000, 0.000340], // 610
+    [0.854450, 0.381000, 0.000190], // 620
+    [0.642400, 0.265000, 0.000050], // 630
+    [0.447900, 0.175000, 0.000020], // 640
+    [0.283500, 0.107000, 0.000000], // 650
+    [0.164900, 0.061000, 0.000000], // 660
+    [0.087400, 0.032000, 0.000000], // 670
+    [0.046770, 0.017000, 0.000000], // 680
+    [0.022700, 0.008210, 0.000000], // 690
+    [0.011359, 0.004102, 0.000000], // 700
+    [0.005790, 0.002091, 0.000000], // 710
+    [0.002899, 0.001047, 0.000000], // 720
+    [0.001440, 0.000520, 0.000000], // 730
+    [0.000690, 0.000249, 0.000000], // 740
+    [0.000332, 0.000120, 0.000000], // 750
+    [0.000166, 0.000060, 0.000000], // 760
+    [0.000083, 0.000030, 0.000000], // 770
+    [0.000042, 0.000015, 0.000000], // 780
+];
+
+/// XYZ of a blackbody radiator at a temperature in kelvin, normalized to `Y = 1.0`
+///
+/// Planck's law is summed over the visible spectrum, weighted by the color matching functions.
+pub(crate) fn blackbody_xyz(kelvin: f64) -> [f64; 3] {
+    let xyz = CIE_CMF.iter().enumerate().fold([0.0; 3], |xyz, (i, cmf)| {
+        let wavelength = (380.0 + 10.0 * i as f64) * 1e-9;
+        let radiance = 1.0 / (wavelength.powi(5) * ((C2 / (wavelength * kelvin)).exp() - 1.0));
+        [0, 1, 2].map(|c| xyz[c] + radiance * cmf[c])
+    });
+    xyz.map(|v| v / xyz[1])
+}
+
+/// convert XYZ to CIE 1960 UCS uv chromaticity coordinates
+fn xyz_to_uv(xyz: &[f64; 3]) -> [f64; 2] {
+    let denominator = xyz[0] + 15.0 * xyz[1] + 3.0 * xyz[2];
+    [4.0 * xyz[0] / denominator, 6.0 * xyz[1] / denominator]
+}
+
+/// uv of the Planckian locus at a temperature in mired, and its derivative per mired
+fn planckian_locus(mired: f64) -> ([f64; 2], [f64; 2]) {
+    let point = xyz_to_uv(&blackbody_xyz(1e6 / mired));
+    let next = xyz_to_uv(&blackbody_xyz(1e6 / (mired + 1.0)));
+    (point, [next[0] - point[0], next[1] - point[1]])
+}
+
+/// XYZ of the white point at a temperature in mired, offset from the Planckian locus by a
+/// Duv, normalized to `Y = 1.0`
+pub(crate) fn locus_white_point(mired: f64, duv: f64) -> [f64; 3] {
+    let ([u, v], [du, dv]) = planckian_locus(mired);
+    let length = du.hypot(dv);
+    let (u, v) = (u - duv * dv / length, v + duv * du / length);
+    let denominator = 2.0 * u - 8.0 * v + 4.0;
+    xy_to_xyz(3.0 * u / denominator, 2.0 * v / denominator)
+}
+
+/// Convert XYZ to its correlated color temperature in kelvin and Duv
+///
+/// McCamy's cubic approximation from the xy chromaticity is refined to the closest point of the
+/// Planckian locus in CIE 1960 uv, clamped to 1000K - 40000K. Duv is the distance to the locus,
+/// positive above it (greenish) and negative below it (pinkish).
+///
+/// reference: McCamy, [Correlated color temperature as an explicit function of chromaticity coordinates](https://doi.org/10.1002/col.5080170211)
+pub(crate) fn xyz2cct(xyz: &[f64; 3]) -> [f64; 2] {
+    let sum = xyz[0] + xyz[1] + xyz[2];
+    let n = (xyz[0] / sum - 0.3320) / (xyz[1] / sum - 0.1858);
+    let cct = -449.0 * n.powi(3) + 3525.0 * n.powi(2) - 6823.3 * n + 5520.33;
+
+    // Newton steps in mired, where the locus is close to a straight line
+    let [u, v] = xyz_to_uv(xyz);
+    let mut mired = 1e6 / cct.clamp(1000.0, 40000.0);
+    for _ in 0..4 {
+        let ([locus_u, locus_v], [du, dv]) = planckian_locus(mired);
+        let step = ((u - locus_u) * du + (v - locus_v) * dv) / (du * du + dv * dv);
+        mired = (mired + step).clamp(25.0, 1000.0);
+    }
+    let ([locus_u, locus_v], [du, dv]) = planckian_locus(mired);
+    let duv = ((v - locus_v) * du - (u - locus_u) * dv) / du.hypot(dv);
+    [1e6 / mired, duv]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::utils::D65;
+
+    #[test]
+    fn test_blackbody_xyz() {
+        // chromaticities of the Planckian locus
+        for (kelvin, [x, y]) in [
+            (1000.0, [0.6528, 0.3444]),
+            (2856.0, [0.4476, 0.4074]),
+            (4000.0, [0.3805, 0.3768]),
+            (10000.0, [0.2807, 0.2884]),
+        ] {
+            let xyz = blackbody_xyz(kelvin);
+            let sum = xyz[0] + xyz[1] + xyz[2];
+            assert!((xyz[0] / sum - x).abs() < 2e-4, "x of {}K", kelvin);
+            assert!((xyz[1] / sum - y).abs() < 2e-4, "y of {}K", kelvin);
+            assert_eq!(xyz[1], 1.0);
+        }
+    }
+
+    #[test]
+    fn test_xyz2cct() {
+        for kelvin in [1000.0, 2000.0, 2856.0, 4000.0, 6500.0, 10000.0, 40000.0] {
+            let [cct, duv] = xyz2cct(&blackbody_xyz(kelvin));
+            assert!((cct - kelvin).abs() < 0.01, "{}K", kelvin);
+            assert!(duv.abs() < 1e-9);
+        }
+        // D65 lies slightly above the Planckian locus
+        let [cct, duv] = xyz2cct(&D65);
+        assert!((cct - 6504.0).abs() < 10.0);
+        assert!((duv - 0.0032).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_locus_white_point() {
+        for (kelvin, duv) in [(2700.0, 0.0), (5000.0, 0.01), (9000.0, -0.02)] {
+            let [cct, offset] = xyz2cct(&locus_white_point(1e6 / kelvin, duv));
+            assert!((cct - kelvin).abs() < 1.0);
+            assert!((offset - duv).abs() < 1e-6);
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use crate::utils::multiply_matrices;
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
 
 // standard white points, defined by 4-figure CIE x,y chromaticities
 pub(crate) const D50: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
-// pub(crate) const D65: [f64; 3] = [0.3127 / 0.329, 1.0, (1.0 - 0.3127 - 0.329) / 0.329];
+pub(crate) const D65: [f64; 3] = [0.3127 / 0.329, 1.0, (1.0 - 0.3127 - 0.329) / 0.329];
 
 static RGB2XYZ_MATRIX: [[f64; 3]; 3] = [
     [506752.0 / 1228815.0, 87881.0 / 245763.0, 12673.0 / 70218.0],
@@ -20,69 +21,59 @@ static XYZ2RGB_MATRIX: [[f64; 3]; 3] = [
     [705.0 / 12673.0, -2585.0 / 12673.0, 705.0 / 667.0],
 ];
 
+/// multiply a 3x3 matrix by a 3 component vector
+pub(crate) fn multiply_matrix_vec(matrix: &[[f64; 3]; 3], vec: &[f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * vec[0] + row[1] * vec[1] + row[2] * vec[2])
+}
+
 /// convert an array of linear-light sRGB values to CIE XYZ
 ///
 /// using sRGB's own white, D65 (no chromatic adaptation)
-pub(crate) fn lin_srgb_to_xyz(rgb: &[f64]) -> Vec<f64> {
-    let rgb = rgb.iter().map(|&v| vec![v]).collect::<Vec<_>>();
-
-    let rgb2xyz_matrix = RGB2XYZ_MATRIX.map(|v| v.to_vec()).to_vec();
-
-    multiply_matrices(rgb2xyz_matrix, rgb)
-        .iter()
-        .map(|v| v[0])
-        .collect()
+pub(crate) fn lin_srgb_to_xyz(rgb: &[f64; 3]) -> [f64; 3] {
+    multiply_matrix_vec(&RGB2XYZ_MATRIX, rgb)
 }
 
 /// convert XYZ to linear-light sRGB
-pub(crate) fn xyz_to_lin_srgb(xyz: &[f64]) -> Vec<f64> {
-    let xyz = xyz.iter().map(|&v| vec![v]).collect::<Vec<_>>();
-
-    let xyz2rgb_matrix = XYZ2RGB_MATRIX.map(|v| v.to_vec()).to_vec();
+pub(crate) fn xyz_to_lin_srgb(xyz: &[f64; 3]) -> [f64; 3] {
+    multiply_matrix_vec(&XYZ2RGB_MATRIX, xyz)
+}
 
-    multiply_matrices(xyz2rgb_matrix, xyz)
-        .iter()
-        .map(|v| v[0])
-        .collect()
+pub(crate) fn lin_srgb(rgb: &[f64; 3]) -> [f64; 3] {
+    rgb.map(|v| {
+        let sign = v.signum();
+        let abs = v.abs();
+        if abs < 0.04045 {
+            v / 12.92
+        } else {
+            sign * ((abs + 0.055) / 1.055).powf(2.4)
+        }
+    })
 }
 
-pub(crate) fn lin_srgb(rgb: &[f64]) -> Vec<f64> {
-    rgb.iter()
-        .map(|&v| {
-            let sign = v.signum();
-            let abs = v.abs();
-            if abs < 0.04045 {
-                v / 12.92
-            } else {
-                sign * ((abs + 0.055) / 1.055).powf(2.4)
-            }
-        })
-        .collect()
+pub(crate) fn xyz2lab(xyz: &[f64; 3]) -> [f64; 3] {
+    xyz2lab_with_white(xyz, &D50)
 }
 
-pub(crate) fn xyz2lab(xyz: Vec<f64>) -> Vec<f64> {
+/// convert XYZ to Lab relative to the given reference white
+pub(crate) fn xyz2lab_with_white(xyz: &[f64; 3], white: &[f64; 3]) -> [f64; 3] {
     const E: f64 = 216.0 / 24389.0; // 6^3/29^3
     const K: f64 = 24389.0 / 27.0; // 29^3/3^3
-    let xyz: Vec<_> = xyz
-        .iter()
-        .zip(D50.iter())
-        .map(|(v1, v2)| v1 / v2)
-        .map(|v| {
-            if v > E {
-                v.cbrt()
-            } else {
-                (K * v + 16.0) / 116.0
-            }
-        })
-        .collect();
-    vec![
+    let xyz = [0, 1, 2].map(|i| {
+        let v = xyz[i] / white[i];
+        if v > E {
+            v.cbrt()
+        } else {
+            (K * v + 16.0) / 116.0
+        }
+    });
+    [
         116.0 * xyz[1] - 16.0,
         500.0 * (xyz[0] - xyz[1]),
         200.0 * (xyz[1] - xyz[2]),
     ]
 }
 
-pub(crate) fn d65_to_d50(xyz: Vec<f64>) -> Vec<f64> {
+pub(crate) fn d65_to_d50(xyz: &[f64; 3]) -> [f64; 3] {
     let m = [
         [
             1.0479298208405488,
@@ -100,12 +91,10 @@ pub(crate) fn d65_to_d50(xyz: Vec<f64>) -> Vec<f64> {
             0.7518742899580008,
         ],
     ];
-    let m = m.map(|v| v.to_vec()).to_vec();
-    let xyz = xyz.iter().map(|&v| vec![v]).collect();
-    multiply_matrices(m, xyz).iter().map(|v| v[0]).collect()
+    multiply_matrix_vec(&m, xyz)
 }
 
-pub(crate) fn d50_to_d65(xyz: Vec<f64>) -> Vec<f64> {
+pub(crate) fn d50_to_d65(xyz: &[f64; 3]) -> [f64; 3] {
     let m = [
         [
             0.9554734527042182,
@@ -123,15 +112,13 @@ pub(crate) fn d50_to_d65(xyz: Vec<f64>) -> Vec<f64> {
             1.3303659366080753,
         ],
     ];
-    let m = m.map(|v| v.to_vec()).to_vec();
-    let xyz = xyz.iter().map(|&v| vec![v]).collect();
-    multiply_matrices(m, xyz).iter().map(|v| v[0]).collect()
+    multiply_matrix_vec(&m, xyz)
 }
 
 /// Convert Lab to D50-adapted XYZ
 ///
 /// <http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html>
-pub(crate) fn lab2xyz(lab: &[f64]) -> Vec<f64> {
+pub(crate) fn lab2xyz(lab: &[f64; 3]) -> [f64; 3] {
     const K: f64 = 24389.0 / 27.0; // 29^3/3^3
     const E: f64 = 216.0 / 24389.0; // 6^3/29^3
 
@@ -155,9 +142,49 @@ pub(crate) fn lab2xyz(lab: &[f64]) -> Vec<f64> {
         (116.0 * f2 - 16.0) / K
     };
 
-    [x, y, z]
-        .iter()
-        .zip(D50.iter())
-        .map(|(v1, v2)| v1 * v2)
-        .collect()
+    [x * D50[0], y * D50[1], z * D50[2]]
+}
+
+/// convert an array of linear-light sRGB values in the range 0.0-1.0
+/// to gamma corrected form
+///
+/// <https://www.w3.org/TR/css-color-4/#color-conversion-code>
+pub(crate) fn gam_srgb(rgb: &[f64; 3]) -> [f64; 3] {
+    rgb.map(|v| {
+        let sign = v.signum();
+        let abs = v.abs();
+        if abs > 0.0031308 {
+            sign * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+        } else {
+            12.92 * v
+        }
+    })
+}
+
+/// Convert a rectangular `[L, a, b]` vector (Lab, OKLab, ...) to its polar `[L, C, h]` form.
+///
+/// Hue is reported as `0.0` for (near) achromatic colors.
+pub(crate) fn lab_to_lch(lab: &[f64; 3]) -> [f64; 3] {
+    const E: f64 = 0.0002;
+    let l = lab[0];
+    let c = (lab[1].powi(2) + lab[2].powi(2)).sqrt();
+    let h = if c < E {
+        0.0
+    } else {
+        let h = lab[2].atan2(lab[1]).to_degrees();
+        if h < 0.0 {
+            h + 360.0
+        } else {
+            h
+        }
+    };
+    [l, c, h]
+}
+
+/// Convert a polar `[L, C, h]` vector back to its rectangular `[L, a, b]` form.
+pub(crate) fn lch_to_lab(lch: &[f64; 3]) -> [f64; 3] {
+    let l = lch[0];
+    let c = lch[1];
+    let h = lch[2].to_radians();
+    [l, c * h.cos(), c * h.sin()]
 }
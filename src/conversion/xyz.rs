@@ -1,22 +1,32 @@
-use super::utils::*;
+use super::{
+    chromatic_adaptation::{adapt_xyz, AdaptationMethod, Illuminant},
+    utils::*,
+};
 use crate::utils::*;
 
 /// Convert RGB to XYZ.
-pub fn rgb2xyz(color: &[f64]) -> Vec<f64> {
-    let color = normalize_color(color);
-    let color = lin_srgb(&color);
+pub fn rgb2xyz(color: &[f64; 3]) -> [f64; 3] {
+    lin_srgb_to_xyz(&lin_srgb(&normalize_color(color)))
+}
 
-    lin_srgb_to_xyz(&color)
+/// Convert RGB to XYZ relative to the given illuminant, adapted from D65 with the Bradford transform.
+pub fn rgb2xyz_with_illuminant(color: &[f64; 3], illuminant: Illuminant) -> [f64; 3] {
+    let xyz = rgb2xyz(color);
+    match illuminant {
+        Illuminant::D65 => xyz,
+        Illuminant::D50 => d65_to_d50(&xyz),
+        _ => adapt_xyz(
+            &xyz,
+            Illuminant::D65,
+            illuminant,
+            AdaptationMethod::Bradford,
+        ),
+    }
 }
 
 /// Convert XYZ to RGB.
-pub fn xyz2rgb(color: &[f64]) -> Vec<f64> {
-    let color = xyz_to_lin_srgb(color);
-
-    lin_srgb(&color)
-        .iter()
-        .map(|&v| round(v * 255.0, 0))
-        .collect()
+pub fn xyz2rgb(color: &[f64; 3]) -> [f64; 3] {
+    gam_srgb(&xyz_to_lin_srgb(color)).map(|v| round(v * 255.0, 0))
 }
 
 #[cfg(test)]
@@ -27,38 +37,49 @@ mod tests {
     fn test_rgb2xyz() {
         assert_eq!(
             rgb2xyz(&[118.0, 84.0, 205.0]),
-            vec![0.21659503867453317, 0.1459993720802233, 0.5943650051071222]
+            [0.21659503867453317, 0.1459993720802233, 0.5943650051071222]
         );
 
         assert_eq!(
-            rgb2xyz(&vec![255.0, 255.0, 0.0]),
-            vec![0.7699751386498375, 0.9278076846392662, 0.13852559851021784]
+            rgb2xyz(&[255.0, 255.0, 0.0]),
+            [0.7699751386498375, 0.9278076846392662, 0.13852559851021784]
         );
 
         assert_eq!(
             rgb2xyz(&[255.0, 0.0, 0.0]),
-            vec![0.4123907992659595, 0.21263900587151036, 0.01933081871559185]
+            [0.4123907992659595, 0.21263900587151036, 0.01933081871559185]
         );
 
         assert_eq!(
             rgb2xyz(&[162.0, 184.0, 255.0]),
-            vec![0.5008777711244343, 0.49181501188347304, 1.0146489717861926]
+            [0.5008777711244343, 0.49181501188347304, 1.0146489717861926]
         );
     }
 
     #[test]
-    fn test_xyz2rgb() {
+    fn test_rgb2xyz_with_illuminant() {
         assert_eq!(
-            xyz2rgb(&[0.770033, 0.927831, 0.138527]),
-            vec![255.0, 255.0, 0.0]
+            rgb2xyz_with_illuminant(&[255.0, 0.0, 0.0], Illuminant::D65),
+            rgb2xyz(&[255.0, 0.0, 0.0])
         );
+        let white = rgb2xyz_with_illuminant(&[255.0, 255.0, 255.0], Illuminant::A);
+        let expected = Illuminant::A.white_point();
+        assert!(white
+            .iter()
+            .zip(expected)
+            .all(|(v1, v2)| (v1 - v2).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_xyz2rgb() {
         assert_eq!(
-            xyz2rgb(&[0.412453, 0.212671, 0.019334]),
-            vec![255.0, 0.0, 0.0]
+            xyz2rgb(&[0.770033, 0.927831, 0.138527]),
+            [255.0, 255.0, 0.0]
         );
+        assert_eq!(xyz2rgb(&[0.412453, 0.212671, 0.019334]), [255.0, 0.0, 0.0]);
         assert_eq!(
             xyz2rgb(&[0.70047, 0.723315, 1.048516]),
-            vec![92.0, 122.0, 255.0]
+            [209.0, 221.0, 255.0]
         );
     }
 }
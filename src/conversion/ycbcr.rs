@@ -1,32 +1,79 @@
+use super::ypbpr::{rgb2ypbpr, ypbpr2rgb, ColorMatrix};
 use crate::utils::*;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Quantization range of digital `YCbCr` values.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum YCbCrRange {
+    /// Full range, Y, Cb and Cr use the whole 0-255 range (JPEG).
+    #[default]
+    Full,
+    /// Limited range, Y uses 16-235 and Cb/Cr use 16-240 (broadcast video).
+    Limited,
+}
+
+impl YCbCrRange {
+    /// `[offset, luma scale, chroma scale]` of the range.
+    fn quantization(&self) -> [f64; 3] {
+        match self {
+            YCbCrRange::Full => [0.0, 255.0, 255.0],
+            YCbCrRange::Limited => [16.0, 219.0, 224.0],
+        }
+    }
+}
+
 static RGB2YCRCB_COEFFS: [f64; 5] = [0.299, 0.587, 0.114, 0.713, 0.564];
 static YCRCB2RGB_COEFFS: [f64; 4] = [1.403, -0.714, -0.344, 1.773];
 
 /// Convert `RGB` to `YCbCr`
 ///
 /// reference: [RGB2YCrCb](https://github.com/opencv/opencv_contrib/blob/master/modules/cudev/include/opencv2/cudev/functional/detail/color_cvt.hpp#L427)
-pub fn rgb2ycbcr(color: &[f64]) -> Vec<f64> {
+pub fn rgb2ycbcr(color: &[f64; 3]) -> [f64; 3] {
     let r = color[0];
     let g = color[1];
     let b = color[2];
     let y = RGB2YCRCB_COEFFS[0] * r + RGB2YCRCB_COEFFS[1] * g + RGB2YCRCB_COEFFS[2] * b;
     let cr = (r - y) * RGB2YCRCB_COEFFS[3] + 128.0;
     let cb = (b - y) * RGB2YCRCB_COEFFS[4] + 128.0;
-    vec![y, cb, cr]
+    [y, cb, cr]
 }
 
 /// Convert `YCbCr` to `RGB`
 ///
 /// reference: [YCrCb2RGB](https://github.com/opencv/opencv_contrib/blob/master/modules/cudev/include/opencv2/cudev/functional/detail/color_cvt.hpp#L481)
-pub fn ycbcr2rgb(color: &[f64]) -> Vec<f64> {
+pub fn ycbcr2rgb(color: &[f64; 3]) -> [f64; 3] {
     let y = color[0];
     let cb = color[1];
     let cr = color[2];
     let r = y + YCRCB2RGB_COEFFS[0] * (cr - 128.0);
     let g = y + YCRCB2RGB_COEFFS[2] * (cb - 128.0) + YCRCB2RGB_COEFFS[1] * (cr - 128.0);
     let b = y + YCRCB2RGB_COEFFS[3] * (cb - 128.0);
-    vec![round(r, 0), round(g, 0), round(b, 0)]
+    [round(r, 0), round(g, 0), round(b, 0)]
+}
+
+/// Convert `RGB` to `YCbCr` with the given luma coefficients and quantization range
+///
+/// reference: [YCbCr](https://en.wikipedia.org/wiki/YCbCr#R'G'B'_to_Y%E2%80%B2PbPr)
+pub fn rgb2ycbcr_with(color: &[f64; 3], matrix: ColorMatrix, range: YCbCrRange) -> [f64; 3] {
+    let [offset, luma, chroma] = range.quantization();
+    let ypbpr = rgb2ypbpr(color, matrix);
+    [
+        offset + luma * ypbpr[0],
+        128.0 + chroma * ypbpr[1],
+        128.0 + chroma * ypbpr[2],
+    ]
+}
+
+/// Convert `YCbCr` with the given luma coefficients and quantization range to `RGB`
+pub fn ycbcr2rgb_with(color: &[f64; 3], matrix: ColorMatrix, range: YCbCrRange) -> [f64; 3] {
+    let [offset, luma, chroma] = range.quantization();
+    let y = (color[0] - offset) / luma;
+    let pb = (color[1] - 128.0) / chroma;
+    let pr = (color[2] - 128.0) / chroma;
+    ypbpr2rgb(&[y, pb, pr], matrix)
 }
 
 #[cfg(test)]
@@ -37,11 +84,69 @@ mod tests {
     fn test_rgb2ycbcr() {
         assert_eq!(
             rgb2ycbcr(&[255.0, 255.0, 0.0]),
-            vec![225.93, 0.5754800000000131, 148.72691]
+            [225.93, 0.5754800000000131, 148.72691]
         );
         assert_eq!(
             rgb2ycbcr(&[255.0, 0.0, 0.0]),
-            vec![76.24499999999999, 84.99782000000002, 255.452315]
+            [76.24499999999999, 84.99782000000002, 255.452315]
+        );
+    }
+
+    #[test]
+    fn test_rgb2ycbcr_with() {
+        let round4_vec = |vec: [f64; 3]| vec.map(|v| round(v, 4));
+
+        assert_eq!(
+            round4_vec(rgb2ycbcr_with(
+                &[255.0, 255.0, 255.0],
+                ColorMatrix::BT709,
+                YCbCrRange::Limited
+            )),
+            [235.0, 128.0, 128.0]
+        );
+        assert_eq!(
+            round4_vec(rgb2ycbcr_with(
+                &[0.0, 0.0, 0.0],
+                ColorMatrix::BT2020,
+                YCbCrRange::Limited
+            )),
+            [16.0, 128.0, 128.0]
+        );
+        assert_eq!(
+            round4_vec(rgb2ycbcr_with(
+                &[255.0, 0.0, 0.0],
+                ColorMatrix::BT709,
+                YCbCrRange::Limited
+            )),
+            [62.5594, 102.3358, 240.0]
+        );
+        assert_eq!(
+            round4_vec(rgb2ycbcr_with(
+                &[0.0, 255.0, 0.0],
+                ColorMatrix::BT601,
+                YCbCrRange::Full
+            )),
+            [149.685, 43.5277, 21.2347]
+        );
+    }
+
+    #[test]
+    fn test_ycbcr2rgb_with() {
+        assert_eq!(
+            ycbcr2rgb_with(
+                &[62.5594, 102.3358, 240.0],
+                ColorMatrix::BT709,
+                YCbCrRange::Limited
+            ),
+            [255.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            ycbcr2rgb_with(
+                &[235.0, 128.0, 128.0],
+                ColorMatrix::BT2020,
+                YCbCrRange::Limited
+            ),
+            [255.0, 255.0, 255.0]
         );
     }
 
@@ -49,11 +154,11 @@ mod tests {
     fn test_ycbcr2rgb() {
         assert_eq!(
             ycbcr2rgb(&[225.93, 0.5754800000000131, 148.72691]),
-            vec![255.0, 255.0, 0.0]
+            [255.0, 255.0, 0.0]
         );
         assert_eq!(
             ycbcr2rgb(&[76.24499999999999, 84.99782000000002, 255.452315]),
-            vec![255.0, 0.0, 0.0]
+            [255.0, 0.0, 0.0]
         );
     }
 }
@@ -0,0 +1,100 @@
+use crate::utils::*;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Luma coefficients used by the `YPbPr` and `YCbCr` conversions.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColorMatrix {
+    /// [ITU-R BT.601](https://en.wikipedia.org/wiki/Rec._601), used by SD video.
+    #[default]
+    BT601,
+    /// [ITU-R BT.709](https://en.wikipedia.org/wiki/Rec._709), used by HD video.
+    BT709,
+    /// [ITU-R BT.2020](https://en.wikipedia.org/wiki/Rec._2020), used by UHD video.
+    BT2020,
+}
+
+impl ColorMatrix {
+    /// `[Kr, Kb]` coefficients of the matrix.
+    pub(crate) fn coeffs(&self) -> [f64; 2] {
+        match self {
+            ColorMatrix::BT601 => [0.299, 0.114],
+            ColorMatrix::BT709 => [0.2126, 0.0722],
+            ColorMatrix::BT2020 => [0.2627, 0.0593],
+        }
+    }
+}
+
+/// Convert `RGB` to `YPbPr`
+///
+/// reference: [YPbPr](https://en.wikipedia.org/wiki/YPbPr#Formal_derivation_from_Y%E2%80%B2UV)
+pub fn rgb2ypbpr(color: &[f64; 3], matrix: ColorMatrix) -> [f64; 3] {
+    let color = normalize_color(color);
+    let [kr, kb] = matrix.coeffs();
+    let r = color[0];
+    let g = color[1];
+    let b = color[2];
+    let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+    let pb = 0.5 * (b - y) / (1.0 - kb);
+    let pr = 0.5 * (r - y) / (1.0 - kr);
+    [y, pb, pr]
+}
+
+/// Convert `YPbPr` to `RGB`
+pub fn ypbpr2rgb(color: &[f64; 3], matrix: ColorMatrix) -> [f64; 3] {
+    let [kr, kb] = matrix.coeffs();
+    let y = color[0];
+    let pb = color[1];
+    let pr = color[2];
+    let r = y + 2.0 * (1.0 - kr) * pr;
+    let b = y + 2.0 * (1.0 - kb) * pb;
+    let g = (y - kr * r - kb * b) / (1.0 - kr - kb);
+    [
+        round(r * 255.0, 0),
+        round(g * 255.0, 0),
+        round(b * 255.0, 0),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round4_vec<const N: usize>(vec: [f64; N]) -> [f64; N] {
+        vec.map(|v| round(v, 4))
+    }
+
+    #[test]
+    fn test_rgb2ypbpr() {
+        assert_eq!(
+            round4_vec(rgb2ypbpr(&[255.0, 0.0, 0.0], ColorMatrix::BT601)),
+            [0.299, -0.1687, 0.5]
+        );
+        assert_eq!(
+            round4_vec(rgb2ypbpr(&[255.0, 0.0, 0.0], ColorMatrix::BT709)),
+            [0.2126, -0.1146, 0.5]
+        );
+        assert_eq!(
+            round4_vec(rgb2ypbpr(&[255.0, 255.0, 255.0], ColorMatrix::BT709)),
+            [1.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_ypbpr2rgb() {
+        assert_eq!(
+            ypbpr2rgb(&[0.299, -0.1687, 0.5], ColorMatrix::BT601),
+            [255.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            ypbpr2rgb(&[0.2126, -0.1146, 0.5], ColorMatrix::BT709),
+            [255.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            ypbpr2rgb(&[1.0, 0.0, 0.0], ColorMatrix::BT709),
+            [255.0, 255.0, 255.0]
+        );
+    }
+}
@@ -0,0 +1,32 @@
+//! Qualitative palettes for categorical data.
+
+/// The `tab10` palette of matplotlib and Tableau, also d3's `schemeCategory10`.
+pub(crate) const TAB10: [&str; 10] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f",
+    "#bcbd22", "#17becf",
+];
+
+/// The `tab20` palette of matplotlib, the `tab10` colors each followed by a lighter shade.
+pub(crate) const TAB20: [&str; 20] = [
+    "#1f77b4", "#aec7e8", "#ff7f0e", "#ffbb78", "#2ca02c", "#98df8a", "#d62728", "#ff9896",
+    "#9467bd", "#c5b0d5", "#8c564b", "#c49c94", "#e377c2", "#f7b6d2", "#7f7f7f", "#c7c7c7",
+    "#bcbd22", "#dbdb8d", "#17becf", "#9edae5",
+];
+
+/// The Tableau 10 palette introduced in 2016.
+pub(crate) const TABLEAU10: [&str; 10] = [
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+    "#9c755f", "#bab0ac",
+];
+
+/// The ColorBrewer `Set1` palette.
+pub(crate) const SET1: [&str; 9] = [
+    "#e41a1c", "#377eb8", "#4daf4a", "#984ea3", "#ff7f00", "#ffff33", "#a65628", "#f781bf",
+    "#999999",
+];
+
+/// The palette of [Okabe and Ito](https://jfly.uni-koeln.de/color/), safe for all common
+/// color vision deficiencies.
+pub(crate) const OKABE_ITO: [&str; 8] = [
+    "#000000", "#e69f00", "#56b4e9", "#009e73", "#f0e442", "#0072b2", "#d55e00", "#cc79a7",
+];
@@ -1,537 +1,1062 @@
-use std::collections::HashMap;
+use phf::phf_map;
 
-lazy_static! {
-    /// Chinese traditional colors.
-    ///
-    /// reference: <https://github.com/jufjzq/Chinese-Traditional-Colors/blob/master/src/assets/colors.json>
-    pub static ref CHINESE_COLOR_HASHMAP: HashMap<&'static str, &'static str> = {
-        HashMap::from([
-            ("乳白", "#f9f4dc"),
-            ("杏仁黄", "#f7e8aa"),
-            ("茉莉黄", "#f8df72"),
-            ("麦秆黄", "#f8df70"),
-            ("油菜花黄", "#fbda41"),
-            ("佛手黄", "#fed71a"),
-            ("篾黄", "#f7de98"),
-            ("葵扇黄", "#f8d86a"),
-            ("柠檬黄", "#fcd337"),
-            ("金瓜黄", "#fcd217"),
-            ("藤黄", "#ffd111"),
-            ("酪黄", "#f6dead"),
-            ("香水玫瑰黄", "#f7da94"),
-            ("淡密黄", "#f9d367"),
-            ("大豆黄", "#fbcd31"),
-            ("素馨黄", "#fccb16"),
-            ("向日葵黄", "#fecc11"),
-            ("雅梨黄", "#fbc82f"),
-            ("黄连黄", "#fcc515"),
-            ("金盏黄", "#fcc307"),
-            ("蛋壳黄", "#f8c387"),
-            ("肉色", "#f7c173"),
-            ("鹅掌黄", "#fbb929"),
-            ("鸡蛋黄", "#fbb612"),
-            ("鼬黄", "#fcb70a"),
-            ("榴萼黄", "#f9a633"),
-            ("淡橘橙", "#fba414"),
-            ("枇杷黄", "#fca106"),
-            ("橙皮黄", "#fca104"),
-            ("北瓜黄", "#fc8c23"),
-            ("杏黄", "#f28e16"),
-            ("雄黄", "#ff9900"),
-            ("万寿菊黄", "#fb8b05"),
-            ("菊蕾白", "#e9ddb6"),
-            ("秋葵黄", "#eed045"),
-            ("硫华黄", "#f2ce2b"),
-            ("柚黄", "#f1ca17"),
-            ("芒果黄", "#ddc871"),
-            ("蒿黄", "#dfc243"),
-            ("姜黄", "#e2c027"),
-            ("香蕉黄", "#e4bf11"),
-            ("草黄", "#d2b42c"),
-            ("新禾绿", "#d2b116"),
-            ("月灰", "#b7ae8f"),
-            ("淡灰绿", "#ad9e5f"),
-            ("草灰绿", "#8e804b"),
-            ("苔绿", "#887322"),
-            ("碧螺春绿", "#867018"),
-            ("燕羽灰", "#685e48"),
-            ("蟹壳灰", "#695e45"),
-            ("潭水绿", "#645822"),
-            ("橄榄绿", "#5e5314"),
-            ("蚌肉白", "#f9f1db"),
-            ("豆汁黄", "#f8e8c1"),
-            ("淡茧黄", "#f9d770"),
-            ("乳鸭黄", "#ffc90c"),
-            ("荔肉白", "#f2e6ce"),
-            ("象牙黄", "#f0d695"),
-            ("炒米黄", "#f4ce69"),
-            ("鹦鹉冠黄", "#f6c430"),
-            ("木瓜黄", "#f9c116"),
-            ("浅烙黄", "#f9bd10"),
-            ("莲子白", "#e5d3aa"),
-            ("谷黄", "#e8b004"),
-            ("栀子黄", "#ebb10d"),
-            ("芥黄", "#d9a40e"),
-            ("银鼠灰", "#b5aa90"),
-            ("尘灰", "#b6a476"),
-            ("枯绿", "#b78d12"),
-            ("鲛青", "#87723e"),
-            ("粽叶绿", "#876818"),
-            ("灰绿", "#8a6913"),
-            ("鹤灰", "#4a4035"),
-            ("淡松烟", "#4d4030"),
-            ("暗海水绿", "#584717"),
-            ("棕榈绿", "#5b4913"),
-            ("米色", "#f9e9cd"),
-            ("淡肉色", "#f8e0b0"),
-            ("麦芽糖黄", "#f9d27d"),
-            ("琥珀黄", "#feba07"),
-            ("甘草黄", "#f3bf4c"),
-            ("初熟杏黄", "#f8bc31"),
-            ("浅驼色", "#e2c17c"),
-            ("沙石黄", "#e5b751"),
-            ("虎皮黄", "#eaad1a"),
-            ("土黄", "#d6a01d"),
-            ("百灵鸟灰", "#b4a992"),
-            ("山鸡黄", "#b78b26"),
-            ("龟背黄", "#826b48"),
-            ("苍黄", "#806332"),
-            ("莱阳梨黄", "#815f25"),
-            ("蜴蜊绿", "#835e1d"),
-            ("松鼠灰", "#4f4032"),
-            ("橄榄灰", "#503e2a"),
-            ("蟹壳绿", "#513c20"),
-            ("古铜绿", "#533c1b"),
-            ("焦茶绿", "#553b18"),
-            ("粉白", "#fbf2e3"),
-            ("落英淡粉", "#f9e8d0"),
-            ("瓜瓤粉", "#f9cb8b"),
-            ("蜜黄", "#fbb957"),
-            ("金叶黄", "#ffa60f"),
-            ("金莺黄", "#f4a83a"),
-            ("鹿角棕", "#e3bd8d"),
-            ("凋叶棕", "#e7a23f"),
-            ("玳瑁黄", "#daa45a"),
-            ("软木黄", "#de9e44"),
-            ("风帆黄", "#dc9123"),
-            ("桂皮淡棕", "#c09351"),
-            ("猴毛灰", "#97846c"),
-            ("山鸡褐", "#986524"),
-            ("驼色", "#66462a"),
-            ("茶褐", "#5d3d21"),
-            ("古铜褐", "#5c3719"),
-            ("荷花白", "#fbecde"),
-            ("玫瑰粉", "#f8b37f"),
-            ("橘橙", "#f97d1c"),
-            ("美人焦橙", "#fa7e23"),
-            ("润红", "#f7cdbc"),
-            ("淡桃红", "#f6cec1"),
-            ("海螺橙", "#f0945d"),
-            ("桃红", "#f0ada0"),
-            ("颊红", "#eeaa9c"),
-            ("淡罂粟红", "#eea08c"),
-            ("晨曦红", "#ea8958"),
-            ("蟹壳红", "#f27635"),
-            ("金莲花橙", "#f86b1d"),
-            ("草莓红", "#ef6f48"),
-            ("龙睛鱼红", "#ef632b"),
-            ("蜻蜓红", "#f1441d"),
-            ("大红", "#f04b22"),
-            ("柿红", "#f2481b"),
-            ("榴花红", "#f34718"),
-            ("银朱", "#f43e06"),
-            ("朱红", "#ed5126"),
-            ("鲑鱼红", "#f09c5a"),
-            ("金黄", "#f26b1f"),
-            ("鹿皮褐", "#d99156"),
-            ("醉瓜肉", "#db8540"),
-            ("麂棕", "#de7622"),
-            ("淡银灰", "#c1b2a3"),
-            ("淡赭", "#be7e4a"),
-            ("槟榔综", "#c1651a"),
-            ("银灰", "#918072"),
-            ("海鸥灰", "#9a8878"),
-            ("淡咖啡", "#945833"),
-            ("岩石棕", "#964d22"),
-            ("芒果棕", "#954416"),
-            ("石板灰", "#624941"),
-            ("珠母灰", "#64483d"),
-            ("丁香棕", "#71361d"),
-            ("咖啡", "#753117"),
-            ("筍皮棕", "#732e12"),
-            ("燕颔红", "#fc6315"),
-            ("玉粉红", "#e8b49a"),
-            ("金驼", "#e46828"),
-            ("铁棕", "#d85916"),
-            ("蛛网灰", "#b7a091"),
-            ("淡可可棕", "#b7511d"),
-            ("中红灰", "#8b614d"),
-            ("淡土黄", "#8c4b31"),
-            ("淡豆沙", "#873d24"),
-            ("椰壳棕", "#883a1e"),
-            ("淡铁灰", "#5b423a"),
-            ("中灰驼", "#603d30"),
-            ("淡栗棕", "#673424"),
-            ("可可棕", "#652b1c"),
-            ("柞叶棕", "#692a1b"),
-            ("野蔷薇红", "#fb9968"),
-            ("菠萝红", "#fc7930"),
-            ("藕荷", "#edc3ae"),
-            ("陶瓷红", "#e16723"),
-            ("晓灰", "#d4c4b7"),
-            ("余烬红", "#cf7543"),
-            ("火砖红", "#cd6227"),
-            ("火泥棕", "#aa6a4c"),
-            ("绀红", "#a6522c"),
-            ("橡树棕", "#773d31"),
-            ("海报灰", "#483332"),
-            ("玫瑰灰", "#4b2e2b"),
-            ("火山棕", "#482522"),
-            ("豆沙", "#481e1c"),
-            ("淡米粉", "#fbeee2"),
-            ("初桃粉红", "#f6dcce"),
-            ("介壳淡粉红", "#f7cfba"),
-            ("淡藏花红", "#f6ad8f"),
-            ("瓜瓤红", "#f68c60"),
-            ("芙蓉红", "#f9723d"),
-            ("莓酱红", "#fa5d19"),
-            ("法螺红", "#ee8055"),
-            ("落霞红", "#cf4813"),
-            ("淡玫瑰灰", "#b89485"),
-            ("蟹蝥红", "#b14b28"),
-            ("火岩棕", "#863020"),
-            ("赭石", "#862617"),
-            ("暗驼棕", "#592620"),
-            ("酱棕", "#5a1f1b"),
-            ("栗棕", "#5c1e19"),
-            ("洋水仙红", "#f4c7ba"),
-            ("谷鞘红", "#f17666"),
-            ("苹果红", "#f15642"),
-            ("铁水红", "#f5391c"),
-            ("桂红", "#f25a47"),
-            ("极光红", "#f33b1f"),
-            ("粉红", "#f2b9b2"),
-            ("舌红", "#f19790"),
-            ("曲红", "#f05a46"),
-            ("红汞红", "#f23e23"),
-            ("淡绯", "#f2cac9"),
-            ("无花果红", "#efafad"),
-            ("榴子红", "#f1908c"),
-            ("胭脂红", "#f03f24"),
-            ("合欢红", "#f0a1a8"),
-            ("春梅红", "#f1939c"),
-            ("香叶红", "#f07c82"),
-            ("珊瑚红", "#f04a3a"),
-            ("萝卜红", "#f13c22"),
-            ("淡茜红", "#e77c8e"),
-            ("艳红", "#ed5a65"),
-            ("淡菽红", "#ed4845"),
-            ("鱼鳃红", "#ed3b2f"),
-            ("樱桃红", "#ed3321"),
-            ("淡蕊香红", "#ee4866"),
-            ("石竹红", "#ee4863"),
-            ("草茉莉红", "#ef475d"),
-            ("茶花红", "#ee3f4d"),
-            ("枸枢红", "#ed3333"),
-            ("秋海棠红", "#ec2b24"),
-            ("丽春红", "#eb261a"),
-            ("夕阳红", "#de2a18"),
-            ("鹤顶红", "#d42517"),
-            ("鹅血石红", "#ab372f"),
-            ("覆盆子红", "#ac1f18"),
-            ("貂紫", "#5d3131"),
-            ("暗玉紫", "#5c2223"),
-            ("栗紫", "#5a191b"),
-            ("葡萄酱紫", "#5a1216"),
-            ("牡丹粉红", "#eea2a4"),
-            ("山茶红", "#ed556a"),
-            ("海棠红", "#f03752"),
-            ("玉红", "#c04851"),
-            ("高粱红", "#c02c38"),
-            ("满江红", "#a7535a"),
-            ("枣红", "#7c1823"),
-            ("葡萄紫", "#4c1f24"),
-            ("酱紫", "#4d1018"),
-            ("淡曙红", "#ee2746"),
-            ("唐菖蒲红", "#de1c31"),
-            ("鹅冠红", "#d11a2d"),
-            ("莓红", "#c45a65"),
-            ("枫叶红", "#c21f30"),
-            ("苋菜红", "#a61b29"),
-            ("烟红", "#894e54"),
-            ("暗紫苑红", "#82202b"),
-            ("殷红", "#82111f"),
-            ("猪肝紫", "#541e24"),
-            ("金鱼紫", "#500a16"),
-            ("草珠红", "#f8ebe6"),
-            ("淡绛红", "#ec7696"),
-            ("品红", "#ef3473"),
-            ("凤仙花红", "#ea7293"),
-            ("粉团花红", "#ec9bad"),
-            ("夹竹桃红", "#eb507e"),
-            ("榲桲红", "#ed2f6a"),
-            ("姜红", "#eeb8c3"),
-            ("莲瓣红", "#ea517f"),
-            ("水红", "#f1c4cd"),
-            ("报春红", "#ec8aa4"),
-            ("月季红", "#ce5777"),
-            ("豇豆红", "#ed9db2"),
-            ("霞光红", "#ef82a0"),
-            ("松叶牡丹红", "#eb3c70"),
-            ("喜蛋红", "#ec2c64"),
-            ("鼠鼻红", "#e3b4b8"),
-            ("尖晶玉红", "#cc163a"),
-            ("山黎豆红", "#c27c88"),
-            ("锦葵红", "#bf3553"),
-            ("鼠背灰", "#73575c"),
-            ("甘蔗紫", "#621624"),
-            ("石竹紫", "#63071c"),
-            ("苍蝇灰", "#36282b"),
-            ("卵石紫", "#30161c"),
-            ("李紫", "#2b1216"),
-            ("茄皮紫", "#2d0c13"),
-            ("吊钟花红", "#ce5e8a"),
-            ("兔眼红", "#ec4e8a"),
-            ("紫荆红", "#ee2c79"),
-            ("菜头紫", "#951c48"),
-            ("鹞冠紫", "#621d34"),
-            ("葡萄酒红", "#62102e"),
-            ("磨石紫", "#382129"),
-            ("檀紫", "#381924"),
-            ("火鹅紫", "#33141e"),
-            ("墨紫", "#310f1b"),
-            ("晶红", "#eea6b7"),
-            ("扁豆花红", "#ef498b"),
-            ("白芨红", "#de7897"),
-            ("嫩菱红", "#de3f7c"),
-            ("菠根红", "#d13c74"),
-            ("酢酱草红", "#c5708b"),
-            ("洋葱紫", "#a8456b"),
-            ("海象紫", "#4b1e2f"),
-            ("绀紫", "#461629"),
-            ("古铜紫", "#440e25"),
-            ("石蕊红", "#f0c9cf"),
-            ("芍药耕红", "#eba0b3"),
-            ("藏花红", "#ec2d7a"),
-            ("初荷红", "#e16c96"),
-            ("马鞭草紫", "#ede3e7"),
-            ("丁香淡紫", "#e9d7df"),
-            ("丹紫红", "#d2568c"),
-            ("玫瑰红", "#d2357d"),
-            ("淡牵牛紫", "#d1c2d3"),
-            ("凤信紫", "#c8adc4"),
-            ("萝兰紫", "#c08eaf"),
-            ("玫瑰紫", "#ba2f7b"),
-            ("藤萝紫", "#8076a3"),
-            ("槿紫", "#806d9e"),
-            ("蕈紫", "#815c94"),
-            ("桔梗紫", "#813c85"),
-            ("魏紫", "#7e1671"),
-            ("芝兰紫", "#e9ccd3"),
-            ("菱锰红", "#d276a3"),
-            ("龙须红", "#cc5595"),
-            ("蓟粉红", "#e6d2d5"),
-            ("电气石红", "#c35691"),
-            ("樱草紫", "#c06f98"),
-            ("芦穗灰", "#bdaead"),
-            ("隐红灰", "#b598a1"),
-            ("苋菜紫", "#9b1e64"),
-            ("芦灰", "#856d72"),
-            ("暮云灰", "#4f383e"),
-            ("斑鸠灰", "#482936"),
-            ("淡藤萝紫", "#f2e7e5"),
-            ("淡青紫", "#e0c8d1"),
-            ("青蛤壳紫", "#bc84a8"),
-            ("豆蔻紫", "#ad6598"),
-            ("扁豆紫", "#a35c8f"),
-            ("芥花紫", "#983680"),
-            ("青莲", "#8b2671"),
-            ("芓紫", "#894276"),
-            ("葛巾紫", "#7e2065"),
-            ("牵牛紫", "#681752"),
-            ("紫灰", "#5d3f51"),
-            ("龙睛鱼紫", "#4e2a40"),
-            ("荸荠紫", "#411c35"),
-            ("古鼎灰", "#36292f"),
-            ("乌梅紫", "#1e131d"),
-            ("深牵牛紫", "#1c0d1a"),
-            ("银白", "#f1f0ed"),
-            ("芡食白", "#e2e1e4"),
-            ("远山紫", "#ccccd6"),
-            ("淡蓝紫", "#a7a8bd"),
-            ("山梗紫", "#61649f"),
-            ("螺甸紫", "#74759b"),
-            ("玛瑙灰", "#cfccc9"),
-            ("野菊紫", "#525288"),
-            ("满天星紫", "#2e317c"),
-            ("锌灰", "#7a7374"),
-            ("野葡萄紫", "#302f4b"),
-            ("剑锋紫", "#3e3841"),
-            ("龙葵紫", "#322f3b"),
-            ("暗龙胆紫", "#22202e"),
-            ("晶石紫", "#1f2040"),
-            ("暗蓝紫", "#131124"),
-            ("景泰蓝", "#2775b6"),
-            ("尼罗蓝", "#2474b5"),
-            ("远天蓝", "#d0dfe6"),
-            ("星蓝", "#93b5cf"),
-            ("羽扇豆蓝", "#619ac3"),
-            ("花青", "#2376b7"),
-            ("睛蓝", "#5698c3"),
-            ("虹蓝", "#2177b8"),
-            ("湖水蓝", "#b0d5df"),
-            ("秋波蓝", "#8abcd1"),
-            ("涧石蓝", "#66a9c9"),
-            ("潮蓝", "#2983bb"),
-            ("群青", "#1772b4"),
-            ("霁青", "#63bbd0"),
-            ("碧青", "#5cb3cc"),
-            ("宝石蓝", "#2486b9"),
-            ("天蓝", "#1677b3"),
-            ("柏林蓝", "#126bae"),
-            ("海青", "#22a2c3"),
-            ("钴蓝", "#1a94bc"),
-            ("鸢尾蓝", "#158bb8"),
-            ("牵牛花蓝", "#1177b0"),
-            ("飞燕草蓝", "#0f59a4"),
-            ("品蓝", "#2b73af"),
-            ("银鱼白", "#cdd1d3"),
-            ("安安蓝", "#3170a7"),
-            ("鱼尾灰", "#5e616d"),
-            ("鲸鱼灰", "#475164"),
-            ("海参灰", "#fffefa"),
-            ("沙鱼灰", "#35333c"),
-            ("钢蓝", "#0f1423"),
-            ("云水蓝", "#baccd9"),
-            ("晴山蓝", "#8fb2c9"),
-            ("靛青", "#1661ab"),
-            ("大理石灰", "#c4cbcf"),
-            ("海涛蓝", "#15559a"),
-            ("蝶翅蓝", "#4e7ca1"),
-            ("海军蓝", "#346c9c"),
-            ("水牛灰", "#2f2f35"),
-            ("牛角灰", "#2d2e36"),
-            ("燕颔蓝", "#131824"),
-            ("云峰白", "#d8e3e7"),
-            ("井天蓝", "#c3d7df"),
-            ("云山蓝", "#2f90b9"),
-            ("釉蓝", "#1781b5"),
-            ("鸥蓝", "#c7d2d4"),
-            ("搪磁蓝", "#11659a"),
-            ("月影白", "#c0c4c3"),
-            ("星灰", "#b2bbbe"),
-            ("淡蓝灰", "#5e7987"),
-            ("鷃蓝", "#144a74"),
-            ("嫩灰", "#74787a"),
-            ("战舰灰", "#495c69"),
-            ("瓦罐灰", "#47484c"),
-            ("青灰", "#2b333e"),
-            ("鸽蓝", "#1c2938"),
-            ("钢青", "#142334"),
-            ("暗蓝", "#101f30"),
-            ("月白", "#eef7f2"),
-            ("海天蓝", "#c6e6e8"),
-            ("清水蓝", "#93d5dc"),
-            ("瀑布蓝", "#51c4d3"),
-            ("蔚蓝", "#29b7cb"),
-            ("孔雀蓝", "#0eb0c9"),
-            ("甸子蓝", "#10aec2"),
-            ("石绿", "#57c3c2"),
-            ("竹篁绿", "#b9dec9"),
-            ("粉绿", "#83cbac"),
-            ("美蝶绿", "#12aa9c"),
-            ("毛绿", "#66c18c"),
-            ("蔻梢绿", "#5dbe8a"),
-            ("麦苗绿", "#55bb8a"),
-            ("蛙绿", "#45b787"),
-            ("铜绿", "#2bae85"),
-            ("竹绿", "#1ba784"),
-            ("蓝绿", "#12a182"),
-            ("穹灰", "#c4d7d6"),
-            ("翠蓝", "#1e9eb3"),
-            ("胆矾蓝", "#0f95b0"),
-            ("樫鸟蓝", "#1491a8"),
-            ("闪蓝", "#7cabb1"),
-            ("冰山蓝", "#a4aca7"),
-            ("虾壳青", "#869d9d"),
-            ("晚波蓝", "#648e93"),
-            ("蜻蜓蓝", "#3b818c"),
-            ("玉鈫蓝", "#126e82"),
-            ("垩灰", "#737c7b"),
-            ("夏云灰", "#617172"),
-            ("苍蓝", "#134857"),
-            ("黄昏灰", "#474b4c"),
-            ("灰蓝", "#21373d"),
-            ("深灰蓝", "#132c33"),
-            ("玉簪绿", "#a4cab6"),
-            ("青矾绿", "#2c9678"),
-            ("草原远绿", "#9abeaf"),
-            ("梧枝绿", "#69a794"),
-            ("浪花绿", "#92b3a5"),
-            ("海王绿", "#248067"),
-            ("亚丁绿", "#428675"),
-            ("镍灰", "#9fa39a"),
-            ("明灰", "#8a988e"),
-            ("淡绿灰", "#70887d"),
-            ("飞泉绿", "#497568"),
-            ("狼烟灰", "#5d655f"),
-            ("绿灰", "#314a43"),
-            ("苍绿", "#223e36"),
-            ("深海绿", "#1a3b32"),
-            ("长石灰", "#363433"),
-            ("苷蓝绿", "#1f2623"),
-            ("莽丛绿", "#141e1b"),
-            ("淡翠绿", "#c6dfc8"),
-            ("明绿", "#9eccab"),
-            ("田园绿", "#68b88e"),
-            ("翠绿", "#20a162"),
-            ("淡绿", "#61ac85"),
-            ("葱绿", "#40a070"),
-            ("孔雀绿", "#229453"),
-            ("艾绿", "#cad3c3"),
-            ("蟾绿", "#3c9566"),
-            ("宫殿绿", "#20894d"),
-            ("松霜绿", "#83a78d"),
-            ("蛋白石绿", "#579572"),
-            ("薄荷绿", "#207f4c"),
-            ("瓦松绿", "#6e8b74"),
-            ("荷叶绿", "#1a6840"),
-            ("田螺绿", "#5e665b"),
-            ("白屈菜绿", "#485b4d"),
-            ("河豚灰", "#393733"),
-            ("蒽油绿", "#373834"),
-            ("槲寄生绿", "#2b312c"),
-            ("云杉绿", "#15231b"),
-            ("嫩菊绿", "#f0f5e5"),
-            ("艾背绿", "#dfecd5"),
-            ("嘉陵水绿", "#add5a2"),
-            ("玉髓绿", "#41b349"),
-            ("鲜绿", "#43b244"),
-            ("宝石绿", "#41ae3c"),
-            ("海沬绿", "#e2e7bf"),
-            ("姚黄", "#d0deaa"),
-            ("橄榄石绿", "#b2cf87"),
-            ("水绿", "#8cc269"),
-            ("芦苇绿", "#b7d07a"),
-            ("槐花黄绿", "#d2d97a"),
-            ("苹果绿", "#bacf65"),
-            ("芽绿", "#96c24e"),
-            ("蝶黄", "#e2d849"),
-            ("橄榄黄绿", "#bec936"),
-            ("鹦鹉绿", "#5bae23"),
-            ("油绿", "#253d24"),
-            ("象牙白", "#fffef8"),
-            ("汉白玉", "#f8f4ed"),
-            ("雪白", "#fffef9"),
-            ("鱼肚白", "#f7f4ed"),
-            ("珍珠灰", "#e4dfd7"),
-            ("浅灰", "#dad4cb"),
-            ("铅灰", "#bbb5ac"),
-            ("中灰", "#bbb5ac"),
-            ("瓦灰", "#867e76"),
-            ("夜灰", "#847c74"),
-            ("雁灰", "#80766e"),
-            ("深灰", "#81776e"),
-        ])
-    };
-}
+/// Chinese traditional colors.
+///
+/// reference: <https://github.com/jufjzq/Chinese-Traditional-Colors/blob/master/src/assets/colors.json>
+pub static CHINESE_COLOR: phf::Map<&'static str, &'static str> = phf_map! {
+    "乳白" => "#f9f4dc",
+    "杏仁黄" => "#f7e8aa",
+    "茉莉黄" => "#f8df72",
+    "麦秆黄" => "#f8df70",
+    "油菜花黄" => "#fbda41",
+    "佛手黄" => "#fed71a",
+    "篾黄" => "#f7de98",
+    "葵扇黄" => "#f8d86a",
+    "柠檬黄" => "#fcd337",
+    "金瓜黄" => "#fcd217",
+    "藤黄" => "#ffd111",
+    "酪黄" => "#f6dead",
+    "香水玫瑰黄" => "#f7da94",
+    "淡密黄" => "#f9d367",
+    "大豆黄" => "#fbcd31",
+    "素馨黄" => "#fccb16",
+    "向日葵黄" => "#fecc11",
+    "雅梨黄" => "#fbc82f",
+    "黄连黄" => "#fcc515",
+    "金盏黄" => "#fcc307",
+    "蛋壳黄" => "#f8c387",
+    "肉色" => "#f7c173",
+    "鹅掌黄" => "#fbb929",
+    "鸡蛋黄" => "#fbb612",
+    "鼬黄" => "#fcb70a",
+    "榴萼黄" => "#f9a633",
+    "淡橘橙" => "#fba414",
+    "枇杷黄" => "#fca106",
+    "橙皮黄" => "#fca104",
+    "北瓜黄" => "#fc8c23",
+    "杏黄" => "#f28e16",
+    "雄黄" => "#ff9900",
+    "万寿菊黄" => "#fb8b05",
+    "菊蕾白" => "#e9ddb6",
+    "秋葵黄" => "#eed045",
+    "硫华黄" => "#f2ce2b",
+    "柚黄" => "#f1ca17",
+    "芒果黄" => "#ddc871",
+    "蒿黄" => "#dfc243",
+    "姜黄" => "#e2c027",
+    "香蕉黄" => "#e4bf11",
+    "草黄" => "#d2b42c",
+    "新禾绿" => "#d2b116",
+    "月灰" => "#b7ae8f",
+    "淡灰绿" => "#ad9e5f",
+    "草灰绿" => "#8e804b",
+    "苔绿" => "#887322",
+    "碧螺春绿" => "#867018",
+    "燕羽灰" => "#685e48",
+    "蟹壳灰" => "#695e45",
+    "潭水绿" => "#645822",
+    "橄榄绿" => "#5e5314",
+    "蚌肉白" => "#f9f1db",
+    "豆汁黄" => "#f8e8c1",
+    "淡茧黄" => "#f9d770",
+    "乳鸭黄" => "#ffc90c",
+    "荔肉白" => "#f2e6ce",
+    "象牙黄" => "#f0d695",
+    "炒米黄" => "#f4ce69",
+    "鹦鹉冠黄" => "#f6c430",
+    "木瓜黄" => "#f9c116",
+    "浅烙黄" => "#f9bd10",
+    "莲子白" => "#e5d3aa",
+    "谷黄" => "#e8b004",
+    "栀子黄" => "#ebb10d",
+    "芥黄" => "#d9a40e",
+    "银鼠灰" => "#b5aa90",
+    "尘灰" => "#b6a476",
+    "枯绿" => "#b78d12",
+    "鲛青" => "#87723e",
+    "粽叶绿" => "#876818",
+    "灰绿" => "#8a6913",
+    "鹤灰" => "#4a4035",
+    "淡松烟" => "#4d4030",
+    "暗海水绿" => "#584717",
+    "棕榈绿" => "#5b4913",
+    "米色" => "#f9e9cd",
+    "淡肉色" => "#f8e0b0",
+    "麦芽糖黄" => "#f9d27d",
+    "琥珀黄" => "#feba07",
+    "甘草黄" => "#f3bf4c",
+    "初熟杏黄" => "#f8bc31",
+    "浅驼色" => "#e2c17c",
+    "沙石黄" => "#e5b751",
+    "虎皮黄" => "#eaad1a",
+    "土黄" => "#d6a01d",
+    "百灵鸟灰" => "#b4a992",
+    "山鸡黄" => "#b78b26",
+    "龟背黄" => "#826b48",
+    "苍黄" => "#806332",
+    "莱阳梨黄" => "#815f25",
+    "蜴蜊绿" => "#835e1d",
+    "松鼠灰" => "#4f4032",
+    "橄榄灰" => "#503e2a",
+    "蟹壳绿" => "#513c20",
+    "古铜绿" => "#533c1b",
+    "焦茶绿" => "#553b18",
+    "粉白" => "#fbf2e3",
+    "落英淡粉" => "#f9e8d0",
+    "瓜瓤粉" => "#f9cb8b",
+    "蜜黄" => "#fbb957",
+    "金叶黄" => "#ffa60f",
+    "金莺黄" => "#f4a83a",
+    "鹿角棕" => "#e3bd8d",
+    "凋叶棕" => "#e7a23f",
+    "玳瑁黄" => "#daa45a",
+    "软木黄" => "#de9e44",
+    "风帆黄" => "#dc9123",
+    "桂皮淡棕" => "#c09351",
+    "猴毛灰" => "#97846c",
+    "山鸡褐" => "#986524",
+    "驼色" => "#66462a",
+    "茶褐" => "#5d3d21",
+    "古铜褐" => "#5c3719",
+    "荷花白" => "#fbecde",
+    "玫瑰粉" => "#f8b37f",
+    "橘橙" => "#f97d1c",
+    "美人焦橙" => "#fa7e23",
+    "润红" => "#f7cdbc",
+    "淡桃红" => "#f6cec1",
+    "海螺橙" => "#f0945d",
+    "桃红" => "#f0ada0",
+    "颊红" => "#eeaa9c",
+    "淡罂粟红" => "#eea08c",
+    "晨曦红" => "#ea8958",
+    "蟹壳红" => "#f27635",
+    "金莲花橙" => "#f86b1d",
+    "草莓红" => "#ef6f48",
+    "龙睛鱼红" => "#ef632b",
+    "蜻蜓红" => "#f1441d",
+    "大红" => "#f04b22",
+    "柿红" => "#f2481b",
+    "榴花红" => "#f34718",
+    "银朱" => "#f43e06",
+    "朱红" => "#ed5126",
+    "鲑鱼红" => "#f09c5a",
+    "金黄" => "#f26b1f",
+    "鹿皮褐" => "#d99156",
+    "醉瓜肉" => "#db8540",
+    "麂棕" => "#de7622",
+    "淡银灰" => "#c1b2a3",
+    "淡赭" => "#be7e4a",
+    "槟榔综" => "#c1651a",
+    "银灰" => "#918072",
+    "海鸥灰" => "#9a8878",
+    "淡咖啡" => "#945833",
+    "岩石棕" => "#964d22",
+    "芒果棕" => "#954416",
+    "石板灰" => "#624941",
+    "珠母灰" => "#64483d",
+    "丁香棕" => "#71361d",
+    "咖啡" => "#753117",
+    "筍皮棕" => "#732e12",
+    "燕颔红" => "#fc6315",
+    "玉粉红" => "#e8b49a",
+    "金驼" => "#e46828",
+    "铁棕" => "#d85916",
+    "蛛网灰" => "#b7a091",
+    "淡可可棕" => "#b7511d",
+    "中红灰" => "#8b614d",
+    "淡土黄" => "#8c4b31",
+    "淡豆沙" => "#873d24",
+    "椰壳棕" => "#883a1e",
+    "淡铁灰" => "#5b423a",
+    "中灰驼" => "#603d30",
+    "淡栗棕" => "#673424",
+    "可可棕" => "#652b1c",
+    "柞叶棕" => "#692a1b",
+    "野蔷薇红" => "#fb9968",
+    "菠萝红" => "#fc7930",
+    "藕荷" => "#edc3ae",
+    "陶瓷红" => "#e16723",
+    "晓灰" => "#d4c4b7",
+    "余烬红" => "#cf7543",
+    "火砖红" => "#cd6227",
+    "火泥棕" => "#aa6a4c",
+    "绀红" => "#a6522c",
+    "橡树棕" => "#773d31",
+    "海报灰" => "#483332",
+    "玫瑰灰" => "#4b2e2b",
+    "火山棕" => "#482522",
+    "豆沙" => "#481e1c",
+    "淡米粉" => "#fbeee2",
+    "初桃粉红" => "#f6dcce",
+    "介壳淡粉红" => "#f7cfba",
+    "淡藏花红" => "#f6ad8f",
+    "瓜瓤红" => "#f68c60",
+    "芙蓉红" => "#f9723d",
+    "莓酱红" => "#fa5d19",
+    "法螺红" => "#ee8055",
+    "落霞红" => "#cf4813",
+    "淡玫瑰灰" => "#b89485",
+    "蟹蝥红" => "#b14b28",
+    "火岩棕" => "#863020",
+    "赭石" => "#862617",
+    "暗驼棕" => "#592620",
+    "酱棕" => "#5a1f1b",
+    "栗棕" => "#5c1e19",
+    "洋水仙红" => "#f4c7ba",
+    "谷鞘红" => "#f17666",
+    "苹果红" => "#f15642",
+    "铁水红" => "#f5391c",
+    "桂红" => "#f25a47",
+    "极光红" => "#f33b1f",
+    "粉红" => "#f2b9b2",
+    "舌红" => "#f19790",
+    "曲红" => "#f05a46",
+    "红汞红" => "#f23e23",
+    "淡绯" => "#f2cac9",
+    "无花果红" => "#efafad",
+    "榴子红" => "#f1908c",
+    "胭脂红" => "#f03f24",
+    "合欢红" => "#f0a1a8",
+    "春梅红" => "#f1939c",
+    "香叶红" => "#f07c82",
+    "珊瑚红" => "#f04a3a",
+    "萝卜红" => "#f13c22",
+    "淡茜红" => "#e77c8e",
+    "艳红" => "#ed5a65",
+    "淡菽红" => "#ed4845",
+    "鱼鳃红" => "#ed3b2f",
+    "樱桃红" => "#ed3321",
+    "淡蕊香红" => "#ee4866",
+    "石竹红" => "#ee4863",
+    "草茉莉红" => "#ef475d",
+    "茶花红" => "#ee3f4d",
+    "枸枢红" => "#ed3333",
+    "秋海棠红" => "#ec2b24",
+    "丽春红" => "#eb261a",
+    "夕阳红" => "#de2a18",
+    "鹤顶红" => "#d42517",
+    "鹅血石红" => "#ab372f",
+    "覆盆子红" => "#ac1f18",
+    "貂紫" => "#5d3131",
+    "暗玉紫" => "#5c2223",
+    "栗紫" => "#5a191b",
+    "葡萄酱紫" => "#5a1216",
+    "牡丹粉红" => "#eea2a4",
+    "山茶红" => "#ed556a",
+    "海棠红" => "#f03752",
+    "玉红" => "#c04851",
+    "高粱红" => "#c02c38",
+    "满江红" => "#a7535a",
+    "枣红" => "#7c1823",
+    "葡萄紫" => "#4c1f24",
+    "酱紫" => "#4d1018",
+    "淡曙红" => "#ee2746",
+    "唐菖蒲红" => "#de1c31",
+    "鹅冠红" => "#d11a2d",
+    "莓红" => "#c45a65",
+    "枫叶红" => "#c21f30",
+    "苋菜红" => "#a61b29",
+    "烟红" => "#894e54",
+    "暗紫苑红" => "#82202b",
+    "殷红" => "#82111f",
+    "猪肝紫" => "#541e24",
+    "金鱼紫" => "#500a16",
+    "草珠红" => "#f8ebe6",
+    "淡绛红" => "#ec7696",
+    "品红" => "#ef3473",
+    "凤仙花红" => "#ea7293",
+    "粉团花红" => "#ec9bad",
+    "夹竹桃红" => "#eb507e",
+    "榲桲红" => "#ed2f6a",
+    "姜红" => "#eeb8c3",
+    "莲瓣红" => "#ea517f",
+    "水红" => "#f1c4cd",
+    "报春红" => "#ec8aa4",
+    "月季红" => "#ce5777",
+    "豇豆红" => "#ed9db2",
+    "霞光红" => "#ef82a0",
+    "松叶牡丹红" => "#eb3c70",
+    "喜蛋红" => "#ec2c64",
+    "鼠鼻红" => "#e3b4b8",
+    "尖晶玉红" => "#cc163a",
+    "山黎豆红" => "#c27c88",
+    "锦葵红" => "#bf3553",
+    "鼠背灰" => "#73575c",
+    "甘蔗紫" => "#621624",
+    "石竹紫" => "#63071c",
+    "苍蝇灰" => "#36282b",
+    "卵石紫" => "#30161c",
+    "李紫" => "#2b1216",
+    "茄皮紫" => "#2d0c13",
+    "吊钟花红" => "#ce5e8a",
+    "兔眼红" => "#ec4e8a",
+    "紫荆红" => "#ee2c79",
+    "菜头紫" => "#951c48",
+    "鹞冠紫" => "#621d34",
+    "葡萄酒红" => "#62102e",
+    "磨石紫" => "#382129",
+    "檀紫" => "#381924",
+    "火鹅紫" => "#33141e",
+    "墨紫" => "#310f1b",
+    "晶红" => "#eea6b7",
+    "扁豆花红" => "#ef498b",
+    "白芨红" => "#de7897",
+    "嫩菱红" => "#de3f7c",
+    "菠根红" => "#d13c74",
+    "酢酱草红" => "#c5708b",
+    "洋葱紫" => "#a8456b",
+    "海象紫" => "#4b1e2f",
+    "绀紫" => "#461629",
+    "古铜紫" => "#440e25",
+    "石蕊红" => "#f0c9cf",
+    "芍药耕红" => "#eba0b3",
+    "藏花红" => "#ec2d7a",
+    "初荷红" => "#e16c96",
+    "马鞭草紫" => "#ede3e7",
+    "丁香淡紫" => "#e9d7df",
+    "丹紫红" => "#d2568c",
+    "玫瑰红" => "#d2357d",
+    "淡牵牛紫" => "#d1c2d3",
+    "凤信紫" => "#c8adc4",
+    "萝兰紫" => "#c08eaf",
+    "玫瑰紫" => "#ba2f7b",
+    "藤萝紫" => "#8076a3",
+    "槿紫" => "#806d9e",
+    "蕈紫" => "#815c94",
+    "桔梗紫" => "#813c85",
+    "魏紫" => "#7e1671",
+    "芝兰紫" => "#e9ccd3",
+    "菱锰红" => "#d276a3",
+    "龙须红" => "#cc5595",
+    "蓟粉红" => "#e6d2d5",
+    "电气石红" => "#c35691",
+    "樱草紫" => "#c06f98",
+    "芦穗灰" => "#bdaead",
+    "隐红灰" => "#b598a1",
+    "苋菜紫" => "#9b1e64",
+    "芦灰" => "#856d72",
+    "暮云灰" => "#4f383e",
+    "斑鸠灰" => "#482936",
+    "淡藤萝紫" => "#f2e7e5",
+    "淡青紫" => "#e0c8d1",
+    "青蛤壳紫" => "#bc84a8",
+    "豆蔻紫" => "#ad6598",
+    "扁豆紫" => "#a35c8f",
+    "芥花紫" => "#983680",
+    "青莲" => "#8b2671",
+    "芓紫" => "#894276",
+    "葛巾紫" => "#7e2065",
+    "牵牛紫" => "#681752",
+    "紫灰" => "#5d3f51",
+    "龙睛鱼紫" => "#4e2a40",
+    "荸荠紫" => "#411c35",
+    "古鼎灰" => "#36292f",
+    "乌梅紫" => "#1e131d",
+    "深牵牛紫" => "#1c0d1a",
+    "银白" => "#f1f0ed",
+    "芡食白" => "#e2e1e4",
+    "远山紫" => "#ccccd6",
+    "淡蓝紫" => "#a7a8bd",
+    "山梗紫" => "#61649f",
+    "螺甸紫" => "#74759b",
+    "玛瑙灰" => "#cfccc9",
+    "野菊紫" => "#525288",
+    "满天星紫" => "#2e317c",
+    "锌灰" => "#7a7374",
+    "野葡萄紫" => "#302f4b",
+    "剑锋紫" => "#3e3841",
+    "龙葵紫" => "#322f3b",
+    "暗龙胆紫" => "#22202e",
+    "晶石紫" => "#1f2040",
+    "暗蓝紫" => "#131124",
+    "景泰蓝" => "#2775b6",
+    "尼罗蓝" => "#2474b5",
+    "远天蓝" => "#d0dfe6",
+    "星蓝" => "#93b5cf",
+    "羽扇豆蓝" => "#619ac3",
+    "花青" => "#2376b7",
+    "睛蓝" => "#5698c3",
+    "虹蓝" => "#2177b8",
+    "湖水蓝" => "#b0d5df",
+    "秋波蓝" => "#8abcd1",
+    "涧石蓝" => "#66a9c9",
+    "潮蓝" => "#2983bb",
+    "群青" => "#1772b4",
+    "霁青" => "#63bbd0",
+    "碧青" => "#5cb3cc",
+    "宝石蓝" => "#2486b9",
+    "天蓝" => "#1677b3",
+    "柏林蓝" => "#126bae",
+    "海青" => "#22a2c3",
+    "钴蓝" => "#1a94bc",
+    "鸢尾蓝" => "#158bb8",
+    "牵牛花蓝" => "#1177b0",
+    "飞燕草蓝" => "#0f59a4",
+    "品蓝" => "#2b73af",
+    "银鱼白" => "#cdd1d3",
+    "安安蓝" => "#3170a7",
+    "鱼尾灰" => "#5e616d",
+    "鲸鱼灰" => "#475164",
+    "海参灰" => "#fffefa",
+    "沙鱼灰" => "#35333c",
+    "钢蓝" => "#0f1423",
+    "云水蓝" => "#baccd9",
+    "晴山蓝" => "#8fb2c9",
+    "靛青" => "#1661ab",
+    "大理石灰" => "#c4cbcf",
+    "海涛蓝" => "#15559a",
+    "蝶翅蓝" => "#4e7ca1",
+    "海军蓝" => "#346c9c",
+    "水牛灰" => "#2f2f35",
+    "牛角灰" => "#2d2e36",
+    "燕颔蓝" => "#131824",
+    "云峰白" => "#d8e3e7",
+    "井天蓝" => "#c3d7df",
+    "云山蓝" => "#2f90b9",
+    "釉蓝" => "#1781b5",
+    "鸥蓝" => "#c7d2d4",
+    "搪磁蓝" => "#11659a",
+    "月影白" => "#c0c4c3",
+    "星灰" => "#b2bbbe",
+    "淡蓝灰" => "#5e7987",
+    "鷃蓝" => "#144a74",
+    "嫩灰" => "#74787a",
+    "战舰灰" => "#495c69",
+    "瓦罐灰" => "#47484c",
+    "青灰" => "#2b333e",
+    "鸽蓝" => "#1c2938",
+    "钢青" => "#142334",
+    "暗蓝" => "#101f30",
+    "月白" => "#eef7f2",
+    "海天蓝" => "#c6e6e8",
+    "清水蓝" => "#93d5dc",
+    "瀑布蓝" => "#51c4d3",
+    "蔚蓝" => "#29b7cb",
+    "孔雀蓝" => "#0eb0c9",
+    "甸子蓝" => "#10aec2",
+    "石绿" => "#57c3c2",
+    "竹篁绿" => "#b9dec9",
+    "粉绿" => "#83cbac",
+    "美蝶绿" => "#12aa9c",
+    "毛绿" => "#66c18c",
+    "蔻梢绿" => "#5dbe8a",
+    "麦苗绿" => "#55bb8a",
+    "蛙绿" => "#45b787",
+    "铜绿" => "#2bae85",
+    "竹绿" => "#1ba784",
+    "蓝绿" => "#12a182",
+    "穹灰" => "#c4d7d6",
+    "翠蓝" => "#1e9eb3",
+    "胆矾蓝" => "#0f95b0",
+    "樫鸟蓝" => "#1491a8",
+    "闪蓝" => "#7cabb1",
+    "冰山蓝" => "#a4aca7",
+    "虾壳青" => "#869d9d",
+    "晚波蓝" => "#648e93",
+    "蜻蜓蓝" => "#3b818c",
+    "玉鈫蓝" => "#126e82",
+    "垩灰" => "#737c7b",
+    "夏云灰" => "#617172",
+    "苍蓝" => "#134857",
+    "黄昏灰" => "#474b4c",
+    "灰蓝" => "#21373d",
+    "深灰蓝" => "#132c33",
+    "玉簪绿" => "#a4cab6",
+    "青矾绿" => "#2c9678",
+    "草原远绿" => "#9abeaf",
+    "梧枝绿" => "#69a794",
+    "浪花绿" => "#92b3a5",
+    "海王绿" => "#248067",
+    "亚丁绿" => "#428675",
+    "镍灰" => "#9fa39a",
+    "明灰" => "#8a988e",
+    "淡绿灰" => "#70887d",
+    "飞泉绿" => "#497568",
+    "狼烟灰" => "#5d655f",
+    "绿灰" => "#314a43",
+    "苍绿" => "#223e36",
+    "深海绿" => "#1a3b32",
+    "长石灰" => "#363433",
+    "苷蓝绿" => "#1f2623",
+    "莽丛绿" => "#141e1b",
+    "淡翠绿" => "#c6dfc8",
+    "明绿" => "#9eccab",
+    "田园绿" => "#68b88e",
+    "翠绿" => "#20a162",
+    "淡绿" => "#61ac85",
+    "葱绿" => "#40a070",
+    "孔雀绿" => "#229453",
+    "艾绿" => "#cad3c3",
+    "蟾绿" => "#3c9566",
+    "宫殿绿" => "#20894d",
+    "松霜绿" => "#83a78d",
+    "蛋白石绿" => "#579572",
+    "薄荷绿" => "#207f4c",
+    "瓦松绿" => "#6e8b74",
+    "荷叶绿" => "#1a6840",
+    "田螺绿" => "#5e665b",
+    "白屈菜绿" => "#485b4d",
+    "河豚灰" => "#393733",
+    "蒽油绿" => "#373834",
+    "槲寄生绿" => "#2b312c",
+    "云杉绿" => "#15231b",
+    "嫩菊绿" => "#f0f5e5",
+    "艾背绿" => "#dfecd5",
+    "嘉陵水绿" => "#add5a2",
+    "玉髓绿" => "#41b349",
+    "鲜绿" => "#43b244",
+    "宝石绿" => "#41ae3c",
+    "海沬绿" => "#e2e7bf",
+    "姚黄" => "#d0deaa",
+    "橄榄石绿" => "#b2cf87",
+    "水绿" => "#8cc269",
+    "芦苇绿" => "#b7d07a",
+    "槐花黄绿" => "#d2d97a",
+    "苹果绿" => "#bacf65",
+    "芽绿" => "#96c24e",
+    "蝶黄" => "#e2d849",
+    "橄榄黄绿" => "#bec936",
+    "鹦鹉绿" => "#5bae23",
+    "油绿" => "#253d24",
+    "象牙白" => "#fffef8",
+    "汉白玉" => "#f8f4ed",
+    "雪白" => "#fffef9",
+    "鱼肚白" => "#f7f4ed",
+    "珍珠灰" => "#e4dfd7",
+    "浅灰" => "#dad4cb",
+    "铅灰" => "#bbb5ac",
+    "中灰" => "#bbb5ac",
+    "瓦灰" => "#867e76",
+    "夜灰" => "#847c74",
+    "雁灰" => "#80766e",
+    "深灰" => "#81776e",
+};
+
+/// The reverse of [`CHINESE_COLOR`], a hex string to the first name listed for it.
+pub(crate) static CHINESE_COLOR_NAMES: phf::Map<&'static str, &'static str> = phf_map! {
+    "#f9f4dc" => "乳白",
+    "#f7e8aa" => "杏仁黄",
+    "#f8df72" => "茉莉黄",
+    "#f8df70" => "麦秆黄",
+    "#fbda41" => "油菜花黄",
+    "#fed71a" => "佛手黄",
+    "#f7de98" => "篾黄",
+    "#f8d86a" => "葵扇黄",
+    "#fcd337" => "柠檬黄",
+    "#fcd217" => "金瓜黄",
+    "#ffd111" => "藤黄",
+    "#f6dead" => "酪黄",
+    "#f7da94" => "香水玫瑰黄",
+    "#f9d367" => "淡密黄",
+    "#fbcd31" => "大豆黄",
+    "#fccb16" => "素馨黄",
+    "#fecc11" => "向日葵黄",
+    "#fbc82f" => "雅梨黄",
+    "#fcc515" => "黄连黄",
+    "#fcc307" => "金盏黄",
+    "#f8c387" => "蛋壳黄",
+    "#f7c173" => "肉色",
+    "#fbb929" => "鹅掌黄",
+    "#fbb612" => "鸡蛋黄",
+    "#fcb70a" => "鼬黄",
+    "#f9a633" => "榴萼黄",
+    "#fba414" => "淡橘橙",
+    "#fca106" => "枇杷黄",
+    "#fca104" => "橙皮黄",
+    "#fc8c23" => "北瓜黄",
+    "#f28e16" => "杏黄",
+    "#ff9900" => "雄黄",
+    "#fb8b05" => "万寿菊黄",
+    "#e9ddb6" => "菊蕾白",
+    "#eed045" => "秋葵黄",
+    "#f2ce2b" => "硫华黄",
+    "#f1ca17" => "柚黄",
+    "#ddc871" => "芒果黄",
+    "#dfc243" => "蒿黄",
+    "#e2c027" => "姜黄",
+    "#e4bf11" => "香蕉黄",
+    "#d2b42c" => "草黄",
+    "#d2b116" => "新禾绿",
+    "#b7ae8f" => "月灰",
+    "#ad9e5f" => "淡灰绿",
+    "#8e804b" => "草灰绿",
+    "#887322" => "苔绿",
+    "#867018" => "碧螺春绿",
+    "#685e48" => "燕羽灰",
+    "#695e45" => "蟹壳灰",
+    "#645822" => "潭水绿",
+    "#5e5314" => "橄榄绿",
+    "#f9f1db" => "蚌肉白",
+    "#f8e8c1" => "豆汁黄",
+    "#f9d770" => "淡茧黄",
+    "#ffc90c" => "乳鸭黄",
+    "#f2e6ce" => "荔肉白",
+    "#f0d695" => "象牙黄",
+    "#f4ce69" => "炒米黄",
+    "#f6c430" => "鹦鹉冠黄",
+    "#f9c116" => "木瓜黄",
+    "#f9bd10" => "浅烙黄",
+    "#e5d3aa" => "莲子白",
+    "#e8b004" => "谷黄",
+    "#ebb10d" => "栀子黄",
+    "#d9a40e" => "芥黄",
+    "#b5aa90" => "银鼠灰",
+    "#b6a476" => "尘灰",
+    "#b78d12" => "枯绿",
+    "#87723e" => "鲛青",
+    "#876818" => "粽叶绿",
+    "#8a6913" => "灰绿",
+    "#4a4035" => "鹤灰",
+    "#4d4030" => "淡松烟",
+    "#584717" => "暗海水绿",
+    "#5b4913" => "棕榈绿",
+    "#f9e9cd" => "米色",
+    "#f8e0b0" => "淡肉色",
+    "#f9d27d" => "麦芽糖黄",
+    "#feba07" => "琥珀黄",
+    "#f3bf4c" => "甘草黄",
+    "#f8bc31" => "初熟杏黄",
+    "#e2c17c" => "浅驼色",
+    "#e5b751" => "沙石黄",
+    "#eaad1a" => "虎皮黄",
+    "#d6a01d" => "土黄",
+    "#b4a992" => "百灵鸟灰",
+    "#b78b26" => "山鸡黄",
+    "#826b48" => "龟背黄",
+    "#806332" => "苍黄",
+    "#815f25" => "莱阳梨黄",
+    "#835e1d" => "蜴蜊绿",
+    "#4f4032" => "松鼠灰",
+    "#503e2a" => "橄榄灰",
+    "#513c20" => "蟹壳绿",
+    "#533c1b" => "古铜绿",
+    "#553b18" => "焦茶绿",
+    "#fbf2e3" => "粉白",
+    "#f9e8d0" => "落英淡粉",
+    "#f9cb8b" => "瓜瓤粉",
+    "#fbb957" => "蜜黄",
+    "#ffa60f" => "金叶黄",
+    "#f4a83a" => "金莺黄",
+    "#e3bd8d" => "鹿角棕",
+    "#e7a23f" => "凋叶棕",
+    "#daa45a" => "玳瑁黄",
+    "#de9e44" => "软木黄",
+    "#dc9123" => "风帆黄",
+    "#c09351" => "桂皮淡棕",
+    "#97846c" => "猴毛灰",
+    "#986524" => "山鸡褐",
+    "#66462a" => "驼色",
+    "#5d3d21" => "茶褐",
+    "#5c3719" => "古铜褐",
+    "#fbecde" => "荷花白",
+    "#f8b37f" => "玫瑰粉",
+    "#f97d1c" => "橘橙",
+    "#fa7e23" => "美人焦橙",
+    "#f7cdbc" => "润红",
+    "#f6cec1" => "淡桃红",
+    "#f0945d" => "海螺橙",
+    "#f0ada0" => "桃红",
+    "#eeaa9c" => "颊红",
+    "#eea08c" => "淡罂粟红",
+    "#ea8958" => "晨曦红",
+    "#f27635" => "蟹壳红",
+    "#f86b1d" => "金莲花橙",
+    "#ef6f48" => "草莓红",
+    "#ef632b" => "龙睛鱼红",
+    "#f1441d" => "蜻蜓红",
+    "#f04b22" => "大红",
+    "#f2481b" => "柿红",
+    "#f34718" => "榴花红",
+    "#f43e06" => "银朱",
+    "#ed5126" => "朱红",
+    "#f09c5a" => "鲑鱼红",
+    "#f26b1f" => "金黄",
+    "#d99156" => "鹿皮褐",
+    "#db8540" => "醉瓜肉",
+    "#de7622" => "麂棕",
+    "#c1b2a3" => "淡银灰",
+    "#be7e4a" => "淡赭",
+    "#c1651a" => "槟榔综",
+    "#918072" => "银灰",
+    "#9a8878" => "海鸥灰",
+    "#945833" => "淡咖啡",
+    "#964d22" => "岩石棕",
+    "#954416" => "芒果棕",
+    "#624941" => "石板灰",
+    "#64483d" => "珠母灰",
+    "#71361d" => "丁香棕",
+    "#753117" => "咖啡",
+    "#732e12" => "筍皮棕",
+    "#fc6315" => "燕颔红",
+    "#e8b49a" => "玉粉红",
+    "#e46828" => "金驼",
+    "#d85916" => "铁棕",
+    "#b7a091" => "蛛网灰",
+    "#b7511d" => "淡可可棕",
+    "#8b614d" => "中红灰",
+    "#8c4b31" => "淡土黄",
+    "#873d24" => "淡豆沙",
+    "#883a1e" => "椰壳棕",
+    "#5b423a" => "淡铁灰",
+    "#603d30" => "中灰驼",
+    "#673424" => "淡栗棕",
+    "#652b1c" => "可可棕",
+    "#692a1b" => "柞叶棕",
+    "#fb9968" => "野蔷薇红",
+    "#fc7930" => "菠萝红",
+    "#edc3ae" => "藕荷",
+    "#e16723" => "陶瓷红",
+    "#d4c4b7" => "晓灰",
+    "#cf7543" => "余烬红",
+    "#cd6227" => "火砖红",
+    "#aa6a4c" => "火泥棕",
+    "#a6522c" => "绀红",
+    "#773d31" => "橡树棕",
+    "#483332" => "海报灰",
+    "#4b2e2b" => "玫瑰灰",
+    "#482522" => "火山棕",
+    "#481e1c" => "豆沙",
+    "#fbeee2" => "淡米粉",
+    "#f6dcce" => "初桃粉红",
+    "#f7cfba" => "介壳淡粉红",
+    "#f6ad8f" => "淡藏花红",
+    "#f68c60" => "瓜瓤红",
+    "#f9723d" => "芙蓉红",
+    "#fa5d19" => "莓酱红",
+    "#ee8055" => "法螺红",
+    "#cf4813" => "落霞红",
+    "#b89485" => "淡玫瑰灰",
+    "#b14b28" => "蟹蝥红",
+    "#863020" => "火岩棕",
+    "#862617" => "赭石",
+    "#592620" => "暗驼棕",
+    "#5a1f1b" => "酱棕",
+    "#5c1e19" => "栗棕",
+    "#f4c7ba" => "洋水仙红",
+    "#f17666" => "谷鞘红",
+    "#f15642" => "苹果红",
+    "#f5391c" => "铁水红",
+    "#f25a47" => "桂红",
+    "#f33b1f" => "极光红",
+    "#f2b9b2" => "粉红",
+    "#f19790" => "舌红",
+    "#f05a46" => "曲红",
+    "#f23e23" => "红汞红",
+    "#f2cac9" => "淡绯",
+    "#efafad" => "无花果红",
+    "#f1908c" => "榴子红",
+    "#f03f24" => "胭脂红",
+    "#f0a1a8" => "合欢红",
+    "#f1939c" => "春梅红",
+    "#f07c82" => "香叶红",
+    "#f04a3a" => "珊瑚红",
+    "#f13c22" => "萝卜红",
+    "#e77c8e" => "淡茜红",
+    "#ed5a65" => "艳红",
+    "#ed4845" => "淡菽红",
+    "#ed3b2f" => "鱼鳃红",
+    "#ed3321" => "樱桃红",
+    "#ee4866" => "淡蕊香红",
+    "#ee4863" => "石竹红",
+    "#ef475d" => "草茉莉红",
+    "#ee3f4d" => "茶花红",
+    "#ed3333" => "枸枢红",
+    "#ec2b24" => "秋海棠红",
+    "#eb261a" => "丽春红",
+    "#de2a18" => "夕阳红",
+    "#d42517" => "鹤顶红",
+    "#ab372f" => "鹅血石红",
+    "#ac1f18" => "覆盆子红",
+    "#5d3131" => "貂紫",
+    "#5c2223" => "暗玉紫",
+    "#5a191b" => "栗紫",
+    "#5a1216" => "葡萄酱紫",
+    "#eea2a4" => "牡丹粉红",
+    "#ed556a" => "山茶红",
+    "#f03752" => "海棠红",
+    "#c04851" => "玉红",
+    "#c02c38" => "高粱红",
+    "#a7535a" => "满江红",
+    "#7c1823" => "枣红",
+    "#4c1f24" => "葡萄紫",
+    "#4d1018" => "酱紫",
+    "#ee2746" => "淡曙红",
+    "#de1c31" => "唐菖蒲红",
+    "#d11a2d" => "鹅冠红",
+    "#c45a65" => "莓红",
+    "#c21f30" => "枫叶红",
+    "#a61b29" => "苋菜红",
+    "#894e54" => "烟红",
+    "#82202b" => "暗紫苑红",
+    "#82111f" => "殷红",
+    "#541e24" => "猪肝紫",
+    "#500a16" => "金鱼紫",
+    "#f8ebe6" => "草珠红",
+    "#ec7696" => "淡绛红",
+    "#ef3473" => "品红",
+    "#ea7293" => "凤仙花红",
+    "#ec9bad" => "粉团花红",
+    "#eb507e" => "夹竹桃红",
+    "#ed2f6a" => "榲桲红",
+    "#eeb8c3" => "姜红",
+    "#ea517f" => "莲瓣红",
+    "#f1c4cd" => "水红",
+    "#ec8aa4" => "报春红",
+    "#ce5777" => "月季红",
+    "#ed9db2" => "豇豆红",
+    "#ef82a0" => "霞光红",
+    "#eb3c70" => "松叶牡丹红",
+    "#ec2c64" => "喜蛋红",
+    "#e3b4b8" => "鼠鼻红",
+    "#cc163a" => "尖晶玉红",
+    "#c27c88" => "山黎豆红",
+    "#bf3553" => "锦葵红",
+    "#73575c" => "鼠背灰",
+    "#621624" => "甘蔗紫",
+    "#63071c" => "石竹紫",
+    "#36282b" => "苍蝇灰",
+    "#30161c" => "卵石紫",
+    "#2b1216" => "李紫",
+    "#2d0c13" => "茄皮紫",
+    "#ce5e8a" => "吊钟花红",
+    "#ec4e8a" => "兔眼红",
+    "#ee2c79" => "紫荆红",
+    "#951c48" => "菜头紫",
+    "#621d34" => "鹞冠紫",
+    "#62102e" => "葡萄酒红",
+    "#382129" => "磨石紫",
+    "#381924" => "檀紫",
+    "#33141e" => "火鹅紫",
+    "#310f1b" => "墨紫",
+    "#eea6b7" => "晶红",
+    "#ef498b" => "扁豆花红",
+    "#de7897" => "白芨红",
+    "#de3f7c" => "嫩菱红",
+    "#d13c74" => "菠根红",
+    "#c5708b" => "酢酱草红",
+    "#a8456b" => "洋葱紫",
+    "#4b1e2f" => "海象紫",
+    "#461629" => "绀紫",
+    "#440e25" => "古铜紫",
+    "#f0c9cf" => "石蕊红",
+    "#eba0b3" => "芍药耕红",
+    "#ec2d7a" => "藏花红",
+    "#e16c96" => "初荷红",
+    "#ede3e7" => "马鞭草紫",
+    "#e9d7df" => "丁香淡紫",
+    "#d2568c" => "丹紫红",
+    "#d2357d" => "玫瑰红",
+    "#d1c2d3" => "淡牵牛紫",
+    "#c8adc4" => "凤信紫",
+    "#c08eaf" => "萝兰紫",
+    "#ba2f7b" => "玫瑰紫",
+    "#8076a3" => "藤萝紫",
+    "#806d9e" => "槿紫",
+    "#815c94" => "蕈紫",
+    "#813c85" => "桔梗紫",
+    "#7e1671" => "魏紫",
+    "#e9ccd3" => "芝兰紫",
+    "#d276a3" => "菱锰红",
+    "#cc5595" => "龙须红",
+    "#e6d2d5" => "蓟粉红",
+    "#c35691" => "电气石红",
+    "#c06f98" => "樱草紫",
+    "#bdaead" => "芦穗灰",
+    "#b598a1" => "隐红灰",
+    "#9b1e64" => "苋菜紫",
+    "#856d72" => "芦灰",
+    "#4f383e" => "暮云灰",
+    "#482936" => "斑鸠灰",
+    "#f2e7e5" => "淡藤萝紫",
+    "#e0c8d1" => "淡青紫",
+    "#bc84a8" => "青蛤壳紫",
+    "#ad6598" => "豆蔻紫",
+    "#a35c8f" => "扁豆紫",
+    "#983680" => "芥花紫",
+    "#8b2671" => "青莲",
+    "#894276" => "芓紫",
+    "#7e2065" => "葛巾紫",
+    "#681752" => "牵牛紫",
+    "#5d3f51" => "紫灰",
+    "#4e2a40" => "龙睛鱼紫",
+    "#411c35" => "荸荠紫",
+    "#36292f" => "古鼎灰",
+    "#1e131d" => "乌梅紫",
+    "#1c0d1a" => "深牵牛紫",
+    "#f1f0ed" => "银白",
+    "#e2e1e4" => "芡食白",
+    "#ccccd6" => "远山紫",
+    "#a7a8bd" => "淡蓝紫",
+    "#61649f" => "山梗紫",
+    "#74759b" => "螺甸紫",
+    "#cfccc9" => "玛瑙灰",
+    "#525288" => "野菊紫",
+    "#2e317c" => "满天星紫",
+    "#7a7374" => "锌灰",
+    "#302f4b" => "野葡萄紫",
+    "#3e3841" => "剑锋紫",
+    "#322f3b" => "龙葵紫",
+    "#22202e" => "暗龙胆紫",
+    "#1f2040" => "晶石紫",
+    "#131124" => "暗蓝紫",
+    "#2775b6" => "景泰蓝",
+    "#2474b5" => "尼罗蓝",
+    "#d0dfe6" => "远天蓝",
+    "#93b5cf" => "星蓝",
+    "#619ac3" => "羽扇豆蓝",
+    "#2376b7" => "花青",
+    "#5698c3" => "睛蓝",
+    "#2177b8" => "虹蓝",
+    "#b0d5df" => "湖水蓝",
+    "#8abcd1" => "秋波蓝",
+    "#66a9c9" => "涧石蓝",
+    "#2983bb" => "潮蓝",
+    "#1772b4" => "群青",
+    "#63bbd0" => "霁青",
+    "#5cb3cc" => "碧青",
+    "#2486b9" => "宝石蓝",
+    "#1677b3" => "天蓝",
+    "#126bae" => "柏林蓝",
+    "#22a2c3" => "海青",
+    "#1a94bc" => "钴蓝",
+    "#158bb8" => "鸢尾蓝",
+    "#1177b0" => "牵牛花蓝",
+    "#0f59a4" => "飞燕草蓝",
+    "#2b73af" => "品蓝",
+    "#cdd1d3" => "银鱼白",
+    "#3170a7" => "安安蓝",
+    "#5e616d" => "鱼尾灰",
+    "#475164" => "鲸鱼灰",
+    "#fffefa" => "海参灰",
+    "#35333c" => "沙鱼灰",
+    "#0f1423" => "钢蓝",
+    "#baccd9" => "云水蓝",
+    "#8fb2c9" => "晴山蓝",
+    "#1661ab" => "靛青",
+    "#c4cbcf" => "大理石灰",
+    "#15559a" => "海涛蓝",
+    "#4e7ca1" => "蝶翅蓝",
+    "#346c9c" => "海军蓝",
+    "#2f2f35" => "水牛灰",
+    "#2d2e36" => "牛角灰",
+    "#131824" => "燕颔蓝",
+    "#d8e3e7" => "云峰白",
+    "#c3d7df" => "井天蓝",
+    "#2f90b9" => "云山蓝",
+    "#1781b5" => "釉蓝",
+    "#c7d2d4" => "鸥蓝",
+    "#11659a" => "搪磁蓝",
+    "#c0c4c3" => "月影白",
+    "#b2bbbe" => "星灰",
+    "#5e7987" => "淡蓝灰",
+    "#144a74" => "鷃蓝",
+    "#74787a" => "嫩灰",
+    "#495c69" => "战舰灰",
+    "#47484c" => "瓦罐灰",
+    "#2b333e" => "青灰",
+    "#1c2938" => "鸽蓝",
+    "#142334" => "钢青",
+    "#101f30" => "暗蓝",
+    "#eef7f2" => "月白",
+    "#c6e6e8" => "海天蓝",
+    "#93d5dc" => "清水蓝",
+    "#51c4d3" => "瀑布蓝",
+    "#29b7cb" => "蔚蓝",
+    "#0eb0c9" => "孔雀蓝",
+    "#10aec2" => "甸子蓝",
+    "#57c3c2" => "石绿",
+    "#b9dec9" => "竹篁绿",
+    "#83cbac" => "粉绿",
+    "#12aa9c" => "美蝶绿",
+    "#66c18c" => "毛绿",
+    "#5dbe8a" => "蔻梢绿",
+    "#55bb8a" => "麦苗绿",
+    "#45b787" => "蛙绿",
+    "#2bae85" => "铜绿",
+    "#1ba784" => "竹绿",
+    "#12a182" => "蓝绿",
+    "#c4d7d6" => "穹灰",
+    "#1e9eb3" => "翠蓝",
+    "#0f95b0" => "胆矾蓝",
+    "#1491a8" => "樫鸟蓝",
+    "#7cabb1" => "闪蓝",
+    "#a4aca7" => "冰山蓝",
+    "#869d9d" => "虾壳青",
+    "#648e93" => "晚波蓝",
+    "#3b818c" => "蜻蜓蓝",
+    "#126e82" => "玉鈫蓝",
+    "#737c7b" => "垩灰",
+    "#617172" => "夏云灰",
+    "#134857" => "苍蓝",
+    "#474b4c" => "黄昏灰",
+    "#21373d" => "灰蓝",
+    "#132c33" => "深灰蓝",
+    "#a4cab6" => "玉簪绿",
+    "#2c9678" => "青矾绿",
+    "#9abeaf" => "草原远绿",
+    "#69a794" => "梧枝绿",
+    "#92b3a5" => "浪花绿",
+    "#248067" => "海王绿",
+    "#428675" => "亚丁绿",
+    "#9fa39a" => "镍灰",
+    "#8a988e" => "明灰",
+    "#70887d" => "淡绿灰",
+    "#497568" => "飞泉绿",
+    "#5d655f" => "狼烟灰",
+    "#314a43" => "绿灰",
+    "#223e36" => "苍绿",
+    "#1a3b32" => "深海绿",
+    "#363433" => "长石灰",
+    "#1f2623" => "苷蓝绿",
+    "#141e1b" => "莽丛绿",
+    "#c6dfc8" => "淡翠绿",
+    "#9eccab" => "明绿",
+    "#68b88e" => "田园绿",
+    "#20a162" => "翠绿",
+    "#61ac85" => "淡绿",
+    "#40a070" => "葱绿",
+    "#229453" => "孔雀绿",
+    "#cad3c3" => "艾绿",
+    "#3c9566" => "蟾绿",
+    "#20894d" => "宫殿绿",
+    "#83a78d" => "松霜绿",
+    "#579572" => "蛋白石绿",
+    "#207f4c" => "薄荷绿",
+    "#6e8b74" => "瓦松绿",
+    "#1a6840" => "荷叶绿",
+    "#5e665b" => "田螺绿",
+    "#485b4d" => "白屈菜绿",
+    "#393733" => "河豚灰",
+    "#373834" => "蒽油绿",
+    "#2b312c" => "槲寄生绿",
+    "#15231b" => "云杉绿",
+    "#f0f5e5" => "嫩菊绿",
+    "#dfecd5" => "艾背绿",
+    "#add5a2" => "嘉陵水绿",
+    "#41b349" => "玉髓绿",
+    "#43b244" => "鲜绿",
+    "#41ae3c" => "宝石绿",
+    "#e2e7bf" => "海沬绿",
+    "#d0deaa" => "姚黄",
+    "#b2cf87" => "橄榄石绿",
+    "#8cc269" => "水绿",
+    "#b7d07a" => "芦苇绿",
+    "#d2d97a" => "槐花黄绿",
+    "#bacf65" => "苹果绿",
+    "#96c24e" => "芽绿",
+    "#e2d849" => "蝶黄",
+    "#bec936" => "橄榄黄绿",
+    "#5bae23" => "鹦鹉绿",
+    "#253d24" => "油绿",
+    "#fffef8" => "象牙白",
+    "#f8f4ed" => "汉白玉",
+    "#fffef9" => "雪白",
+    "#f7f4ed" => "鱼肚白",
+    "#e4dfd7" => "珍珠灰",
+    "#dad4cb" => "浅灰",
+    "#bbb5ac" => "铅灰",
+    "#867e76" => "瓦灰",
+    "#847c74" => "夜灰",
+    "#80766e" => "雁灰",
+    "#81776e" => "深灰",
+};
@@ -0,0 +1,87 @@
+//! Evenly spaced samples of the [matplotlib](https://bids.github.io/colormap/) perceptual
+//! colormaps, at every ninth and every quarter of the maps.
+//!
+//! The maps are smooth enough that interpolating the samples in sRGB stays within a few units
+//! of the full 256-entry tables.
+
+/// Samples of the matplotlib `viridis` colormap.
+pub(crate) const VIRIDIS: [(f64, &str); 13] = [
+    (0.0, "#440154"),
+    (1.0 / 9.0, "#482878"),
+    (2.0 / 9.0, "#3e4a89"),
+    (1.0 / 4.0, "#3b528b"),
+    (1.0 / 3.0, "#31688e"),
+    (4.0 / 9.0, "#26828e"),
+    (1.0 / 2.0, "#21918c"),
+    (5.0 / 9.0, "#1f9e89"),
+    (2.0 / 3.0, "#35b779"),
+    (3.0 / 4.0, "#5ec962"),
+    (7.0 / 9.0, "#6dcd59"),
+    (8.0 / 9.0, "#b4de2c"),
+    (1.0, "#fde725"),
+];
+
+/// Samples of the matplotlib `magma` colormap.
+pub(crate) const MAGMA: [(f64, &str); 13] = [
+    (0.0, "#000004"),
+    (1.0 / 9.0, "#180f3e"),
+    (2.0 / 9.0, "#451077"),
+    (1.0 / 4.0, "#51127c"),
+    (1.0 / 3.0, "#721f81"),
+    (4.0 / 9.0, "#9f2f7f"),
+    (1.0 / 2.0, "#b73779"),
+    (5.0 / 9.0, "#cd4071"),
+    (2.0 / 3.0, "#f1605d"),
+    (3.0 / 4.0, "#fc8961"),
+    (7.0 / 9.0, "#fd9567"),
+    (8.0 / 9.0, "#fec98d"),
+    (1.0, "#fcfdbf"),
+];
+
+/// Samples of the matplotlib `inferno` colormap.
+pub(crate) const INFERNO: [(f64, &str); 13] = [
+    (0.0, "#000004"),
+    (1.0 / 9.0, "#1b0c42"),
+    (2.0 / 9.0, "#4b0c6b"),
+    (1.0 / 4.0, "#56106e"),
+    (1.0 / 3.0, "#781c6d"),
+    (4.0 / 9.0, "#a52c60"),
+    (1.0 / 2.0, "#bb3754"),
+    (5.0 / 9.0, "#cf4446"),
+    (2.0 / 3.0, "#ed6925"),
+    (3.0 / 4.0, "#f98c0a"),
+    (7.0 / 9.0, "#fb9a06"),
+    (8.0 / 9.0, "#f7d03c"),
+    (1.0, "#fcffa4"),
+];
+
+/// Samples of the matplotlib `plasma` colormap.
+pub(crate) const PLASMA: [(f64, &str); 13] = [
+    (0.0, "#0d0887"),
+    (1.0 / 9.0, "#47039f"),
+    (2.0 / 9.0, "#7301a8"),
+    (1.0 / 4.0, "#7e03a8"),
+    (1.0 / 3.0, "#9c179e"),
+    (4.0 / 9.0, "#bd3786"),
+    (1.0 / 2.0, "#cc4778"),
+    (5.0 / 9.0, "#d8576b"),
+    (2.0 / 3.0, "#ed7953"),
+    (3.0 / 4.0, "#f89540"),
+    (7.0 / 9.0, "#fa9e3b"),
+    (8.0 / 9.0, "#fdc926"),
+    (1.0, "#f0f921"),
+];
+
+/// Samples of the matplotlib `cividis` colormap.
+pub(crate) const CIVIDIS: [(f64, &str); 10] = [
+    (0.0, "#00204d"),
+    (1.0 / 9.0, "#00336f"),
+    (2.0 / 9.0, "#39486b"),
+    (1.0 / 3.0, "#575c6d"),
+    (4.0 / 9.0, "#707173"),
+    (5.0 / 9.0, "#8a8779"),
+    (2.0 / 3.0, "#a69d75"),
+    (7.0 / 9.0, "#c4b56c"),
+    (8.0 / 9.0, "#e4cf5b"),
+    (1.0, "#ffea46"),
+];
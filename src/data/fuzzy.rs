@@ -0,0 +1,82 @@
+use super::{NameSource, CATALOGS};
+use alloc::{vec, vec::Vec};
+
+/// Maximum number of names suggested for an unknown color name.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Find the known color names closest to an unknown name, closest first.
+///
+/// Names are compared by their edit distance (counting a swap of two adjacent characters as
+/// a single edit), and only names within a third of the length of `name` are suggested.
+/// Only CSS keywords are suggested if `css_only`.
+pub(crate) fn suggest_names(name: &str, css_only: bool) -> Vec<&'static str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    let mut candidates = CATALOGS
+        .iter()
+        .filter(|catalog| !css_only || catalog.source == NameSource::Css)
+        .flat_map(|catalog| catalog.names.keys())
+        .map(|candidate| (edit_distance(name, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect::<Vec<_>>();
+    candidates.sort();
+    // a name can be in several catalogs
+    candidates.dedup();
+
+    candidates
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .take(MAX_SUGGESTIONS)
+        .collect()
+}
+
+/// The optimal string alignment distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    // dist[i][j] is the distance between the first i chars of a and the first j chars of b
+    let mut dist = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in dist[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            dist[i][j] = (dist[i - 1][j] + 1)
+                .min(dist[i][j - 1] + 1)
+                .min(dist[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                dist[i][j] = dist[i][j].min(dist[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    dist[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("white", "white"), 0);
+        assert_eq!(edit_distance("whiet", "white"), 1);
+        assert_eq!(edit_distance("gren", "green"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "red"), 3);
+    }
+
+    #[test]
+    #[cfg(all(feature = "css-names", feature = "chinese-names"))]
+    fn test_suggest_names() {
+        assert_eq!(suggest_names("whiet", true), vec!["white"]);
+        assert_eq!(suggest_names("gren", true), vec!["green", "grey"]);
+        assert!(suggest_names("水禄", false).contains(&"水绿"));
+        assert!(suggest_names("水禄", true).is_empty());
+        assert!(suggest_names("fff", false).is_empty());
+    }
+}
@@ -0,0 +1,156 @@
+use phf::phf_map;
+
+/// Japanese traditional colors, a subset of 71 common ones.
+///
+/// The catalog is not complete, nipponcolors.com lists 250 colors, so a missing name is not
+/// necessarily an unknown traditional color.
+///
+/// reference: <https://nipponcolors.com>
+pub static JAPANESE_COLOR: phf::Map<&'static str, &'static str> = phf_map! {
+    "撫子" => "#dc9fb4",
+    "紅梅" => "#e16b8c",
+    "蘇芳" => "#8e354a",
+    "退紅" => "#f8c3cd",
+    "一斥染" => "#f4a7b9",
+    "桑染" => "#64363c",
+    "桃" => "#f596aa",
+    "苺" => "#b5495b",
+    "薄紅" => "#e87a90",
+    "今様" => "#d05a6e",
+    "中紅" => "#db4d6d",
+    "桜" => "#fedfe1",
+    "梅鼠" => "#9e7a7a",
+    "韓紅花" => "#d0104c",
+    "燕脂" => "#9f353a",
+    "紅" => "#cb1b45",
+    "鴇" => "#eea9a9",
+    "長春" => "#bf6766",
+    "深緋" => "#86473f",
+    "桜鼠" => "#b19693",
+    "甚三紅" => "#eb7a77",
+    "小豆" => "#954a45",
+    "蘇芳香" => "#a96360",
+    "赤紅" => "#cb4042",
+    "真朱" => "#ab3b3a",
+    "灰桜" => "#d7c4bb",
+    "栗梅" => "#904840",
+    "海老茶" => "#734338",
+    "銀朱" => "#c73e3a",
+    "黒鳶" => "#554236",
+    "紅鳶" => "#994639",
+    "曙" => "#f19483",
+    "紅樺" => "#b54434",
+    "珊瑚朱" => "#f17c67",
+    "紅檜皮" => "#884c3a",
+    "猩猩緋" => "#e83015",
+    "鉛丹" => "#d75455",
+    "芝翫茶" => "#b55d4c",
+    "檜皮" => "#854836",
+    "柿渋" => "#a35e47",
+    "紅緋" => "#f75c2f",
+    "鳶" => "#724832",
+    "山吹" => "#ffb11b",
+    "鬱金" => "#efbb24",
+    "刈安" => "#e9cd4c",
+    "菜の花" => "#f7d94c",
+    "黄蘗" => "#fbe251",
+    "萌黄" => "#7ba23f",
+    "若竹" => "#5dac81",
+    "緑" => "#227d51",
+    "常磐" => "#1b813e",
+    "青竹" => "#00896c",
+    "浅葱" => "#33a6b8",
+    "藍" => "#0d5661",
+    "瓶覗" => "#a5dee4",
+    "瑠璃" => "#005caf",
+    "群青" => "#51a8dd",
+    "露草" => "#2ea9df",
+    "縹" => "#006284",
+    "藤" => "#8b81c3",
+    "桔梗" => "#6a4c9c",
+    "菫" => "#66327c",
+    "江戸紫" => "#77428d",
+    "紫" => "#8f77b5",
+    "白" => "#fcfaf2",
+    "胡粉" => "#fffffb",
+    "墨" => "#1c1c1c",
+    "黒" => "#080808",
+    "鈍" => "#656765",
+    "銀鼠" => "#91989f",
+    "利休鼠" => "#707c74",
+};
+
+/// The reverse of [`JAPANESE_COLOR`], a hex string to the first name listed for it.
+pub(crate) static JAPANESE_COLOR_NAMES: phf::Map<&'static str, &'static str> = phf_map! {
+    "#dc9fb4" => "撫子",
+    "#e16b8c" => "紅梅",
+    "#8e354a" => "蘇芳",
+    "#f8c3cd" => "退紅",
+    "#f4a7b9" => "一斥染",
+    "#64363c" => "桑染",
+    "#f596aa" => "桃",
+    "#b5495b" => "苺",
+    "#e87a90" => "薄紅",
+    "#d05a6e" => "今様",
+    "#db4d6d" => "中紅",
+    "#fedfe1" => "桜",
+    "#9e7a7a" => "梅鼠",
+    "#d0104c" => "韓紅花",
+    "#9f353a" => "燕脂",
+    "#cb1b45" => "紅",
+    "#eea9a9" => "鴇",
+    "#bf6766" => "長春",
+    "#86473f" => "深緋",
+    "#b19693" => "桜鼠",
+    "#eb7a77" => "甚三紅",
+    "#954a45" => "小豆",
+    "#a96360" => "蘇芳香",
+    "#cb4042" => "赤紅",
+    "#ab3b3a" => "真朱",
+    "#d7c4bb" => "灰桜",
+    "#904840" => "栗梅",
+    "#734338" => "海老茶",
+    "#c73e3a" => "銀朱",
+    "#554236" => "黒鳶",
+    "#994639" => "紅鳶",
+    "#f19483" => "曙",
+    "#b54434" => "紅樺",
+    "#f17c67" => "珊瑚朱",
+    "#884c3a" => "紅檜皮",
+    "#e83015" => "猩猩緋",
+    "#d75455" => "鉛丹",
+    "#b55d4c" => "芝翫茶",
+    "#854836" => "檜皮",
+    "#a35e47" => "柿渋",
+    "#f75c2f" => "紅緋",
+    "#724832" => "鳶",
+    "#ffb11b" => "山吹",
+    "#efbb24" => "鬱金",
+    "#e9cd4c" => "刈安",
+    "#f7d94c" => "菜の花",
+    "#fbe251" => "黄蘗",
+    "#7ba23f" => "萌黄",
+    "#5dac81" => "若竹",
+    "#227d51" => "緑",
+    "#1b813e" => "常磐",
+    "#00896c" => "青竹",
+    "#33a6b8" => "浅葱",
+    "#0d5661" => "藍",
+    "#a5dee4" => "瓶覗",
+    "#005caf" => "瑠璃",
+    "#51a8dd" => "群青",
+    "#2ea9df" => "露草",
+    "#006284" => "縹",
+    "#8b81c3" => "藤",
+    "#6a4c9c" => "桔梗",
+    "#66327c" => "菫",
+    "#77428d" => "江戸紫",
+    "#8f77b5" => "紫",
+    "#fcfaf2" => "白",
+    "#fffffb" => "胡粉",
+    "#1c1c1c" => "墨",
+    "#080808" => "黒",
+    "#656765" => "鈍",
+    "#91989f" => "銀鼠",
+    "#707c74" => "利休鼠",
+};
@@ -1,42 +1,182 @@
-pub mod alpha_hex_map;
+#[cfg(feature = "brewer")]
+pub(crate) mod brewer;
+pub(crate) mod categorical;
+#[cfg(feature = "chinese-names")]
 pub mod chinese_color;
+#[cfg(feature = "colormaps")]
+pub(crate) mod colormaps;
+pub(crate) mod fuzzy;
+#[cfg(feature = "japanese-names")]
+pub mod japanese_color;
+#[cfg(feature = "material")]
+pub mod material;
+pub mod registry;
+#[cfg(feature = "tailwind")]
+pub mod tailwind;
+#[cfg(feature = "css-names")]
 pub mod w3cx11;
+#[cfg(feature = "x11-names")]
+pub mod x11_color;
+#[cfg(feature = "xkcd-names")]
+pub mod xkcd_color;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The catalog a color name comes from, in lookup order. The built-in ones are each behind a
+/// cargo feature.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NameSource {
+    /// Application defined names of the installed [`NameRegistry`](crate::NameRegistry).
+    Custom,
+    /// CSS color keywords, `css-names` feature (default).
+    Css,
+    /// 中国传统色 (Chinese traditional colors), `chinese-names` feature (default).
+    Chinese,
+    /// X11 `rgb.txt` names, including the numbered variants like `seagreen3`, `x11-names` feature.
+    X11,
+    /// Names from the xkcd color survey like `dusty rose`, `xkcd-names` feature.
+    Xkcd,
+    /// 日本の伝統色 (Japanese traditional colors), a subset of 71 common ones, `japanese-names`
+    /// feature.
+    Japanese,
+}
+
+/// A built-in catalog of color names.
+pub(crate) struct Catalog {
+    /// Color names to hex strings.
+    pub names: &'static phf::Map<&'static str, &'static str>,
+    /// Hex strings to the first name listed for them.
+    pub hexes: &'static phf::Map<&'static str, &'static str>,
+    /// The catalog reported for the names, by [`Color::name_with_source`](crate::Color::name_with_source).
+    pub source: NameSource,
+}
+
+/// The catalogs enabled by the cargo features, in lookup order.
+pub(crate) static CATALOGS: &[Catalog] = &[
+    #[cfg(feature = "css-names")]
+    Catalog {
+        names: &w3cx11::W3CX11,
+        hexes: &w3cx11::W3CX11_NAMES,
+        source: NameSource::Css,
+    },
+    #[cfg(feature = "chinese-names")]
+    Catalog {
+        names: &chinese_color::CHINESE_COLOR,
+        hexes: &chinese_color::CHINESE_COLOR_NAMES,
+        source: NameSource::Chinese,
+    },
+    #[cfg(feature = "x11-names")]
+    Catalog {
+        names: &x11_color::X11_COLOR,
+        hexes: &x11_color::X11_COLOR_NAMES,
+        source: NameSource::X11,
+    },
+    #[cfg(feature = "xkcd-names")]
+    Catalog {
+        names: &xkcd_color::XKCD_COLOR,
+        hexes: &xkcd_color::XKCD_COLOR_NAMES,
+        source: NameSource::Xkcd,
+    },
+    #[cfg(feature = "japanese-names")]
+    Catalog {
+        names: &japanese_color::JAPANESE_COLOR,
+        hexes: &japanese_color::JAPANESE_COLOR_NAMES,
+        source: NameSource::Japanese,
+    },
+];
+
+/// A design system with a palette of color tokens, each one behind a cargo feature.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DesignSystem {
+    /// The Material Design palette, tokens like `red-500` or `teal-a400`, `material` feature.
+    Material,
+    /// The Tailwind CSS palette, tokens like `slate-700`, `tailwind` feature.
+    Tailwind,
+}
+
+impl DesignSystem {
+    /// The prefix of the tokens of the design system, like `tailwind` in `tailwind:sky-400`.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            DesignSystem::Material => "material",
+            DesignSystem::Tailwind => "tailwind",
+        }
+    }
+}
+
+/// The design system palettes enabled by the cargo features, token to hex string.
+pub(crate) static DESIGN_SYSTEMS: &[(DesignSystem, &phf::Map<&'static str, &'static str>)] = &[
+    #[cfg(feature = "material")]
+    (DesignSystem::Material, &material::MATERIAL),
+    #[cfg(feature = "tailwind")]
+    (DesignSystem::Tailwind, &tailwind::TAILWIND),
+];
+
+/// The hex string of a color name, a constant time lookup in the perfect hash maps.
 pub(crate) fn hex_of_name(color_name: &str) -> Option<&'static str> {
-    let hex_str = crate::W3CX11.get(color_name);
-    match hex_str {
-        Some(hex_str) => Some(hex_str),
-        None => {
-            let res = crate::CHINESE_COLOR.get(color_name);
-            match res {
-                Some(hex_str) => Some(hex_str),
-                None => None,
+    CATALOGS
+        .iter()
+        .find_map(|catalog| catalog.names.get(color_name))
+        .copied()
+}
+
+/// The hex string of a CSS color keyword.
+pub(crate) fn hex_of_css_name(color_name: &str) -> Option<&'static str> {
+    CATALOGS
+        .iter()
+        .filter(|catalog| catalog.source == NameSource::Css)
+        .find_map(|catalog| catalog.names.get(color_name))
+        .copied()
+}
+
+/// [`hex_of_css_name`] for constant evaluation, ignoring ASCII case. A linear scan, as the
+/// hash lookup isn't `const`.
+#[cfg_attr(not(feature = "css-names"), allow(unused_variables))]
+pub(crate) const fn hex_of_css_name_const(color_name: &str) -> Option<&'static str> {
+    #[cfg(feature = "css-names")]
+    {
+        let entries = w3cx11::W3CX11_ENTRIES;
+        let mut i = 0;
+        while i < entries.len() {
+            let (name, hex) = entries[i];
+            if eq_ignore_ascii_case(name.as_bytes(), color_name.as_bytes()) {
+                return Some(hex);
             }
+            i += 1;
         }
     }
+    None
 }
 
-pub(crate) fn name_of_hex(hex_str: &str) -> Option<&'static str> {
-    let result = crate::W3CX11
-        .clone()
-        .into_iter()
-        .find(|(_k, v)| *v == hex_str)
-        .map(|(k, _v)| k);
-
-    match result {
-        Some(name) => Some(name),
-        None => {
-            let result = crate::CHINESE_COLOR
-                .clone()
-                .into_iter()
-                .find(|(_k, v)| *v == hex_str)
-                .map(|(k, _v)| k);
-            match result {
-                Some(name) => Some(name),
-                None => None,
-            }
+/// `<[u8]>::eq_ignore_ascii_case`, which is only `const` since Rust 1.89.
+#[cfg(feature = "css-names")]
+const fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
         }
+        i += 1;
     }
+    true
+}
+
+/// The name of a lowercase `#rrggbb` hex string, English names first.
+///
+/// Colors with several names (e.g. `aqua` and `cyan`) get the one listed first.
+pub(crate) fn name_of_hex(hex_str: &str) -> Option<(&'static str, NameSource)> {
+    CATALOGS.iter().find_map(|catalog| {
+        catalog
+            .hexes
+            .get(hex_str)
+            .map(|name| (*name, catalog.source))
+    })
 }
 
 #[cfg(test)]
@@ -44,6 +184,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[cfg(all(feature = "css-names", feature = "chinese-names"))]
     fn test_get_color_name() {
         let color_name = hex_of_name("yellow");
         assert_eq!(color_name, Some("#ffff00"));
@@ -54,4 +195,54 @@ mod tests {
         let color_name = hex_of_name("没有的颜色");
         assert_eq!(color_name, None);
     }
+
+    #[test]
+    #[cfg(all(feature = "css-names", feature = "chinese-names"))]
+    fn test_get_name_of_hex() {
+        assert_eq!(name_of_hex("#ffff00"), Some(("yellow", NameSource::Css)));
+        assert_eq!(name_of_hex("#8cc269"), Some(("水绿", NameSource::Chinese)));
+        assert_eq!(name_of_hex("#00ffff"), Some(("aqua", NameSource::Css)));
+        assert_eq!(name_of_hex("#808080"), Some(("gray", NameSource::Css)));
+        assert_eq!(name_of_hex("#123456"), None);
+    }
+
+    #[test]
+    fn test_catalogs_round_trip() {
+        #[cfg(feature = "css-names")]
+        for (name, hex) in w3cx11::W3CX11.entries() {
+            assert_eq!(hex_of_name(name), Some(*hex));
+            assert_eq!(hex_of_name(name_of_hex(hex).unwrap().0), Some(*hex));
+            assert_eq!(hex_of_css_name_const(name), Some(*hex));
+        }
+        #[cfg(feature = "css-names")]
+        assert_eq!(w3cx11::W3CX11_ENTRIES.len(), w3cx11::W3CX11.len());
+        for catalog in CATALOGS {
+            for (hex, name) in catalog.hexes.entries() {
+                assert_eq!(catalog.names.get(name), Some(hex));
+            }
+            for hex in catalog.names.values() {
+                assert!(catalog.hexes.contains_key(hex));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "x11-names",
+        feature = "xkcd-names",
+        feature = "japanese-names"
+    ))]
+    fn test_optional_catalogs() {
+        assert_eq!(hex_of_name("seagreen3"), Some("#43cd80"));
+        assert_eq!(hex_of_name("gray50"), Some("#7f7f7f"));
+        assert_eq!(hex_of_name("dusty rose"), Some("#c0737a"));
+        assert_eq!(hex_of_name("桜"), Some("#fedfe1"));
+        // CSS names come first
+        assert_eq!(hex_of_name("gray"), Some("#808080"));
+        assert_eq!(
+            name_of_hex("#c0737a"),
+            Some(("dusty rose", NameSource::Xkcd))
+        );
+        assert_eq!(hex_of_css_name("seagreen3"), None);
+    }
 }
@@ -0,0 +1,263 @@
+use crate::Color;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::sync::{PoisonError, RwLock};
+
+/// The registry installed with [`NameRegistry::install`].
+#[cfg(feature = "std")]
+static GLOBAL: RwLock<NameRegistry> = RwLock::new(NameRegistry::new());
+
+#[derive(Clone, PartialEq, Debug)]
+struct Entry {
+    name: String,
+    /// The lowercase name, names are case insensitive like the built-in ones.
+    key: String,
+    color: Color,
+}
+
+/// Application defined color names, like brand colors or design tokens.
+///
+/// Once [installed](NameRegistry::install), the names are consulted before the built-in
+/// catalogs when naming colors, by [`Color::name`], [`Color::name_with_source`] and
+/// [`Color::nearest_name`], with [`NameSource::Custom`](crate::NameSource::Custom) as their
+/// source. [`Color::from_name`] and the non-strict string parsing look them up after the
+/// built-in names, so a custom name can't change what `red` means. Names with parentheses
+/// can't be parsed, they are taken for color functions.
+///
+/// A registry can also be used without installing it, with [`Color::from_str_with_names`].
+///
+/// Lookups are linear, the registry is meant for palettes of tens of colors. Installing a
+/// registry needs the `std` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{Color, NameRegistry};
+/// use std::str::FromStr;
+///
+/// NameRegistry::new()
+///     .with("Brand Blue", Color::new(0, 85, 255, 1.0))
+///     .with("brand-accent", Color::new(255, 170, 0, 1.0))
+///     .install();
+///
+/// let color = Color::from_str("brand blue").unwrap();
+/// assert_eq!(color.name(), "Brand Blue");
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct NameRegistry {
+    entries: Vec<Entry>,
+}
+
+impl NameRegistry {
+    /// Creates an empty registry.
+    pub const fn new() -> Self {
+        NameRegistry {
+            entries: Vec::new(),
+        }
+    }
+    /// Adds a name, replacing the color of an existing name.
+    pub fn with(mut self, name: &str, color: Color) -> Self {
+        self.insert(name, color);
+        self
+    }
+    /// Adds a name, returning the color it replaced.
+    pub fn insert(&mut self, name: &str, color: Color) -> Option<Color> {
+        let key = name.to_lowercase();
+        match self.entries.iter_mut().find(|entry| entry.key == key) {
+            Some(entry) => {
+                entry.name = name.to_string();
+                Some(core::mem::replace(&mut entry.color, color))
+            }
+            None => {
+                self.entries.push(Entry {
+                    name: name.to_string(),
+                    key,
+                    color,
+                });
+                None
+            }
+        }
+    }
+    /// Removes a name, returning its color.
+    pub fn remove(&mut self, name: &str) -> Option<Color> {
+        let key = name.to_lowercase();
+        let index = self.entries.iter().position(|entry| entry.key == key)?;
+        Some(self.entries.remove(index).color)
+    }
+    /// The color of a name, ignoring case.
+    pub fn get(&self, name: &str) -> Option<Color> {
+        let key = || name.chars().flat_map(char::to_lowercase);
+        self.entries
+            .iter()
+            .find(|entry| entry.key.chars().eq(key()))
+            .map(|entry| entry.color)
+    }
+    /// The first registered name of a color, colors are equal if their hex strings are.
+    pub fn name_of(&self, color: &Color) -> Option<&str> {
+        let hex = color.hex();
+        self.entries
+            .iter()
+            .find(|entry| entry.color.hex() == hex)
+            .map(|entry| entry.name.as_str())
+    }
+    /// The names and their colors, in the order they were first added.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Color)> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry.color))
+    }
+    /// The number of names.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    /// Whether there are no names.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    /// Adds the names of this registry to the installed one, consulted by the color names and
+    /// parsing. Names installed before are kept, unless this registry has the same name.
+    #[cfg(feature = "std")]
+    pub fn install(self) {
+        let mut installed = GLOBAL.write().unwrap_or_else(PoisonError::into_inner);
+        for entry in self.entries {
+            installed.insert(&entry.name, entry.color);
+        }
+    }
+    /// Adds a name to the installed registry.
+    #[cfg(feature = "std")]
+    pub fn register(name: &str, color: Color) {
+        GLOBAL
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(name, color);
+    }
+    /// Removes a name from the installed registry, returning its color.
+    #[cfg(feature = "std")]
+    pub fn unregister(name: &str) -> Option<Color> {
+        GLOBAL
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(name)
+    }
+    /// A copy of the installed registry.
+    #[cfg(feature = "std")]
+    pub fn installed() -> NameRegistry {
+        NameRegistry::with_installed(NameRegistry::clone)
+    }
+
+    /// Runs `f` with the installed registry, without copying it.
+    #[cfg(feature = "std")]
+    pub(crate) fn with_installed<T>(f: impl FnOnce(&NameRegistry) -> T) -> T {
+        f(&GLOBAL.read().unwrap_or_else(PoisonError::into_inner))
+    }
+    /// Runs `f` with an empty registry, none can be installed without `std`.
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn with_installed<T>(f: impl FnOnce(&NameRegistry) -> T) -> T {
+        f(&NameRegistry::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_name_registry() {
+        let mut registry = NameRegistry::new()
+            .with("Ocean", color!(#0a3d62))
+            .with("Sand", color!(#f6e58d));
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.get("OCEAN"), Some(color!(#0a3d62)));
+        assert_eq!(registry.name_of(&color!(#f6e58d)), Some("Sand"));
+        assert_eq!(registry.name_of(&Color::new(246, 229, 141, 0.5)), None);
+
+        assert_eq!(
+            registry.insert("ocean", color!(#0c2461)),
+            Some(color!(#0a3d62))
+        );
+        assert_eq!(registry.iter().next(), Some(("ocean", color!(#0c2461))));
+        assert_eq!(registry.remove("Sand"), Some(color!(#f6e58d)));
+        assert_eq!(registry.get("sand"), None);
+        assert!(!registry.is_empty());
+    }
+
+    // the only test touching the installed registry, tests run in parallel
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_installed_registry() {
+        NameRegistry::new()
+            .with("Brand Primary", Color::new(1, 2, 3, 1.0))
+            .with("brand-overlay", Color::new(1, 2, 3, 0.5))
+            .install();
+        NameRegistry::new()
+            .with("brand-overlay", Color::new(1, 2, 3, 0.5))
+            .install();
+        NameRegistry::register("brand secondary", Color::new(3, 2, 1, 1.0));
+        assert_eq!(NameRegistry::installed().len(), 3);
+
+        let color = Color::from_str("brand primary").unwrap();
+        assert_eq!(color, Color::new(1, 2, 3, 1.0));
+        assert_eq!(color.name(), "Brand Primary");
+        assert_eq!(
+            Color::from_str_lenient("BRAND-OVERLAY").unwrap(),
+            Color::new(1, 2, 3, 0.5)
+        );
+        assert_eq!(Color::new(1, 2, 3, 0.5).name(), "brand-overlay");
+        assert!(Color::from_str_strict("brand primary").is_err());
+        assert_eq!(
+            Color::from_name("Brand Secondary").unwrap(),
+            Color::new(3, 2, 1, 1.0)
+        );
+        assert_eq!(
+            Color::new(4, 3, 2, 1.0).nearest_name_in(&[NameSource::Custom]),
+            Some(("brand secondary".to_string(), NameSource::Custom))
+        );
+        assert_eq!(
+            Color::new(1, 2, 3, 1.0).name_with_source(),
+            Some(("Brand Primary".to_string(), NameSource::Custom))
+        );
+
+        // built-in names come first
+        NameRegistry::register("Red", Color::new(1, 2, 3, 1.0));
+        #[cfg(feature = "css-names")]
+        {
+            assert_eq!(Color::from_str("red").unwrap(), Color::new(255, 0, 0, 1.0));
+            assert_eq!(Color::from_name("red").unwrap(), Color::new(255, 0, 0, 1.0));
+        }
+
+        for name in ["brand primary", "BRAND-OVERLAY", "brand secondary", "red"] {
+            assert!(NameRegistry::unregister(name).is_some(), "{}", name);
+        }
+        assert!(NameRegistry::installed().is_empty());
+        assert!(Color::from_str("brand primary").is_err());
+        assert_eq!(Color::new(1, 2, 3, 1.0).name(), "#010203");
+    }
+
+    #[test]
+    fn test_from_str_with_names() {
+        let names = NameRegistry::new()
+            .with("Brand-Ocean", color!(#0a3d62))
+            .with("white", color!(#fafafa))
+            .with("(not a name)", color!(#000));
+        assert_eq!(
+            Color::from_str_with_names(" BRAND-OCEAN ", &names).unwrap(),
+            color!(#0a3d62)
+        );
+        assert_eq!(
+            Color::from_str_with_names("rgb(from brand-ocean r g b / 0.5)", &names).unwrap(),
+            Color::new(10, 61, 98, 0.5)
+        );
+        #[cfg(feature = "css-names")]
+        assert_eq!(
+            Color::from_str_with_names("WHITE", &names).unwrap(),
+            Color::WHITE
+        );
+        assert!(Color::from_str_with_names("(not a name)", &names).is_err());
+        assert!(Color::from_str_with_names("brand-sand", &names).is_err());
+        assert!(Color::from_str("brand-ocean").is_err());
+    }
+}
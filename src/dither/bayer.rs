@@ -0,0 +1,119 @@
+use super::{check_image, nearest};
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{quantize::Quantized, Color, Error, Palette};
+use alloc::{format, vec, vec::Vec};
+
+/// Maps pixels to a palette with [ordered dithering](https://en.wikipedia.org/wiki/Ordered_dithering)
+/// by a Bayer matrix.
+///
+/// Each pixel is offset by the threshold of the matrix at its position before taking the nearest
+/// palette color, so the result has a regular crosshatch pattern and each pixel only depends on
+/// its own color. The offsets are scaled to the distance between the levels of a channel in a
+/// palette of that size, at least 2 levels.
+///
+/// # Errors
+///
+/// If `size` is not 2, 4 or 8, the palette is empty, or the number of pixels is not a multiple
+/// of `width`.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{color, dither, Palette};
+///
+/// let palette = Palette::new(vec![color!(#000), color!(#fff)]);
+/// let pixels = vec![color!(#808080); 4];
+/// let dithered = dither::bayer(&pixels, 2, &palette, 2).unwrap();
+/// assert_eq!(dithered.indices, vec![0, 1, 1, 0]);
+/// ```
+pub fn bayer(
+    pixels: &[Color],
+    width: usize,
+    palette: &Palette,
+    size: usize,
+) -> Result<Quantized, Error> {
+    if ![2, 4, 8].contains(&size) {
+        return Err(Error::InvalidParamsError(format!(
+            "Bayer matrix size must be 2, 4 or 8, got {}",
+            size
+        )));
+    }
+    check_image(pixels, width, palette)?;
+    let matrix = matrix(size);
+    let levels = (palette.len() as f64).cbrt().max(2.0);
+    let spread = 255.0 / (levels - 1.0);
+
+    let indices = pixels
+        .iter()
+        .enumerate()
+        .map(|(i, pixel)| {
+            let (x, y) = (i % width, i / width);
+            let threshold = (matrix[y % size][x % size] as f64 + 0.5) / (size * size) as f64 - 0.5;
+            let rgb = pixel.rgb.map(|v| v + threshold * spread);
+            nearest(palette, &rgb)
+        })
+        .collect();
+    Ok(Quantized {
+        palette: palette.clone(),
+        indices,
+    })
+}
+
+/// The Bayer matrix of a size, built by doubling the 1×1 matrix.
+fn matrix(size: usize) -> Vec<Vec<usize>> {
+    let mut matrix = vec![vec![0]];
+    while matrix.len() < size {
+        let n = matrix.len();
+        let mut doubled = vec![vec![0; n * 2]; n * 2];
+        for (y, row) in matrix.iter().enumerate() {
+            for (x, &v) in row.iter().enumerate() {
+                doubled[y][x] = 4 * v;
+                doubled[y][x + n] = 4 * v + 2;
+                doubled[y + n][x] = 4 * v + 3;
+                doubled[y + n][x + n] = 4 * v + 1;
+            }
+        }
+        matrix = doubled;
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matrix;
+    use crate::*;
+
+    #[test]
+    fn test_matrix() {
+        assert_eq!(matrix(2), vec![vec![0, 2], vec![3, 1]]);
+        assert_eq!(
+            matrix(4),
+            vec![
+                vec![0, 8, 2, 10],
+                vec![12, 4, 14, 6],
+                vec![3, 11, 1, 9],
+                vec![15, 7, 13, 5]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bayer() {
+        let palette = Palette::new(vec![color!(#000), color!(#fff)]);
+        assert!(dither::bayer(&[color!(#fff)], 1, &palette, 3).is_err());
+        assert!(dither::bayer(&[color!(#fff)], 1, &Palette::default(), 2).is_err());
+        assert!(dither::bayer(&[color!(#fff); 3], 2, &palette, 2).is_err());
+
+        // colors of the palette are kept
+        let pixels = [color!(#000), color!(#fff), color!(#fff), color!(#000)];
+        let dithered = dither::bayer(&pixels, 2, &palette, 4).unwrap();
+        assert_eq!(dithered.pixels(), pixels);
+
+        // a quarter grey lights a quarter of the pixels
+        let pixels = vec![Color::new(64, 64, 64, 1.0); 8 * 8];
+        let dithered = dither::bayer(&pixels, 8, &palette, 8).unwrap();
+        let white = dithered.indices.iter().filter(|&&i| i == 1).count();
+        assert_eq!(white, 16);
+    }
+}
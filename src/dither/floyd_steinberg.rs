@@ -0,0 +1,90 @@
+use super::{check_image, nearest};
+use crate::{quantize::Quantized, Color, Error, Palette};
+use alloc::vec::Vec;
+
+/// Maps pixels to a palette with [Floyd–Steinberg](https://en.wikipedia.org/wiki/Floyd%E2%80%93Steinberg_dithering)
+/// error diffusion.
+///
+/// The pixels are visited row by row, and the difference between each pixel and its palette
+/// color is spread over the pixels right and below it not visited yet.
+///
+/// # Errors
+///
+/// If the palette is empty, or the number of pixels is not a multiple of `width`.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{color, dither, Palette};
+///
+/// let palette = Palette::new(vec![color!(#000), color!(#fff)]);
+/// let pixels = vec![color!(#404040); 8];
+/// let dithered = dither::floyd_steinberg(&pixels, 4, &palette).unwrap();
+/// // a quarter of the pixels are white
+/// assert_eq!(dithered.indices.iter().filter(|&&i| i == 1).count(), 2);
+/// ```
+pub fn floyd_steinberg(
+    pixels: &[Color],
+    width: usize,
+    palette: &Palette,
+) -> Result<Quantized, Error> {
+    check_image(pixels, width, palette)?;
+    let mut buffer = pixels.iter().map(|pixel| pixel.rgb).collect::<Vec<_>>();
+    let mut indices = Vec::with_capacity(pixels.len());
+    let height = pixels.len().checked_div(width).unwrap_or(0);
+
+    for y in 0..height {
+        for x in 0..width {
+            let rgb = buffer[y * width + x].map(|v| v.clamp(0.0, 255.0));
+            let index = nearest(palette, &rgb);
+            indices.push(index);
+            let error = [0, 1, 2].map(|i| rgb[i] - palette[index].rgb[i]);
+            let mut spread = |x: usize, y: usize, weight: f64| {
+                if x < width && y < height {
+                    for (v, e) in buffer[y * width + x].iter_mut().zip(error) {
+                        *v += e * weight;
+                    }
+                }
+            };
+            spread(x + 1, y, 7.0 / 16.0);
+            if x > 0 {
+                spread(x - 1, y + 1, 3.0 / 16.0);
+            }
+            spread(x, y + 1, 5.0 / 16.0);
+            spread(x + 1, y + 1, 1.0 / 16.0);
+        }
+    }
+    Ok(Quantized {
+        palette: palette.clone(),
+        indices,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_floyd_steinberg() {
+        let palette = Palette::new(vec![color!(#000), color!(#fff)]);
+        assert!(dither::floyd_steinberg(&[color!(#fff)], 1, &Palette::default()).is_err());
+        assert!(dither::floyd_steinberg(&[color!(#fff); 3], 2, &palette).is_err());
+        assert!(dither::floyd_steinberg(&[color!(#fff)], 0, &palette).is_err());
+        assert!(dither::floyd_steinberg(&[], 0, &palette)
+            .unwrap()
+            .indices
+            .is_empty());
+
+        // colors of the palette are kept
+        let pixels = [color!(#000), color!(#fff), color!(#fff), color!(#000)];
+        let dithered = dither::floyd_steinberg(&pixels, 2, &palette).unwrap();
+        assert_eq!(dithered.indices, vec![0, 1, 1, 0]);
+        assert_eq!(dithered.pixels(), pixels);
+
+        // the mean brightness is kept
+        let pixels = vec![Color::new(64, 64, 64, 1.0); 16 * 16];
+        let dithered = dither::floyd_steinberg(&pixels, 16, &palette).unwrap();
+        let white = dithered.indices.iter().filter(|&&i| i == 1).count();
+        assert!((60..=68).contains(&white));
+    }
+}
@@ -0,0 +1,58 @@
+//! Dithering, mapping the pixels of an image to a fixed palette.
+//!
+//! The pixels are given as a slice of colors in rows of `width` pixels. The palette color of a
+//! pixel is the nearest one in RGB, the alpha of the pixels is not dithered.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use color_art::{color, dither, Palette};
+//!
+//! let palette = Palette::new(vec![color!(#000), color!(#fff)]);
+//! let pixels = vec![color!(#808080); 4];
+//! let dithered = dither::floyd_steinberg(&pixels, 2, &palette).unwrap();
+//! assert_eq!(dithered.indices, vec![1, 0, 0, 1]);
+//! ```
+
+mod bayer;
+mod floyd_steinberg;
+
+pub use bayer::bayer;
+pub use floyd_steinberg::floyd_steinberg;
+
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{Color, Error, Palette};
+use alloc::{format, string::ToString};
+
+/// Checks that the pixels are rows of `width` pixels and that the palette has colors.
+fn check_image(pixels: &[Color], width: usize, palette: &Palette) -> Result<(), Error> {
+    if palette.is_empty() {
+        return Err(Error::InvalidParamsError(
+            "cannot dither to an empty palette".to_string(),
+        ));
+    }
+    if width == 0 && !pixels.is_empty() || width != 0 && !pixels.len().is_multiple_of(width) {
+        return Err(Error::InvalidParamsError(format!(
+            "{} pixels are not rows of {} pixels",
+            pixels.len(),
+            width
+        )));
+    }
+    Ok(())
+}
+
+/// The index of the palette color nearest to a color in RGB.
+fn nearest(palette: &Palette, rgb: &[f64; 3]) -> usize {
+    let distance = |color: &Color| {
+        color
+            .rgb
+            .iter()
+            .zip(rgb)
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f64>()
+    };
+    (0..palette.len())
+        .min_by(|&i, &j| distance(&palette[i]).total_cmp(&distance(&palette[j])))
+        .unwrap()
+}
@@ -1,3 +1,4 @@
+use alloc::{format, string::String, vec::Vec};
 use thiserror::Error;
 
 #[cfg(feature = "serde")]
@@ -13,6 +14,38 @@ pub enum Error {
     #[error("{0}")]
     InvalidParamsError(String),
 
+    /// The input does not match the color string grammar.
+    #[error("Expected {expected} at offset {offset}, found {found}")]
+    ParseError {
+        /// Byte offset of the unexpected token in the input.
+        offset: usize,
+        /// Description of the tokens that would have been valid.
+        expected: String,
+        /// The unexpected token.
+        found: String,
+    },
+
+    /// The input looks like a color name, but is not a known one.
+    #[error("Unknown color name `{name}`{}", did_you_mean(.suggestions))]
+    UnknownColorName {
+        /// The unknown name.
+        name: String,
+        /// The closest known color names, closest first.
+        suggestions: Vec<String>,
+    },
+
+    /// The input is the CSS `currentColor` keyword, which depends on the context of the element.
+    #[error("`currentColor` can not be resolved without the context of an element")]
+    CurrentColor,
+
     #[error("unknown error, please report this error to the developers")]
     Unknown,
 }
+
+fn did_you_mean(suggestions: &[String]) -> String {
+    match suggestions {
+        [] => String::new(),
+        [name] => format!(", did you mean `{}`?", name),
+        [names @ .., last] => format!(", did you mean `{}` or `{}`?", names.join("`, `"), last),
+    }
+}
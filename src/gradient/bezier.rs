@@ -0,0 +1,102 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{Color, Error, Gradient, InterpolationSpace};
+use alloc::{string::ToString, vec::Vec};
+
+/// Number of segments a Bézier curve is sampled into.
+const SEGMENTS: usize = 32;
+
+impl Gradient {
+    /// Creates a gradient along a [Bézier curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve)
+    /// in Lab color space, with the colors as control points.
+    ///
+    /// Only the first and last colors are on the ramp, the others pull it towards them, which
+    /// gives smoother lightness changes than stops interpolated piecewise. The curve is sampled
+    /// into evenly spaced stops on the domain `0.0..=1.0`, interpolated in Lab.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Gradient};
+    ///
+    /// let gradient = Gradient::bezier(&[color!(#ff0), color!(#f00), color!(#000)]).unwrap();
+    /// assert_eq!(gradient.domain(), (0.0, 1.0));
+    /// assert_eq!(gradient.at(0.0).hex(), "#ff0");
+    /// assert_eq!(gradient.at(0.5).hex(), "#bf5f09");
+    /// assert_eq!(gradient.at(1.0).hex(), "#000");
+    /// ```
+    pub fn bezier(colors: &[Color]) -> Result<Self, Error> {
+        if colors.is_empty() {
+            return Err(Error::InvalidParamsError(
+                "bezier gradient must have at least one color".to_string(),
+            ));
+        }
+        let space = InterpolationSpace::Lab;
+        let points = colors
+            .iter()
+            .map(|color| {
+                let [v0, v1, v2] = space.values_of(&color.rgb);
+                [v0, v1, v2, color.alpha]
+            })
+            .collect::<Vec<_>>();
+
+        let stops = (0..=SEGMENTS)
+            .map(|i| {
+                let t = i as f64 / SEGMENTS as f64;
+                let values = bernstein(&points, t);
+                let [r, g, b] = space.rgb_of(&[values[0], values[1], values[2]]);
+                (t, Color::new(r, g, b, values[3]))
+            })
+            .collect();
+        Ok(Gradient::new(stops)?.space(space))
+    }
+}
+
+/// Evaluates the Bézier curve of the control points at `t` with the Bernstein polynomials.
+fn bernstein(points: &[[f64; 4]], t: f64) -> [f64; 4] {
+    let n = points.len() - 1;
+    let mut values = [0.0; 4];
+    let mut binomial = 1.0;
+    for (i, point) in points.iter().enumerate() {
+        let weight = binomial * (1.0 - t).powi((n - i) as i32) * t.powi(i as i32);
+        for (value, v) in values.iter_mut().zip(point) {
+            *value += weight * v;
+        }
+        binomial = binomial * (n - i) as f64 / (i + 1) as f64;
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_bezier() {
+        assert!(Gradient::bezier(&[]).is_err());
+
+        let gradient = Gradient::bezier(&[color!(#f00)]).unwrap();
+        assert_eq!(gradient.at(0.5).hex(), "#f00");
+
+        // two colors are a straight line in Lab
+        let gradient = Gradient::bezier(&[color!(#fff), color!(rgba(0, 0, 0, 0.5))]).unwrap();
+        let lab = gradient.at(0.5).vec_of(ColorSpace::Lab);
+        assert!((lab[0] - 50.0).abs() < 0.5);
+        assert_eq!(gradient.at(0.5).alpha(), 0.75);
+
+        let colors = [color!(#ff0), color!(#f00), color!(#00f), color!(#000)];
+        let gradient = Gradient::bezier(&colors).unwrap();
+        assert_eq!(gradient.stops().len(), 33);
+        assert_eq!(gradient.at(0.0).hex(), "#ff0");
+        assert_eq!(gradient.at(1.0).hex(), "#000");
+
+        // the lightness of the ramp keeps going down
+        let lightness = gradient
+            .colors(11)
+            .iter()
+            .map(|color| color.vec_of(ColorSpace::Lab)[0])
+            .collect::<Vec<_>>();
+        assert!(lightness.windows(2).all(|w| w[0] > w[1]));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{data::colormaps, Color, Gradient, InterpolationSpace};
+use core::str::FromStr;
+
+impl Gradient {
+    /// The matplotlib `viridis` colormap, from dark purple to yellow.
+    ///
+    /// It is perceptually uniform, and readable with color vision deficiencies and in greyscale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Gradient;
+    ///
+    /// let viridis = Gradient::viridis();
+    /// assert_eq!(viridis.at(0.0).hex(), "#440154");
+    /// assert_eq!(viridis.at(0.5).hex(), "#21918c");
+    /// assert_eq!(viridis.at(1.0).hex(), "#fde725");
+    /// ```
+    pub fn viridis() -> Self {
+        colormap(&colormaps::VIRIDIS)
+    }
+    /// The matplotlib `magma` colormap, from black through purple and orange to light yellow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Gradient;
+    ///
+    /// let magma = Gradient::magma();
+    /// assert_eq!(magma.at(0.5).hex(), "#b73779");
+    /// ```
+    pub fn magma() -> Self {
+        colormap(&colormaps::MAGMA)
+    }
+    /// The matplotlib `inferno` colormap, from black through red and orange to light yellow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Gradient;
+    ///
+    /// let inferno = Gradient::inferno();
+    /// assert_eq!(inferno.at(0.5).hex(), "#bb3754");
+    /// ```
+    pub fn inferno() -> Self {
+        colormap(&colormaps::INFERNO)
+    }
+    /// The matplotlib `plasma` colormap, from dark blue through magenta to yellow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Gradient;
+    ///
+    /// let plasma = Gradient::plasma();
+    /// assert_eq!(plasma.at(0.5).hex(), "#cc4778");
+    /// ```
+    pub fn plasma() -> Self {
+        colormap(&colormaps::PLASMA)
+    }
+    /// The matplotlib `cividis` colormap, from dark blue to yellow, optimized for viewers with
+    /// color vision deficiencies.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Gradient;
+    ///
+    /// let cividis = Gradient::cividis();
+    /// assert_eq!(cividis.at(0.0).hex(), "#00204d");
+    /// assert_eq!(cividis.at(1.0).hex(), "#ffea46");
+    /// ```
+    pub fn cividis() -> Self {
+        colormap(&colormaps::CIVIDIS)
+    }
+}
+
+fn colormap(samples: &[(f64, &str)]) -> Gradient {
+    let stops = samples
+        .iter()
+        .map(|&(position, hex)| (position, Color::from_str(hex).unwrap()))
+        .collect();
+    Gradient::new(stops)
+        .unwrap()
+        .space(InterpolationSpace::Srgb)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_colormaps() {
+        for gradient in [
+            Gradient::viridis(),
+            Gradient::magma(),
+            Gradient::inferno(),
+            Gradient::plasma(),
+            Gradient::cividis(),
+        ] {
+            assert_eq!(gradient.domain(), (0.0, 1.0));
+
+            // the lightness of the maps keeps going up
+            let lightness = gradient
+                .colors(32)
+                .iter()
+                .map(|color| color.vec_of(ColorSpace::Lab)[0])
+                .collect::<Vec<_>>();
+            assert!(lightness.windows(2).all(|w| w[0] < w[1]));
+        }
+
+        assert_eq!(Gradient::viridis().at(0.25).hex(), "#3b528b");
+        assert_eq!(Gradient::magma().at(0.0).hex(), "#000004");
+        assert_eq!(Gradient::inferno().at(1.0).hex(), "#fcffa4");
+        assert_eq!(Gradient::plasma().at(0.75).hex(), "#f89540");
+        assert_eq!(Gradient::cividis().at(0.5).hex(), "#7d7c76");
+    }
+}
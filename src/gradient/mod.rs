@@ -0,0 +1,323 @@
+pub mod bezier;
+#[cfg(feature = "colormaps")]
+pub mod colormaps;
+pub mod scale;
+pub mod stringify;
+
+use crate::{
+    parser::gradient::parse_linear_gradient, Color, Error, HueInterpolation, InterpolationSpace,
+};
+use alloc::{string::ToString, vec, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A color gradient made of color stops at positions along a line.
+///
+/// Colors between two stops are interpolated in OKLab by default, like CSS gradients.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "RawGradient")
+)]
+pub struct Gradient {
+    stops: Vec<(f64, Color)>,
+    space: InterpolationSpace,
+    hue: HueInterpolation,
+}
+
+/// The serialized fields of a [`Gradient`], checked by [`Gradient::new`] when deserializing.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawGradient {
+    stops: Vec<(f64, Color)>,
+    space: InterpolationSpace,
+    hue: HueInterpolation,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawGradient> for Gradient {
+    type Error = Error;
+    fn try_from(raw: RawGradient) -> Result<Self, Error> {
+        Ok(Gradient::new(raw.stops)?.space(raw.space).hue(raw.hue))
+    }
+}
+
+impl Gradient {
+    /// Creates a new [`Gradient`] from color stops.
+    ///
+    /// The stops are sorted by position. Several stops at the same position make a hard edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `stops` - The positions and colors of the stops, at least one is required.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Gradient};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     (0.0, color!(#f00)),
+    ///     (0.5, color!(#fff)),
+    ///     (1.0, color!(#00f)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(gradient.at(0.5).hex(), "#fff");
+    /// ```
+    pub fn new(mut stops: Vec<(f64, Color)>) -> Result<Self, Error> {
+        if stops.is_empty() {
+            return Err(Error::InvalidParamsError(
+                "gradient must have at least one color stop".to_string(),
+            ));
+        }
+        if stops.iter().any(|(position, _)| !position.is_finite()) {
+            return Err(Error::InvalidParamsError(
+                "color stop positions must be finite".to_string(),
+            ));
+        }
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(Gradient {
+            stops,
+            space: InterpolationSpace::default(),
+            hue: HueInterpolation::default(),
+        })
+    }
+    /// Sets the color space in which the colors between stops are interpolated, OKLab by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Gradient, InterpolationSpace};
+    ///
+    /// let gradient = Gradient::new(vec![(0.0, color!(#f00)), (1.0, color!(#00f))])
+    ///     .unwrap()
+    ///     .space(InterpolationSpace::Srgb);
+    /// assert_eq!(gradient.at(0.5).hex(), "#800080");
+    /// ```
+    pub fn space(mut self, space: InterpolationSpace) -> Self {
+        self.space = space;
+        self
+    }
+    /// Sets the direction around the hue circle when interpolating in a polar color space,
+    /// the shorter arc by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Gradient, HueInterpolation, InterpolationSpace};
+    ///
+    /// let gradient = Gradient::new(vec![(0.0, color!(#f00)), (1.0, color!(#00f))])
+    ///     .unwrap()
+    ///     .space(InterpolationSpace::Hsl)
+    ///     .hue(HueInterpolation::Longer);
+    /// assert_eq!(gradient.at(0.5).hex(), "#0f0");
+    /// ```
+    pub fn hue(mut self, hue: HueInterpolation) -> Self {
+        self.hue = hue;
+        self
+    }
+    /// Parses a CSS `linear-gradient()` into its angle in degrees and a gradient.
+    ///
+    /// Stop positions must be percentages, they are mapped to `0.0..=1.0`, and missing ones are
+    /// filled in as in CSS. The color interpolation method (e.g. `in oklch longer hue`) is
+    /// supported. `to <side>` directions are converted to angles, corners are taken at 45 degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Gradient;
+    ///
+    /// let (angle, gradient) =
+    ///     Gradient::from_css_linear("linear-gradient(90deg, #f00 0%, rgba(0,0,255,.5) 100%)").unwrap();
+    /// assert_eq!(angle, 90.0);
+    /// assert_eq!(gradient.domain(), (0.0, 1.0));
+    /// assert_eq!(gradient.at(1.0).hex(), "#0000ff80");
+    /// ```
+    pub fn from_css_linear(input: &str) -> Result<(f64, Self), Error> {
+        parse_linear_gradient(input)
+    }
+    /// The positions and colors of the stops, sorted by position.
+    pub fn stops(&self) -> &[(f64, Color)] {
+        &self.stops
+    }
+    /// The positions of the first and last stops.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Gradient};
+    ///
+    /// let gradient = Gradient::new(vec![(10.0, color!(#f00)), (-10.0, color!(#00f))]).unwrap();
+    /// assert_eq!(gradient.domain(), (-10.0, 10.0));
+    /// ```
+    pub fn domain(&self) -> (f64, f64) {
+        (self.stops[0].0, self.stops[self.stops.len() - 1].0)
+    }
+    /// Gets the color of the gradient at a position.
+    ///
+    /// Positions outside of the domain get the color of the nearest end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Gradient};
+    ///
+    /// let gradient = Gradient::new(vec![(0.0, color!(#000)), (1.0, color!(#fff))]).unwrap();
+    /// assert_eq!(gradient.at(0.5).hex(), "#636363");
+    /// assert_eq!(gradient.at(-1.0).hex(), "#000");
+    /// assert_eq!(gradient.at(2.0).hex(), "#fff");
+    /// ```
+    pub fn at(&self, t: f64) -> Color {
+        let (start, end) = self.domain();
+        if t.is_nan() || t <= start {
+            return self.stops[0].1;
+        }
+        if t >= end {
+            return self.stops[self.stops.len() - 1].1;
+        }
+        // the first stop after the position, the last one wins at a hard edge
+        let index = self.stops.partition_point(|(position, _)| *position <= t);
+        let (p1, color1) = self.stops[index - 1];
+        let (p2, color2) = self.stops[index];
+        color1.mix_in_with_hue(&color2, self.space, self.hue, (t - p1) / (p2 - p1))
+    }
+    /// Samples `n` evenly spaced colors of the gradient, from the start to the end of its domain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Gradient};
+    ///
+    /// let gradient = Gradient::new(vec![(0.0, color!(#f00)), (1.0, color!(#00f))]).unwrap();
+    /// let colors = gradient.colors(3);
+    /// assert_eq!(colors.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#f00", "#8c53a2", "#00f"]);
+    /// ```
+    pub fn colors(&self, n: usize) -> Vec<Color> {
+        let (start, end) = self.domain();
+        match n {
+            0 => vec![],
+            1 => vec![self.at(start)],
+            _ => (0..n)
+                .map(|i| self.at(start + (end - start) * i as f64 / (n - 1) as f64))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_gradient_new() {
+        assert!(Gradient::new(vec![]).is_err());
+        assert!(Gradient::new(vec![(f64::NAN, color!(#f00))]).is_err());
+        assert!(Gradient::new(vec![(f64::INFINITY, color!(#f00))]).is_err());
+
+        let gradient = Gradient::new(vec![(1.0, color!(#00f)), (0.0, color!(#f00))]).unwrap();
+        assert_eq!(
+            gradient.stops(),
+            &[(0.0, color!(#f00)), (1.0, color!(#00f))]
+        );
+        assert_eq!(gradient.domain(), (0.0, 1.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_gradient_serde() {
+        let gradient = Gradient::new(vec![(0.0, color!(#f00)), (1.0, color!(#00f))])
+            .unwrap()
+            .space(InterpolationSpace::Hsl);
+        let json = serde_json::to_string(&gradient).unwrap();
+        assert_eq!(serde_json::from_str::<Gradient>(&json).unwrap(), gradient);
+
+        let unsorted = r##"{"stops":[[1.0,"#00f"],[0.0,"#f00"]],"space":"Hsl","hue":"Shorter"}"##;
+        assert_eq!(
+            serde_json::from_str::<Gradient>(unsorted).unwrap(),
+            gradient
+        );
+
+        let empty = r#"{"stops":[],"space":"Oklab","hue":"Shorter"}"#;
+        assert!(serde_json::from_str::<Gradient>(empty).is_err());
+    }
+
+    #[test]
+    fn test_gradient_at() {
+        let gradient = Gradient::new(vec![
+            (0.0, color!(#f00)),
+            (0.5, color!(#fff)),
+            (1.0, color!(rgba(0, 0, 255, 0.5))),
+        ])
+        .unwrap();
+        assert_eq!(gradient.at(0.0).hex(), "#f00");
+        assert_eq!(
+            gradient.at(0.25),
+            color!(#f00).mix_in(&color!(#fff), InterpolationSpace::Oklab, 0.5)
+        );
+        assert_eq!(gradient.at(0.5).hex(), "#fff");
+        assert_eq!(gradient.at(0.75).alpha(), 0.75);
+        assert_eq!(gradient.at(1.0).hex(), "#0000ff80");
+        assert_eq!(gradient.at(f64::NAN).hex(), "#f00");
+
+        // a single stop is a solid color
+        let gradient = Gradient::new(vec![(0.5, color!(#0f0))]).unwrap();
+        assert_eq!(gradient.at(0.0).hex(), "#0f0");
+        assert_eq!(gradient.at(1.0).hex(), "#0f0");
+        assert_eq!(gradient.colors(2).len(), 2);
+    }
+
+    #[test]
+    fn test_gradient_hard_edge() {
+        let gradient = Gradient::new(vec![
+            (0.0, color!(#f00)),
+            (0.5, color!(#f00)),
+            (0.5, color!(#00f)),
+            (1.0, color!(#00f)),
+        ])
+        .unwrap();
+        assert_eq!(gradient.at(0.49).hex(), "#f00");
+        assert_eq!(gradient.at(0.5).hex(), "#00f");
+        assert_eq!(gradient.at(0.51).hex(), "#00f");
+    }
+
+    #[test]
+    fn test_gradient_space() {
+        let stops = vec![(0.0, color!(#f00)), (1.0, color!(#0f0))];
+        for space in [
+            InterpolationSpace::Srgb,
+            InterpolationSpace::LinearRgb,
+            InterpolationSpace::Hsl,
+            InterpolationSpace::Lab,
+            InterpolationSpace::Lch,
+            InterpolationSpace::Oklab,
+            InterpolationSpace::Oklch,
+        ] {
+            let gradient = Gradient::new(stops.clone()).unwrap().space(space);
+            assert_eq!(
+                gradient.at(0.3),
+                color!(#f00).mix_in(&color!(#0f0), space, 0.3)
+            );
+        }
+
+        let gradient = Gradient::new(stops)
+            .unwrap()
+            .space(InterpolationSpace::Hsl)
+            .hue(HueInterpolation::Decreasing);
+        assert_eq!(gradient.at(0.5).hsl(), "hsl(240, 100%, 50%)");
+    }
+
+    #[test]
+    fn test_gradient_colors() {
+        let gradient = Gradient::new(vec![(0.0, color!(#000)), (100.0, color!(#fff))]).unwrap();
+        assert!(gradient.colors(0).is_empty());
+        assert_eq!(gradient.colors(1), vec![color!(#000)]);
+
+        let colors = gradient.colors(5);
+        assert_eq!(colors.len(), 5);
+        assert_eq!(colors[0].hex(), "#000");
+        assert_eq!(colors[2], gradient.at(50.0));
+        assert_eq!(colors[4].hex(), "#fff");
+    }
+}
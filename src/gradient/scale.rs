@@ -0,0 +1,316 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{Color, ColorSpace, Error, Gradient};
+use alloc::{format, vec, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Maps numbers of a domain to the colors of a [`Gradient`], like the scales of chroma.js.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{color, Gradient, Scale};
+///
+/// let gradient = Gradient::new(vec![(0.0, color!(#fff)), (1.0, color!(#f00))]).unwrap();
+/// let scale = Scale::new(gradient).domain([0.0, 100.0]).classes(5);
+///
+/// assert_eq!(scale.at(0.0).hex(), "#fff");
+/// assert_eq!(scale.at(15.0).hex(), "#fff");
+/// assert_eq!(scale.at(100.0).hex(), "#f00");
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "RawScale")
+)]
+pub struct Scale {
+    gradient: Gradient,
+    domain: [f64; 2],
+    classes: usize,
+    gamma: f64,
+    correct_lightness: bool,
+}
+
+/// The serialized fields of a [`Scale`], rebuilt with [`Scale::new`] when deserializing.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawScale {
+    gradient: Gradient,
+    domain: [f64; 2],
+    classes: usize,
+    gamma: f64,
+    correct_lightness: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawScale> for Scale {
+    type Error = Error;
+    fn try_from(raw: RawScale) -> Result<Self, Error> {
+        check_domain(raw.domain)?;
+        check_gamma(raw.gamma)?;
+        Ok(Scale::new(raw.gradient)
+            .domain(raw.domain)
+            .classes(raw.classes)
+            .gamma(raw.gamma)
+            .correct_lightness(raw.correct_lightness))
+    }
+}
+
+/// A domain must have two different, finite ends.
+fn check_domain(domain: [f64; 2]) -> Result<(), Error> {
+    if domain.iter().any(|v| !v.is_finite()) || domain[0] == domain[1] {
+        return Err(Error::InvalidParamsError(format!(
+            "scale domain must have two different finite ends, got {:?}",
+            domain
+        )));
+    }
+    Ok(())
+}
+
+/// A gamma must be a positive, finite number.
+fn check_gamma(gamma: f64) -> Result<(), Error> {
+    if !(gamma.is_finite() && gamma > 0.0) {
+        return Err(Error::InvalidParamsError(format!(
+            "scale gamma must be a positive number, got {}",
+            gamma
+        )));
+    }
+    Ok(())
+}
+
+impl Scale {
+    /// Creates a new [`Scale`] over a gradient, with the domain `0.0..=1.0`.
+    pub fn new(gradient: Gradient) -> Self {
+        Scale {
+            gradient,
+            domain: [0.0, 1.0],
+            classes: 0,
+            gamma: 1.0,
+            correct_lightness: false,
+        }
+    }
+    /// Sets the values mapped to the start and the end of the gradient.
+    ///
+    /// The end can be lower than the start to reverse the scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an end is not finite, or both ends are equal.
+    pub fn domain(mut self, domain: [f64; 2]) -> Self {
+        check_domain(domain).unwrap_or_else(|err| panic!("{}", err));
+        self.domain = domain;
+        self
+    }
+    /// Splits the domain into `n` equal classes that get one color each, 0 for a continuous scale.
+    pub fn classes(mut self, n: usize) -> Self {
+        self.classes = n;
+        self
+    }
+    /// Sets the gamma of a continuous scale, 1.0 by default.
+    ///
+    /// A gamma above 1.0 spends more of the domain on the start of the gradient,
+    /// below 1.0 on its end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the gamma is not a positive, finite number.
+    pub fn gamma(mut self, gamma: f64) -> Self {
+        check_gamma(gamma).unwrap_or_else(|err| panic!("{}", err));
+        self.gamma = gamma;
+        self
+    }
+    /// Sets whether the lightness of the colors changes linearly along the domain.
+    ///
+    /// Gradients through colors of very different lightness, e.g. yellow and blue, can change
+    /// lightness unevenly. The correction moves along the gradient so that the Lab lightness
+    /// goes evenly from the lightness of the first color to the one of the last color.
+    pub fn correct_lightness(mut self, correct: bool) -> Self {
+        self.correct_lightness = correct;
+        self
+    }
+    /// Gets the color of a value, values outside of the domain get the color of the nearest end.
+    pub fn at(&self, value: f64) -> Color {
+        let [min, max] = self.domain;
+        let mut t = ((value - min) / (max - min)).clamp(0.0, 1.0);
+        if t.is_nan() {
+            t = 0.0;
+        }
+        t = match self.classes {
+            0 => t.powf(self.gamma),
+            1 => 0.5,
+            n => ((t * n as f64).floor() as usize).min(n - 1) as f64 / (n - 1) as f64,
+        };
+        if self.correct_lightness {
+            t = self.lightness_corrected(t);
+        }
+        self.sample(t)
+    }
+    /// Samples `n` evenly spaced colors of the domain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Gradient, Scale};
+    ///
+    /// let gradient = Gradient::new(vec![(0.0, color!(#000)), (1.0, color!(#fff))]).unwrap();
+    /// let colors = Scale::new(gradient).gamma(2.0).colors(3);
+    /// assert_eq!(colors.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#000", "#222", "#fff"]);
+    /// ```
+    pub fn colors(&self, n: usize) -> Vec<Color> {
+        let [min, max] = self.domain;
+        match n {
+            0 => vec![],
+            1 => vec![self.at(min)],
+            _ => (0..n)
+                .map(|i| self.at(min + (max - min) * i as f64 / (n - 1) as f64))
+                .collect(),
+        }
+    }
+
+    /// Color at a fraction of the gradient.
+    fn sample(&self, t: f64) -> Color {
+        let (start, end) = self.gradient.domain();
+        self.gradient.at(start + (end - start) * t)
+    }
+    fn lightness(&self, t: f64) -> f64 {
+        self.sample(t).vec_of(ColorSpace::Lab)[0]
+    }
+    /// Finds the fraction of the gradient with the linearly interpolated lightness by bisection.
+    fn lightness_corrected(&self, t: f64) -> f64 {
+        let (l0, l1) = (self.lightness(0.0), self.lightness(1.0));
+        let target = l0 + (l1 - l0) * t;
+        let increasing = l1 > l0;
+        let (mut low, mut high) = (0.0, 1.0);
+        let mut mid = t;
+        for _ in 0..20 {
+            let l = self.lightness(mid);
+            if (l - target).abs() < 0.01 {
+                break;
+            }
+            if (l < target) == increasing {
+                low = mid;
+            } else {
+                high = mid;
+            }
+            mid = (low + high) / 2.0;
+        }
+        mid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::vec::Vec;
+
+    fn black_white() -> Gradient {
+        Gradient::new(vec![(0.0, color!(#000)), (1.0, color!(#fff))])
+            .unwrap()
+            .space(InterpolationSpace::Srgb)
+    }
+
+    #[test]
+    fn test_scale_domain() {
+        let scale = Scale::new(black_white()).domain([10.0, 20.0]);
+        assert_eq!(scale.at(10.0).hex(), "#000");
+        assert_eq!(scale.at(15.0).hex(), "#808080");
+        assert_eq!(scale.at(20.0).hex(), "#fff");
+        assert_eq!(scale.at(0.0).hex(), "#000");
+        assert_eq!(scale.at(30.0).hex(), "#fff");
+        assert_eq!(scale.at(f64::NAN).hex(), "#000");
+
+        let scale = scale.domain([20.0, 10.0]);
+        assert_eq!(scale.at(10.0).hex(), "#fff");
+    }
+
+    #[test]
+    #[should_panic(expected = "scale domain must have two different finite ends")]
+    fn test_scale_domain_equal_ends() {
+        Scale::new(black_white()).domain([1.0, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "scale domain must have two different finite ends")]
+    fn test_scale_domain_nan() {
+        Scale::new(black_white()).domain([0.0, f64::NAN]);
+    }
+
+    #[test]
+    #[should_panic(expected = "scale gamma must be a positive number")]
+    fn test_scale_gamma_negative() {
+        Scale::new(black_white()).gamma(-1.0);
+    }
+
+    #[test]
+    fn test_scale_classes() {
+        let scale = Scale::new(black_white()).domain([0.0, 100.0]).classes(3);
+        let hexes = [0.0, 33.0, 34.0, 66.0, 67.0, 100.0]
+            .iter()
+            .map(|&v| scale.at(v).hex())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hexes,
+            vec!["#000", "#000", "#808080", "#808080", "#fff", "#fff"]
+        );
+
+        assert_eq!(scale.clone().classes(1).at(0.0).hex(), "#808080");
+        assert_eq!(scale.classes(0).at(25.0).hex(), "#404040");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_scale_serde() {
+        let scale = Scale::new(black_white()).domain([0.0, 100.0]).gamma(2.0);
+        let json = serde_json::to_string(&scale).unwrap();
+        assert_eq!(serde_json::from_str::<Scale>(&json).unwrap(), scale);
+
+        for invalid in [
+            json.replace(r#""gamma":2.0"#, r#""gamma":0.0"#),
+            json.replace(r#""gamma":2.0"#, r#""gamma":-1.0"#),
+            json.replace(r#""domain":[0.0,100.0]"#, r#""domain":[0.0,0.0]"#),
+            json.replace(
+                r##""stops":[[0.0,"#000000"],[1.0,"#ffffff"]]"##,
+                r#""stops":[]"#,
+            ),
+        ] {
+            assert_ne!(invalid, json);
+            assert!(
+                serde_json::from_str::<Scale>(&invalid).is_err(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_scale_gamma() {
+        let scale = Scale::new(black_white()).gamma(2.0);
+        assert_eq!(scale.at(0.5).hex(), "#404040");
+        let scale = Scale::new(black_white()).gamma(0.5);
+        assert_eq!(scale.at(0.25).hex(), "#808080");
+    }
+
+    #[test]
+    fn test_scale_correct_lightness() {
+        let gradient = Gradient::new(vec![
+            (0.0, color!(#ff0)),
+            (0.5, color!(#008ae5)),
+            (1.0, color!(#000)),
+        ])
+        .unwrap();
+        let scale = Scale::new(gradient).correct_lightness(true);
+        let lightness = scale
+            .colors(5)
+            .iter()
+            .map(|color| color.vec_of(ColorSpace::Lab)[0])
+            .collect::<Vec<_>>();
+        // OKLab interpolation rounds the channels, which moves the lightness a little
+        let step = (lightness[4] - lightness[0]) / 4.0;
+        for (i, l) in lightness.iter().enumerate() {
+            assert!((l - (lightness[0] + step * i as f64)).abs() < 0.5);
+        }
+    }
+}
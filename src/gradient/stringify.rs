@@ -0,0 +1,170 @@
+use crate::{utils::round, Gradient, HueInterpolation, InterpolationSpace};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+impl Gradient {
+    /// `linear-gradient()` string of the gradient.
+    ///
+    /// Stop positions are written as percentages of the domain. The color interpolation method
+    /// is only written if it is not the default OKLab.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The direction of the gradient line in degrees, 0 points up and 90 to the right.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Gradient, InterpolationSpace};
+    ///
+    /// let gradient = Gradient::new(vec![(0.0, color!(#f00)), (0.5, color!(#fff)), (1.0, color!(#00f))]).unwrap();
+    /// assert_eq!(gradient.to_css_linear(90.0), "linear-gradient(90deg, #f00 0%, #fff 50%, #00f 100%)");
+    ///
+    /// let gradient = gradient.space(InterpolationSpace::Srgb);
+    /// assert_eq!(gradient.to_css_linear(45.0), "linear-gradient(45deg in srgb, #f00 0%, #fff 50%, #00f 100%)");
+    /// ```
+    pub fn to_css_linear(&self, angle: f64) -> String {
+        let mut prelude = format!("{}deg", round(angle, 2));
+        if let Some(method) = self.interpolation_method() {
+            prelude.push(' ');
+            prelude.push_str(&method);
+        }
+        format!("linear-gradient({}, {})", prelude, self.css_stops())
+    }
+    /// `radial-gradient()` string of the gradient.
+    ///
+    /// Stop positions are written as percentages of the domain. The color interpolation method
+    /// is only written if it is not the default OKLab.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape, size and position of the gradient, e.g. `circle at top left`,
+    ///   written as is. `None` is an ellipse in the center of the box.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Gradient};
+    ///
+    /// let gradient = Gradient::new(vec![(0.0, color!(#fff)), (1.0, color!(#000))]).unwrap();
+    /// assert_eq!(gradient.to_css_radial(None), "radial-gradient(#fff 0%, #000 100%)");
+    /// assert_eq!(
+    ///     gradient.to_css_radial(Some("circle at top")),
+    ///     "radial-gradient(circle at top, #fff 0%, #000 100%)"
+    /// );
+    /// ```
+    pub fn to_css_radial(&self, shape: Option<&str>) -> String {
+        let prelude = [shape.map(str::to_string), self.interpolation_method()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if prelude.is_empty() {
+            format!("radial-gradient({})", self.css_stops())
+        } else {
+            format!("radial-gradient({}, {})", prelude, self.css_stops())
+        }
+    }
+
+    fn interpolation_method(&self) -> Option<String> {
+        let space = match self.space {
+            InterpolationSpace::Srgb => "srgb",
+            InterpolationSpace::LinearRgb => "srgb-linear",
+            InterpolationSpace::Hsl => "hsl",
+            InterpolationSpace::Lab => "lab",
+            InterpolationSpace::Lch => "lch",
+            InterpolationSpace::Oklab => return None,
+            InterpolationSpace::Oklch => "oklch",
+        };
+        let hue = match self.hue {
+            HueInterpolation::Shorter => return Some(format!("in {}", space)),
+            _ if self.space.hue_index().is_none() => return Some(format!("in {}", space)),
+            HueInterpolation::Longer => "longer",
+            HueInterpolation::Increasing => "increasing",
+            HueInterpolation::Decreasing => "decreasing",
+        };
+        Some(format!("in {} {} hue", space, hue))
+    }
+
+    fn css_stops(&self) -> String {
+        let (start, end) = self.domain();
+        self.stops
+            .iter()
+            .map(|(position, color)| {
+                let percent = if end > start {
+                    (position - start) / (end - start) * 100.0
+                } else {
+                    0.0
+                };
+                format!("{} {}%", color.hex(), round(percent, 2))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_to_css_linear() {
+        let gradient = Gradient::new(vec![
+            (-1.0, color!(rgba(255, 0, 0, 0.5))),
+            (0.2, color!(#0f0)),
+            (1.0, color!(#00f)),
+        ])
+        .unwrap();
+        assert_eq!(
+            gradient.to_css_linear(0.125),
+            "linear-gradient(0.13deg, #ff000080 0%, #0f0 60%, #00f 100%)"
+        );
+
+        let gradient = gradient
+            .space(InterpolationSpace::Oklch)
+            .hue(HueInterpolation::Longer);
+        assert_eq!(
+            gradient.to_css_linear(180.0),
+            "linear-gradient(180deg in oklch longer hue, #ff000080 0%, #0f0 60%, #00f 100%)"
+        );
+
+        // a rectangular space has no hue interpolation method
+        let gradient = gradient.space(InterpolationSpace::LinearRgb);
+        assert_eq!(
+            gradient.to_css_linear(180.0),
+            "linear-gradient(180deg in srgb-linear, #ff000080 0%, #0f0 60%, #00f 100%)"
+        );
+
+        let gradient = Gradient::new(vec![(0.5, color!(#f00))]).unwrap();
+        assert_eq!(
+            gradient.to_css_linear(90.0),
+            "linear-gradient(90deg, #f00 0%)"
+        );
+    }
+
+    #[test]
+    fn test_to_css_radial() {
+        let gradient = Gradient::new(vec![(0.0, color!(#fff)), (1.0, color!(#000))])
+            .unwrap()
+            .space(InterpolationSpace::Hsl);
+        assert_eq!(
+            gradient.to_css_radial(None),
+            "radial-gradient(in hsl, #fff 0%, #000 100%)"
+        );
+        assert_eq!(
+            gradient.to_css_radial(Some("circle")),
+            "radial-gradient(circle in hsl, #fff 0%, #000 100%)"
+        );
+    }
+
+    #[test]
+    fn test_css_round_trip() {
+        let css =
+            "linear-gradient(45deg in oklch decreasing hue, #f00 0%, #ff000080 25%, #00f 100%)";
+        let (angle, gradient) = Gradient::from_css_linear(css).unwrap();
+        assert_eq!(gradient.to_css_linear(angle), css);
+    }
+}
@@ -5,27 +5,70 @@
 //! [docs-rs]: https://img.shields.io/badge/docs.rs-66c2a5?style=for-the-badge&labelColor=555555&logo=docs.rs
 //!
 //! A rust crate for working with colors and color spaces.
+//!
+//! # `no_std`
+//!
+//! Without the default `std` feature the crate only needs `alloc`, and the `libm` feature
+//! provides the floating point math. Custom names of a [`NameRegistry`] can't be installed
+//! without `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// the test harness links `std`, whose float methods take over from `Float` in the tests
+#![cfg_attr(all(test, not(feature = "std")), allow(unused_imports))]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature is required for the floating point math");
 
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
 #[macro_use]
-extern crate lazy_static;
+extern crate std;
 
+pub mod bulk;
 mod color;
 mod color_calc;
 mod color_generator;
 mod color_ops;
+#[cfg(feature = "serde")]
+pub mod color_serde;
 mod color_space;
 mod conversion;
 mod data;
+pub mod dither;
 mod error;
+mod gradient;
+mod palette;
+pub mod palettes;
 mod parser;
+pub mod quantize;
 mod utils;
+#[cfg(feature = "wasm")]
+mod wasm;
 
-pub use color::Color;
+pub use color::classify::HueFamily;
+pub use color::formatter::{AlphaFormat, ColorFormatter, CssSyntax};
+pub use color::{color32::Color32, color_u8::ColorU8, Color};
+pub use color_calc::apca::*;
 pub use color_calc::blend::*;
+pub use color_calc::composite::{composite_premultiplied, CompositeOp};
 pub use color_calc::contrast_ratio::*;
 pub use color_calc::delta_e::*;
 pub use color_calc::distance::*;
+pub use color_generator::random_color::{Hue, Luminosity, RandomColor};
+pub use color_ops::cvd::{is_cvd_safe, CvdType};
+pub use color_ops::mix::{HueInterpolation, InterpolationSpace};
+pub use color_ops::saturate::GreyscaleStrategy;
 pub use color_space::ColorSpace;
-use data::chinese_color::CHINESE_COLOR_HASHMAP as CHINESE_COLOR;
-use data::w3cx11::W3CX11_HASHMAP as W3CX11;
+pub use conversion::cam16::{Cam16, Surround, ViewingConditions};
+pub use conversion::chromatic_adaptation::{AdaptationMethod, Illuminant};
+pub use conversion::hex::HexOrder;
+pub use conversion::ycbcr::YCbCrRange;
+pub use conversion::ypbpr::ColorMatrix;
+pub use data::{registry::NameRegistry, DesignSystem, NameSource};
 pub use error::Error;
+pub use gradient::{scale::Scale, Gradient};
+#[cfg(feature = "brewer")]
+pub use palette::brewer::{BrewerKind, BrewerScheme};
+#[cfg(feature = "json")]
+pub use palette::json::JsonPalette;
+pub use palette::{sort::SortKey, Palette};
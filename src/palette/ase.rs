@@ -0,0 +1,253 @@
+use crate::{utils::round, Color, Error, Palette};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+const SIGNATURE: &[u8; 4] = b"ASEF";
+const GROUP_START: u16 = 0xc001;
+const GROUP_END: u16 = 0xc002;
+const COLOR_ENTRY: u16 = 0x0001;
+/// The `normal` color type, neither global nor spot.
+const NORMAL: u16 = 2;
+
+impl Palette {
+    /// Adobe Swatch Exchange (`.ase`) file of the palette, as used by Photoshop and Illustrator.
+    ///
+    /// The colors are written as RGB swatches named by their CSS name or hex code. ASE files
+    /// have no alpha channel, the colors are written as opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette};
+    ///
+    /// let palette = Palette::new(vec![color!(#f00), color!(#00f)]);
+    /// let ase = palette.to_ase();
+    /// assert_eq!(&ase[..4], b"ASEF");
+    /// assert_eq!(Palette::from_ase(&ase).unwrap(), palette);
+    /// ```
+    pub fn to_ase(&self) -> Vec<u8> {
+        let mut ase = SIGNATURE.to_vec();
+        ase.extend_from_slice(&1u16.to_be_bytes());
+        ase.extend_from_slice(&0u16.to_be_bytes());
+        ase.extend_from_slice(&(self.len() as u32).to_be_bytes());
+
+        for color in self.iter() {
+            let name = Color::new(color.rgb[0], color.rgb[1], color.rgb[2], 1.0).name();
+            let name = name.encode_utf16().chain([0]).collect::<Vec<_>>();
+
+            let mut block = (name.len() as u16).to_be_bytes().to_vec();
+            for unit in name {
+                block.extend_from_slice(&unit.to_be_bytes());
+            }
+            block.extend_from_slice(b"RGB ");
+            for value in color.rgb {
+                block.extend_from_slice(&((value / 255.0) as f32).to_be_bytes());
+            }
+            block.extend_from_slice(&NORMAL.to_be_bytes());
+
+            ase.extend_from_slice(&COLOR_ENTRY.to_be_bytes());
+            ase.extend_from_slice(&(block.len() as u32).to_be_bytes());
+            ase.extend_from_slice(&block);
+        }
+        ase
+    }
+    /// Parses an Adobe Swatch Exchange (`.ase`) file into a palette.
+    ///
+    /// RGB, CMYK, Lab and gray swatches are supported, CMYK and Lab ones are converted with
+    /// [`Color::from_cmyk`] and [`Color::from_lab`]. Groups are flattened, and the swatch
+    /// names and color types (global, spot) are ignored.
+    pub fn from_ase(input: &[u8]) -> Result<Palette, Error> {
+        let mut reader = Reader { input, offset: 0 };
+        if reader.take(4)? != SIGNATURE {
+            return Err(Error::ColorParserError(
+                "ASE file must start with `ASEF`".to_string(),
+            ));
+        }
+        // version
+        reader.take(4)?;
+        let count = reader.u32()?;
+
+        let mut palette = Palette::default();
+        for _ in 0..count {
+            let kind = reader.u16()?;
+            let length = reader.u32()? as usize;
+            let block = reader.take(length)?;
+            match kind {
+                COLOR_ENTRY => palette.push(parse_color_entry(block)?),
+                GROUP_START | GROUP_END => {}
+                _ => {
+                    return Err(Error::ColorParserError(format!(
+                        "unknown ASE block type {:#06x}",
+                        kind
+                    )))
+                }
+            }
+        }
+        Ok(palette)
+    }
+}
+
+fn parse_color_entry(block: &[u8]) -> Result<Color, Error> {
+    let mut reader = Reader {
+        input: block,
+        offset: 0,
+    };
+    let name_length = reader.u16()? as usize;
+    reader.take(name_length * 2)?;
+    let model = reader.take(4)?;
+    // the values are 32-bit floats, the rounding drops their error after scaling to 0..=255
+    let color = match model {
+        b"RGB " => {
+            let [r, g, b] = [reader.f32()?, reader.f32()?, reader.f32()?]
+                .map(|v| round(v.clamp(0.0, 1.0) * 255.0, 3));
+            Color::new(r, g, b, 1.0)
+        }
+        b"CMYK" => {
+            let [c, m, y, k] = [reader.f32()?, reader.f32()?, reader.f32()?, reader.f32()?]
+                .map(|v| v.clamp(0.0, 1.0));
+            Color::from_cmyk(c, m, y, k)?
+        }
+        b"LAB " => {
+            let l = (reader.f32()? * 100.0).clamp(0.0, 100.0);
+            let a = reader.f32()?.clamp(-128.0, 127.0);
+            let b = reader.f32()?.clamp(-128.0, 127.0);
+            Color::from_lab(l, a, b)?
+        }
+        b"Gray" => {
+            let v = round(reader.f32()?.clamp(0.0, 1.0) * 255.0, 3);
+            Color::new(v, v, v, 1.0)
+        }
+        _ => {
+            return Err(Error::ColorParserError(format!(
+                "unknown ASE color model `{}`",
+                String::from_utf8_lossy(model)
+            )))
+        }
+    };
+    Ok(color)
+}
+
+/// Reads big-endian values from the bytes of an ASE file.
+struct Reader<'a> {
+    input: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        let end = self.offset.checked_add(n);
+        let bytes = end
+            .and_then(|end| self.input.get(self.offset..end))
+            .ok_or_else(|| Error::ColorParserError("unexpected end of ASE file".to_string()))?;
+        self.offset += n;
+        Ok(bytes)
+    }
+    fn u16(&mut self) -> Result<u16, Error> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+    fn u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+    fn f32(&mut self) -> Result<f64, Error> {
+        Ok(f32::from_be_bytes(self.take(4)?.try_into().unwrap()) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::vec::Vec;
+
+    /// A color entry block with a one letter name.
+    fn entry(model: &[u8; 4], values: &[f32]) -> Vec<u8> {
+        let mut block = vec![0, 2, 0, b'x', 0, 0];
+        block.extend_from_slice(model);
+        for value in values {
+            block.extend_from_slice(&value.to_be_bytes());
+        }
+        block.extend_from_slice(&[0, 0]);
+
+        let mut bytes = vec![0, 1];
+        bytes.extend_from_slice(&(block.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&block);
+        bytes
+    }
+
+    fn ase(blocks: &[Vec<u8>]) -> Vec<u8> {
+        let mut bytes = b"ASEF\x00\x01\x00\x00".to_vec();
+        bytes.extend_from_slice(&(blocks.len() as u32).to_be_bytes());
+        for block in blocks {
+            bytes.extend_from_slice(block);
+        }
+        bytes
+    }
+
+    #[test]
+    #[cfg(feature = "css-names")]
+    fn test_to_ase() {
+        let palette = Palette::new(vec![color!(#f00)]);
+        let mut expected = b"ASEF\x00\x01\x00\x00\x00\x00\x00\x01\x00\x01\x00\x00\x00\x1c".to_vec();
+        expected.extend_from_slice(b"\x00\x04\x00r\x00e\x00d\x00\x00RGB ");
+        expected.extend_from_slice(&1f32.to_be_bytes());
+        expected.extend_from_slice(&[0; 8]);
+        expected.extend_from_slice(&[0, 2]);
+        assert_eq!(palette.to_ase(), expected);
+
+        assert_eq!(
+            Palette::default().to_ase(),
+            b"ASEF\x00\x01\x00\x00\x00\x00\x00\x00"
+        );
+    }
+
+    #[test]
+    fn test_from_ase() {
+        let group_start = [0xc0, 0x01, 0, 0, 0, 4, 0, 1, 0, 0].to_vec();
+        let group_end = [0xc0, 0x02, 0, 0, 0, 0].to_vec();
+        let bytes = ase(&[
+            group_start,
+            entry(b"RGB ", &[0.0, 0.5, 1.0]),
+            entry(b"CMYK", &[0.0, 0.8, 0.4, 0.0]),
+            entry(b"LAB ", &[0.5429, 80.8, 69.89]),
+            entry(b"Gray", &[0.2]),
+            group_end,
+        ]);
+        let palette = Palette::from_ase(&bytes).unwrap();
+        assert_eq!(
+            palette.iter().map(|c| c.hex()).collect::<Vec<_>>(),
+            vec!["#0080ff", "#f39", "#f00", "#333"]
+        );
+
+        assert!(Palette::from_ase(b"").is_err());
+        assert!(Palette::from_ase(b"ASEX\x00\x01\x00\x00\x00\x00\x00\x00").is_err());
+        // more blocks than in the file
+        assert!(Palette::from_ase(b"ASEF\x00\x01\x00\x00\x00\x00\x00\x01").is_err());
+        assert!(Palette::from_ase(&ase(&[entry(b"HSB ", &[0.0, 0.0, 0.0])])).is_err());
+        let mut truncated = ase(&[entry(b"RGB ", &[0.0, 0.5, 1.0])]);
+        truncated.pop();
+        assert!(Palette::from_ase(&truncated).is_err());
+        // a block longer than the file
+        assert!(
+            Palette::from_ase(b"ASEF\x00\x01\x00\x00\x00\x00\x00\x01\x00\x01\xff\xff\xff\xff")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_reader_overflow() {
+        let mut reader = super::Reader {
+            input: &[0; 4],
+            offset: 2,
+        };
+        assert!(reader.take(usize::MAX).is_err());
+        assert_eq!(reader.take(2).unwrap(), &[0, 0]);
+    }
+
+    #[test]
+    fn test_ase_round_trip() {
+        let palette = Palette::new(palettes::tab20());
+        assert_eq!(Palette::from_ase(&palette.to_ase()).unwrap(), palette);
+    }
+}
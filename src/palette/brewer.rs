@@ -0,0 +1,328 @@
+use crate::{
+    data::{brewer, categorical},
+    Color, Error,
+};
+use alloc::{format, vec::Vec};
+use core::{ops::RangeInclusive, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kind of a [`BrewerScheme`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BrewerKind {
+    /// Ordered data going from low to high.
+    Sequential,
+    /// Ordered data with a critical midpoint, e.g. deviations from a mean.
+    Diverging,
+    /// Categories without an order.
+    Qualitative,
+}
+
+/// A [ColorBrewer](https://colorbrewer2.org) color scheme by Cynthia Brewer, designed for maps
+/// and charts.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BrewerScheme {
+    /// Blue to green, sequential.
+    BuGn,
+    /// Blue to purple, sequential.
+    BuPu,
+    /// Green to blue, sequential.
+    GnBu,
+    /// Orange to red, sequential.
+    OrRd,
+    /// Purple to blue to green, sequential.
+    PuBuGn,
+    /// Purple to blue, sequential.
+    PuBu,
+    /// Purple to red, sequential.
+    PuRd,
+    /// Red to purple, sequential.
+    RdPu,
+    /// Yellow to green to blue, sequential.
+    YlGnBu,
+    /// Yellow to green, sequential.
+    YlGn,
+    /// Yellow to orange to brown, sequential.
+    YlOrBr,
+    /// Yellow to orange to red, sequential.
+    YlOrRd,
+    /// Light to dark blue, sequential.
+    Blues,
+    /// Light to dark green, sequential.
+    Greens,
+    /// White to black, sequential.
+    Greys,
+    /// Light to dark orange, sequential.
+    Oranges,
+    /// Light to dark purple, sequential.
+    Purples,
+    /// Light to dark red, sequential.
+    Reds,
+    /// Brown to blue-green, diverging.
+    BrBG,
+    /// Pink to yellow-green, diverging.
+    PiYG,
+    /// Purple to green, diverging.
+    PRGn,
+    /// Orange to purple, diverging.
+    PuOr,
+    /// Red to blue, diverging.
+    RdBu,
+    /// Red to grey, diverging.
+    RdGy,
+    /// Red to yellow to blue, diverging.
+    RdYlBu,
+    /// Red to yellow to green, diverging.
+    RdYlGn,
+    /// Red to yellow to blue through the spectrum, diverging.
+    Spectral,
+    /// Accent colors, qualitative, up to 8 classes.
+    Accent,
+    /// Dark colors, qualitative, up to 8 classes.
+    Dark2,
+    /// Pairs of light and dark colors, qualitative, up to 12 classes.
+    Paired,
+    /// Pastel colors, qualitative, up to 9 classes.
+    Pastel1,
+    /// Pastel colors, qualitative, up to 8 classes.
+    Pastel2,
+    /// Saturated colors, qualitative, up to 9 classes.
+    Set1,
+    /// Soft colors, qualitative, up to 8 classes.
+    Set2,
+    /// Light colors, qualitative, up to 12 classes.
+    Set3,
+}
+
+impl BrewerScheme {
+    /// All the ColorBrewer schemes.
+    pub const ALL: [BrewerScheme; 35] = [
+        BrewerScheme::BuGn,
+        BrewerScheme::BuPu,
+        BrewerScheme::GnBu,
+        BrewerScheme::OrRd,
+        BrewerScheme::PuBuGn,
+        BrewerScheme::PuBu,
+        BrewerScheme::PuRd,
+        BrewerScheme::RdPu,
+        BrewerScheme::YlGnBu,
+        BrewerScheme::YlGn,
+        BrewerScheme::YlOrBr,
+        BrewerScheme::YlOrRd,
+        BrewerScheme::Blues,
+        BrewerScheme::Greens,
+        BrewerScheme::Greys,
+        BrewerScheme::Oranges,
+        BrewerScheme::Purples,
+        BrewerScheme::Reds,
+        BrewerScheme::BrBG,
+        BrewerScheme::PiYG,
+        BrewerScheme::PRGn,
+        BrewerScheme::PuOr,
+        BrewerScheme::RdBu,
+        BrewerScheme::RdGy,
+        BrewerScheme::RdYlBu,
+        BrewerScheme::RdYlGn,
+        BrewerScheme::Spectral,
+        BrewerScheme::Accent,
+        BrewerScheme::Dark2,
+        BrewerScheme::Paired,
+        BrewerScheme::Pastel1,
+        BrewerScheme::Pastel2,
+        BrewerScheme::Set1,
+        BrewerScheme::Set2,
+        BrewerScheme::Set3,
+    ];
+
+    /// The kind of data the scheme is designed for.
+    pub fn kind(&self) -> BrewerKind {
+        match self {
+            BrewerScheme::BuGn => BrewerKind::Sequential,
+            BrewerScheme::BuPu => BrewerKind::Sequential,
+            BrewerScheme::GnBu => BrewerKind::Sequential,
+            BrewerScheme::OrRd => BrewerKind::Sequential,
+            BrewerScheme::PuBuGn => BrewerKind::Sequential,
+            BrewerScheme::PuBu => BrewerKind::Sequential,
+            BrewerScheme::PuRd => BrewerKind::Sequential,
+            BrewerScheme::RdPu => BrewerKind::Sequential,
+            BrewerScheme::YlGnBu => BrewerKind::Sequential,
+            BrewerScheme::YlGn => BrewerKind::Sequential,
+            BrewerScheme::YlOrBr => BrewerKind::Sequential,
+            BrewerScheme::YlOrRd => BrewerKind::Sequential,
+            BrewerScheme::Blues => BrewerKind::Sequential,
+            BrewerScheme::Greens => BrewerKind::Sequential,
+            BrewerScheme::Greys => BrewerKind::Sequential,
+            BrewerScheme::Oranges => BrewerKind::Sequential,
+            BrewerScheme::Purples => BrewerKind::Sequential,
+            BrewerScheme::Reds => BrewerKind::Sequential,
+            BrewerScheme::BrBG => BrewerKind::Diverging,
+            BrewerScheme::PiYG => BrewerKind::Diverging,
+            BrewerScheme::PRGn => BrewerKind::Diverging,
+            BrewerScheme::PuOr => BrewerKind::Diverging,
+            BrewerScheme::RdBu => BrewerKind::Diverging,
+            BrewerScheme::RdGy => BrewerKind::Diverging,
+            BrewerScheme::RdYlBu => BrewerKind::Diverging,
+            BrewerScheme::RdYlGn => BrewerKind::Diverging,
+            BrewerScheme::Spectral => BrewerKind::Diverging,
+            _ => BrewerKind::Qualitative,
+        }
+    }
+    /// The recommended numbers of classes of the scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::BrewerScheme;
+    ///
+    /// assert_eq!(BrewerScheme::Blues.class_range(), 3..=9);
+    /// assert_eq!(BrewerScheme::RdYlBu.class_range(), 3..=11);
+    /// assert_eq!(BrewerScheme::Set3.class_range(), 3..=12);
+    /// ```
+    pub fn class_range(&self) -> RangeInclusive<usize> {
+        3..=self.hexes().len() + 2
+    }
+    /// The colors of the scheme for a number of classes.
+    ///
+    /// Sequential and diverging schemes have a different set of colors for each number of
+    /// classes. Qualitative schemes take their first colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of classes, see [`BrewerScheme::class_range`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::BrewerScheme;
+    ///
+    /// let colors = BrewerScheme::RdYlBu.colors(5).unwrap();
+    /// let hexes = colors.iter().map(|c| c.hex()).collect::<Vec<_>>();
+    /// assert_eq!(hexes, vec!["#d7191c", "#fdae61", "#ffffbf", "#abd9e9", "#2c7bb6"]);
+    ///
+    /// assert!(BrewerScheme::Blues.colors(12).is_err());
+    /// ```
+    pub fn colors(&self, n: usize) -> Result<Vec<Color>, Error> {
+        let range = self.class_range();
+        if !range.contains(&n) {
+            return Err(Error::InvalidParamsError(format!(
+                "{:?} has {} to {} classes, got {}",
+                self,
+                range.start(),
+                range.end(),
+                n
+            )));
+        }
+        Ok(self.hexes()[n - 3]
+            .iter()
+            .map(|hex| Color::from_str(hex).unwrap())
+            .collect())
+    }
+
+    /// The colors of the scheme for each number of classes, from 3 on.
+    fn hexes(&self) -> Vec<&'static [&'static str]> {
+        match self {
+            BrewerScheme::BuGn => brewer::BU_GN.to_vec(),
+            BrewerScheme::BuPu => brewer::BU_PU.to_vec(),
+            BrewerScheme::GnBu => brewer::GN_BU.to_vec(),
+            BrewerScheme::OrRd => brewer::OR_RD.to_vec(),
+            BrewerScheme::PuBuGn => brewer::PU_BU_GN.to_vec(),
+            BrewerScheme::PuBu => brewer::PU_BU.to_vec(),
+            BrewerScheme::PuRd => brewer::PU_RD.to_vec(),
+            BrewerScheme::RdPu => brewer::RD_PU.to_vec(),
+            BrewerScheme::YlGnBu => brewer::YL_GN_BU.to_vec(),
+            BrewerScheme::YlGn => brewer::YL_GN.to_vec(),
+            BrewerScheme::YlOrBr => brewer::YL_OR_BR.to_vec(),
+            BrewerScheme::YlOrRd => brewer::YL_OR_RD.to_vec(),
+            BrewerScheme::Blues => brewer::BLUES.to_vec(),
+            BrewerScheme::Greens => brewer::GREENS.to_vec(),
+            BrewerScheme::Greys => brewer::GREYS.to_vec(),
+            BrewerScheme::Oranges => brewer::ORANGES.to_vec(),
+            BrewerScheme::Purples => brewer::PURPLES.to_vec(),
+            BrewerScheme::Reds => brewer::REDS.to_vec(),
+            BrewerScheme::BrBG => brewer::BR_BG.to_vec(),
+            BrewerScheme::PiYG => brewer::PI_YG.to_vec(),
+            BrewerScheme::PRGn => brewer::PR_GN.to_vec(),
+            BrewerScheme::PuOr => brewer::PU_OR.to_vec(),
+            BrewerScheme::RdBu => brewer::RD_BU.to_vec(),
+            BrewerScheme::RdGy => brewer::RD_GY.to_vec(),
+            BrewerScheme::RdYlBu => brewer::RD_YL_BU.to_vec(),
+            BrewerScheme::RdYlGn => brewer::RD_YL_GN.to_vec(),
+            BrewerScheme::Spectral => brewer::SPECTRAL.to_vec(),
+            BrewerScheme::Accent => qualitative(&brewer::ACCENT),
+            BrewerScheme::Dark2 => qualitative(&brewer::DARK2),
+            BrewerScheme::Paired => qualitative(&brewer::PAIRED),
+            BrewerScheme::Pastel1 => qualitative(&brewer::PASTEL1),
+            BrewerScheme::Pastel2 => qualitative(&brewer::PASTEL2),
+            BrewerScheme::Set1 => qualitative(&categorical::SET1),
+            BrewerScheme::Set2 => qualitative(&brewer::SET2),
+            BrewerScheme::Set3 => qualitative(&brewer::SET3),
+        }
+    }
+}
+
+/// Qualitative schemes take their first colors, list the prefixes like the other schemes.
+fn qualitative(hexes: &'static [&'static str]) -> Vec<&'static [&'static str]> {
+    (3..=hexes.len()).map(|n| &hexes[..n]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_brewer_colors() {
+        for scheme in BrewerScheme::ALL {
+            for n in scheme.class_range() {
+                assert_eq!(scheme.colors(n).unwrap().len(), n);
+            }
+            assert!(scheme.colors(2).is_err());
+            assert!(scheme.colors(scheme.class_range().end() + 1).is_err());
+        }
+
+        let colors = BrewerScheme::Blues.colors(3).unwrap();
+        assert_eq!(colors[0].hex(), "#deebf7");
+        assert_eq!(colors[2].hex(), "#3182bd");
+
+        // qualitative schemes take their first colors
+        let set1 = BrewerScheme::Set1.colors(9).unwrap();
+        assert_eq!(BrewerScheme::Set1.colors(4).unwrap(), set1[..4]);
+    }
+
+    #[test]
+    fn test_brewer_kind() {
+        assert_eq!(BrewerScheme::YlOrRd.kind(), BrewerKind::Sequential);
+        assert_eq!(BrewerScheme::Spectral.kind(), BrewerKind::Diverging);
+        assert_eq!(BrewerScheme::Paired.kind(), BrewerKind::Qualitative);
+
+        // sequential schemes get darker, diverging schemes are lightest in the middle
+        for scheme in BrewerScheme::ALL {
+            let lightness = scheme
+                .colors(7)
+                .unwrap()
+                .iter()
+                .map(|color| color.vec_of(ColorSpace::Lab)[0])
+                .collect::<Vec<_>>();
+            match scheme.kind() {
+                BrewerKind::Sequential => {
+                    assert!(lightness.windows(2).all(|w| w[0] > w[1]), "{:?}", scheme)
+                }
+                BrewerKind::Diverging => {
+                    assert!(
+                        lightness[..4].windows(2).all(|w| w[0] < w[1]),
+                        "{:?}",
+                        scheme
+                    );
+                    assert!(
+                        lightness[3..].windows(2).all(|w| w[0] > w[1]),
+                        "{:?}",
+                        scheme
+                    );
+                }
+                BrewerKind::Qualitative => {}
+            }
+        }
+    }
+}
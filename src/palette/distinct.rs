@@ -0,0 +1,191 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{
+    color_calc::delta_e::ciede2000, conversion::lab::rgb2lab, delta_e, utils::rng::SplitMix64,
+    Color, Error, Palette,
+};
+use alloc::{format, vec, vec::Vec};
+use core::ops::RangeInclusive;
+
+/// Number of values per channel of the sRGB grid the colors are picked from.
+const STEPS: usize = 18;
+
+impl Palette {
+    /// Generates `n` colors that are as different from each other as possible.
+    ///
+    /// The colors are picked one by one from a grid over the sRGB cube, each one the farthest
+    /// by CIEDE2000 [`delta_e`] from all colors picked before (farthest-point sampling),
+    /// starting from the most colorful. The result is always the same, see [`Palette::distinct_with`] to seed it or
+    /// to limit the lightness.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Palette;
+    ///
+    /// let palette = Palette::distinct(8);
+    /// assert_eq!(palette.len(), 8);
+    /// assert!(palette.min_delta_e() > 30.0);
+    /// ```
+    pub fn distinct(n: usize) -> Palette {
+        Palette::distinct_with(n, 0.0..=100.0, None).unwrap()
+    }
+    /// Generates `n` colors that are as different from each other as possible, with a Lab
+    /// lightness in a range, see [`Palette::distinct`].
+    ///
+    /// With a seed, the grid is shifted randomly and the first color is a random one, so each
+    /// seed gives other colors.
+    ///
+    /// # Errors
+    ///
+    /// If less than `n` colors of the grid are in the lightness range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{ColorSpace, Palette};
+    ///
+    /// let palette = Palette::distinct_with(5, 40.0..=70.0, Some(7)).unwrap();
+    /// assert!(palette.iter().all(|c| (40.0..=70.0).contains(&c.vec_of(ColorSpace::Lab)[0])));
+    /// assert_eq!(palette, Palette::distinct_with(5, 40.0..=70.0, Some(7)).unwrap());
+    /// ```
+    pub fn distinct_with(
+        n: usize,
+        lightness: RangeInclusive<f64>,
+        seed: Option<u64>,
+    ) -> Result<Palette, Error> {
+        let mut rng = seed.map(SplitMix64::new);
+        let step = 255.0 / (STEPS - 1) as f64;
+        let mut candidates = Vec::with_capacity(STEPS.pow(3));
+        for r in 0..STEPS {
+            for g in 0..STEPS {
+                for b in 0..STEPS {
+                    let rgb = [r, g, b].map(|v| match rng.as_mut() {
+                        Some(rng) => (v as f64 * step + (rng.next_f64() - 0.5) * step)
+                            .clamp(0.0, 255.0)
+                            .round(),
+                        None => (v as f64 * step).round(),
+                    });
+                    let lab = rgb2lab(&rgb);
+                    if lightness.contains(&lab[0]) {
+                        candidates.push((rgb, lab));
+                    }
+                }
+            }
+        }
+        if candidates.len() < n {
+            return Err(Error::InvalidParamsError(format!(
+                "only {} colors have a lightness in {:?}, {} requested",
+                candidates.len(),
+                lightness,
+                n
+            )));
+        }
+        if n == 0 {
+            return Ok(Palette::default());
+        }
+
+        let first = match rng.as_mut() {
+            Some(rng) => (rng.next_u64() % candidates.len() as u64) as usize,
+            None => (0..candidates.len())
+                .max_by(|&i, &j| chroma(&candidates[i].1).total_cmp(&chroma(&candidates[j].1)))
+                .unwrap(),
+        };
+        // the distance of each candidate to the nearest picked color
+        let mut nearest = vec![f64::INFINITY; candidates.len()];
+        let mut picked = vec![first];
+        while picked.len() < n {
+            let last = &candidates[picked[picked.len() - 1]].1;
+            for (distance, (_, lab)) in nearest.iter_mut().zip(&candidates) {
+                *distance = distance.min(ciede2000(last, lab));
+            }
+            let next = (0..candidates.len())
+                .max_by(|&i, &j| nearest[i].total_cmp(&nearest[j]))
+                .unwrap();
+            picked.push(next);
+        }
+        Ok(picked
+            .into_iter()
+            .map(|i| {
+                let [r, g, b] = candidates[i].0;
+                Color::new(r, g, b, 1.0)
+            })
+            .collect())
+    }
+    /// The smallest CIEDE2000 [`delta_e`] between two colors of the palette,
+    /// infinity for less than two colors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette};
+    ///
+    /// let palette = Palette::new(vec![color!(#000), color!(#fff), color!(#010101)]);
+    /// assert!(palette.min_delta_e() < 1.0);
+    /// ```
+    pub fn min_delta_e(&self) -> f64 {
+        let colors = self.colors();
+        colors
+            .iter()
+            .enumerate()
+            .flat_map(|(i, color1)| {
+                colors[i + 1..]
+                    .iter()
+                    .map(move |color2| delta_e(color1, color2))
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+fn chroma(lab: &[f64]) -> f64 {
+    lab[1].hypot(lab[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_distinct() {
+        assert!(Palette::distinct(0).is_empty());
+
+        let palette = Palette::distinct(12);
+        assert_eq!(palette.len(), 12);
+        assert_eq!(palette, Palette::distinct(12));
+        // the first colors are the first ones of a longer palette
+        assert_eq!(palette.colors()[..6], Palette::distinct(6).colors()[..]);
+        assert!(palette.min_delta_e() > 25.0);
+        assert!(palette.min_delta_e() > Palette::new(palettes::tab20()).min_delta_e());
+    }
+
+    #[test]
+    fn test_distinct_with() {
+        let palette = Palette::distinct_with(6, 60.0..=80.0, None).unwrap();
+        for color in palette.iter() {
+            let l = color.vec_of(ColorSpace::Lab)[0];
+            assert!((60.0..=80.0).contains(&l));
+        }
+
+        let palette1 = Palette::distinct_with(6, 0.0..=100.0, Some(1)).unwrap();
+        let palette2 = Palette::distinct_with(6, 0.0..=100.0, Some(2)).unwrap();
+        assert_ne!(palette1, palette2);
+        assert_eq!(
+            palette1,
+            Palette::distinct_with(6, 0.0..=100.0, Some(1)).unwrap()
+        );
+
+        assert!(Palette::distinct_with(1, 101.0..=102.0, None).is_err());
+        assert!(Palette::distinct_with(0, 101.0..=102.0, None).is_ok());
+    }
+
+    #[test]
+    fn test_min_delta_e() {
+        assert_eq!(Palette::default().min_delta_e(), f64::INFINITY);
+        assert_eq!(
+            Palette::new(vec![color!(#f00)]).min_delta_e(),
+            f64::INFINITY
+        );
+        let palette = Palette::new(vec![color!(#000), color!(#fff), color!(#000)]);
+        assert_eq!(palette.min_delta_e(), 0.0);
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{Color, Error, Palette};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+impl Palette {
+    /// [GIMP palette](https://developer.gimp.org/core/standards/gpl/) (`.gpl`) file of the palette.
+    ///
+    /// Each color is written with its CSS name or its hex code as swatch name. GIMP palettes
+    /// have no alpha channel, the colors are written as opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette};
+    ///
+    /// let palette = Palette::new(vec![color!(#f00), color!(#123456)]);
+    /// assert_eq!(
+    ///     palette.to_gpl("Example"),
+    ///     "GIMP Palette\nName: Example\n#\n255   0   0\tred\n 18  52  86\t#123456\n"
+    /// );
+    /// ```
+    pub fn to_gpl(&self, name: &str) -> String {
+        let mut gpl = format!("GIMP Palette\nName: {}\n#\n", name);
+        for color in self.iter() {
+            let [r, g, b] = color.rgb.map(|v| v.round() as u8);
+            let opaque = Color::new(r, g, b, 1.0);
+            gpl.push_str(&format!("{:>3} {:>3} {:>3}\t{}\n", r, g, b, opaque.name()));
+        }
+        gpl
+    }
+    /// Parses a [GIMP palette](https://developer.gimp.org/core/standards/gpl/) (`.gpl`) file
+    /// into its name, if it has one, and a palette.
+    ///
+    /// The swatch names and the `Columns` header are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Palette;
+    ///
+    /// let gpl = "GIMP Palette\nName: Example\nColumns: 4\n# comment\n255 0 0 Red\n  0 0 255\n";
+    /// let (name, palette) = Palette::from_gpl(gpl).unwrap();
+    /// assert_eq!(name.as_deref(), Some("Example"));
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#f00", "#00f"]);
+    /// ```
+    pub fn from_gpl(input: &str) -> Result<(Option<String>, Palette), Error> {
+        let mut lines = input.lines().enumerate();
+        match lines.next() {
+            Some((_, header)) if header.trim() == "GIMP Palette" => {}
+            _ => {
+                return Err(Error::ColorParserError(
+                    "GIMP palette must start with `GIMP Palette`".to_string(),
+                ))
+            }
+        }
+
+        let mut name = None;
+        let mut palette = Palette::default();
+        for (index, line) in lines {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("Columns:") {
+                continue;
+            }
+            if let Some(value) = line.strip_prefix("Name:") {
+                name = Some(value.trim().to_string());
+                continue;
+            }
+            let channels = line
+                .split_whitespace()
+                .take(3)
+                .map(|value| value.parse::<u8>())
+                .collect::<Result<Vec<_>, _>>();
+            match channels.as_deref() {
+                Ok(&[r, g, b]) => palette.push(Color::new(r, g, b, 1.0)),
+                _ => {
+                    return Err(Error::ColorParserError(format!(
+                        "invalid color on line {} of GIMP palette: `{}`",
+                        index + 1,
+                        line
+                    )))
+                }
+            }
+        }
+        Ok((name, palette))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    #[cfg(feature = "css-names")]
+    fn test_to_gpl() {
+        let palette = Palette::new(vec![
+            color!(#fff),
+            color!(rgba(0, 128, 0, 0.5)),
+            color!(#0a0b0c),
+        ]);
+        assert_eq!(
+            palette.to_gpl("Test"),
+            "GIMP Palette\nName: Test\n#\n255 255 255\twhite\n  0 128   0\tgreen\n 10  11  12\t#0a0b0c\n"
+        );
+        assert_eq!(
+            Palette::default().to_gpl("Empty"),
+            "GIMP Palette\nName: Empty\n#\n"
+        );
+    }
+
+    #[test]
+    fn test_from_gpl() {
+        let gpl = "GIMP Palette\r\nName: Test palette\r\nColumns: 2\r\n#\r\n\r\n 10  11  12\tDark\r\n255 255 255\r\n";
+        let (name, palette) = Palette::from_gpl(gpl).unwrap();
+        assert_eq!(name.as_deref(), Some("Test palette"));
+        assert_eq!(palette, Palette::new(vec![color!(#0a0b0c), color!(#fff)]));
+
+        let (name, palette) = Palette::from_gpl("GIMP Palette\n").unwrap();
+        assert_eq!(name, None);
+        assert!(palette.is_empty());
+
+        assert!(Palette::from_gpl("").is_err());
+        assert!(Palette::from_gpl("JASC-PAL\n").is_err());
+        assert!(Palette::from_gpl("GIMP Palette\n255 0\n").is_err());
+        assert!(Palette::from_gpl("GIMP Palette\n256 0 0\n").is_err());
+        assert!(Palette::from_gpl("GIMP Palette\nred\n").is_err());
+    }
+
+    #[test]
+    fn test_gpl_round_trip() {
+        let palette = Palette::new(palettes::tab10());
+        let (name, parsed) = Palette::from_gpl(&palette.to_gpl("tab10")).unwrap();
+        assert_eq!(name.as_deref(), Some("tab10"));
+        assert_eq!(parsed, palette);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{Color, Error, Palette};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+/// The JSON document of a palette.
+#[derive(Serialize, Deserialize)]
+struct PaletteJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    colors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    labels: Option<Vec<String>>,
+}
+
+/// A palette with the name and labels of its JSON document.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{color, JsonPalette, Palette};
+///
+/// let json = JsonPalette {
+///     name: Some("Brand".to_string()),
+///     palette: Palette::new(vec![color!(#f00), color!(rgba(0, 0, 255, 0.5))]),
+///     labels: Some(vec!["primary".to_string(), "overlay".to_string()]),
+/// };
+/// let s = json.to_json().unwrap();
+/// assert_eq!(
+///     s,
+///     r##"{"name":"Brand","colors":["#ff0000","#0000ff80"],"labels":["primary","overlay"]}"##
+/// );
+/// assert_eq!(JsonPalette::from_json(&s).unwrap().to_json().unwrap(), s);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct JsonPalette {
+    /// The name of the palette.
+    pub name: Option<String>,
+    /// The colors.
+    pub palette: Palette,
+    /// The label of each color.
+    pub labels: Option<Vec<String>>,
+}
+
+impl JsonPalette {
+    /// JSON document of the palette.
+    ///
+    /// The document is an object with the keys:
+    ///
+    /// * `name` - The name of the palette, left out if `None`.
+    /// * `colors` - The colors as hex strings, `#rrggbb` or `#rrggbbaa` if not opaque.
+    /// * `labels` - A label for each color, e.g. the design token name, left out if `None`.
+    ///
+    /// # Errors
+    ///
+    /// If the number of labels is not the number of colors.
+    pub fn to_json(&self) -> Result<String, Error> {
+        if let Some(labels) = &self.labels {
+            if labels.len() != self.palette.len() {
+                return Err(Error::InvalidParamsError(format!(
+                    "palette has {} colors, got {} labels",
+                    self.palette.len(),
+                    labels.len()
+                )));
+            }
+        }
+        let json = PaletteJson {
+            name: self.name.clone(),
+            colors: self.palette.iter().map(|color| color.hex_full()).collect(),
+            labels: self.labels.clone(),
+        };
+        serde_json::to_string(&json).map_err(|err| Error::InvalidParamsError(err.to_string()))
+    }
+    /// Parses a JSON document of a palette, see [`JsonPalette::to_json`] for the keys.
+    ///
+    /// The colors can be any CSS color string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::JsonPalette;
+    ///
+    /// let json = r##"{"name": "Brand", "colors": ["#ff0000", "blue"], "labels": ["primary", "link"]}"##;
+    /// let json = JsonPalette::from_json(json).unwrap();
+    /// assert_eq!(json.name.as_deref(), Some("Brand"));
+    /// assert_eq!(json.palette[1].hex(), "#00f");
+    /// assert_eq!(json.labels, Some(vec!["primary".to_string(), "link".to_string()]));
+    /// ```
+    pub fn from_json(input: &str) -> Result<JsonPalette, Error> {
+        let json: PaletteJson = serde_json::from_str(input)
+            .map_err(|err| Error::ColorParserError(format!("invalid palette JSON: {}", err)))?;
+        if let Some(labels) = &json.labels {
+            if labels.len() != json.colors.len() {
+                return Err(Error::ColorParserError(format!(
+                    "palette JSON has {} colors, but {} labels",
+                    json.colors.len(),
+                    labels.len()
+                )));
+            }
+        }
+        let palette = json
+            .colors
+            .iter()
+            .map(|color| Color::from_str(color))
+            .collect::<Result<Palette, Error>>()?;
+        Ok(JsonPalette {
+            name: json.name,
+            palette,
+            labels: json.labels,
+        })
+    }
+}
+
+impl Palette {
+    /// JSON document of the palette without a name or labels, see [`JsonPalette::to_json`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette};
+    ///
+    /// let palette = Palette::new(vec![color!(#f00), color!(rgba(0, 0, 255, 0.5))]);
+    /// assert_eq!(palette.to_json(), r##"{"colors":["#ff0000","#0000ff80"]}"##);
+    /// ```
+    pub fn to_json(&self) -> String {
+        let json = PaletteJson {
+            name: None,
+            colors: self.iter().map(|color| color.hex_full()).collect(),
+            labels: None,
+        };
+        serde_json::to_string(&json).expect("a palette JSON document always serializes")
+    }
+    /// Parses the colors of a JSON document of a palette, see [`JsonPalette::from_json`].
+    ///
+    /// The name and labels of the document are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Palette;
+    ///
+    /// let palette = Palette::from_json(r##"{"name": "Brand", "colors": ["#ff0000", "blue"]}"##).unwrap();
+    /// assert_eq!(palette[1].hex(), "#00f");
+    /// ```
+    pub fn from_json(input: &str) -> Result<Palette, Error> {
+        JsonPalette::from_json(input).map(|json| json.palette)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_to_json() {
+        let palette = Palette::new(vec![color!(#fff), color!(#000)]);
+        assert_eq!(palette.to_json(), r##"{"colors":["#ffffff","#000000"]}"##);
+        let mut json = JsonPalette {
+            name: Some("Greys".to_string()),
+            palette,
+            labels: None,
+        };
+        assert_eq!(
+            json.to_json().unwrap(),
+            r##"{"name":"Greys","colors":["#ffffff","#000000"]}"##
+        );
+        json.labels = Some(vec!["white".to_string()]);
+        assert!(json.to_json().is_err());
+        let json = JsonPalette {
+            name: None,
+            palette: Palette::default(),
+            labels: Some(vec![]),
+        };
+        assert_eq!(json.to_json().unwrap(), r#"{"colors":[],"labels":[]}"#);
+    }
+
+    #[test]
+    fn test_from_json() {
+        let json = JsonPalette::from_json(r#"{"colors": []}"#).unwrap();
+        assert_eq!(json.name, None);
+        assert!(json.palette.is_empty());
+        assert_eq!(json.labels, None);
+
+        assert!(JsonPalette::from_json("").is_err());
+        assert!(JsonPalette::from_json(r#"{"name": "no colors"}"#).is_err());
+        assert!(JsonPalette::from_json(r##"{"colors": ["#ff"]}"##).is_err());
+        assert!(JsonPalette::from_json(r##"{"colors": ["#fff"], "labels": []}"##).is_err());
+        assert!(Palette::from_json(r##"{"colors": ["#fff"], "labels": []}"##).is_err());
+        assert_eq!(
+            Palette::from_json(r##"{"name": "White", "colors": ["#fff"]}"##).unwrap(),
+            Palette::new(vec![color!(#fff)])
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let palette = Palette::new(palettes::okabe_ito());
+        let labels = [
+            "black", "orange", "sky", "green", "yellow", "blue", "red", "purple",
+        ];
+        let json = JsonPalette {
+            name: Some("Okabe-Ito".to_string()),
+            palette: palette.clone(),
+            labels: Some(labels.iter().map(|label| label.to_string()).collect()),
+        };
+        assert_eq!(
+            JsonPalette::from_json(&json.to_json().unwrap()).unwrap(),
+            json
+        );
+        assert_eq!(Palette::from_json(&palette.to_json()).unwrap(), palette);
+    }
+}
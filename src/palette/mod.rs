@@ -0,0 +1,350 @@
+pub mod ase;
+#[cfg(feature = "brewer")]
+pub mod brewer;
+pub mod distinct;
+pub mod gpl;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod sort;
+pub mod text;
+
+use crate::{delta_e, is_cvd_safe, Color, Gradient};
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut};
+
+#[cfg(feature = "brewer")]
+use crate::{BrewerScheme, Error};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An ordered list of colors.
+///
+/// The methods named `sort_*` reorder the palette in place, each has a `sorted_*`
+/// counterpart that returns a sorted copy instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{color, Palette};
+///
+/// let palette = Palette::new(vec![color!(#00f), color!(#f00), color!(#0f0)]);
+/// assert_eq!(palette.len(), 3);
+/// assert_eq!(palette[1].hex(), "#f00");
+///
+/// let palette = palette.lighten_all(0.1);
+/// assert_eq!(palette[1].hex(), "#f33");
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Palette {
+    colors: Vec<Color>,
+}
+
+impl Palette {
+    /// Creates a new [`Palette`] from colors.
+    pub fn new(colors: Vec<Color>) -> Self {
+        Palette { colors }
+    }
+    /// Creates a palette from a [ColorBrewer](https://colorbrewer2.org) scheme with `n` classes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{BrewerScheme, Palette};
+    ///
+    /// let palette = Palette::brewer(BrewerScheme::Blues, 3).unwrap();
+    /// assert_eq!(palette[0].hex(), "#deebf7");
+    /// ```
+    #[cfg(feature = "brewer")]
+    pub fn brewer(scheme: BrewerScheme, n: usize) -> Result<Self, Error> {
+        Ok(Palette::new(scheme.colors(n)?))
+    }
+    /// The colors of the palette.
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+    /// The number of colors in the palette.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+    /// Whether the palette has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+    /// An iterator over the colors of the palette.
+    pub fn iter(&self) -> core::slice::Iter<'_, Color> {
+        self.colors.iter()
+    }
+    /// Appends a color to the end of the palette.
+    pub fn push(&mut self, color: Color) {
+        self.colors.push(color);
+    }
+    /// Applies a function to every color of the palette.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette};
+    ///
+    /// let palette = Palette::new(vec![color!(#f00), color!(#0f0)]);
+    /// let palette = palette.map(|color| color.negate());
+    /// assert_eq!(palette[0].hex(), "#0ff");
+    /// ```
+    pub fn map<F>(&self, f: F) -> Self
+    where
+        F: FnMut(&Color) -> Color,
+    {
+        Palette::new(self.colors.iter().map(f).collect())
+    }
+    /// Increases the HSL lightness of every color by an absolute amount, see [`Color::lighten`].
+    pub fn lighten_all(&self, amount: f64) -> Self {
+        self.map(|color| color.lighten(amount))
+    }
+    /// Decreases the HSL lightness of every color by an absolute amount, see [`Color::darken`].
+    pub fn darken_all(&self, amount: f64) -> Self {
+        self.map(|color| color.darken(amount))
+    }
+    /// Sorts the colors by hue, from red through green and blue back to red.
+    ///
+    /// The sort is stable, greys have a hue of 0 and come first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette};
+    ///
+    /// let mut palette = Palette::new(vec![color!(#00f), color!(#f00), color!(#0f0)]);
+    /// palette.sort_by_hue();
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#f00", "#0f0", "#00f"]);
+    /// ```
+    pub fn sort_by_hue(&mut self) {
+        self.colors.sort_by(|a, b| a.hue().total_cmp(&b.hue()));
+    }
+    /// A copy of the palette sorted by hue, see [`Palette::sort_by_hue`].
+    pub fn sorted_by_hue(&self) -> Self {
+        let mut palette = self.clone();
+        palette.sort_by_hue();
+        palette
+    }
+    /// Reverses the order of the colors.
+    pub fn reverse(&mut self) {
+        self.colors.reverse();
+    }
+    /// Resamples the palette to `n` colors, interpolated in OKLab along a gradient through
+    /// the colors of the palette.
+    ///
+    /// The first and last colors are kept, an empty palette stays empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette};
+    ///
+    /// let palette = Palette::new(vec![color!(#000), color!(#fff)]);
+    /// let palette = palette.sample(3);
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#000", "#636363", "#fff"]);
+    /// ```
+    pub fn sample(&self, n: usize) -> Self {
+        let last = self.colors.len().saturating_sub(1).max(1) as f64;
+        let stops = self
+            .colors
+            .iter()
+            .enumerate()
+            .map(|(i, color)| (i as f64 / last, *color))
+            .collect();
+        match Gradient::new(stops) {
+            Ok(gradient) => Palette::new(gradient.colors(n)),
+            Err(_) => Palette::default(),
+        }
+    }
+    /// Removes the colors closer than `threshold` to an earlier color, by CIEDE2000 [`delta_e`].
+    ///
+    /// The first color of each group of look-alikes is kept, the order of the kept colors
+    /// does not change. A threshold of about 2.3 removes the differences barely noticeable
+    /// side by side.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette};
+    ///
+    /// let mut palette = Palette::new(vec![color!(#f00), color!(#fe0101), color!(#00f), color!(#f00)]);
+    /// palette.dedup_by_delta_e(2.3);
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#f00", "#00f"]);
+    /// ```
+    pub fn dedup_by_delta_e(&mut self, threshold: f64) {
+        let mut kept: Vec<Color> = Vec::with_capacity(self.colors.len());
+        for color in self.colors.drain(..) {
+            if kept.iter().all(|k| delta_e(k, &color) >= threshold) {
+                kept.push(color);
+            }
+        }
+        self.colors = kept;
+    }
+    /// Whether all colors stay apart by at least `min_delta_e` with normal vision and with
+    /// protanopia, deuteranopia and tritanopia, see [`is_cvd_safe`].
+    pub fn is_cvd_safe(&self, min_delta_e: f64) -> bool {
+        is_cvd_safe(&self.colors, min_delta_e)
+    }
+}
+
+impl From<Vec<Color>> for Palette {
+    fn from(colors: Vec<Color>) -> Self {
+        Palette::new(colors)
+    }
+}
+
+impl From<Palette> for Vec<Color> {
+    fn from(palette: Palette) -> Self {
+        palette.colors
+    }
+}
+
+impl FromIterator<Color> for Palette {
+    fn from_iter<I: IntoIterator<Item = Color>>(iter: I) -> Self {
+        Palette::new(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Palette {
+    type Item = Color;
+    type IntoIter = alloc::vec::IntoIter<Color>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.colors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Palette {
+    type Item = &'a Color;
+    type IntoIter = core::slice::Iter<'a, Color>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.colors.iter()
+    }
+}
+
+impl Index<usize> for Palette {
+    type Output = Color;
+
+    fn index(&self, index: usize) -> &Color {
+        &self.colors[index]
+    }
+}
+
+impl IndexMut<usize> for Palette {
+    fn index_mut(&mut self, index: usize) -> &mut Color {
+        &mut self.colors[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::{string::String, vec::Vec};
+
+    /// The short hex strings of the colors of a palette, shared by the tests of the submodules.
+    pub(super) fn hexes(palette: &Palette) -> Vec<String> {
+        palette.iter().map(|color| color.hex()).collect()
+    }
+
+    #[test]
+    fn test_palette() {
+        let mut palette: Palette = vec![color!(#f00), color!(#0f0)].into();
+        palette.push(color!(#00f));
+        assert_eq!(palette.len(), 3);
+        assert!(!palette.is_empty());
+        assert!(Palette::default().is_empty());
+
+        palette[0] = color!(#fff);
+        assert_eq!(hexes(&palette), vec!["#fff", "#0f0", "#00f"]);
+
+        palette.reverse();
+        assert_eq!(hexes(&palette), vec!["#00f", "#0f0", "#fff"]);
+
+        let colors: Vec<Color> = palette.clone().into();
+        assert_eq!(colors.len(), 3);
+        let collected = palette.into_iter().collect::<Palette>();
+        assert_eq!(collected.colors().len(), 3);
+    }
+
+    #[test]
+    fn test_palette_map() {
+        let palette = Palette::new(vec![color!(#800000), color!(#008000)]);
+        assert_eq!(hexes(&palette.lighten_all(0.25)), vec!["#f00", "#0f0"]);
+        assert_eq!(hexes(&palette.darken_all(0.25)), vec!["#000", "#000"]);
+        assert_eq!(
+            palette.map(|color| color.fade(0.5)),
+            Palette::new(vec![
+                color!(rgba(128, 0, 0, 0.5)),
+                color!(rgba(0, 128, 0, 0.5))
+            ])
+        );
+    }
+
+    #[test]
+    fn test_palette_sort_by_hue() {
+        let mut palette = Palette::new(vec![
+            color!(#f0f),
+            color!(#808080),
+            color!(#0ff),
+            color!(#ff0),
+            color!(#fff),
+        ]);
+        let sorted = palette.sorted_by_hue();
+        palette.sort_by_hue();
+        assert_eq!(
+            hexes(&palette),
+            vec!["#808080", "#fff", "#ff0", "#0ff", "#f0f"]
+        );
+        assert_eq!(sorted, palette);
+    }
+
+    #[test]
+    fn test_palette_sample() {
+        assert!(Palette::default().sample(3).is_empty());
+
+        let palette = Palette::new(vec![color!(#f00)]);
+        assert_eq!(hexes(&palette.sample(2)), vec!["#f00", "#f00"]);
+
+        let palette = Palette::new(vec![color!(#f00), color!(#0f0), color!(#00f)]);
+        let sampled = palette.sample(5);
+        assert_eq!(sampled.len(), 5);
+        assert_eq!(sampled[0], palette[0]);
+        assert_eq!(sampled[2], palette[1]);
+        assert_eq!(sampled[4], palette[2]);
+        assert_eq!(hexes(&palette.sample(2)), vec!["#f00", "#00f"]);
+    }
+
+    #[test]
+    fn test_palette_dedup_by_delta_e() {
+        let mut palette = Palette::new(vec![
+            color!(#808080),
+            color!(#f00),
+            color!(#828282),
+            color!(#7f7f7f),
+            color!(#000),
+            color!(#ff0505),
+        ]);
+        palette.dedup_by_delta_e(0.0);
+        assert_eq!(palette.len(), 6);
+
+        palette.dedup_by_delta_e(2.3);
+        assert_eq!(hexes(&palette), vec!["#808080", "#f00", "#000"]);
+
+        palette.dedup_by_delta_e(100.0);
+        assert_eq!(hexes(&palette), vec!["#808080"]);
+
+        let mut palette = Palette::default();
+        palette.dedup_by_delta_e(2.3);
+        assert!(palette.is_empty());
+    }
+
+    #[test]
+    fn test_palette_is_cvd_safe() {
+        let palette = Palette::new(vec![color!(#d62728), color!(#2ca02c)]);
+        assert!(!palette.is_cvd_safe(10.0));
+        let palette = Palette::new(palettes::okabe_ito());
+        assert!(palette.is_cvd_safe(5.0));
+    }
+}
@@ -0,0 +1,299 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{Color, ColorSpace, Palette};
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A property of colors to sort a [`Palette`] by, in ascending order.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SortKey {
+    /// The HSL hue, from red through green and blue back to red. Greys have a hue of 0.
+    Hue,
+    /// The perceptual lightness, the L of Lab.
+    Lightness,
+    /// The [relative luminance](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance) of WCAG.
+    Luminance,
+    /// The colorfulness, the C of LCh.
+    Chroma,
+}
+
+impl SortKey {
+    /// The value of the key for a color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, SortKey};
+    ///
+    /// assert_eq!(SortKey::Hue.value(&color!(#0f0)), 120.0);
+    /// assert_eq!(SortKey::Luminance.value(&color!(#fff)), 1.0);
+    /// ```
+    pub fn value(&self, color: &Color) -> f64 {
+        match self {
+            SortKey::Hue => color.hue(),
+            SortKey::Lightness => color.vec_of(ColorSpace::Lab)[0],
+            SortKey::Luminance => color.luminance(),
+            SortKey::Chroma => color.vec_of(ColorSpace::LCH)[1],
+        }
+    }
+}
+
+impl Palette {
+    /// Sorts the colors by a key.
+    ///
+    /// The sort is stable, colors with the same value of the key keep their order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette, SortKey};
+    ///
+    /// let mut palette = Palette::new(vec![color!(#fff), color!(#000), color!(#808080)]);
+    /// palette.sort_by_key(SortKey::Lightness);
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#000", "#808080", "#fff"]);
+    /// ```
+    pub fn sort_by_key(&mut self, key: SortKey) {
+        self.sort_by_keys(&[key]);
+    }
+    /// A copy of the palette sorted by a key, see [`Palette::sort_by_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette, SortKey};
+    ///
+    /// let palette = Palette::new(vec![color!(#fff), color!(#000), color!(#808080)]);
+    /// let sorted = palette.sorted_by_key(SortKey::Lightness);
+    /// assert_eq!(sorted.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#000", "#808080", "#fff"]);
+    /// ```
+    pub fn sorted_by_key(&self, key: SortKey) -> Self {
+        self.sorted_by_keys(&[key])
+    }
+    /// Sorts the colors by several keys, the later keys break the ties of the earlier ones.
+    ///
+    /// The sort is stable, colors with the same values of all keys keep their order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette, SortKey};
+    ///
+    /// // the greys all have a hue of 0, they are ordered by lightness
+    /// let mut palette = Palette::new(vec![color!(#fff), color!(#00f), color!(#000)]);
+    /// palette.sort_by_keys(&[SortKey::Hue, SortKey::Lightness]);
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#000", "#fff", "#00f"]);
+    /// ```
+    pub fn sort_by_keys(&mut self, keys: &[SortKey]) {
+        let mut keyed = self
+            .colors
+            .drain(..)
+            .map(|color| {
+                let values = keys.iter().map(|key| key.value(&color)).collect::<Vec<_>>();
+                (values, color)
+            })
+            .collect::<Vec<_>>();
+        keyed.sort_by(|(a, _), (b, _)| {
+            a.iter()
+                .zip(b)
+                .map(|(a, b)| a.total_cmp(b))
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+        self.colors = keyed.into_iter().map(|(_, color)| color).collect();
+    }
+    /// A copy of the palette sorted by several keys, see [`Palette::sort_by_keys`].
+    pub fn sorted_by_keys(&self, keys: &[SortKey]) -> Self {
+        let mut palette = self.clone();
+        palette.sort_by_keys(keys);
+        palette
+    }
+    /// Orders the colors so that neighbors look alike, to show the palette as a smooth strip.
+    ///
+    /// Finding the order with the lowest total distance is the traveling salesman problem,
+    /// so this is a heuristic: starting from the darkest color, the nearest remaining color
+    /// in Lab is picked next, then the path is improved by reversing parts of it (2-opt).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Palette};
+    ///
+    /// let mut palette = Palette::new(vec![color!(#fff), color!(#000), color!(#aaa), color!(#555)]);
+    /// palette.sort_smooth();
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#000", "#555", "#aaa", "#fff"]);
+    /// ```
+    pub fn sort_smooth(&mut self) {
+        let n = self.len();
+        if n < 3 {
+            return;
+        }
+        let labs = self
+            .iter()
+            .map(|color| color.vec_of(ColorSpace::Lab))
+            .collect::<Vec<_>>();
+        let distance = |i: usize, j: usize| {
+            labs[i]
+                .iter()
+                .zip(&labs[j])
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f64>()
+                .sqrt()
+        };
+
+        let start = (0..n)
+            .min_by(|&i, &j| labs[i][0].total_cmp(&labs[j][0]))
+            .unwrap();
+        let mut path = vec![start];
+        let mut visited = vec![false; n];
+        visited[start] = true;
+        for _ in 1..n {
+            let last = path[path.len() - 1];
+            let next = (0..n)
+                .filter(|&i| !visited[i])
+                .min_by(|&i, &j| distance(last, i).total_cmp(&distance(last, j)))
+                .unwrap();
+            visited[next] = true;
+            path.push(next);
+        }
+
+        // reversing path[i + 1..=j] swaps the edges (i, i + 1) and (j, j + 1)
+        // for (i, j) and (i + 1, j + 1), the start stays the darkest color
+        for _ in 0..100 {
+            let mut improved = false;
+            for i in 0..n - 2 {
+                for j in i + 2..n {
+                    let before = distance(path[i], path[i + 1])
+                        + if j + 1 < n {
+                            distance(path[j], path[j + 1])
+                        } else {
+                            0.0
+                        };
+                    let after = distance(path[i], path[j])
+                        + if j + 1 < n {
+                            distance(path[i + 1], path[j + 1])
+                        } else {
+                            0.0
+                        };
+                    if after < before - 1e-9 {
+                        path[i + 1..=j].reverse();
+                        improved = true;
+                    }
+                }
+            }
+            if !improved {
+                break;
+            }
+        }
+
+        self.colors = path.into_iter().map(|i| self.colors[i]).collect();
+    }
+    /// A copy of the palette ordered so that neighbors look alike, see [`Palette::sort_smooth`].
+    pub fn sorted_smooth(&self) -> Self {
+        let mut palette = self.clone();
+        palette.sort_smooth();
+        palette
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::palette::tests::hexes;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_sorted_by_key() {
+        let palette = Palette::new(vec![
+            color!(#00f),
+            color!(#ff0),
+            color!(#808080),
+            color!(#f00),
+        ]);
+        assert_eq!(
+            hexes(&palette.sorted_by_key(SortKey::Hue)),
+            vec!["#808080", "#f00", "#ff0", "#00f"]
+        );
+        assert_eq!(
+            hexes(&palette.sorted_by_key(SortKey::Lightness)),
+            vec!["#00f", "#808080", "#f00", "#ff0"]
+        );
+        assert_eq!(
+            hexes(&palette.sorted_by_key(SortKey::Luminance)),
+            vec!["#00f", "#f00", "#808080", "#ff0"]
+        );
+        assert_eq!(
+            hexes(&palette.sorted_by_key(SortKey::Chroma)),
+            vec!["#808080", "#ff0", "#f00", "#00f"]
+        );
+        // the palette itself is left as is
+        assert_eq!(palette[0].hex(), "#00f");
+
+        let mut sorted = palette.clone();
+        sorted.sort_by_key(SortKey::Luminance);
+        assert_eq!(sorted, palette.sorted_by_key(SortKey::Luminance));
+    }
+
+    #[test]
+    fn test_sorted_by_keys() {
+        let palette = Palette::new(vec![
+            color!(#f00),
+            color!(#fff),
+            color!(#800000),
+            color!(#000),
+        ]);
+        assert_eq!(
+            hexes(&palette.sorted_by_keys(&[SortKey::Hue, SortKey::Lightness])),
+            vec!["#000", "#800000", "#f00", "#fff"]
+        );
+        assert_eq!(
+            hexes(&palette.sorted_by_keys(&[SortKey::Chroma, SortKey::Luminance])),
+            vec!["#000", "#fff", "#800000", "#f00"]
+        );
+        // without keys the order stays the same
+        assert_eq!(palette.sorted_by_keys(&[]), palette);
+
+        let mut sorted = palette.clone();
+        sorted.sort_by_keys(&[SortKey::Hue, SortKey::Lightness]);
+        assert_eq!(
+            sorted,
+            palette.sorted_by_keys(&[SortKey::Hue, SortKey::Lightness])
+        );
+    }
+
+    fn path_length(palette: &Palette) -> f64 {
+        palette
+            .colors()
+            .windows(2)
+            .map(|w| w[0].delta_e_76(&w[1]))
+            .sum()
+    }
+
+    #[test]
+    fn test_sort_smooth() {
+        let mut palette = Palette::new(vec![color!(#f00)]);
+        palette.sort_smooth();
+        assert_eq!(hexes(&palette), vec!["#f00"]);
+
+        // a shuffled ramp is put back in order
+        let ramp = Gradient::new(vec![(0.0, color!(#000)), (1.0, color!(#ff0))])
+            .unwrap()
+            .colors(8);
+        let mut palette = Palette::new(
+            [3, 7, 0, 5, 1, 6, 2, 4]
+                .iter()
+                .map(|&i| ramp[i])
+                .collect::<Vec<_>>(),
+        );
+        palette.sort_smooth();
+        assert_eq!(palette, Palette::new(ramp));
+
+        let palette = Palette::new(palettes::tab10());
+        let sorted = palette.sorted_smooth();
+        assert_eq!(sorted.len(), 10);
+        assert!(path_length(&sorted) < path_length(&palette) * 0.6);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{Color, Error, Palette};
+use alloc::format;
+
+impl Palette {
+    /// Parses a [Lospec](https://lospec.com/palette-list) hex file (`.hex`, or the `.txt`
+    /// download), one `RRGGBB` hex code per line.
+    ///
+    /// A leading `#` is allowed, blank lines are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Palette;
+    ///
+    /// let palette = Palette::from_hex_list("1a1c2c\n5d275d\n#b13e53\n").unwrap();
+    /// assert_eq!(palette.len(), 3);
+    /// assert_eq!(palette[2].hex(), "#b13e53");
+    /// ```
+    pub fn from_hex_list(input: &str) -> Result<Palette, Error> {
+        let mut palette = Palette::default();
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let hex = line.strip_prefix('#').unwrap_or(line);
+            match parse_hex_digits::<3>(hex) {
+                Some([r, g, b]) => palette.push(Color::new(r, g, b, 1.0)),
+                None => return Err(invalid_line("hex list", index, line)),
+            }
+        }
+        Ok(palette)
+    }
+    /// Parses a [paint.net](https://www.getpaint.net/doc/latest/WorkingWithPalettes.html)
+    /// palette (`.txt`), one `AARRGGBB` hex code per line.
+    ///
+    /// Lines starting with `;` are comments, blank lines are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Palette;
+    ///
+    /// let input = "; paint.net Palette File\n; Colors: 2\nFFFF0000\n800000FF\n";
+    /// let palette = Palette::from_paint_net(input).unwrap();
+    /// assert_eq!(palette.iter().map(|c| c.hex()).collect::<Vec<_>>(), vec!["#f00", "#0000ff80"]);
+    /// ```
+    pub fn from_paint_net(input: &str) -> Result<Palette, Error> {
+        let mut palette = Palette::default();
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') {
+                continue;
+            }
+            match parse_hex_digits::<4>(line) {
+                Some([a, r, g, b]) => palette.push(Color::new(r, g, b, a as f64 / 255.0)),
+                None => return Err(invalid_line("paint.net palette", index, line)),
+            }
+        }
+        Ok(palette)
+    }
+}
+
+/// Parses exactly `N` bytes written as two hex digits each.
+fn parse_hex_digits<const N: usize>(hex: &str) -> Option<[u8; N]> {
+    if hex.len() != N * 2 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let mut bytes = [0; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
+
+fn invalid_line(format: &str, index: usize, line: &str) -> Error {
+    Error::ColorParserError(format!(
+        "invalid color on line {} of {}: `{}`",
+        index + 1,
+        format,
+        line
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::palette::tests::hexes;
+    use crate::*;
+    use alloc::{string::ToString, vec::Vec};
+
+    #[test]
+    fn test_from_hex_list() {
+        let palette = Palette::from_hex_list("000000\r\n\r\n#FFFFFF\r\nff8000").unwrap();
+        assert_eq!(hexes(&palette), vec!["#000", "#fff", "#ff8000"]);
+        assert!(Palette::from_hex_list("").unwrap().is_empty());
+
+        let err = Palette::from_hex_list("000000\nfff\n").unwrap_err();
+        assert_eq!(
+            err,
+            Error::ColorParserError("invalid color on line 2 of hex list: `fff`".to_string())
+        );
+        assert!(Palette::from_hex_list("00000g").is_err());
+        assert!(Palette::from_hex_list("ff000080").is_err());
+        assert!(Palette::from_hex_list("+f+f+f").is_err());
+    }
+
+    #[test]
+    fn test_from_paint_net() {
+        let input = ";paint.net Palette File\n;Palette Name: Test\n;Colors: 3\nFF000000\nffffffff\n00ff8000\n";
+        let palette = Palette::from_paint_net(input).unwrap();
+        assert_eq!(hexes(&palette), vec!["#000", "#fff", "#ff800000"]);
+
+        assert!(Palette::from_paint_net("FFFFFF").is_err());
+        assert!(Palette::from_paint_net("# FFFFFFFF").is_err());
+    }
+}
@@ -0,0 +1,73 @@
+//! Ready-made qualitative palettes for categorical data, e.g. the series of a chart.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use color_art::palettes;
+//!
+//! let colors = palettes::okabe_ito();
+//! assert_eq!(colors.len(), 8);
+//! assert_eq!(colors[1].hex(), "#e69f00");
+//! ```
+
+use crate::{data::categorical, Color};
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+/// The `tab10` palette of matplotlib and Tableau, also d3's `schemeCategory10`.
+pub fn tab10() -> Vec<Color> {
+    colors(&categorical::TAB10)
+}
+
+/// The `tab20` palette of matplotlib, the [`tab10`] colors each followed by a lighter shade.
+pub fn tab20() -> Vec<Color> {
+    colors(&categorical::TAB20)
+}
+
+/// The Tableau 10 palette introduced in 2016, softer than [`tab10`].
+pub fn tableau10() -> Vec<Color> {
+    colors(&categorical::TABLEAU10)
+}
+
+/// The ColorBrewer `Set1` palette of 9 saturated colors.
+pub fn set1() -> Vec<Color> {
+    colors(&categorical::SET1)
+}
+
+/// The palette of [Okabe and Ito](https://jfly.uni-koeln.de/color/), black and 7 colors that
+/// stay distinguishable with all common color vision deficiencies.
+pub fn okabe_ito() -> Vec<Color> {
+    colors(&categorical::OKABE_ITO)
+}
+
+fn colors(hexes: &[&str]) -> Vec<Color> {
+    hexes
+        .iter()
+        .map(|hex| Color::from_str(hex).unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::is_cvd_safe;
+
+    #[test]
+    fn test_palettes() {
+        assert_eq!(tab10().len(), 10);
+        assert_eq!(tab20().len(), 20);
+        assert_eq!(tableau10().len(), 10);
+        assert_eq!(set1().len(), 9);
+        assert_eq!(okabe_ito().len(), 8);
+
+        // tab20 interleaves tab10 with lighter shades
+        assert_eq!(tab20().into_iter().step_by(2).collect::<Vec<_>>(), tab10());
+        assert_eq!(tab10()[0].hex(), "#1f77b4");
+    }
+
+    #[test]
+    fn test_okabe_ito_is_cvd_safe() {
+        assert!(is_cvd_safe(&okabe_ito(), 5.0));
+        assert!(!is_cvd_safe(&tab10(), 5.0));
+    }
+}
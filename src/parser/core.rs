@@ -1,4 +1,12 @@
-use crate::{ColorSpace, Error};
+use crate::{
+    conversion::utils::{d50_to_d65, gam_srgb},
+    ColorSpace, Error,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,6 +16,8 @@ use serde::{Deserialize, Serialize};
 pub struct Token {
     pub kind: TokenKind,
     pub value: String,
+    /// byte offset of the token in the input
+    pub offset: usize,
 }
 
 #[derive(Debug, PartialEq)]
@@ -18,20 +28,37 @@ pub enum TokenKind {
     LeftParen,
     RightParen,
     Comma,
+    Slash,
     Whitespace,
+    Unknown,
     Eof,
 }
 
+/// How strictly the input has to follow the CSS color grammar.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParseMode {
+    /// Any supported color space, out of range values are an error.
+    #[default]
+    Default,
+    /// Only the CSS color functions and separators.
+    Strict,
+    /// Any supported color space, out of range values are clamped.
+    Lenient,
+}
+
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Parser {
     pub tokens: Vec<Token>,
     pub current: usize,
     pub values: Vec<f64>,
+    pub alpha: Option<f64>,
     pub color_space: ColorSpace,
+    pub mode: ParseMode,
 }
 
-type PeekableChars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+type PeekableChars<'a> = core::iter::Peekable<core::str::CharIndices<'a>>;
 
 impl Parser {
     pub fn new() -> Self {
@@ -39,106 +66,172 @@ impl Parser {
             tokens: Vec::new(),
             current: 0,
             values: Vec::new(),
+            alpha: None,
             color_space: ColorSpace::Unknown,
+            mode: ParseMode::Default,
         }
     }
     pub fn tokenize(&mut self, input: &str) -> &mut Self {
-        let mut chars = input.chars().peekable();
+        let mut chars = input.char_indices().peekable();
 
-        while let Some(&c) = chars.peek() {
+        while let Some(&(offset, c)) = chars.peek() {
             match c {
                 '0'..='9' | '%' | '.' | '-' => self.tokenize_number(&mut chars),
                 'a'..='z' | 'A'..='Z' => self.tokenize_identifier(&mut chars),
-                '(' => self.tokenize_left_paren(&mut chars),
-                ')' => self.tokenize_right_paren(&mut chars),
-                ',' => self.tokenize_comma(&mut chars),
-                ' ' | '\t' | '\n' => self.tokenize_whitespace(&mut chars),
-                _ => self.tokenize_operator(&mut chars),
+                '(' => self.tokenize_char(&mut chars, TokenKind::LeftParen),
+                ')' => self.tokenize_char(&mut chars, TokenKind::RightParen),
+                ',' => self.tokenize_char(&mut chars, TokenKind::Comma),
+                '/' => self.tokenize_char(&mut chars, TokenKind::Slash),
+                ' ' | '\t' | '\n' => self.tokenize_char(&mut chars, TokenKind::Whitespace),
+                _ => {
+                    chars.next();
+                    self.tokens.push(Token {
+                        kind: TokenKind::Unknown,
+                        value: c.to_string(),
+                        offset,
+                    });
+                }
             }
         }
 
         self.tokens.push(Token {
             kind: TokenKind::Eof,
             value: String::new(),
+            offset: input.len(),
         });
 
         self
     }
 
+    /// Parse the tokens as `<name>(<value> [, <value>]* [/ <alpha>])`, where the values are
+    /// separated either all by commas or all by whitespace.
     pub fn validate(&mut self) -> Result<(), Error> {
-        let mut stack = Vec::new();
+        self.current = 0;
+        let mode = self.mode;
+        let mut predefined_space = None;
+
+        let name = self.expect(TokenKind::Identifier, "a color space name")?;
+        if name.value == "color" {
+            self.expect(TokenKind::LeftParen, "'('")?;
+            let space = self.expect(TokenKind::Identifier, "a predefined color space")?;
+            predefined_space = Some(space.value.clone());
+        } else {
+            let color_space = ColorSpace::from(&name.value);
+            if color_space == ColorSpace::Unknown {
+                return Err(unexpected(name, "a color space name"));
+            }
+            if mode == ParseMode::Strict && !color_space.is_css_function() {
+                return Err(unexpected(name, "a CSS color function"));
+            }
+            self.color_space = color_space;
+            self.expect(TokenKind::LeftParen, "'('")?;
+        }
 
-        while let Some(token) = self.tokens.get(self.current) {
+        // CSS only allows commas in the legacy `rgb()` and `hsl()` syntax
+        let commas_allowed = mode != ParseMode::Strict
+            || (predefined_space.is_none()
+                && matches!(
+                    self.color_space,
+                    ColorSpace::RGB | ColorSpace::RGBA | ColorSpace::HSL | ColorSpace::HSLA
+                ));
+        let mut comma_separated = if commas_allowed { None } else { Some(false) };
+        loop {
+            let value = self.expect_value()?;
+            self.values.push(value);
+
+            let token = self.peek();
             match token.kind {
-                TokenKind::LeftParen => {
-                    stack.push(token);
+                TokenKind::Comma if comma_separated != Some(false) => {
+                    comma_separated = Some(true);
+                    self.current += 1;
                 }
-                TokenKind::RightParen => {
-                    if stack.pop().is_some() {
-                        // do nothing
-                    } else {
-                        return Err(Error::ColorParserError(
-                            "Unmatched right parenthesis".to_string(),
-                        ));
-                    }
+                TokenKind::Value if comma_separated != Some(true) => {
+                    comma_separated = Some(false);
                 }
-                TokenKind::Value => {
-                    if token.value.contains('%') {
-                        let value = token.value.replace('%', "");
-                        if let Ok(value) = value.parse::<f64>() {
-                            self.values.push(value / 100.0);
-                        } else {
-                            return Err(Error::ColorParserError("Invalid value".to_string()));
-                        }
-                    } else if let Ok(value) = token.value.parse::<f64>() {
-                        self.values.push(value);
-                    } else {
-                        return Err(Error::ColorParserError("Invalid value".to_string()));
-                    }
+                TokenKind::Slash if mode != ParseMode::Strict || comma_separated != Some(true) => {
+                    self.current += 1;
+                    self.alpha = Some(self.expect_value()?);
+                    break;
                 }
-                TokenKind::Identifier => {
-                    let color_space = ColorSpace::from(&token.value);
-                    if color_space == ColorSpace::Unknown {
-                        return Err(Error::ColorParserError("Invalid input".to_string()));
-                    } else {
-                        self.color_space = color_space;
-                    }
+                TokenKind::RightParen => break,
+                _ if comma_separated == Some(true) => return Err(unexpected(token, "',' or ')'")),
+                _ if comma_separated == Some(false) => {
+                    return Err(unexpected(token, "a value, '/' or ')'"))
                 }
-                _ => {}
+                _ => return Err(unexpected(token, "a value, ',', '/' or ')'")),
             }
-
-            self.current += 1;
         }
+        self.expect(TokenKind::RightParen, "')'")?;
+        self.expect(TokenKind::Eof, "end of input")?;
 
-        match self.color_space {
-            ColorSpace::Unknown => {
-                return Err(Error::ColorParserError("No color space found".to_string()))
+        if let Some(space) = predefined_space {
+            self.color_space = resolve_predefined_space(&space, &mut self.values)?;
+        }
+        if self.mode == ParseMode::Lenient {
+            self.alpha = self.alpha.map(|alpha| alpha.clamp(0.0, 1.0));
+        }
+        if let Some(alpha) = self.alpha {
+            if !(0.0..=1.0).contains(&alpha) {
+                return Err(Error::ColorParserError(format!(
+                    "Alpha must be between 0.0 and 1.0, got {}",
+                    alpha
+                )));
             }
-            _ => {
-                if self.values.len() != self.color_space.value_count() {
-                    return Err(Error::ColorParserError(
-                        "Invalid number of values".to_string(),
-                    ));
-                }
-                self.color_space.valid(&self.values)?;
+            // the legacy alpha spaces keep alpha as their last value
+            if matches!(self.color_space, ColorSpace::RGBA | ColorSpace::HSLA) {
+                self.values.push(alpha);
+                self.alpha = None;
             }
         }
 
-        if !self.values.is_empty() && stack.is_empty() {
-            Ok(())
-        } else if self.values.is_empty() {
-            Err(Error::ColorParserError("No values found".to_string()))
+        if self.values.len() != self.color_space.value_count() {
+            return Err(Error::ColorParserError(
+                "Invalid number of values".to_string(),
+            ));
+        }
+        if self.mode == ParseMode::Lenient {
+            self.values = self.color_space.clamp(&self.values);
+        }
+        self.color_space.valid(&self.values)
+    }
+
+    /// The next token that is not whitespace.
+    fn peek(&mut self) -> &Token {
+        while self.tokens[self.current].kind == TokenKind::Whitespace {
+            self.current += 1;
+        }
+        &self.tokens[self.current]
+    }
+
+    fn expect(&mut self, kind: TokenKind, expected: &str) -> Result<&Token, Error> {
+        let index = {
+            let token = self.peek();
+            if token.kind != kind {
+                return Err(unexpected(token, expected));
+            }
+            self.current
+        };
+        self.current += 1;
+        Ok(&self.tokens[index])
+    }
+
+    fn expect_value(&mut self) -> Result<f64, Error> {
+        let index = self.values.len();
+        let percent_reference = self.color_space.percent_reference(index);
+        let token = self.expect(TokenKind::Value, "a value")?;
+        let value = parse_value(&token.value).ok_or_else(|| unexpected(token, "a number"))?;
+        if token.value.ends_with('%') {
+            Ok(value * percent_reference)
         } else {
-            Err(Error::ColorParserError(
-                "Unmatched left parenthesis".to_string(),
-            ))
+            Ok(value)
         }
     }
 
     fn tokenize_number(&mut self, chars: &mut PeekableChars) {
         let mut value = String::new();
+        let offset = chars.peek().map_or(0, |&(offset, _)| offset);
 
-        while let Some(&c) = chars.peek() {
+        while let Some(&(_, c)) = chars.peek() {
             match c {
                 '0'..='9' | '.' => {
                     value.push(c);
@@ -158,6 +251,22 @@ impl Parser {
                     value.push(c);
                     chars.next();
                 }
+                '°' => {
+                    value.push(c);
+                    chars.next();
+                    break;
+                }
+                'a'..='z' | 'A'..='Z' => {
+                    // angle units directly follow the number, e.g. `120deg`
+                    while let Some(&(_, c)) = chars.peek() {
+                        if !c.is_ascii_alphabetic() {
+                            break;
+                        }
+                        value.push(c);
+                        chars.next();
+                    }
+                    break;
+                }
                 _ => {
                     break;
                 }
@@ -167,15 +276,17 @@ impl Parser {
         self.tokens.push(Token {
             kind: TokenKind::Value,
             value,
+            offset,
         });
     }
 
     fn tokenize_identifier(&mut self, chars: &mut PeekableChars) {
         let mut value = String::new();
+        let offset = chars.peek().map_or(0, |&(offset, _)| offset);
 
-        while let Some(&c) = chars.peek() {
+        while let Some(&(_, c)) = chars.peek() {
             match c {
-                'a'..='z' | 'A'..='Z' => {
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '-' => {
                     value.push(c);
                     chars.next();
                 }
@@ -188,47 +299,84 @@ impl Parser {
         self.tokens.push(Token {
             kind: TokenKind::Identifier,
             value,
+            offset,
         });
     }
 
-    fn tokenize_left_paren(&mut self, chars: &mut PeekableChars) {
-        chars.next();
-
-        self.tokens.push(Token {
-            kind: TokenKind::LeftParen,
-            value: String::from("("),
-        });
+    fn tokenize_char(&mut self, chars: &mut PeekableChars, kind: TokenKind) {
+        if let Some((offset, c)) = chars.next() {
+            self.tokens.push(Token {
+                kind,
+                value: c.to_string(),
+                offset,
+            });
+        }
     }
+}
 
-    fn tokenize_right_paren(&mut self, chars: &mut PeekableChars) {
-        chars.next();
-
-        self.tokens.push(Token {
-            kind: TokenKind::RightParen,
-            value: String::from(")"),
-        });
+/// The error for a token that does not match the grammar.
+fn unexpected(token: &Token, expected: &str) -> Error {
+    let found = match token.kind {
+        TokenKind::Eof => "end of input".to_string(),
+        _ => format!("'{}'", token.value),
+    };
+    Error::ParseError {
+        offset: token.offset,
+        expected: expected.to_string(),
+        found,
     }
+}
 
-    fn tokenize_comma(&mut self, chars: &mut PeekableChars) {
-        chars.next();
-
-        self.tokens.push(Token {
-            kind: TokenKind::Comma,
-            value: String::from(","),
-        });
+/// Resolve the values of a CSS `color()` function to one of the supported color spaces.
+fn resolve_predefined_space(space: &str, values: &mut Vec<f64>) -> Result<ColorSpace, Error> {
+    if values.len() != 3 {
+        return Err(Error::ColorParserError(
+            "Invalid number of values".to_string(),
+        ));
     }
+    let color_space = match space {
+        "srgb" => {
+            *values = values.iter().map(|v| v * 255.0).collect();
+            ColorSpace::RGB
+        }
+        "srgb-linear" => {
+            *values = gam_srgb(&[values[0], values[1], values[2]])
+                .map(|v| v * 255.0)
+                .to_vec();
+            ColorSpace::RGB
+        }
+        "xyz" | "xyz-d65" => ColorSpace::XYZ,
+        "xyz-d50" => {
+            *values = d50_to_d65(&[values[0], values[1], values[2]]).to_vec();
+            ColorSpace::XYZ
+        }
+        "rec2020" => ColorSpace::Rec2020,
+        _ => {
+            return Err(Error::ColorParserError(format!(
+                "Unsupported color space in color(): {}",
+                space
+            )))
+        }
+    };
+    Ok(color_space)
+}
 
-    fn tokenize_whitespace(&mut self, chars: &mut PeekableChars) {
-        chars.next();
-
-        self.tokens.push(Token {
-            kind: TokenKind::Whitespace,
-            value: String::from(" "),
-        });
+/// Parse a numeric token, resolving percentages to fractions and angle units to degrees.
+fn parse_value(token: &str) -> Option<f64> {
+    if let Some(value) = token.strip_suffix('%') {
+        return value.parse::<f64>().ok().map(|v| v / 100.0);
     }
-
-    fn tokenize_operator(&self, chars: &mut PeekableChars) {
-        chars.next();
+    let unit_start = token
+        .find(|c: char| c.is_ascii_alphabetic() || c == '°')
+        .unwrap_or(token.len());
+    let (value, unit) = token.split_at(unit_start);
+    let value = value.parse::<f64>().ok()?;
+    match unit {
+        "" | "deg" | "°" => Some(value),
+        "rad" => Some(value.to_degrees()),
+        "grad" => Some(value * 0.9),
+        "turn" => Some(value * 360.0),
+        _ => None,
     }
 }
 
@@ -257,4 +405,126 @@ mod tests {
 
         assert!(parser.validate().is_ok());
     }
+
+    #[test]
+    fn test_parse_space_separated() {
+        let mut parser = Parser::new();
+        parser.tokenize("rgb(255 0 0 / 0.5)");
+
+        assert!(parser.validate().is_ok());
+        assert_eq!(parser.color_space, ColorSpace::RGB);
+        assert_eq!(parser.values, vec![255.0, 0.0, 0.0]);
+        assert_eq!(parser.alpha, Some(0.5));
+
+        let mut parser = Parser::new();
+        parser.tokenize("hsla(120deg 50% 50% / 40%)");
+
+        assert!(parser.validate().is_ok());
+        assert_eq!(parser.values, vec![120.0, 0.5, 0.5, 0.4]);
+        assert_eq!(parser.alpha, None);
+
+        for input in [
+            "rgb(255 0 0 /)",
+            "rgb(255 0 0 / 0.5 / 0.5)",
+            "rgb(/ 255 0 0)",
+        ] {
+            let mut parser = Parser::new();
+            parser.tokenize(input);
+            assert!(parser.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_color_function() {
+        let mut parser = Parser::new();
+        parser.tokenize("color(srgb 1 0.5 0)");
+
+        assert!(parser.validate().is_ok());
+        assert_eq!(parser.color_space, ColorSpace::RGB);
+        assert_eq!(parser.values, vec![255.0, 127.5, 0.0]);
+
+        let mut parser = Parser::new();
+        parser.tokenize("color(xyz-d65 0.4124 0.2126 0.0193 / 50%)");
+
+        assert!(parser.validate().is_ok());
+        assert_eq!(parser.color_space, ColorSpace::XYZ);
+        assert_eq!(parser.alpha, Some(0.5));
+
+        for input in ["color(display-p3 1 0 0)", "color(1 0 0)", "color(srgb 1 0)"] {
+            let mut parser = Parser::new();
+            parser.tokenize(input);
+            assert!(parser.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_error_offset() {
+        let cases = [
+            ("rgb255, 0, 0)", 0, "a color space name", "'rgb255'"),
+            ("rgb 255, 0, 0)", 4, "'('", "'255'"),
+            ("rgb(255, 0, 0", 13, "',' or ')'", "end of input"),
+            ("rgb(255; 0, 0)", 7, "a value, ',', '/' or ')'", "';'"),
+            ("rgb(255, 0 0)", 11, "',' or ')'", "'0'"),
+            ("rgb(255, 0, 0,)", 14, "a value", "')'"),
+            ("rgb(255, 0, 0) x", 15, "end of input", "'x'"),
+            ("rgb(1.2.3, 0, 0)", 4, "a number", "'1.2.3'"),
+        ];
+        for (input, offset, expected, found) in cases {
+            let mut parser = Parser::new();
+            parser.tokenize(input);
+            assert_eq!(
+                parser.validate(),
+                Err(Error::ParseError {
+                    offset,
+                    expected: expected.to_string(),
+                    found: found.to_string(),
+                }),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_mode() {
+        let mut parser = Parser::new();
+        parser.mode = ParseMode::Strict;
+        parser.tokenize("rgb(255, 0, 0 / 0.5)");
+        assert_eq!(
+            parser.validate(),
+            Err(Error::ParseError {
+                offset: 14,
+                expected: "',' or ')'".to_string(),
+                found: "'/'".to_string(),
+            })
+        );
+
+        let mut parser = Parser::new();
+        parser.mode = ParseMode::Strict;
+        parser.tokenize("oklch(0.5, 0.1, 30)");
+        assert_eq!(
+            parser.validate(),
+            Err(Error::ParseError {
+                offset: 9,
+                expected: "a value, '/' or ')'".to_string(),
+                found: "','".to_string(),
+            })
+        );
+
+        let mut parser = Parser::new();
+        parser.mode = ParseMode::Lenient;
+        parser.tokenize("hsla(400, 120%, -10%, 2)");
+        assert!(parser.validate().is_ok());
+        assert_eq!(parser.values, vec![40.0, 1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(parse_value("50%"), Some(0.5));
+        assert_eq!(parse_value("120deg"), Some(120.0));
+        assert_eq!(parse_value("120°"), Some(120.0));
+        assert_eq!(parse_value("0.5turn"), Some(180.0));
+        assert_eq!(parse_value("100grad"), Some(90.0));
+        assert_eq!(parse_value("120px"), None);
+    }
 }
@@ -0,0 +1,351 @@
+use crate::{Color, Error, Gradient, HueInterpolation, InterpolationSpace};
+use alloc::{format, string::ToString, vec, vec::Vec};
+use core::str::FromStr;
+
+/// Parse a CSS [`linear-gradient()`](https://www.w3.org/TR/css-images-4/#linear-gradients)
+/// into its angle in degrees and its color stops.
+///
+/// Stop positions must be percentages, and are mapped to `0.0..=1.0`.
+/// Color hints and lengths (e.g. `10px`) are not supported.
+pub(crate) fn parse_linear_gradient(input: &str) -> Result<(f64, Gradient), Error> {
+    let input = input.trim();
+    let args = input
+        .strip_prefix("linear-gradient(")
+        .ok_or_else(|| Error::ColorParserError(format!("Not a linear gradient: {}", input)))?
+        .strip_suffix(')')
+        .ok_or_else(|| Error::ColorParserError("Unmatched left parenthesis".to_string()))?;
+
+    let mut args = split_top_level(args, ',')?;
+    let mut angle = 180.0;
+    let mut space = InterpolationSpace::Oklab;
+    let mut hue = HueInterpolation::Shorter;
+    if let Some(first) = args.first() {
+        if let Some((a, s, h)) = parse_direction(first)? {
+            angle = a;
+            space = s;
+            hue = h;
+            args.remove(0);
+        }
+    }
+
+    let stops = args
+        .iter()
+        .map(|arg| parse_color_stop(arg))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    if stops.len() < 2 {
+        return Err(Error::ColorParserError(
+            "A gradient needs at least two color stops".to_string(),
+        ));
+    }
+
+    let gradient = Gradient::new(fix_positions(stops))?.space(space).hue(hue);
+    Ok((angle, gradient))
+}
+
+/// Parse the optional first argument: an angle or a side, and the interpolation method.
+fn parse_direction(
+    arg: &str,
+) -> Result<Option<(f64, InterpolationSpace, HueInterpolation)>, Error> {
+    let words = arg.split_whitespace().collect::<Vec<_>>();
+    let mut angle = None;
+    let mut method = None;
+    let mut i = 0;
+    while i < words.len() {
+        match words[i] {
+            "to" if angle.is_none() => {
+                let side_end = words[i + 1..]
+                    .iter()
+                    .position(|&w| w == "in")
+                    .map_or(words.len(), |len| i + 1 + len);
+                angle = Some(side_angle(&words[i + 1..side_end])?);
+                i = side_end;
+            }
+            "in" if method.is_none() => {
+                let method_end = words[i + 1..]
+                    .iter()
+                    .position(|&w| w == "to" || parse_angle(w).is_some())
+                    .map_or(words.len(), |len| i + 1 + len);
+                method = Some(interpolation_method(&words[i + 1..method_end])?);
+                i = method_end;
+            }
+            word if angle.is_none() => match parse_angle(word) {
+                Some(a) => {
+                    angle = Some(a);
+                    i += 1;
+                }
+                // the first argument is a color stop
+                None if i == 0 => return Ok(None),
+                None => return Err(invalid_direction(arg)),
+            },
+            _ => return Err(invalid_direction(arg)),
+        }
+    }
+    let (space, hue) = method.unwrap_or((InterpolationSpace::Oklab, HueInterpolation::Shorter));
+    Ok(Some((angle.unwrap_or(180.0), space, hue)))
+}
+
+fn invalid_direction(arg: &str) -> Error {
+    Error::ColorParserError(format!("Invalid gradient direction `{}`", arg))
+}
+
+fn parse_angle(word: &str) -> Option<f64> {
+    let (value, factor) = if let Some(value) = word.strip_suffix("deg") {
+        (value, 1.0)
+    } else if let Some(value) = word.strip_suffix("grad") {
+        (value, 0.9)
+    } else if let Some(value) = word.strip_suffix("rad") {
+        (value, 180.0 / core::f64::consts::PI)
+    } else if let Some(value) = word.strip_suffix("turn") {
+        (value, 360.0)
+    } else if word == "0" {
+        (word, 0.0)
+    } else {
+        return None;
+    };
+    value.parse::<f64>().ok().map(|value| value * factor)
+}
+
+/// Angle of `to <side-or-corner>`, corners are taken at 45 degrees as for a square box.
+fn side_angle(sides: &[&str]) -> Result<f64, Error> {
+    let mut sides = sides.to_vec();
+    sides.sort_unstable();
+    match sides.as_slice() {
+        ["top"] => Ok(0.0),
+        ["right", "top"] => Ok(45.0),
+        ["right"] => Ok(90.0),
+        ["bottom", "right"] => Ok(135.0),
+        ["bottom"] => Ok(180.0),
+        ["bottom", "left"] => Ok(225.0),
+        ["left"] => Ok(270.0),
+        ["left", "top"] => Ok(315.0),
+        _ => Err(Error::ColorParserError(format!(
+            "Invalid gradient side `to {}`",
+            sides.join(" ")
+        ))),
+    }
+}
+
+fn interpolation_method(words: &[&str]) -> Result<(InterpolationSpace, HueInterpolation), Error> {
+    let invalid = || {
+        Error::ColorParserError(format!(
+            "Invalid color interpolation method `in {}`",
+            words.join(" ")
+        ))
+    };
+    let space = match words.first() {
+        Some(&"srgb") => InterpolationSpace::Srgb,
+        Some(&"srgb-linear") => InterpolationSpace::LinearRgb,
+        Some(&"hsl") => InterpolationSpace::Hsl,
+        Some(&"lab") => InterpolationSpace::Lab,
+        Some(&"lch") => InterpolationSpace::Lch,
+        Some(&"oklab") => InterpolationSpace::Oklab,
+        Some(&"oklch") => InterpolationSpace::Oklch,
+        _ => return Err(invalid()),
+    };
+    let hue = match words[1..] {
+        [] => HueInterpolation::Shorter,
+        [method, "hue"] if space.hue_index().is_some() => match method {
+            "shorter" => HueInterpolation::Shorter,
+            "longer" => HueInterpolation::Longer,
+            "increasing" => HueInterpolation::Increasing,
+            "decreasing" => HueInterpolation::Decreasing,
+            _ => return Err(invalid()),
+        },
+        _ => return Err(invalid()),
+    };
+    Ok((space, hue))
+}
+
+/// Parse a color stop, a color with up to two positions.
+fn parse_color_stop(arg: &str) -> Result<Vec<(Option<f64>, Color)>, Error> {
+    let tokens = split_top_level(arg, ' ')?;
+    let (color, positions) = match tokens.split_first() {
+        Some((color, positions)) if positions.len() <= 2 => (color, positions),
+        _ => {
+            return Err(Error::ColorParserError(format!(
+                "Invalid color stop `{}`",
+                arg
+            )))
+        }
+    };
+    if color.ends_with('%') {
+        return Err(Error::ColorParserError(
+            "Color hints are not supported".to_string(),
+        ));
+    }
+    let color = Color::from_str(color)?;
+    if positions.is_empty() {
+        return Ok(vec![(None, color)]);
+    }
+    positions
+        .iter()
+        .map(|position| {
+            let value = position
+                .strip_suffix('%')
+                .and_then(|value| value.parse::<f64>().ok())
+                .ok_or_else(|| {
+                    Error::ColorParserError(format!(
+                        "Color stop positions must be percentages, got `{}`",
+                        position
+                    ))
+                })?;
+            Ok((Some(value / 100.0), color))
+        })
+        .collect()
+}
+
+/// Resolve the missing positions of color stops as in CSS: the first and last stops default to
+/// the ends, positions never go backwards, and the others are spread evenly in between.
+fn fix_positions(stops: Vec<(Option<f64>, Color)>) -> Vec<(f64, Color)> {
+    let mut positions = stops.iter().map(|(p, _)| *p).collect::<Vec<_>>();
+    let last = positions.len() - 1;
+    positions[0] = positions[0].or(Some(0.0));
+    positions[last] = positions[last].or(Some(1.0));
+
+    let mut max = f64::MIN;
+    for position in positions.iter_mut().flatten() {
+        max = max.max(*position);
+        *position = max;
+    }
+
+    let mut start = 0;
+    for i in 1..=last {
+        if let Some(end) = positions[i] {
+            let from = positions[start].unwrap_or_default();
+            for (j, position) in positions.iter_mut().enumerate().take(i).skip(start + 1) {
+                *position = Some(from + (end - from) * (j - start) as f64 / (i - start) as f64);
+            }
+            start = i;
+        }
+    }
+
+    positions
+        .into_iter()
+        .zip(stops)
+        .map(|(position, (_, color))| (position.unwrap_or_default(), color))
+        .collect()
+}
+
+/// Split on a separator outside of parentheses, skipping empty parts around spaces.
+fn split_top_level(input: &str, separator: char) -> Result<Vec<&str>, Error> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(input[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(Error::ColorParserError(
+            "Unmatched left parenthesis".to_string(),
+        ));
+    }
+    parts.push(input[start..].trim());
+    if separator == ' ' {
+        parts.retain(|part| !part.is_empty());
+    }
+    Ok(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "css-names")]
+    fn positions(gradient: &Gradient) -> Vec<f64> {
+        gradient.stops().iter().map(|(p, _)| *p).collect()
+    }
+
+    #[test]
+    #[cfg(feature = "css-names")]
+    fn test_parse_linear_gradient() {
+        let (angle, gradient) =
+            parse_linear_gradient("linear-gradient(90deg, #f00 0%, rgba(0,0,255,.5) 100%)")
+                .unwrap();
+        assert_eq!(angle, 90.0);
+        assert_eq!(
+            gradient.stops(),
+            &[
+                (0.0, Color::new(255, 0, 0, 1.0)),
+                (1.0, Color::new(0, 0, 255, 0.5))
+            ]
+        );
+
+        let (angle, gradient) = parse_linear_gradient("linear-gradient(red, blue)").unwrap();
+        assert_eq!(angle, 180.0);
+        assert_eq!(positions(&gradient), vec![0.0, 1.0]);
+
+        let (angle, _) = parse_linear_gradient("linear-gradient(to top left, red, blue)").unwrap();
+        assert_eq!(angle, 315.0);
+        let (angle, _) = parse_linear_gradient("linear-gradient(0.25turn, red, blue)").unwrap();
+        assert_eq!(angle, 90.0);
+        let (angle, _) = parse_linear_gradient("linear-gradient(0, red, blue)").unwrap();
+        assert_eq!(angle, 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "css-names")]
+    fn test_parse_linear_gradient_positions() {
+        let (_, gradient) =
+            parse_linear_gradient("linear-gradient(red, lime, blue 80%, white)").unwrap();
+        assert_eq!(positions(&gradient), vec![0.0, 0.4, 0.8, 1.0]);
+
+        // positions never go backwards
+        let (_, gradient) = parse_linear_gradient("linear-gradient(red 50%, blue 20%)").unwrap();
+        assert_eq!(positions(&gradient), vec![0.5, 0.5]);
+
+        // two positions make two stops
+        let (_, gradient) =
+            parse_linear_gradient("linear-gradient(red 0% 50%, blue 50% 100%)").unwrap();
+        assert_eq!(positions(&gradient), vec![0.0, 0.5, 0.5, 1.0]);
+        assert_eq!(gradient.at(0.25).hex(), "#f00");
+    }
+
+    #[test]
+    #[cfg(feature = "css-names")]
+    fn test_parse_linear_gradient_interpolation() {
+        let (angle, gradient) =
+            parse_linear_gradient("linear-gradient(in hsl longer hue, red, blue)").unwrap();
+        assert_eq!(angle, 180.0);
+        assert_eq!(gradient.at(0.5).hex(), "#0f0");
+
+        let (angle, gradient) =
+            parse_linear_gradient("linear-gradient(to right in srgb, red, blue)").unwrap();
+        assert_eq!(angle, 90.0);
+        assert_eq!(gradient.at(0.5).hex(), "#800080");
+
+        let (angle, _) =
+            parse_linear_gradient("linear-gradient(in oklch 45deg, red, blue)").unwrap();
+        assert_eq!(angle, 45.0);
+    }
+
+    #[test]
+    fn test_parse_linear_gradient_err() {
+        for input in [
+            "radial-gradient(red, blue)",
+            "linear-gradient(red, blue",
+            "linear-gradient(red)",
+            "linear-gradient(90deg)",
+            "linear-gradient(to middle, red, blue)",
+            "linear-gradient(in srgb longer hue, red, blue)",
+            "linear-gradient(in cmyk, red, blue)",
+            "linear-gradient(90deg 45deg, red, blue)",
+            "linear-gradient(red 10px, blue)",
+            "linear-gradient(red, 50%, blue)",
+            "linear-gradient(red 0% 10% 20%, blue)",
+            "linear-gradient(red, notacolor)",
+        ] {
+            assert!(parse_linear_gradient(input).is_err(), "{}", input);
+        }
+    }
+}
@@ -1,4 +1,8 @@
 use crate::Error;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 
 pub fn parse_hex_str(s: impl ToString) -> Result<String, crate::Error> {
     let s = s.to_string();
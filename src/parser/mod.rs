@@ -1,4 +1,7 @@
 pub(crate) mod core;
+pub(crate) mod gradient;
 pub(crate) mod hex;
+pub(crate) mod relative;
+pub(crate) mod scan;
 
-pub(crate) use self::core::Parser;
+pub(crate) use self::core::{ParseMode, Parser};
@@ -0,0 +1,466 @@
+use super::ParseMode;
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{color::from_str::parse_nested_color, ColorSpace, Error, NameRegistry};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// how deeply relative colors and channel expressions may be nested
+const MAX_DEPTH: usize = 32;
+
+/// Check if the input is a [CSS relative color](https://www.w3.org/TR/css-color-5/#relative-colors),
+/// e.g. `rgb(from #ff0000 r g b / 0.5)`
+pub(crate) fn is_relative(input: &str) -> bool {
+    match input.split_once('(') {
+        Some((_, args)) => args.trim_start().starts_with("from "),
+        None => false,
+    }
+}
+
+/// Parse a CSS relative color into the values of its color space, and its alpha.
+///
+/// The channel keywords of the color space (e.g. `r`, `g`, `b` or `h`, `s`, `l`) and `alpha`
+/// resolve to the channels of the origin color, and can be used in `calc()` expressions.
+/// The origin color and the resulting channels follow the same [`ParseMode`] as absolute colors.
+///
+/// `depth` is how deeply the color is nested in other relative colors, shared with the nesting
+/// of its `calc()` expressions, so that deeply nested inputs are an error instead of a stack
+/// overflow. Custom names of the origin come from `names`, or the installed registry if `None`.
+pub(crate) fn parse_relative(
+    input: &str,
+    mode: ParseMode,
+    depth: usize,
+    names: Option<&NameRegistry>,
+) -> Result<(ColorSpace, Vec<f64>, f64), Error> {
+    if depth >= MAX_DEPTH {
+        return Err(too_deep());
+    }
+    let (name, args) = input
+        .split_once('(')
+        .ok_or_else(|| Error::ColorParserError("Invalid relative color".to_string()))?;
+    let args = args
+        .trim_end()
+        .strip_suffix(')')
+        .ok_or_else(|| Error::ColorParserError("Unmatched left parenthesis".to_string()))?;
+    let args = args.trim_start().trim_start_matches("from").trim_start();
+
+    let color_space = match ColorSpace::from(name.trim()) {
+        ColorSpace::RGBA => ColorSpace::RGB,
+        ColorSpace::HSLA => ColorSpace::HSL,
+        color_space => color_space,
+    };
+    let channels = channel_names(&color_space).ok_or_else(|| {
+        Error::ColorParserError(format!(
+            "Relative color syntax is not supported for `{}`",
+            name.trim()
+        ))
+    })?;
+    if mode == ParseMode::Strict && !color_space.is_css_function() {
+        return Err(Error::ColorParserError(format!(
+            "Expected a CSS color function, got `{}`",
+            name.trim()
+        )));
+    }
+
+    let (origin, rest) = split_origin(args)?;
+    if origin.starts_with("var(") {
+        return Err(Error::ColorParserError(format!(
+            "CSS variables can not be resolved: {}",
+            origin
+        )));
+    }
+    let origin = parse_nested_color(origin, mode, depth + 1, names)?;
+    let origin_values = origin.vec_of(color_space);
+
+    let (components, alpha) = split_components(rest)?;
+    if components.len() != 3 {
+        return Err(Error::ColorParserError(
+            "Invalid number of values".to_string(),
+        ));
+    }
+
+    let keyword = |name: &str| match channels.iter().position(|&c| c == name) {
+        Some(index) => Some(origin_values[index]),
+        None if name == "alpha" => Some(origin.alpha),
+        None => None,
+    };
+
+    let mut values = components
+        .iter()
+        .enumerate()
+        .map(|(index, component)| {
+            let mut evaluator = Evaluator {
+                chars: component.chars().collect(),
+                pos: 0,
+                depth,
+                percent_reference: color_space.percent_reference(index),
+                keyword: &keyword,
+            };
+            evaluator.evaluate()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // hue angles wrap around instead of being out of range
+    for (index, channel) in channels.iter().enumerate() {
+        if *channel == "h" {
+            values[index] = values[index].rem_euclid(360.0);
+        }
+    }
+    if mode == ParseMode::Lenient {
+        values = color_space.clamp(&values);
+    }
+    color_space.valid(&values)?;
+
+    let alpha = match alpha {
+        Some(alpha) => {
+            let mut evaluator = Evaluator {
+                chars: alpha.chars().collect(),
+                pos: 0,
+                depth,
+                percent_reference: 1.0,
+                keyword: &keyword,
+            };
+            evaluator.evaluate()?
+        }
+        None => origin.alpha,
+    };
+    let alpha = match mode {
+        ParseMode::Lenient => alpha.clamp(0.0, 1.0),
+        _ => alpha,
+    };
+    if !(0.0..=1.0).contains(&alpha) {
+        return Err(Error::ColorParserError(format!(
+            "Alpha must be between 0.0 and 1.0, got {}",
+            alpha
+        )));
+    }
+
+    Ok((color_space, values, alpha))
+}
+
+fn too_deep() -> Error {
+    Error::ColorParserError(format!(
+        "Relative color is nested more than {} levels deep",
+        MAX_DEPTH
+    ))
+}
+
+fn channel_names(color_space: &ColorSpace) -> Option<[&'static str; 3]> {
+    match color_space {
+        ColorSpace::RGB => Some(["r", "g", "b"]),
+        ColorSpace::HSL => Some(["h", "s", "l"]),
+        ColorSpace::HSV => Some(["h", "s", "v"]),
+        ColorSpace::HWB => Some(["h", "w", "b"]),
+        ColorSpace::Lab | ColorSpace::OKLab => Some(["l", "a", "b"]),
+        ColorSpace::LCH | ColorSpace::OKLCH => Some(["l", "c", "h"]),
+        _ => None,
+    }
+}
+
+/// Split the origin color from the channel expressions that follow it.
+fn split_origin(args: &str) -> Result<(&str, &str), Error> {
+    let mut depth = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok((&args[..=i], &args[i + 1..]));
+                }
+            }
+            ' ' | '\t' | '\n' if depth == 0 => return Ok((&args[..i], &args[i..])),
+            _ => {}
+        }
+    }
+    Err(Error::ColorParserError(
+        "Missing channels in relative color".to_string(),
+    ))
+}
+
+/// Split the channel expressions on whitespace and `/` outside of parentheses.
+fn split_components(args: &str) -> Result<(Vec<String>, Option<String>), Error> {
+    let mut components = vec![];
+    let mut alpha = None;
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut after_slash = false;
+
+    let mut push = |current: &mut String, after_slash: bool| -> Result<(), Error> {
+        if current.is_empty() {
+            return Ok(());
+        }
+        let value = core::mem::take(current);
+        if !after_slash {
+            components.push(value);
+        } else if alpha.is_none() {
+            alpha = Some(value);
+        } else {
+            return Err(Error::ColorParserError(
+                "Only one alpha value is allowed after '/'".to_string(),
+            ));
+        }
+        Ok(())
+    };
+
+    for c in args.chars() {
+        match c {
+            '(' => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' => {
+                depth -= 1;
+                current.push(c);
+            }
+            ' ' | '\t' | '\n' if depth == 0 => push(&mut current, after_slash)?,
+            '/' if depth == 0 => {
+                push(&mut current, after_slash)?;
+                if after_slash {
+                    return Err(Error::ColorParserError("Unexpected '/'".to_string()));
+                }
+                after_slash = true;
+            }
+            _ => current.push(c),
+        }
+    }
+    push(&mut current, after_slash)?;
+
+    if depth != 0 {
+        return Err(Error::ColorParserError(
+            "Unmatched left parenthesis".to_string(),
+        ));
+    }
+    if after_slash && alpha.is_none() {
+        return Err(Error::ColorParserError(
+            "Missing alpha value after '/'".to_string(),
+        ));
+    }
+    Ok((components, alpha))
+}
+
+/// Evaluates a channel expression: a number, a channel keyword or a `calc()` expression.
+struct Evaluator<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    depth: usize,
+    percent_reference: f64,
+    keyword: &'a dyn Fn(&str) -> Option<f64>,
+}
+
+impl Evaluator<'_> {
+    fn evaluate(&mut self) -> Result<f64, Error> {
+        let value = self.expression()?;
+        self.skip_whitespace();
+        if self.pos < self.chars.len() {
+            return Err(self.error());
+        }
+        Ok(value)
+    }
+
+    fn expression(&mut self) -> Result<f64, Error> {
+        let mut value = self.term()?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('+') => {
+                    self.pos += 1;
+                    value += self.term()?;
+                }
+                Some('-') => {
+                    self.pos += 1;
+                    value -= self.term()?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<f64, Error> {
+        let mut value = self.factor()?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('*') => {
+                    self.pos += 1;
+                    value *= self.factor()?;
+                }
+                Some('/') => {
+                    self.pos += 1;
+                    value /= self.factor()?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Result<f64, Error> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(too_deep());
+        }
+        let value = self.unary();
+        self.depth -= 1;
+        value
+    }
+
+    fn unary(&mut self) -> Result<f64, Error> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('-') => {
+                self.pos += 1;
+                Ok(-self.factor()?)
+            }
+            Some('(') => {
+                self.pos += 1;
+                let value = self.expression()?;
+                self.expect(')')?;
+                Ok(value)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => self.number(),
+            Some(c) if c.is_ascii_alphabetic() => {
+                let name = self.identifier();
+                if name == "calc" {
+                    self.expect('(')?;
+                    let value = self.expression()?;
+                    self.expect(')')?;
+                    Ok(value)
+                } else if name == "var" {
+                    Err(Error::ColorParserError(
+                        "CSS variables can not be resolved".to_string(),
+                    ))
+                } else {
+                    (self.keyword)(&name).ok_or_else(|| {
+                        Error::ColorParserError(format!("Unknown channel keyword `{}`", name))
+                    })
+                }
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, Error> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit() || c == '.') {
+            self.pos += 1;
+        }
+        let value = self.chars[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .parse::<f64>()
+            .map_err(|_| self.error())?;
+        if self.peek() == Some('%') {
+            self.pos += 1;
+            return Ok(value / 100.0 * self.percent_reference);
+        }
+        match self.identifier().as_str() {
+            "" | "deg" => Ok(value),
+            "rad" => Ok(value.to_degrees()),
+            "grad" => Ok(value * 0.9),
+            "turn" => Ok(value * 360.0),
+            _ => Err(self.error()),
+        }
+    }
+
+    fn identifier(&mut self) -> String {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphabetic()) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), Error> {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn error(&self) -> Error {
+        Error::ColorParserError(format!(
+            "Invalid channel expression `{}`",
+            self.chars.iter().collect::<String>()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_relative() {
+        assert!(is_relative("rgb(from #ff0000 r g b)"));
+        assert!(is_relative("hsl( from red h s l)"));
+        assert!(!is_relative("rgb(255, 0, 0)"));
+        assert!(!is_relative("#ff0000"));
+    }
+
+    #[test]
+    #[cfg(feature = "css-names")]
+    fn test_parse_relative() {
+        let (color_space, values, alpha) =
+            parse_relative("rgb(from #ff0000 r g b / 0.5)", ParseMode::Default, 0, None).unwrap();
+        assert_eq!(color_space, ColorSpace::RGB);
+        assert_eq!(values, vec![255.0, 0.0, 0.0]);
+        assert_eq!(alpha, 0.5);
+
+        let (color_space, values, alpha) = parse_relative(
+            "hsl(from rgb(255, 0, 0) calc(h + 120) s calc(l - 10%))",
+            ParseMode::Default,
+            0,
+            None,
+        )
+        .unwrap();
+        assert_eq!(color_space, ColorSpace::HSL);
+        assert_eq!(values, vec![120.0, 1.0, 0.4]);
+        assert_eq!(alpha, 1.0);
+
+        let (_, values, alpha) = parse_relative(
+            "rgb(from red b g r / calc(alpha / 2))",
+            ParseMode::Default,
+            0,
+            None,
+        )
+        .unwrap();
+        assert_eq!(values, vec![0.0, 0.0, 255.0]);
+        assert_eq!(alpha, 0.5);
+    }
+
+    #[test]
+    fn test_parse_relative_err() {
+        for input in [
+            "hsl(from var(--brand) h s calc(l + 10%))",
+            "hsl(from red h s calc(l + var(--step)))",
+            "rgb(from red r g)",
+            "rgb(from red r g x)",
+            "rgb(from red r g b / 0.5 / 0.5)",
+            "rgb(from red calc(r + 1) g b)",
+            "cmyk(from red c m y k)",
+        ] {
+            assert!(
+                parse_relative(input, ParseMode::Default, 0, None).is_err(),
+                "{}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{data::hex_of_css_name, ColorSpace};
+
+/// Find the color tokens in arbitrary text: hex colors, color functions and CSS color names.
+///
+/// Color functions are returned up to their matching right parenthesis, so they can still be
+/// malformed. Hex colors and names are only returned when they are a whole word.
+pub(crate) fn scan_colors(text: &str) -> impl Iterator<Item = &str> + '_ {
+    let bytes = text.as_bytes();
+    let mut pos = 0;
+
+    core::iter::from_fn(move || {
+        while pos < bytes.len() {
+            let start = pos;
+            if bytes[pos] == b'#' {
+                pos += 1;
+                let end = word_end(bytes, pos);
+                let digits = &text[pos..end];
+                pos = end;
+                if matches!(digits.len(), 3 | 4 | 6 | 8)
+                    && digits.bytes().all(|b| b.is_ascii_hexdigit())
+                    && is_boundary(bytes, start)
+                {
+                    return Some(&text[start..end]);
+                }
+            } else if is_word_byte(bytes[pos]) {
+                let end = word_end(bytes, pos);
+                let word = text[start..end].to_ascii_lowercase();
+                pos = end;
+                if !is_boundary(bytes, start) {
+                    continue;
+                }
+                if bytes.get(end) == Some(&b'(') && is_color_function(&word) {
+                    if let Some(close) = matching_paren(bytes, end) {
+                        pos = close + 1;
+                        return Some(&text[start..pos]);
+                    }
+                } else if word == "transparent" || hex_of_css_name(&word).is_some() {
+                    return Some(&text[start..end]);
+                }
+            } else {
+                // skip the whole char, `text` is not necessarily ascii
+                pos += text[pos..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        None
+    })
+}
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'-' || b == b'_'
+}
+
+fn word_end(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|&b| !is_word_byte(b))
+        .map_or(bytes.len(), |len| start + len)
+}
+
+/// Whether a token starting at `start` is not glued to a preceding word.
+fn is_boundary(bytes: &[u8], start: usize) -> bool {
+    start == 0 || !is_word_byte(bytes[start - 1])
+}
+
+fn is_color_function(name: &str) -> bool {
+    match ColorSpace::from(name) {
+        ColorSpace::HEX | ColorSpace::HEXA => false,
+        ColorSpace::Unknown => name == "color",
+        _ => true,
+    }
+}
+
+fn matching_paren(bytes: &[u8], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, &b) in bytes.iter().enumerate().skip(open) {
+        match b {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(all(test, feature = "css-names"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_colors() {
+        let css = r#"
+            .button { color: #FFF; background-color: rgb(255 0 0 / 50%); }
+            .button:hover { border: 1px solid Red; fill: hsl(120, 100%, calc(25%)); }
+            #header, #add { white-space: nowrap; transform: rotate(45deg) }
+        "#;
+        let tokens = scan_colors(css).collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            vec![
+                "#FFF",
+                "rgb(255 0 0 / 50%)",
+                "Red",
+                "hsl(120, 100%, calc(25%))",
+                "#add"
+            ]
+        );
+
+        let svg = r##"<rect fill="transparent" stroke="color(srgb 1 0 0)" id="tired-red2"/>"##;
+        let tokens = scan_colors(svg).collect::<Vec<_>>();
+        assert_eq!(tokens, vec!["transparent", "color(srgb 1 0 0)"]);
+
+        let text = "色彩 #ff0000ff and rgb(255, 0";
+        let tokens = scan_colors(text).collect::<Vec<_>>();
+        assert_eq!(tokens, vec!["#ff0000ff"]);
+    }
+}
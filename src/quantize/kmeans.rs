@@ -0,0 +1,232 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{
+    conversion::lab::{lab2rgb, rgb2lab},
+    utils::rng::SplitMix64,
+    Color,
+};
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A group of similar pixels found by [`KMeans`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cluster {
+    /// The mean color of the pixels of the cluster.
+    pub color: Color,
+    /// The number of pixels in the cluster.
+    pub population: usize,
+}
+
+/// [K-means clustering](https://en.wikipedia.org/wiki/K-means_clustering) of pixels in Lab.
+///
+/// The centers are initialized with k-means++ from a seed, so the result is always the same
+/// for the same pixels. Slower than the other quantizers, but it finds the most representative
+/// colors.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KMeans {
+    k: usize,
+    max_iterations: usize,
+    tolerance: f64,
+    seed: u64,
+}
+
+impl KMeans {
+    /// Creates a new [`KMeans`] finding `k` clusters.
+    pub fn new(k: usize) -> Self {
+        KMeans {
+            k,
+            max_iterations: 50,
+            tolerance: 0.1,
+            seed: 0,
+        }
+    }
+    /// Sets the maximum number of iterations, 50 by default.
+    pub fn max_iterations(mut self, n: usize) -> Self {
+        self.max_iterations = n;
+        self
+    }
+    /// Stops once no cluster center moves more than `tolerance` in Lab in an iteration,
+    /// 0.1 by default.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+    /// Sets the seed of the random initial centers, 0 by default.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+    /// Clusters the pixels, the clusters are sorted by population, largest first.
+    ///
+    /// There are less than `k` clusters if the pixels have less than `k` different colors.
+    /// The alpha of a cluster color is the mean alpha of its pixels.
+    pub fn run(&self, pixels: &[Color]) -> Vec<Cluster> {
+        let labs = pixels
+            .iter()
+            .map(|pixel| rgb2lab(&pixel.rgb))
+            .collect::<Vec<_>>();
+        let mut centers = self.initial_centers(&labs);
+        if centers.is_empty() {
+            return vec![];
+        }
+        let mut assignments = vec![0; labs.len()];
+
+        for _ in 0..self.max_iterations {
+            for (assignment, lab) in assignments.iter_mut().zip(&labs) {
+                *assignment = nearest(&centers, lab);
+            }
+            let mut sums = vec![[0.0; 3]; centers.len()];
+            let mut counts = vec![0; centers.len()];
+            for (&assignment, lab) in assignments.iter().zip(&labs) {
+                for (sum, v) in sums[assignment].iter_mut().zip(lab) {
+                    *sum += v;
+                }
+                counts[assignment] += 1;
+            }
+            let mut shift: f64 = 0.0;
+            for ((center, sum), &count) in centers.iter_mut().zip(&sums).zip(&counts) {
+                // an empty cluster keeps its center
+                if count > 0 {
+                    let mean = sum.map(|v| v / count as f64);
+                    shift = shift.max(distance_squared(center, &mean).sqrt());
+                    *center = mean;
+                }
+            }
+            if shift <= self.tolerance {
+                break;
+            }
+        }
+        for (assignment, lab) in assignments.iter_mut().zip(&labs) {
+            *assignment = nearest(&centers, lab);
+        }
+
+        let mut alphas = vec![0.0; centers.len()];
+        let mut counts = vec![0; centers.len()];
+        for (&assignment, pixel) in assignments.iter().zip(pixels) {
+            alphas[assignment] += pixel.alpha;
+            counts[assignment] += 1;
+        }
+        let mut clusters = centers
+            .iter()
+            .zip(alphas)
+            .zip(counts)
+            .filter(|(_, count)| *count > 0)
+            .map(|((center, alpha), count)| {
+                let rgb = lab2rgb(center);
+                let [r, g, b] = [0, 1, 2].map(|i| rgb[i].clamp(0.0, 255.0));
+                Cluster {
+                    color: Color::new(r, g, b, alpha / count as f64),
+                    population: count,
+                }
+            })
+            .collect::<Vec<_>>();
+        clusters.sort_by_key(|cluster| core::cmp::Reverse(cluster.population));
+        clusters
+    }
+
+    /// Picks the initial centers with k-means++, each one with a probability proportional to
+    /// the squared distance to the nearest center picked before.
+    fn initial_centers(&self, labs: &[[f64; 3]]) -> Vec<[f64; 3]> {
+        if labs.is_empty() || self.k == 0 {
+            return vec![];
+        }
+        let mut rng = SplitMix64::new(self.seed);
+        let first = (rng.next_u64() % labs.len() as u64) as usize;
+        let mut centers = vec![labs[first]];
+        let mut distances = labs
+            .iter()
+            .map(|lab| distance_squared(lab, &labs[first]))
+            .collect::<Vec<_>>();
+        while centers.len() < self.k {
+            let total = distances.iter().sum::<f64>();
+            // all pixels are on a center already
+            if total == 0.0 {
+                break;
+            }
+            let mut target = rng.next_f64() * total;
+            let index = distances
+                .iter()
+                .position(|&d| {
+                    target -= d;
+                    target < 0.0
+                })
+                .unwrap_or_else(|| distances.iter().rposition(|&d| d > 0.0).unwrap());
+            let center = labs[index];
+            for (distance, lab) in distances.iter_mut().zip(labs) {
+                *distance = distance.min(distance_squared(lab, &center));
+            }
+            centers.push(center);
+        }
+        centers
+    }
+}
+
+fn nearest(centers: &[[f64; 3]], lab: &[f64; 3]) -> usize {
+    (0..centers.len())
+        .min_by(|&i, &j| {
+            distance_squared(&centers[i], lab).total_cmp(&distance_squared(&centers[j], lab))
+        })
+        .unwrap()
+}
+
+fn distance_squared(lab1: &[f64; 3], lab2: &[f64; 3]) -> f64 {
+    lab1.iter().zip(lab2).map(|(a, b)| (a - b).powi(2)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{quantize::*, *};
+
+    #[test]
+    fn test_kmeans() {
+        assert!(KMeans::new(3).run(&[]).is_empty());
+        assert!(KMeans::new(0).run(&[color!(#f00)]).is_empty());
+
+        let mut pixels = vec![];
+        for i in 0..10 {
+            pixels.push(Color::new(250 - i, 0, 0, 1.0));
+            pixels.push(Color::new(0, 0, 250 - i, 0.5));
+        }
+        for i in 0..5 {
+            pixels.push(Color::new(0, 240 + i, 0, 1.0));
+        }
+        let clusters = KMeans::new(3).seed(1).run(&pixels);
+        assert_eq!(
+            clusters.iter().map(|c| c.population).collect::<Vec<_>>(),
+            vec![10, 10, 5]
+        );
+        let green = clusters[2];
+        assert!(green.color.delta_e_2000(&Color::new(0, 242, 0, 1.0)) < 1.0);
+        let alphas = [clusters[0].color.alpha(), clusters[1].color.alpha()];
+        assert!(alphas.contains(&0.5) && alphas.contains(&1.0));
+
+        // the same seed gives the same clusters
+        assert_eq!(clusters, KMeans::new(3).seed(1).run(&pixels));
+        // less distinct colors than clusters
+        let clusters = KMeans::new(4).run(&[color!(#f00), color!(#f00), color!(#fff)]);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].color.hex(), "#f00");
+        assert_eq!(clusters[0].population, 2);
+    }
+
+    #[test]
+    fn test_kmeans_iterations() {
+        let pixels = (0..=255)
+            .map(|v| Color::new(v, v, v, 1.0))
+            .collect::<Vec<_>>();
+        let clusters = KMeans::new(4)
+            .max_iterations(100)
+            .tolerance(0.0)
+            .run(&pixels);
+        assert_eq!(clusters.len(), 4);
+        assert_eq!(clusters.iter().map(|c| c.population).sum::<usize>(), 256);
+
+        // without iterations the clusters are around the initial centers
+        let clusters = KMeans::new(4).max_iterations(0).run(&pixels);
+        assert_eq!(clusters.iter().map(|c| c.population).sum::<usize>(), 256);
+    }
+}
@@ -0,0 +1,134 @@
+use super::{mean_color, Quantized};
+use crate::{Color, Error, Palette};
+use alloc::{string::ToString, vec, vec::Vec};
+
+/// Quantizes pixels with the [median cut](https://en.wikipedia.org/wiki/Median_cut) algorithm.
+///
+/// The RGB box of the pixels is split in two at the median of its widest channel, then the
+/// widest of the boxes, until there are `max_colors` boxes or every box holds a single color.
+/// The palette colors are the mean colors of the boxes, in the order the boxes were split.
+///
+/// # Errors
+///
+/// If `max_colors` is 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{color, quantize};
+///
+/// let pixels = [color!(#f00), color!(#00f), color!(#fe0000), color!(#00f)];
+/// let quantized = quantize::median_cut(&pixels, 2).unwrap();
+/// assert_eq!(quantized.palette.len(), 2);
+/// assert_eq!(quantized.indices, vec![0, 1, 0, 1]);
+/// ```
+pub fn median_cut(pixels: &[Color], max_colors: usize) -> Result<Quantized, Error> {
+    if max_colors == 0 {
+        return Err(Error::InvalidParamsError(
+            "a palette needs at least one color".to_string(),
+        ));
+    }
+    if pixels.is_empty() {
+        return Ok(Quantized::default());
+    }
+    let mut boxes = vec![(0..pixels.len()).collect::<Vec<_>>()];
+    while boxes.len() < max_colors {
+        let widest = boxes
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (i, widest_channel(pixels, b)))
+            .filter(|(_, (_, range))| *range > 0.0)
+            .max_by(|(_, (_, a)), (_, (_, b))| a.total_cmp(b));
+        let Some((i, (channel, _))) = widest else {
+            break;
+        };
+        let mut indices = boxes.swap_remove(i);
+        indices.sort_by(|&a, &b| pixels[a].rgb[channel].total_cmp(&pixels[b].rgb[channel]));
+        // split at the median, keeping pixels of the same value in the same box
+        let median = pixels[indices[indices.len() / 2]].rgb[channel];
+        let mut split = indices.partition_point(|&p| pixels[p].rgb[channel] < median);
+        if split == 0 {
+            split = indices.partition_point(|&p| pixels[p].rgb[channel] <= median);
+        }
+        let upper = indices.split_off(split);
+        boxes.push(indices);
+        boxes.push(upper);
+    }
+
+    let mut indices = vec![0; pixels.len()];
+    for (i, b) in boxes.iter().enumerate() {
+        for &p in b {
+            indices[p] = i;
+        }
+    }
+    let palette = boxes
+        .iter()
+        .map(|b| mean_color(b.iter().map(|&p| &pixels[p])))
+        .collect::<Palette>();
+    Ok(Quantized { palette, indices })
+}
+
+/// The channel with the largest range in a box, and its range.
+fn widest_channel(pixels: &[Color], indices: &[usize]) -> (usize, f64) {
+    (0..3)
+        .map(|channel| {
+            let (min, max) =
+                indices
+                    .iter()
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &p| {
+                        let v = pixels[p].rgb[channel];
+                        (min.min(v), max.max(v))
+                    });
+            (channel, max - min)
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{quantize::*, *};
+
+    #[test]
+    fn test_median_cut() {
+        assert!(median_cut(&[color!(#f00)], 0).is_err());
+        assert_eq!(median_cut(&[], 4).unwrap(), Quantized::default());
+
+        let pixels = [
+            color!(#f00),
+            color!(#0f0),
+            color!(#00f),
+            color!(#f00),
+            color!(#0f0),
+            color!(#00f),
+        ];
+        // as many colors as there are in the image
+        let quantized = median_cut(&pixels, 16).unwrap();
+        assert_eq!(quantized.palette.len(), 3);
+        assert_eq!(quantized.pixels(), pixels);
+
+        let quantized = median_cut(&pixels, 1).unwrap();
+        assert_eq!(quantized.palette[0].hex(), "#555");
+        assert_eq!(quantized.indices, vec![0; 6]);
+    }
+
+    #[test]
+    fn test_median_cut_gradient() {
+        let pixels = (0..=255)
+            .map(|v| Color::new(v, 0, 0, 0.5))
+            .collect::<Vec<_>>();
+        let quantized = median_cut(&pixels, 4).unwrap();
+        let mut reds = quantized
+            .palette
+            .iter()
+            .map(|c| c.red())
+            .collect::<Vec<_>>();
+        reds.sort();
+        assert_eq!(reds, vec![32, 96, 160, 224]);
+        assert!(quantized.palette.iter().all(|c| c.alpha() == 0.5));
+        // each pixel is mapped to the nearest palette color
+        for (pixel, &i) in pixels.iter().zip(&quantized.indices) {
+            assert!((pixel.red() as i32 - quantized.palette[i].red() as i32).abs() <= 32);
+        }
+    }
+}
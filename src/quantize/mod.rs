@@ -0,0 +1,70 @@
+//! Color quantization, reducing the colors of an image to a small palette.
+//!
+//! The pixels are given as a slice of colors, e.g. decoded with the `image` crate.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use color_art::{color, quantize::KMeans};
+//!
+//! let pixels = [color!(#f00), color!(#fe0000), color!(#00f), color!(#0000fe), color!(#f00)];
+//! let clusters = KMeans::new(2).run(&pixels);
+//! assert_eq!(clusters[0].population, 3);
+//! assert_eq!(clusters[1].population, 2);
+//! ```
+
+mod kmeans;
+mod median_cut;
+mod octree;
+
+pub use kmeans::{Cluster, KMeans};
+pub use median_cut::median_cut;
+pub use octree::octree;
+
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+use crate::{Color, Palette};
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An indexed image, a palette and the index of the palette color of each pixel.
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Quantized {
+    /// The colors of the image.
+    pub palette: Palette,
+    /// The index in the palette of each pixel.
+    pub indices: Vec<usize>,
+}
+
+impl Quantized {
+    /// The pixels with their palette colors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, quantize};
+    ///
+    /// let quantized = quantize::median_cut(&[color!(#f00), color!(#fe0000)], 1).unwrap();
+    /// assert_eq!(quantized.pixels(), vec![color!(#ff0000); 2]);
+    /// ```
+    pub fn pixels(&self) -> Vec<Color> {
+        self.indices.iter().map(|&i| self.palette[i]).collect()
+    }
+}
+
+/// The mean color of pixels, with the channels rounded.
+fn mean_color<'a>(pixels: impl Iterator<Item = &'a Color>) -> Color {
+    let mut sum = [0.0; 4];
+    let mut count = 0;
+    for pixel in pixels {
+        for (s, v) in sum.iter_mut().zip(pixel.rgb.iter().chain([&pixel.alpha])) {
+            *s += v;
+        }
+        count += 1;
+    }
+    let [r, g, b, alpha] = sum.map(|v| v / count as f64);
+    Color::new(r.round(), g.round(), b.round(), alpha)
+}
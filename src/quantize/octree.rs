@@ -0,0 +1,180 @@
+use super::{mean_color, Quantized};
+use crate::{Color, Error, Palette};
+use alloc::{string::ToString, vec, vec::Vec};
+
+/// Depth of the tree, one level per bit of the channels.
+const DEPTH: usize = 8;
+
+#[derive(Clone, Default)]
+struct Node {
+    children: [Option<usize>; 8],
+    /// The pixels of a leaf.
+    pixels: Vec<usize>,
+    leaf: bool,
+    index: usize,
+}
+
+/// Quantizes pixels with an [octree](https://en.wikipedia.org/wiki/Octree).
+///
+/// Each pixel is a leaf of a tree splitting the RGB cube in eight at each level. While there
+/// are more than `max_colors` leaves, the deepest node with the fewest pixels is merged into a
+/// leaf. Faster than [`median_cut`](super::median_cut), the palette colors are the mean colors
+/// of the leaves, ordered by RGB.
+///
+/// # Errors
+///
+/// If `max_colors` is 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use color_art::{color, quantize};
+///
+/// let pixels = [color!(#f00), color!(#00f), color!(#fe0000), color!(#00f)];
+/// let quantized = quantize::octree(&pixels, 2).unwrap();
+/// assert_eq!(quantized.palette.len(), 2);
+/// assert_eq!(quantized.indices, vec![1, 0, 1, 0]);
+/// ```
+pub fn octree(pixels: &[Color], max_colors: usize) -> Result<Quantized, Error> {
+    if max_colors == 0 {
+        return Err(Error::InvalidParamsError(
+            "a palette needs at least one color".to_string(),
+        ));
+    }
+    if pixels.is_empty() {
+        return Ok(Quantized::default());
+    }
+    let mut nodes = vec![Node::default()];
+    // the inner nodes at each level, the candidates for merging
+    let mut levels = vec![vec![]; DEPTH];
+    levels[0].push(0);
+    let mut leaves = 0;
+
+    for (p, pixel) in pixels.iter().enumerate() {
+        let rgb = rgb_bytes(pixel);
+        let mut node = 0;
+        for level in 0..DEPTH {
+            let child = child_index(&rgb, level);
+            node = match nodes[node].children[child] {
+                Some(next) => next,
+                None => {
+                    let next = nodes.len();
+                    let leaf = level + 1 == DEPTH;
+                    nodes.push(Node {
+                        leaf,
+                        ..Node::default()
+                    });
+                    if leaf {
+                        leaves += 1;
+                    } else {
+                        levels[level + 1].push(next);
+                    }
+                    nodes[node].children[child] = Some(next);
+                    next
+                }
+            };
+        }
+        nodes[node].pixels.push(p);
+    }
+
+    while leaves > max_colors {
+        // all inner nodes below the deepest level are merged already, so its nodes only have leaves
+        let level = levels.iter().rposition(|l| !l.is_empty()).unwrap();
+        let population = |node: usize| {
+            nodes[node]
+                .children
+                .iter()
+                .flatten()
+                .map(|&child| nodes[child].pixels.len())
+                .sum::<usize>()
+        };
+        let smallest = (0..levels[level].len())
+            .min_by_key(|&i| population(levels[level][i]))
+            .unwrap();
+        let node = levels[level].swap_remove(smallest);
+        let children = core::mem::take(&mut nodes[node].children);
+        let mut merged = vec![];
+        for child in children.into_iter().flatten() {
+            merged.append(&mut nodes[child].pixels);
+            leaves -= 1;
+        }
+        merged.sort_unstable();
+        nodes[node].pixels = merged;
+        nodes[node].leaf = true;
+        leaves += 1;
+    }
+
+    let mut palette = Palette::default();
+    let mut indices = vec![0; pixels.len()];
+    let mut stack = vec![0];
+    while let Some(node) = stack.pop() {
+        if nodes[node].leaf {
+            nodes[node].index = palette.len();
+            palette.push(mean_color(nodes[node].pixels.iter().map(|&p| &pixels[p])));
+            for &p in &nodes[node].pixels {
+                indices[p] = nodes[node].index;
+            }
+        } else {
+            stack.extend(nodes[node].children.iter().rev().flatten());
+        }
+    }
+    Ok(Quantized { palette, indices })
+}
+
+fn rgb_bytes(color: &Color) -> [u8; 3] {
+    [color.red(), color.green(), color.blue()]
+}
+
+/// The child of a node at a level, from the bit of each channel at that level.
+fn child_index(rgb: &[u8; 3], level: usize) -> usize {
+    let shift = 7 - level;
+    rgb.iter()
+        .fold(0, |index, v| (index << 1) | ((v >> shift) & 1) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{quantize::*, *};
+
+    #[test]
+    fn test_octree() {
+        assert!(octree(&[color!(#f00)], 0).is_err());
+        assert_eq!(octree(&[], 4).unwrap(), Quantized::default());
+
+        let pixels = [
+            color!(#f00),
+            color!(#0f0),
+            color!(#00f),
+            color!(#f00),
+            color!(rgba(0, 255, 0, 0.5)),
+            color!(#00f),
+        ];
+        // as many colors as there are in the image
+        let quantized = octree(&pixels, 16).unwrap();
+        assert_eq!(
+            quantized
+                .palette
+                .iter()
+                .map(|c| c.hex())
+                .collect::<Vec<_>>(),
+            vec!["#00f", "#00ff00bf", "#f00"]
+        );
+        assert_eq!(quantized.indices, vec![2, 1, 0, 2, 1, 0]);
+
+        let quantized = octree(&pixels, 1).unwrap();
+        assert_eq!(quantized.palette.len(), 1);
+        assert_eq!(quantized.indices, vec![0; 6]);
+    }
+
+    #[test]
+    fn test_octree_gradient() {
+        let pixels = (0..=255)
+            .map(|v| Color::new(v, v, v, 1.0))
+            .collect::<Vec<_>>();
+        let quantized = octree(&pixels, 8).unwrap();
+        assert!(quantized.palette.len() <= 8);
+        for (pixel, &i) in pixels.iter().zip(&quantized.indices) {
+            assert!((pixel.red() as i32 - quantized.palette[i].red() as i32).abs() <= 32);
+        }
+    }
+}
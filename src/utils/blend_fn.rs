@@ -1,3 +1,6 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+
 pub(crate) fn min(a: f64, b: f64) -> f64 {
     a.min(b)
 }
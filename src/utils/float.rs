@@ -0,0 +1,70 @@
+/// The `f64` methods of `std` which are not in `core`, implemented with `libm`.
+///
+/// Only imported without the `std` feature, the inherent methods are used otherwise.
+#[cfg_attr(test, allow(dead_code))]
+pub(crate) trait Float {
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn floor(self) -> Self;
+    fn round(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+impl Float for f64 {
+    fn powi(self, n: i32) -> Self {
+        libm::pow(self, n as f64)
+    }
+    fn powf(self, n: Self) -> Self {
+        libm::pow(self, n)
+    }
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+    fn cbrt(self) -> Self {
+        libm::cbrt(self)
+    }
+    fn exp(self) -> Self {
+        libm::exp(self)
+    }
+    fn ln(self) -> Self {
+        libm::log(self)
+    }
+    fn sin(self) -> Self {
+        libm::sin(self)
+    }
+    fn cos(self) -> Self {
+        libm::cos(self)
+    }
+    fn acos(self) -> Self {
+        libm::acos(self)
+    }
+    fn atan2(self, other: Self) -> Self {
+        libm::atan2(self, other)
+    }
+    fn hypot(self, other: Self) -> Self {
+        libm::hypot(self, other)
+    }
+    fn floor(self) -> Self {
+        libm::floor(self)
+    }
+    fn round(self) -> Self {
+        libm::round(self)
+    }
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let r = self % rhs;
+        if r < 0.0 {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+}
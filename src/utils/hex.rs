@@ -1,3 +1,5 @@
+use alloc::{format, string::String};
+
 /// Simplifies the hex code to a short hex code if possible.
 pub(crate) fn simplify_hex(hex: String) -> String {
     let hex_len = hex.len();
@@ -33,6 +35,7 @@ pub(crate) fn simplify_hex(hex: String) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
 
     #[test]
     fn test_simplify_hex() {
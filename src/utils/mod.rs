@@ -1,8 +1,11 @@
+#[cfg(not(feature = "std"))]
+use crate::utils::float::Float;
+
 pub mod blend_fn;
+#[cfg(not(feature = "std"))]
+pub(crate) mod float;
 pub mod hex;
-pub mod math;
-
-pub use math::*;
+pub(crate) mod rng;
 
 pub(crate) fn round(val: f64, precision: u32) -> f64 {
     let factor = (10.0_f64).powi(precision as i32);
@@ -15,6 +18,6 @@ pub(crate) fn round(val: f64, precision: u32) -> f64 {
 }
 
 /// normalize color values 0..255 to 0..1
-pub(crate) fn normalize_color(color: &[f64]) -> Vec<f64> {
-    color.iter().map(|&c| c / 255.0).collect()
+pub(crate) fn normalize_color(color: &[f64; 3]) -> [f64; 3] {
+    color.map(|c| c / 255.0)
 }
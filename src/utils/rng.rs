@@ -0,0 +1,37 @@
+/// A small seeded pseudo random number generator,
+/// [SplitMix64](https://prng.di.unimi.it/splitmix64.c), for reproducible randomness
+/// without the `random` feature.
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        SplitMix64(seed)
+    }
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+    /// A number in `0.0..1.0`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splitmix64() {
+        // the first outputs of the reference implementation seeded with 0
+        let mut rng = SplitMix64::new(0);
+        assert_eq!(rng.next_u64(), 0xe220a8397b1dcdaf);
+        assert_eq!(rng.next_u64(), 0x6e789e6aa1b965f4);
+
+        let mut rng = SplitMix64::new(42);
+        assert!((0..1000).all(|_| (0.0..1.0).contains(&rng.next_f64())));
+    }
+}
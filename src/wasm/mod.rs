@@ -0,0 +1,183 @@
+//! JavaScript bindings with [`wasm_bindgen`], with the `wasm` feature.
+//!
+//! [`Color`] is exported as a JS class. Colors are created from and formatted to CSS strings,
+//! palettes are returned as arrays of hex strings. Errors are thrown as JS `Error`s with the
+//! message of the [`Error`](crate::Error). `wasm-pack` needs a `cdylib` crate enabling the
+//! feature, e.g. a crate with `pub use color_art::*;`.
+//!
+//! ```js
+//! import { Color, randomColors } from "color-art";
+//!
+//! const color = Color.parse("hsl(330, 100%, 60%)");
+//! color.lighten(0.1).hex(); // "#ff66b3"
+//! color.triadic(); // ["#f39", "#9f3", "#39f"]
+//! randomColors(5, 42);
+//! ```
+
+use crate::{delta_e, Color, Palette, RandomColor};
+use alloc::{string::String, vec::Vec};
+use core::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+impl Color {
+    /// `new Color(r, g, b, alpha)`, with `r`, `g`, `b` in 0 - 255 and `alpha` in 0 - 1.
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(r: f64, g: f64, b: f64, alpha: f64) -> Result<Color, JsError> {
+        Ok(Color::from_rgba(r, g, b, alpha)?)
+    }
+    /// `Color.parse(css)`, any string accepted by [`Color::from_str`].
+    #[wasm_bindgen(js_name = parse)]
+    pub fn js_parse(css: &str) -> Result<Color, JsError> {
+        Ok(Color::from_str(css)?)
+    }
+    /// `Color.fromName(name)`, see [`Color::from_name`].
+    #[wasm_bindgen(js_name = fromName)]
+    pub fn js_from_name(name: &str) -> Result<Color, JsError> {
+        Ok(Color::from_name(name)?)
+    }
+
+    #[wasm_bindgen(getter, js_name = red)]
+    pub fn js_red(&self) -> u8 {
+        self.red()
+    }
+    #[wasm_bindgen(getter, js_name = green)]
+    pub fn js_green(&self) -> u8 {
+        self.green()
+    }
+    #[wasm_bindgen(getter, js_name = blue)]
+    pub fn js_blue(&self) -> u8 {
+        self.blue()
+    }
+    #[wasm_bindgen(getter, js_name = alpha)]
+    pub fn js_alpha(&self) -> f64 {
+        self.alpha()
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn js_to_string(&self) -> String {
+        self.hex()
+    }
+    #[wasm_bindgen(js_name = hex)]
+    pub fn js_hex(&self) -> String {
+        self.hex()
+    }
+    #[wasm_bindgen(js_name = rgba)]
+    pub fn js_rgba(&self) -> String {
+        self.rgba()
+    }
+    #[wasm_bindgen(js_name = hsla)]
+    pub fn js_hsla(&self) -> String {
+        self.hsla()
+    }
+    #[wasm_bindgen(js_name = lab)]
+    pub fn js_lab(&self) -> String {
+        self.lab()
+    }
+    #[wasm_bindgen(js_name = oklch)]
+    pub fn js_oklch(&self) -> String {
+        self.oklch()
+    }
+    /// The color name, or the hex string if it has none, see [`Color::name`].
+    #[wasm_bindgen(js_name = name)]
+    pub fn js_name(&self) -> String {
+        self.name()
+    }
+
+    #[wasm_bindgen(js_name = lighten)]
+    pub fn js_lighten(&self, amount: f64) -> Color {
+        self.lighten(amount)
+    }
+    #[wasm_bindgen(js_name = darken)]
+    pub fn js_darken(&self, amount: f64) -> Color {
+        self.darken(amount)
+    }
+    #[wasm_bindgen(js_name = saturate)]
+    pub fn js_saturate(&self, amount: f64) -> Color {
+        self.saturate(amount)
+    }
+    #[wasm_bindgen(js_name = desaturate)]
+    pub fn js_desaturate(&self, amount: f64) -> Color {
+        self.desaturate(amount)
+    }
+    #[wasm_bindgen(js_name = spin)]
+    pub fn js_spin(&self, angle: f64) -> Color {
+        self.spin(angle)
+    }
+    #[wasm_bindgen(js_name = withAlpha)]
+    pub fn js_with_alpha(&self, alpha: f64) -> Color {
+        self.with_alpha(alpha)
+    }
+    /// `color.mix(other, weight)`, with the weight of `other`, see [`Color::mix_with`].
+    #[wasm_bindgen(js_name = mix)]
+    pub fn js_mix(&self, other: &Color, weight: f64) -> Color {
+        self.mix_with(other, weight)
+    }
+    #[wasm_bindgen(js_name = deltaE)]
+    pub fn js_delta_e(&self, other: &Color) -> f64 {
+        delta_e(self, other)
+    }
+    #[wasm_bindgen(js_name = textColor)]
+    pub fn js_text_color(&self) -> Color {
+        self.text_color()
+    }
+
+    #[wasm_bindgen(js_name = monochromatic)]
+    pub fn js_monochromatic(&self, n: usize) -> Vec<String> {
+        hexes(self.monochromatic(n))
+    }
+    #[wasm_bindgen(js_name = analogous)]
+    pub fn js_analogous(&self, n: usize, angle: f64) -> Vec<String> {
+        hexes(self.analogous(n, angle))
+    }
+    #[wasm_bindgen(js_name = triadic)]
+    pub fn js_triadic(&self) -> Vec<String> {
+        hexes(self.triadic())
+    }
+    #[wasm_bindgen(js_name = tetradic)]
+    pub fn js_tetradic(&self) -> Vec<String> {
+        hexes(self.tetradic())
+    }
+    #[wasm_bindgen(js_name = splitComplementary)]
+    pub fn js_split_complementary(&self) -> Vec<String> {
+        hexes(self.split_complementary())
+    }
+}
+
+/// `randomColors(count, seed?)`, attractive random colors of [`RandomColor`] as hex strings.
+///
+/// Without a seed, the seed comes from `Math.random()`, so every call gives different colors.
+#[wasm_bindgen(js_name = randomColors)]
+pub fn js_random_colors(count: usize, seed: Option<u32>) -> Vec<String> {
+    let seed = match seed {
+        Some(seed) => seed as u64,
+        None => (js_sys::Math::random() * u64::MAX as f64) as u64,
+    };
+    hexes(RandomColor::new().count(count).seed(seed).generate())
+}
+
+fn hexes(palette: Palette) -> Vec<String> {
+    palette.iter().map(|color| color.hex()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color;
+
+    #[test]
+    fn test_wasm_bindings() {
+        let color = color!(#f39);
+        assert!(
+            matches!(Color::js_parse("hsl(330, 100%, 60%)"), Ok(parsed) if parsed.hex() == "#f39")
+        );
+        assert_eq!(color.js_to_string(), "#f39");
+        assert_eq!(color.js_red(), 255);
+        assert_eq!(color.js_triadic(), hexes(color.triadic()));
+        assert_eq!(
+            js_random_colors(3, Some(7)),
+            js_random_colors(3, Some(7)),
+            "seeded palettes are reproducible"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use color_art::Color;
+use color_art::{Color, HexOrder};
 
 #[test]
 fn test_color_from_space() {
@@ -18,14 +18,45 @@ fn test_color_from_space() {
     let color = Color::from_hsv(60.0, 1.0, 1.0).unwrap();
     assert_eq!(color.hex(), "#ff0");
 
+    // HWB
+    let color = Color::from_hwb(60.0, 0.0, 0.0).unwrap();
+    assert_eq!(color.hex(), "#ff0");
+
     // CMYK
     let color = Color::from_cmyk(0.0, 0.0, 1.0, 0.0).unwrap();
     assert_eq!(color.hex(), "#ff0");
 
+    // XYZ
+    let color = Color::from_xyz(0.769975, 0.927808, 0.138526).unwrap();
+    assert_eq!(color.hex(), "#ff0");
+
+    // Lab
+    let color = Color::from_lab(97.61, -15.75, 93.39).unwrap();
+    assert_eq!(color.hex(), "#ff0");
+
+    // LCH
+    let color = Color::from_lch(97.61, 94.71, 99.57).unwrap();
+    assert_eq!(color.hex(), "#ff0");
+
+    // OKLab
+    let color = Color::from_oklab(0.968, -0.0714, 0.1986).unwrap();
+    assert_eq!(color.hex(), "#ff0");
+
+    // OKLCH
+    let color = Color::from_oklch(0.968, 0.211, 109.77).unwrap();
+    assert_eq!(color.hex(), "#ff0");
+
+    // Rec. 2020
+    let color = Color::from_rec2020(0.9783, 0.9944, 0.2984).unwrap();
+    assert_eq!(color.hex(), "#ff0");
+
     // HEX
     let color = Color::from_hex("#ffff00").unwrap();
     assert_eq!(color.hex(), "#ff0");
 
+    let color = Color::from_hex_with_order("#ffffff00", HexOrder::Argb).unwrap();
+    assert_eq!(color.hex(), "#ff0");
+
     // Name
     let color = Color::from_name("yellow").unwrap();
     assert_eq!(color.hex(), "#ff0");
@@ -1,4 +1,4 @@
-use color_art::color;
+use color_art::{color, Illuminant};
 
 #[test]
 fn test_color_stringify() {
@@ -19,4 +19,14 @@ fn test_color_stringify() {
     assert_eq!(color.yiq(), "yiq(0.886, 0.32126, -0.31114)");
     assert_eq!(color.yuv(), "yuv(0.886, -0.4359, 0.1)");
     assert_eq!(color.lab(), "lab(97.61, -15.75, 93.39)");
+    assert_eq!(
+        color.lab_with_illuminant(Illuminant::D65),
+        "lab(97.14, -21.56, 94.48)"
+    );
+    assert_eq!(color.lch(), "lch(97.61, 94.71, 99.57)");
+    assert_eq!(color.luv(), "luv(97.14, 7.7, 106.81)");
+    assert_eq!(color.lchuv(), "lchuv(97.14, 107.09, 85.87)");
+    assert_eq!(color.oklab(), "oklab(0.968, -0.0714, 0.1986)");
+    assert_eq!(color.oklch(), "oklch(0.968, 0.211, 109.77)");
+    assert_eq!(color.rec2020(), "rec2020(0.9783, 0.9944, 0.2984)");
 }
@@ -1,5 +1,5 @@
-#![cfg(feature = "serde")]
-// Disable this entire file if "serde" is disabled
+#![cfg(all(feature = "serde", feature = "random"))]
+// Disable this entire file if "serde" or "random" is disabled
 
 use color_art::Color;
 use thiserror::Error;